@@ -29,12 +29,19 @@ async fn run(args: &[String]) -> Result<(), String> {
         println!("    block+txids HASH_OR_HEIGHT");
         println!("    block+txs HASH_OR_HEIGHT");
         println!("    block HASH_OR_HEIGHT");
+        println!("    blockstats HASH_OR_HEIGHT");
+        println!("    pools FROM TO");
         println!("    txids ADDRESS");
         println!("    txs ADDRESS");
         println!("    utxos ADDRESS");
+        println!("    utxosetinfo");
+        println!("    supply HEIGHT");
         println!("    richlistcount");
         println!("    rank ADDRESS");
         println!("    richlist OFFSET LIMIT");
+        println!("    richlistat HEIGHT OFFSET LIMIT");
+        println!("    richlistdist HEIGHT");
+        println!("    rankhistory ADDRESS");
         return Ok(());
     }
     let coin = &args[1];
@@ -49,12 +56,19 @@ async fn run(args: &[String]) -> Result<(), String> {
         "block+txids"   => execute(&args, 1, || client.block_with_txids(&args[i])).await,
         "block+txs"     => execute(&args, 1, || client.block_with_txs(&args[i])).await,
         "block"         => execute(&args, 1, || client.block_header(&args[i])).await,
+        "blockstats"    => execute(&args, 1, || client.block_stats(&args[i])).await,
+        "pools"         => execute(&args, 2, || client.pools(Some(args[i].parse().unwrap()), Some(args[i+1].parse().unwrap()))).await,
         "txids"         => execute(&args, 1, || client.txids(&args[i])).await,
         "txs"           => execute(&args, 1, || client.txs(&args[i])).await,
         "utxos"         => execute(&args, 1, || client.utxos(&args[i])).await,
+        "utxosetinfo"   => execute(&args, 0, || client.utxo_set_info(None)).await,
+        "supply"        => execute(&args, 1, || client.supply(Some(args[i].parse().unwrap()))).await,
         "richlistcount" => execute(&args, 0, || client.rich_list_count()).await,
         "rank"          => execute(&args, 1, || client.rich_list_addr_rank(&args[i])).await,
         "richlist"      => execute(&args, 2, || client.rich_list(args[i].parse().unwrap(), args[i+1].parse().unwrap())).await,
+        "richlistat"    => execute(&args, 3, || client.rich_list_at(args[i].parse().unwrap(), args[i+1].parse().unwrap(), args[i+2].parse().unwrap())).await,
+        "richlistdist"  => execute(&args, 1, || client.rich_list_distribution(args[i].parse().unwrap())).await,
+        "rankhistory"   => execute(&args, 1, || client.rich_list_addr_rank_history(&args[i], None, None)).await,
         _ => Err(format!("E: invalid command: {}", command)),
     }
 }
@@ -123,6 +137,14 @@ mod tests {
         assert!(test(&[COIN, "block", BLOCK_HASH]).await.is_ok());
     }
     #[tokio::test]
+    async fn blockstats() {
+        assert!(test(&[COIN, "blockstats", BLOCK_HASH]).await.is_ok());
+    }
+    #[tokio::test]
+    async fn pools() {
+        assert!(test(&[COIN, "pools", "1000", "1009"]).await.is_ok());
+    }
+    #[tokio::test]
     async fn txids() {
         assert!(test(&[COIN, "txids", ADDRESS]).await.is_ok());
     }
@@ -135,6 +157,14 @@ mod tests {
         assert!(test(&[COIN, "utxos", ADDRESS]).await.is_ok());
     }
     #[tokio::test]
+    async fn utxosetinfo() {
+        assert!(test(&[COIN, "utxosetinfo"]).await.is_ok());
+    }
+    #[tokio::test]
+    async fn supply() {
+        assert!(test(&[COIN, "supply", "100000"]).await.is_ok());
+    }
+    #[tokio::test]
     async fn richlistcount() {
         assert!(test(&[COIN, "richlistcount"]).await.is_ok());
     }
@@ -146,4 +176,16 @@ mod tests {
     async fn richlist() {
         assert!(test(&[COIN, "richlist", "0", "10"]).await.is_ok());
     }
+    #[tokio::test]
+    async fn richlistat() {
+        assert!(test(&[COIN, "richlistat", "700000", "0", "10"]).await.is_ok());
+    }
+    #[tokio::test]
+    async fn richlistdist() {
+        assert!(test(&[COIN, "richlistdist", "700000"]).await.is_ok());
+    }
+    #[tokio::test]
+    async fn rankhistory() {
+        assert!(test(&[COIN, "rankhistory", ADDRESS]).await.is_ok());
+    }
 }
@@ -32,6 +32,24 @@ pub struct Vin {
     pub sequence: u32,
     pub value: u64,
     pub address: Option<String>,
+    /// The kind of spend (e.g. "pubkeyhash", "p2sh-witnesspubkeyhash", "witness_v1_taproot_scriptpath").
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub input_type: Option<String>,
+    /// The redeem script of a P2SH input (in hex).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub redeem_script: Option<String>,
+    /// The witness script of a P2WSH input (in hex).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub witness_script: Option<String>,
+    /// The leaf script of a taproot script path spend (in hex).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tapleaf_script: Option<String>,
+    /// The control block of a taproot script path spend (in hex).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub control_block: Option<String>,
+    /// The sighash flags of the signatures (e.g. "ALL", "SINGLE|ANYONECANPAY").
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sighash_flags: Option<Vec<String>>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -40,6 +58,12 @@ pub struct ScriptPubKey {
     pub hex: String,
     pub r#type: String,
     pub address: Option<String>,
+    /// The number of required signatures (only for bare multisig scripts).
+    #[serde(rename = "reqSigs", default, skip_serializing_if = "Option::is_none")]
+    pub req_sigs: Option<u8>,
+    /// The public keys in hex (only for bare multisig scripts).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pubkeys: Option<Vec<String>>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -49,6 +73,9 @@ pub struct Utxo {
     pub vout: u32,
     pub script_pub_key: ScriptPubKey,
     pub value: u64,
+    pub confirmations: u32,
+    pub height: u32,
+    pub coinbase: bool,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -57,10 +84,39 @@ pub struct Vout {
     pub value: u64,
     pub n: usize,
     pub script_pub_key: ScriptPubKey,
+    /// The protocol which the OP_RETURN output is likely to belong to (e.g. "witness_commitment", "omni").
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub protocol: Option<String>,
+}
+
+/// An OP_RETURN output.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OpReturn {
+    pub txid: String,
+    pub vout: u32,
+    pub height: u32,
+    /// The data carried by the output (in hex).
+    pub data: String,
+    pub protocol: Option<String>,
 }
 
+/// A decoded Counterparty (or Monaparty) message.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
+pub struct Counterparty {
+    pub message_type_id: u32,
+    /// The name of the message type (e.g. "send", "issuance" or "unknown").
+    pub message_type: String,
+    /// The asset name (the asset given for orders, and the asset which receives dividends).
+    pub asset: Option<String>,
+    /// The quantity in the smallest unit (the amount per unit for dividends).
+    pub quantity: Option<u64>,
+    /// The message without the type id (in hex).
+    pub data: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct Transaction {
     pub confirmed_height: Option<u32>,
     pub hex: String,
@@ -73,8 +129,24 @@ pub struct Transaction {
     pub locktime: u32,
     pub vin: Vec<Vin>,
     pub vout: Vec<Vout>,
-    pub fee: i64,
-    //counterparty: ,
+    /// The fee in satoshis (null for coinbase transactions or if the previous outputs are unknown).
+    pub fee: Option<u64>,
+    /// The fee rate in satoshis per vbyte (null if `fee` is null).
+    pub fee_rate: Option<f64>,
+    /// True if the transaction signals replaceability (BIP125).
+    pub rbf: bool,
+    pub coinbase: bool,
+    /// The hash of the block which contains the transaction (null if unconfirmed).
+    pub block_hash: Option<String>,
+    /// The timestamp of the block which contains the transaction (null if unconfirmed).
+    pub block_time: Option<u32>,
+    /// The number of confirmations (zero if unconfirmed).
+    /// Omitted in `/block_with_txs` responses, which are cached (use the block height instead).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub confirmations: Option<u32>,
+    /// The Counterparty message carried by the transaction.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub counterparty: Option<Counterparty>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -98,6 +170,7 @@ pub struct BlockHeader {
     pub strippedsize: u32,
     pub weight: u32,
     pub ntxs: usize,
+    pub pool: Option<BlockPool>,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -116,6 +189,7 @@ pub struct BlockWithTxids {
     pub strippedsize: u32,
     pub weight: u32,
     pub txids: Vec<String>,
+    pub pool: Option<BlockPool>,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -134,6 +208,7 @@ pub struct BlockWithTxs {
     pub strippedsize: u32,
     pub weight: u32,
     pub txs: Vec<Transaction>,
+    pub pool: Option<BlockPool>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -153,6 +228,82 @@ pub struct RichListEntry {
     pub value: u64,
 }
 
+/// Filters rich list entries.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RichListFilter {
+    /// Only include scripts of the type (in the same notation as `ScriptPubKey.type`, e.g. "witnesspubkeyhash").
+    pub script_type : Option<String>,
+    /// Only include scripts which can be represented as an address.
+    pub address_only: bool,
+    /// Exclude unspendable scripts and well-known burn addresses.
+    pub exclude_burn: bool,
+}
+
+impl RichListFilter {
+    fn to_query(&self) -> String {
+        let mut query = Vec::new();
+        if let Some(script_type) = &self.script_type {
+            query.push(format!("type={}", script_type));
+        }
+        if self.address_only {
+            query.push("address_only=true".to_string());
+        }
+        if self.exclude_burn {
+            query.push("exclude_burn=true".to_string());
+        }
+        query.join("&")
+    }
+}
+
+/// The number of addresses (and the sum of their balances) whose balance is in `min..max` (in satoshis).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RichListBucket {
+    pub min  : u64,
+    pub max  : Option<u64>,
+    pub count: u32,
+    pub value: u64,
+}
+
+/// The balance distribution of a rich list snapshot.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RichListDistribution {
+    pub height     : u32,
+    pub count      : u32,
+    pub total_value: u64,
+    /// The number of top entries stored in the snapshot.
+    pub entries    : u32,
+    pub buckets    : Vec<RichListBucket>,
+}
+
+/// The rank of an address in a rich list snapshot (`None` if the address is not in the top entries).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RichListRankHistoryEntry {
+    pub height: u32,
+    pub rank  : Option<u32>,
+    pub value : Option<u64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BlockStats {
+    pub height             : u32,
+    pub hash               : String,
+    pub txs                : u32,
+    pub ins                : u32,
+    pub outs               : u32,
+    pub total_in           : u64,
+    pub total_out          : u64,
+    pub totalfee           : u64,
+    pub subsidy            : u64,
+    pub minfeerate         : u64,
+    pub maxfeerate         : u64,
+    /// Fee rates (sat/vB) at the 10th, 25th, 50th, 75th and 90th percentiles (weighted by tx weight).
+    pub feerate_percentiles: [u64; 5],
+    pub swtxs              : u32,
+    pub taproottxs         : u32,
+    pub utxo_increase      : i64,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct BlockSummary {
     pub hash        : String,
@@ -162,6 +313,68 @@ pub struct BlockSummary {
     pub strippedsize: u32,
     pub weight      : u32,
     pub txcount     : usize,
+    pub stats       : Option<BlockStats>,
+    pub pool        : Option<BlockPool>,
+}
+
+/// Statistics of the UTXO set (comparable to Bitcoin Core's `gettxoutsetinfo muhash`, amounts are in satoshis).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UtxoSetInfo {
+    pub height      : u32,
+    pub bestblock   : String,
+    pub txouts      : u64,
+    pub bogosize    : u64,
+    pub muhash      : String,
+    pub total_amount: u64,
+}
+
+/// Amounts which can never be spent (in satoshis).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SupplyUnspendables {
+    pub genesis_block    : u64,
+    pub bip30            : u64,
+    pub scripts          : u64,
+    pub unclaimed_rewards: u64,
+}
+
+/// Cumulative coin issuance up to `height` (in satoshis).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Supply {
+    pub height           : u32,
+    pub total_subsidy    : u64,
+    pub total_fees       : u64,
+    pub total_unspendable: u64,
+    pub unspendables     : SupplyUnspendables,
+    pub circulating      : u64,
+}
+
+/// The mining pool attribution of a block (`name` and `link` are `None` if the pool is unknown).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BlockPool {
+    pub name          : Option<String>,
+    pub link          : Option<String>,
+    pub coinbase_tag  : String,
+    pub payout_address: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PoolStats {
+    pub name    : String,
+    pub link    : Option<String>,
+    pub blocks  : u32,
+    pub share   : f64,
+    /// Estimated hashrate in hash/s.
+    pub hashrate: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Pools {
+    pub from    : u32,
+    pub to      : u32,
+    pub blocks  : u32,
+    /// Estimated network hashrate in hash/s.
+    pub hashrate: f64,
+    pub pools   : Vec<PoolStats>,
 }
 
 #[cfg(feature = "bitcoin")]
@@ -228,12 +441,63 @@ impl Client {
     pub async fn block_header<T: ToString>(&self, hash_or_height: T) -> Result<BlockHeader, reqwest::Error> {
         self.get(&["block", &hash_or_height.to_string()].join("/")).await
     }
+    pub async fn block_stats<T: ToString>(&self, hash_or_height: T) -> Result<BlockStats, reqwest::Error> {
+        self.get(&["block_stats", &hash_or_height.to_string()].join("/")).await
+    }
+    /// Fetch the consensus-encoded block.
+    pub async fn raw_block<T: ToString>(&self, hash_or_height: T) -> Result<Vec<u8>, reqwest::Error> {
+        let url = format!("{}/v1/rawblock/{}", &self.endpoint, hash_or_height.to_string());
+        let bytes = self.reqwest_client.get(url)
+            .send().await?
+            .error_for_status()?
+            .bytes().await?;
+        Ok(bytes.to_vec())
+    }
+    /// Fetch the mining pool statistics of blocks in `from..=to` (the last 144 blocks by default).
+    pub async fn pools(&self, from: Option<u32>, to: Option<u32>) -> Result<Pools, reqwest::Error> {
+        let mut query = Vec::new();
+        if let Some(from) = from {
+            query.push(format!("from={}", from));
+        }
+        if let Some(to) = to {
+            query.push(format!("to={}", to));
+        }
+        self.get(&format!("pools?{}", query.join("&"))).await
+    }
+    /// Fetch the first page of txids (the server returns at most 100 by default, use `txids_page()` for more).
     pub async fn txids(&self, script_or_address: &str) -> Result<Vec<String>, reqwest::Error> {
         self.get(&["txids", script_or_address].join("/")).await
     }
+    /// Fetch the first page of transactions (the server returns at most 100 by default, use `txs_page()` for more).
     pub async fn txs(&self, script_or_address: &str) -> Result<Vec<Transaction>, reqwest::Error> {
         self.get(&["txs", script_or_address].join("/")).await
     }
+    /// Fetch at most `limit` txids, starting right after `after` (the last txid of the previous page).
+    pub async fn txids_page(&self, script_or_address: &str, after: Option<&str>, limit: usize) -> Result<Vec<String>, reqwest::Error> {
+        self.get(&page_path("txids", script_or_address, after, limit)).await
+    }
+    /// Fetch at most `limit` transactions, starting right after `after` (the last txid of the previous page).
+    pub async fn txs_page(&self, script_or_address: &str, after: Option<&str>, limit: usize) -> Result<Vec<Transaction>, reqwest::Error> {
+        self.get(&page_path("txs", script_or_address, after, limit)).await
+    }
+    /// Fetch OP_RETURN outputs whose data start with `hex_prefix` (at most 80 bytes, the first page only).
+    pub async fn op_return(&self, hex_prefix: &str) -> Result<Vec<OpReturn>, reqwest::Error> {
+        self.get(&["op_return", hex_prefix].join("/")).await
+    }
+    /// Fetch at most `limit` OP_RETURN outputs whose data start with `hex_prefix`,
+    /// starting right after `after` (the "txid:vout" of the last entry of the previous page).
+    pub async fn op_return_page(&self, hex_prefix: &str, after: Option<&str>, limit: usize) -> Result<Vec<OpReturn>, reqwest::Error> {
+        self.get(&page_path("op_return", hex_prefix, after, limit)).await
+    }
+    /// Fetch the txids of Counterparty transactions which refer to `asset` (the first page only).
+    pub async fn counterparty_txids(&self, asset: &str) -> Result<Vec<String>, reqwest::Error> {
+        self.get(&["counterparty_txids", asset].join("/")).await
+    }
+    /// Fetch at most `limit` txids of Counterparty transactions which refer to `asset`,
+    /// starting right after `after` (the last txid of the previous page).
+    pub async fn counterparty_txids_page(&self, asset: &str, after: Option<&str>, limit: usize) -> Result<Vec<String>, reqwest::Error> {
+        self.get(&page_path("counterparty_txids", asset, after, limit)).await
+    }
     pub async fn utxos(&self, script_or_address: &str) -> Result<Vec<Utxo>, reqwest::Error> {
         self.get(&["utxos", script_or_address].join("/")).await
     }
@@ -246,6 +510,47 @@ impl Client {
     pub async fn rich_list(&self, offset: u32, limit: u32) -> Result<Vec<Option<RichListEntry>>, reqwest::Error> {
         self.get(&["rich_list", &offset.to_string(), &limit.to_string()].join("/")).await
     }
+    pub async fn rich_list_count_filtered(&self, filter: &RichListFilter) -> Result<RichListCount, reqwest::Error> {
+        self.get(&format!("rich_list_count?{}", filter.to_query())).await
+    }
+    pub async fn rich_list_addr_rank_filtered(&self, script_or_address: &str, filter: &RichListFilter) -> Result<RichListRank, reqwest::Error> {
+        self.get(&format!("rich_list_addr_rank/{}?{}", script_or_address, filter.to_query())).await
+    }
+    pub async fn rich_list_filtered(&self, offset: u32, limit: u32, filter: &RichListFilter) -> Result<Vec<Option<RichListEntry>>, reqwest::Error> {
+        self.get(&format!("rich_list/{}/{}?{}", offset, limit, filter.to_query())).await
+    }
+    /// Fetch `limit` entries starting from `offset` of the rich list snapshot at `height`.
+    pub async fn rich_list_at(&self, height: u32, offset: u32, limit: u32) -> Result<Vec<Option<RichListEntry>>, reqwest::Error> {
+        self.get(&["rich_list_at", &height.to_string(), &offset.to_string(), &limit.to_string()].join("/")).await
+    }
+    pub async fn rich_list_distribution(&self, height: u32) -> Result<RichListDistribution, reqwest::Error> {
+        self.get(&["rich_list_distribution", &height.to_string()].join("/")).await
+    }
+    /// Fetch the rank of the address in each rich list snapshot in `from..=to`.
+    pub async fn rich_list_addr_rank_history(&self, script_or_address: &str, from: Option<u32>, to: Option<u32>) -> Result<Vec<RichListRankHistoryEntry>, reqwest::Error> {
+        let mut query = Vec::new();
+        if let Some(from) = from {
+            query.push(format!("from={}", from));
+        }
+        if let Some(to) = to {
+            query.push(format!("to={}", to));
+        }
+        self.get(&format!("rich_list_addr_rank_history/{}?{}", script_or_address, query.join("&"))).await
+    }
+    /// Fetch the UTXO set statistics at `height` (or at the synced height if `None`).
+    pub async fn utxo_set_info(&self, height: Option<u32>) -> Result<UtxoSetInfo, reqwest::Error> {
+        match height {
+            Some(height) => self.get(&format!("utxo_set_info?height={}", height)).await,
+            None => self.get("utxo_set_info").await,
+        }
+    }
+    /// Fetch the coin supply at `height` (or at the synced height if `None`).
+    pub async fn supply(&self, height: Option<u32>) -> Result<Supply, reqwest::Error> {
+        match height {
+            Some(height) => self.get(&["supply", &height.to_string()].join("/")).await,
+            None => self.get("supply").await,
+        }
+    }
     /// Generate a new valid transaction from private keys and outputs.
     /// 
     /// # Arguments
@@ -340,6 +645,13 @@ impl Client {
     }
 }
 
+fn page_path(api: &str, script_or_address: &str, after: Option<&str>, limit: usize) -> String {
+    match after {
+        Some(after) => format!("{}/{}?limit={}&after={}", api, script_or_address, limit, after),
+        None => format!("{}/{}?limit={}", api, script_or_address, limit),
+    }
+}
+
 /// Create a new `chainseeker` client.
 ///
 /// The `endpoint` will be the string like <https://btc-v3.chainseeker.info/api>
@@ -422,6 +734,30 @@ mod tests {
         let client = new(DEFAULT_ENDPOINT);
         assert_eq!(client.block_header(BLOCK_HEIGHT).await.unwrap().hash, BLOCK_HASH);
     }
+    #[tokio::test]
+    async fn block_stats() {
+        let client = new(DEFAULT_ENDPOINT);
+        assert_eq!(client.block_stats(BLOCK_HASH).await.unwrap().height, BLOCK_HEIGHT);
+    }
+    #[tokio::test]
+    async fn pools() {
+        let client = new(DEFAULT_ENDPOINT);
+        let pools = client.pools(Some(BLOCK_HEIGHT), Some(BLOCK_HEIGHT + 9)).await.unwrap();
+        assert_eq!(pools.blocks, 10);
+        assert_eq!(pools.pools.iter().map(|pool| pool.blocks).sum::<u32>(), 10);
+    }
+    #[tokio::test]
+    async fn utxo_set_info() {
+        let client = new(DEFAULT_ENDPOINT);
+        assert_eq!(client.utxo_set_info(Some(BLOCK_HEIGHT)).await.unwrap().height, BLOCK_HEIGHT);
+    }
+    #[tokio::test]
+    async fn supply() {
+        let client = new(DEFAULT_ENDPOINT);
+        let supply = client.supply(Some(BLOCK_HEIGHT)).await.unwrap();
+        assert_eq!(supply.height, BLOCK_HEIGHT);
+        assert_eq!(supply.total_subsidy, (BLOCK_HEIGHT as u64 + 1) * 5_000_000_000);
+    }
     const ADDRESS: &str = "1CounterpartyXXXXXXXXXXXXXXXUWLpVr";
     #[tokio::test]
     async fn txids() {
@@ -434,6 +770,14 @@ mod tests {
         assert!(!client.txs(ADDRESS).await.unwrap().is_empty());
     }
     #[tokio::test]
+    async fn txids_page() {
+        let client = new(DEFAULT_ENDPOINT);
+        let txids = client.txids_page(ADDRESS, None, 2).await.unwrap();
+        assert_eq!(txids.len(), 2);
+        let next = client.txids_page(ADDRESS, Some(&txids[0]), 1).await.unwrap();
+        assert_eq!(next, [txids[1].clone()]);
+    }
+    #[tokio::test]
     async fn utxos() {
         let client = new(DEFAULT_ENDPOINT);
         assert!(!client.utxos(ADDRESS).await.unwrap().is_empty());
@@ -453,4 +797,31 @@ mod tests {
         let client = new(DEFAULT_ENDPOINT);
         assert_eq!(client.rich_list(0, 100).await.unwrap().len(), 100);
     }
+    #[tokio::test]
+    async fn rich_list_filtered() {
+        let client = new(DEFAULT_ENDPOINT);
+        let filter = RichListFilter {
+            script_type: Some("witnesspubkeyhash".to_string()),
+            address_only: false,
+            exclude_burn: true,
+        };
+        let entries = client.rich_list_filtered(0, 10, &filter).await.unwrap();
+        assert!(entries.iter().all(|entry| entry.as_ref().unwrap().script_pub_key.r#type == "witnesspubkeyhash"));
+        assert!(client.rich_list_count_filtered(&filter).await.unwrap().count < client.rich_list_count().await.unwrap().count);
+    }
+    #[tokio::test]
+    async fn rich_list_at() {
+        let client = new(DEFAULT_ENDPOINT);
+        assert_eq!(client.rich_list_at(700_000, 0, 100).await.unwrap().len(), 100);
+    }
+    #[tokio::test]
+    async fn rich_list_distribution() {
+        let client = new(DEFAULT_ENDPOINT);
+        assert!(client.rich_list_distribution(700_000).await.unwrap().count > 0);
+    }
+    #[tokio::test]
+    async fn rich_list_addr_rank_history() {
+        let client = new(DEFAULT_ENDPOINT);
+        assert!(!client.rich_list_addr_rank_history(ADDRESS, Some(700_000), None).await.unwrap().is_empty());
+    }
 }
@@ -0,0 +1,9 @@
+fn main() {
+    // Use the bundled protoc so that the gRPC code is generated without network access or a system-wide protoc.
+    std::env::set_var("PROTOC", protoc_bin_vendored::protoc_bin_path().expect("Failed to find the bundled protoc."));
+    // Generated clients do not compile in the 2018 edition (they rely on `TryInto` in the prelude).
+    tonic_build::configure()
+        .build_client(false)
+        .compile(&["proto/chainseeker.proto"], &["proto"])
+        .expect("Failed to compile protobuf definitions.");
+}
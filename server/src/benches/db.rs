@@ -1,7 +1,9 @@
 use chainseeker_server::*;
 use criterion::{criterion_group, criterion_main, Criterion};
+use indexmap::IndexMap;
 use bitcoin::consensus::Decodable;
-use bitcoin::Block;
+use bitcoin::hashes::Hash;
+use bitcoin::{Block, Script, WPubkeyHash};
 
 use crate::db::*;
 use crate::db::utxo::UtxoEntry;
@@ -25,13 +27,13 @@ fn bench_db(c: &mut Criterion) {
     let block = Block::consensus_decode(BLOCK).expect("Failed to decode block.");
     let mut utxo_db = UtxoDB::new(COIN, true);
     c.bench_function("UtxoDB", |b| b.iter(|| {
-        utxo_db.process_block(&block, true);
+        utxo_db.process_block(500000, &block, true);
     }));
-    let utxos = utxo_db.process_block(&block, true);
+    let utxos = utxo_db.process_block(500000, &block, true);
     c.bench_function("UtxoServer", |b| b.iter(|| {
         let mut utxo_server = UtxoServer::new();
         for utxo in utxos.iter() {
-            utxo_server.push(&utxo);
+            utxo_server.push(&utxo, 500000, false);
         }
     }));
     // Construct dummy data.
@@ -46,16 +48,58 @@ fn bench_db(c: &mut Criterion) {
             });
         }
     }
-    c.bench_function("RichList", |b| b.iter(|| {
-        let mut rich_list = RichList::new();
-        rich_list.process_block(&block, &previous_utxos);
-        rich_list.finalize();
-    }));
     let addr_index_db = AddressIndexDB::new(COIN, true);
     c.bench_function("AddressIndexDB", |b| b.iter(|| {
-        addr_index_db.process_block(&block, &previous_utxos);
+        addr_index_db.process_block(500000, &block, &previous_utxos);
+    }));
+}
+
+/// Compare the incremental updates of `RichList` with sorting the whole list after every block.
+fn bench_rich_list(c: &mut Criterion) {
+    const ENTRIES: u32 = 1_000_000;
+    let block = Block::consensus_decode(BLOCK).expect("Failed to decode block.");
+    // Credit and then debit the outputs of the block, so that the list is unchanged after each iteration.
+    let utxos = block.txdata.iter().flat_map(|tx| {
+        let txid = tx.txid();
+        tx.output.iter().enumerate().filter(|(_vout, output)| output.value > 0).map(move |(vout, output)| UtxoEntry {
+            script_pubkey: output.script_pubkey.clone(),
+            txid,
+            vout: vout as u32,
+            value: output.value,
+        })
+    }).collect::<Vec<UtxoEntry>>();
+    let entries = (0..ENTRIES).map(|i| {
+        (Script::new_v0_wpkh(&WPubkeyHash::hash(&i.to_le_bytes())), i as u64 + 1)
+    }).collect::<Vec<(Script, u64)>>();
+    let mut rich_list = RichList::new();
+    for (script_pubkey, value) in entries.iter() {
+        rich_list.push(&UtxoEntry { script_pubkey: script_pubkey.clone(), txid: Default::default(), vout: 0, value: *value });
+    }
+    rich_list.finalize();
+    c.bench_function("RichList (order-statistic tree)", |b| b.iter(|| {
+        for utxo in utxos.iter() {
+            rich_list.push(utxo);
+        }
+        for utxo in utxos.iter() {
+            rich_list.remove(&utxo.script_pubkey, utxo.value);
+        }
+    }));
+    // The previous implementation which sorts the whole list.
+    let mut map = entries.into_iter().collect::<IndexMap<Script, u64>>();
+    c.bench_function("RichList (sort after every block)", |b| b.iter(|| {
+        for utxo in utxos.iter() {
+            *map.entry(utxo.script_pubkey.clone()).or_insert(0) += utxo.value;
+        }
+        for utxo in utxos.iter() {
+            let value = map.get_mut(&utxo.script_pubkey).unwrap();
+            *value -= utxo.value;
+            if *value == 0 {
+                map.remove(&utxo.script_pubkey);
+            }
+        }
+        map.par_sort_by(|_k1, v1, _k2, v2| v2.cmp(v1));
     }));
 }
 
-criterion_group!(benches, bench_synced_height_db, bench_db);
+criterion_group!(benches, bench_synced_height_db, bench_db, bench_rich_list);
 criterion_main!(benches);
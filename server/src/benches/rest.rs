@@ -11,8 +11,8 @@ fn bench_rest(c: &mut Criterion) {
     let blocks = fixtures::regtest_blocks();
     let mut utxo_db = UtxoDB::new(COIN, true);
     let addr_index_db = AddressIndexDB::new(COIN, true);
-    for block in blocks.iter() {
-        let prev_utxos = utxo_db.process_block(&block, false);
+    for (height, block) in blocks.iter().enumerate() {
+        let prev_utxos = utxo_db.process_block(height as u32, &block, false);
         addr_index_db.process_block(&block, &prev_utxos);
     }
     c.bench_function("Utxo", |b| b.iter(|| {
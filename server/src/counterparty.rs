@@ -0,0 +1,264 @@
+//! Counterparty (and Monaparty) message decoding.
+//!
+//! See also: <https://github.com/CounterpartyXCP/counterparty-lib/blob/master/counterpartylib/lib/blocks.py>.
+
+use bitcoin::{Script, Transaction, Txid};
+use bitcoin::blockdata::opcodes::all::OP_RETURN;
+use bitcoin::blockdata::script::Builder;
+use crate::*;
+
+/// Asset ids greater than this are numeric assets (e.g. "A95428956661682177").
+const MAX_NAMED_ASSET_ID: u64 = 26u64.pow(12);
+
+/// Encrypt or decrypt `data` with ARC4.
+fn arc4(key: &[u8], data: &[u8]) -> Vec<u8> {
+    let mut s = (0..=255).collect::<Vec<u8>>();
+    let mut j = 0u8;
+    for i in 0..256 {
+        j = j.wrapping_add(s[i]).wrapping_add(key[i % key.len()]);
+        s.swap(i, j as usize);
+    }
+    let (mut i, mut j) = (0u8, 0u8);
+    data.iter().map(|byte| {
+        i = i.wrapping_add(1);
+        j = j.wrapping_add(s[i as usize]);
+        s.swap(i as usize, j as usize);
+        byte ^ s[s[i as usize].wrapping_add(s[j as usize]) as usize]
+    }).collect()
+}
+
+/// Extract the data (without the prefix) embedded in the OP_RETURN and bare multisig outputs of `tx`.
+///
+/// The data is obfuscated with ARC4 keyed by the txid of the first input.
+pub fn extract_data(tx: &Transaction, prefix: &[u8]) -> Option<Vec<u8>> {
+    if prefix.is_empty() || tx.is_coin_base() {
+        return None;
+    }
+    let key = hex::decode(tx.input.first()?.previous_output.txid.to_string()).unwrap();
+    let mut data = Vec::new();
+    let mut found = false;
+    for output in tx.output.iter() {
+        let chunk = if let Some(payload) = op_return_data(&output.script_pubkey) {
+            arc4(&key, &payload)
+        } else if let Some((_m, pubkeys)) = parse_multisig(&output.script_pubkey) {
+            // The last public key belongs to the sender, and the others carry the data
+            // (except for the first sign byte and the last nonce byte).
+            let data_pubkeys = &pubkeys[..pubkeys.len() - 1];
+            let chunk = arc4(&key, &data_pubkeys.iter().flat_map(|pubkey| pubkey[1..pubkey.len()-1].to_vec()).collect::<Vec<u8>>());
+            // The first byte is the length of the chunk.
+            match chunk.split_first() {
+                Some((len, chunk)) if *len as usize <= chunk.len() => chunk[..*len as usize].to_vec(),
+                _ => continue,
+            }
+        } else {
+            continue;
+        };
+        if let Some(chunk) = chunk.strip_prefix(prefix) {
+            data.extend_from_slice(chunk);
+            found = true;
+        }
+    }
+    if found { Some(data) } else { None }
+}
+
+/// Create an OP_RETURN script which carries `data` for a transaction whose first input spends `txid`
+/// (the inverse of `extract_data` for OP_RETURN outputs).
+pub fn encode_op_return(txid: &Txid, prefix: &[u8], data: &[u8]) -> Script {
+    let key = hex::decode(txid.to_string()).unwrap();
+    Builder::new().push_opcode(OP_RETURN).push_slice(&arc4(&key, &[prefix, data].concat())).into_script()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CounterpartyMessage {
+    pub type_id: u32,
+    /// The message without the type id.
+    pub payload: Vec<u8>,
+}
+
+impl CounterpartyMessage {
+    pub fn decode(data: &[u8]) -> Option<Self> {
+        // Older messages have a 4-byte (big endian) type id, and newer ones have a 1-byte type id.
+        let (type_id, payload) = match data.first()? {
+            0 if data.len() >= 4 => (u32::from_be_bytes([data[0], data[1], data[2], data[3]]), &data[4..]),
+            0 => return None,
+            type_id => (*type_id as u32, &data[1..]),
+        };
+        Some(Self {
+            type_id,
+            payload: payload.to_vec(),
+        })
+    }
+    /// Decode the Counterparty message of `tx` if any.
+    pub fn from_tx(tx: &Transaction, config: &Config) -> Option<Self> {
+        Self::decode(&extract_data(tx, config.counterparty_prefix.as_bytes())?)
+    }
+    pub fn message_type(&self) -> &'static str {
+        match self.type_id {
+            0 => "send",
+            2 => "enhanced_send",
+            3 => "mpma_send",
+            4 => "sweep",
+            10 => "order",
+            11 => "btcpay",
+            12 => "dispenser",
+            20..=22 => "issuance",
+            30 => "broadcast",
+            40 => "bet",
+            50 => "dividend",
+            70 => "cancel",
+            80 => "rps",
+            81 => "rpsresolve",
+            110 => "destroy",
+            _ => "unknown",
+        }
+    }
+    fn u64_at(&self, offset: usize) -> Option<u64> {
+        let bytes = self.payload.get(offset..offset+8)?;
+        let mut buf = [0u8; 8];
+        buf.copy_from_slice(bytes);
+        Some(u64::from_be_bytes(buf))
+    }
+    /// Returns (asset id, quantity) of the message.
+    ///
+    /// The quantity is the amount given for orders, and the amount per unit for dividends.
+    pub fn asset_and_quantity(&self) -> Option<(u64, u64)> {
+        match self.type_id {
+            // send, enhanced_send, order, issuance and destroy start with (asset id, quantity).
+            0 | 2 | 10 | 20..=22 | 110 => Some((self.u64_at(0)?, self.u64_at(8)?)),
+            // dividend starts with (quantity per unit, asset id).
+            50 => Some((self.u64_at(8)?, self.u64_at(0)?)),
+            _ => None,
+        }
+    }
+    /// Returns the ids of all assets which the message refers to.
+    pub fn asset_ids(&self) -> Vec<u64> {
+        let second = match self.type_id {
+            // The asset to get.
+            10 => self.u64_at(16),
+            // The asset to pay dividends in.
+            50 => self.u64_at(16),
+            _ => None,
+        };
+        self.asset_and_quantity().map(|(asset_id, _quantity)| asset_id).into_iter().chain(second).collect()
+    }
+}
+
+/// Returns the name of the asset (`native_assets` are the names of the asset ids zero and one).
+pub fn asset_name(asset_id: u64, native_assets: &[String]) -> String {
+    if let Some(name) = native_assets.get(asset_id.min(usize::MAX as u64) as usize) {
+        return name.clone();
+    }
+    if asset_id > MAX_NAMED_ASSET_ID {
+        return format!("A{}", asset_id);
+    }
+    let mut name = Vec::new();
+    let mut id = asset_id;
+    while id > 0 {
+        name.push(b'A' + (id % 26) as u8);
+        id /= 26;
+    }
+    name.reverse();
+    String::from_utf8(name).unwrap()
+}
+
+/// Returns the id of the asset (the inverse of `asset_name`), validating the name as counterparty-lib does.
+pub fn asset_id(name: &str, native_assets: &[String]) -> Option<u64> {
+    if let Some(asset_id) = native_assets.iter().position(|native_asset| native_asset == name) {
+        return Some(asset_id as u64);
+    }
+    match name.strip_prefix('A') {
+        // Numeric assets: "A" followed by the id (without leading zeros) above the range of named assets.
+        Some(id) if id.bytes().all(|c| c.is_ascii_digit()) => {
+            let asset_id = id.parse::<u64>().ok()?;
+            if asset_id > MAX_NAMED_ASSET_ID && id == asset_id.to_string() { Some(asset_id) } else { None }
+        },
+        // Named assets cannot start with "A" (which would be a leading zero).
+        Some(_) => None,
+        // Named assets consist of 4 to 12 uppercase letters.
+        None => {
+            if name.len() < 4 || name.len() > 12 || !name.bytes().all(|c| c.is_ascii_uppercase()) {
+                return None;
+            }
+            Some(name.bytes().fold(0u64, |id, c| id * 26 + (c - b'A') as u64))
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+    use bitcoin::{OutPoint, TxIn, TxOut};
+    use super::*;
+    fn native_assets() -> Vec<String> {
+        vec!["BTC".to_string(), "XCP".to_string()]
+    }
+    #[test]
+    fn arc4() {
+        // Test vectors come from https://en.wikipedia.org/wiki/RC4#Test_vectors.
+        assert_eq!(hex::encode(super::arc4(b"Key", b"Plaintext")), "bbf316e8d940af0ad3");
+        assert_eq!(hex::encode(super::arc4(b"Wiki", b"pedia")), "1021bf0420");
+        assert_eq!(super::arc4(b"Key", &super::arc4(b"Key", b"Plaintext")), b"Plaintext");
+    }
+    #[test]
+    fn asset_name() {
+        let native_assets = native_assets();
+        assert_eq!(super::asset_name(0, &native_assets), "BTC");
+        assert_eq!(super::asset_name(1, &native_assets), "XCP");
+        assert_eq!(super::asset_name(MAX_NAMED_ASSET_ID + 1, &native_assets), "A95428956661682177");
+        for name in ["BTC", "XCP", "PEPECASH", "RAREPEPE", "ZZZZZZZZZZZZ", "A95428956661682177"] {
+            let asset_id = super::asset_id(name, &native_assets).unwrap();
+            assert_eq!(super::asset_name(asset_id, &native_assets), name);
+        }
+        assert_eq!(super::asset_id("A123", &native_assets), None);
+        assert_eq!(super::asset_id("A095428956661682177", &native_assets), None);
+        assert_eq!(super::asset_id("A", &native_assets), None);
+        assert_eq!(super::asset_id("pepecash", &native_assets), None);
+        assert_eq!(super::asset_id("AAAAAAAAAAAAA", &native_assets), None);
+        // Too short names and names starting with "A" would alias other assets (e.g. "B" and "AXCP" to XCP).
+        assert_eq!(super::asset_id("B", &native_assets), None);
+        assert_eq!(super::asset_id("BCD", &native_assets), None);
+        assert_eq!(super::asset_id("AXCP", &native_assets), None);
+        assert_eq!(super::asset_id("APEPE", &native_assets), None);
+        // "B" = 1, "C" = 2, ...
+        assert_eq!(super::asset_id("BCDE", &native_assets), Some(((26 + 2) * 26 + 3) * 26 + 4));
+    }
+    #[test]
+    fn decode() {
+        let prefix = b"CNTRPRTY";
+        let txid = Txid::from_str("0e3e2357e806b6cdb1f70b54c3a3a17b6714ee1f0e68bebb44a74b1efd512098").unwrap();
+        let key = hex::decode(txid.to_string()).unwrap();
+        let asset_id = super::asset_id("PEPECASH", &native_assets()).unwrap();
+        // A classic send of 100 PEPECASH.
+        let message = [&0u32.to_be_bytes()[..], &asset_id.to_be_bytes(), &100u64.to_be_bytes()].concat();
+        let tx = |output: Vec<TxOut>| Transaction {
+            version: 1,
+            lock_time: 0,
+            input: vec![TxIn { previous_output: OutPoint { txid, vout: 0 }, script_sig: Script::new(), sequence: 0xffffffff, witness: vec![] }],
+            output,
+        };
+        let check = |tx: &Transaction| {
+            let message = CounterpartyMessage::decode(&extract_data(tx, prefix).unwrap()).unwrap();
+            assert_eq!(message.type_id, 0);
+            assert_eq!(message.message_type(), "send");
+            assert_eq!(message.asset_and_quantity(), Some((asset_id, 100)));
+            assert_eq!(message.asset_ids(), vec![asset_id]);
+        };
+        // OP_RETURN encoding.
+        check(&tx(vec![TxOut { value: 0, script_pubkey: encode_op_return(&txid, prefix, &message) }]));
+        // Multisig encoding (1-of-2 with a data public key and the sender's public key).
+        let chunk = [&[(prefix.len() + message.len()) as u8][..], prefix, &message].concat();
+        assert!(chunk.len() <= 31 * 2);
+        let chunk = [chunk.clone(), vec![0u8; 31 * 2 - chunk.len()]].concat();
+        let chunk = super::arc4(&key, &chunk);
+        let data_pubkeys = chunk.chunks(31).map(|chunk| [&[0x02][..], chunk, &[0x00]].concat()).collect::<Vec<Vec<u8>>>();
+        let multisig = Builder::new()
+            .push_int(1).push_slice(&data_pubkeys[0]).push_slice(&data_pubkeys[1]).push_slice(&[0x03; 33]).push_int(3)
+            .push_opcode(bitcoin::blockdata::opcodes::all::OP_CHECKMULTISIG).into_script();
+        check(&tx(vec![TxOut { value: 7800, script_pubkey: multisig }]));
+        // Not a Counterparty transaction.
+        assert_eq!(extract_data(&tx(vec![TxOut { value: 0, script_pubkey: Script::from_str("6a0401020304").unwrap() }]), prefix), None);
+        // 1-byte type ids.
+        assert_eq!(CounterpartyMessage::decode(&[20, 0x01]), Some(CounterpartyMessage { type_id: 20, payload: vec![0x01] }));
+        assert_eq!(CounterpartyMessage::decode(&[0, 0]), None);
+    }
+}
@@ -1,22 +1,41 @@
 use std::sync::Arc;
 use tokio::sync::RwLock;
-use crate::Config;
+use crate::{Config, data_dir};
 
 pub mod synced_height;
 pub mod block;
+pub mod block_stats;
+pub mod pool;
+pub mod supply;
 pub mod tx;
 pub mod address_index;
+pub mod op_return;
+pub mod counterparty;
 pub mod utxo;
+pub mod utxo_stats;
 pub mod utxo_server;
 pub mod rich_list;
+pub mod rich_list_snapshot;
 
 pub use synced_height::SyncedHeightDB;
 pub use block::BlockDB;
+pub use block_stats::BlockStatsDB;
+pub use pool::PoolDB;
+pub use supply::SupplyDB;
 pub use tx::TxDB;
 pub use address_index::AddressIndexDB;
+pub use op_return::OpReturnDB;
+pub use counterparty::CounterpartyDB;
 pub use utxo::UtxoDB;
+pub use utxo_stats::UtxoStatsDB;
 pub use utxo_server::UtxoServer;
 pub use rich_list::RichList;
+pub use rich_list_snapshot::RichListSnapshotDB;
+
+/// The version of the on-disk format, which is bumped on incompatible changes of the database keys or values.
+///
+/// Databases written in another format are wiped on startup so that the blockchain is synced again.
+pub const FORMAT_VERSION: u32 = 4;
 
 #[derive(Debug, Clone)]
 pub struct Database {
@@ -24,23 +43,106 @@ pub struct Database {
     pub config: Config,
     pub synced_height_db: Arc<RwLock<SyncedHeightDB>>,
     pub block_db: Arc<RwLock<BlockDB>>,
+    pub block_stats_db: Arc<RwLock<BlockStatsDB>>,
+    pub pool_db: Arc<RwLock<PoolDB>>,
+    pub supply_db: Arc<RwLock<SupplyDB>>,
     pub tx_db: Arc<RwLock<TxDB>>,
     pub addr_index_db: Arc<RwLock<AddressIndexDB>>,
+    pub op_return_db: Arc<RwLock<OpReturnDB>>,
+    pub counterparty_db: Arc<RwLock<CounterpartyDB>>,
+    pub utxo_stats_db: Arc<RwLock<UtxoStatsDB>>,
     pub utxo_server: Arc<RwLock<UtxoServer>>,
     pub rich_list: Arc<RwLock<RichList>>,
+    pub rich_list_snapshot_db: Arc<RwLock<RichListSnapshotDB>>,
 }
 
 impl Database {
+    fn format_version_path(coin: &str) -> String {
+        format!("{}/{}/format_version", data_dir(), coin)
+    }
+    /// Returns the format version of the databases of `coin` (`None` if no database exists yet).
+    ///
+    /// Databases created before the version was recorded are reported as the version 1.
+    pub fn format_version(coin: &str) -> Option<u32> {
+        if !std::path::Path::new(&format!("{}/{}", data_dir(), coin)).exists() {
+            return None;
+        }
+        match std::fs::read_to_string(Self::format_version_path(coin)) {
+            Ok(version) => Some(version.trim().parse().expect("Failed to parse the database format version.")),
+            Err(_) => Some(1),
+        }
+    }
+    /// Wipe the databases of `coin` if they were written in another format, and record the current one.
+    fn check_format_version(coin: &str) {
+        let dir = format!("{}/{}", data_dir(), coin);
+        match Self::format_version(coin) {
+            Some(FORMAT_VERSION) => return,
+            Some(version) => {
+                println!("Database format changed (version {} to {}), removing the databases to resync from scratch...", version, FORMAT_VERSION);
+                std::fs::remove_dir_all(&dir).expect("Failed to remove the databases.");
+            },
+            None => {},
+        }
+        std::fs::create_dir_all(&dir).expect("Failed to create the data directory.");
+        std::fs::write(Self::format_version_path(coin), format!("{}\n", FORMAT_VERSION)).expect("Failed to write the database format version.");
+    }
     pub fn new(coin: &str, config: &Config) -> Self {
+        Self::check_format_version(coin);
+        let synced_height_db = SyncedHeightDB::new(coin);
+        let (utxo_server, rich_list) = if config.low_memory {
+            // Reused across restarts if they are synced to the same height as the other databases.
+            let open = |synced_height| (UtxoServer::new_on_disk(coin, synced_height, false), RichList::new_on_disk(coin, synced_height, false));
+            let (utxo_server, rich_list) = open(synced_height_db.get());
+            if utxo_server.synced_height() == rich_list.synced_height() {
+                (utxo_server, rich_list)
+            } else {
+                // Reload both unless both are synced.
+                drop((utxo_server, rich_list));
+                open(None)
+            }
+        } else {
+            (UtxoServer::new(), RichList::new())
+        };
         Self {
             coin: coin.to_string(),
             config: (*config).clone(),
-            synced_height_db: Arc::new(RwLock::new(SyncedHeightDB::new(coin))),
+            synced_height_db: Arc::new(RwLock::new(synced_height_db)),
             block_db        : Arc::new(RwLock::new(BlockDB::new(coin, false))),
+            block_stats_db  : Arc::new(RwLock::new(BlockStatsDB::new(coin, config, false))),
+            pool_db         : Arc::new(RwLock::new(PoolDB::new(coin, config, false))),
+            supply_db       : Arc::new(RwLock::new(SupplyDB::new(coin, config, false))),
             tx_db           : Arc::new(RwLock::new(TxDB::new(coin, false))),
             addr_index_db   : Arc::new(RwLock::new(AddressIndexDB::new(coin, false))),
-            utxo_server     : Arc::new(RwLock::new(UtxoServer::new())),
-            rich_list       : Arc::new(RwLock::new(RichList::new())),
+            op_return_db    : Arc::new(RwLock::new(OpReturnDB::new(coin, false))),
+            counterparty_db : Arc::new(RwLock::new(CounterpartyDB::new(coin, config, false))),
+            utxo_stats_db   : Arc::new(RwLock::new(UtxoStatsDB::new(coin, false))),
+            utxo_server     : Arc::new(RwLock::new(utxo_server)),
+            rich_list       : Arc::new(RwLock::new(rich_list)),
+            rich_list_snapshot_db: Arc::new(RwLock::new(RichListSnapshotDB::new(coin, config, false))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn check_format_version() {
+        const COIN: &str = "test/format_version";
+        let dir = format!("{}/{}", data_dir(), COIN);
+        if std::path::Path::new(&dir).exists() {
+            std::fs::remove_dir_all(&dir).unwrap();
         }
+        assert_eq!(Database::format_version(COIN), None);
+        Database::check_format_version(COIN);
+        assert_eq!(Database::format_version(COIN), Some(FORMAT_VERSION));
+        // Databases without the version are wiped.
+        let stale = format!("{}/block", dir);
+        std::fs::create_dir_all(&stale).unwrap();
+        std::fs::remove_file(Database::format_version_path(COIN)).unwrap();
+        assert_eq!(Database::format_version(COIN), Some(1));
+        Database::check_format_version(COIN);
+        assert!(!std::path::Path::new(&stale).exists());
+        assert_eq!(Database::format_version(COIN), Some(FORMAT_VERSION));
     }
 }
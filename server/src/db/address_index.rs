@@ -1,22 +1,43 @@
 use crate::*;
 use bitcoin::hashes::Hash;
 use bitcoin::{Block, Txid, Script, WScriptHash};
-use crate::rocks_db::{Serialize, Deserialize, ConstantSize};
+use crate::rocks_db::{Serialize, Deserialize, ConstantSize, Empty};
 use crate::db::utxo::UtxoEntry;
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub struct AddressIndexDBKey {
-    pub wscript_hash: WScriptHash,  // +32 = 32.
-    pub txid: Txid,                 // +32 = 64.
+    pub wscript_hash: WScriptHash,          // +32 = 32.
+    pub confirmed_height: Option<u32>,      // + 4 = 36.
+    pub txid: Txid,                         // +32 = 68.
+}
+
+impl AddressIndexDBKey {
+    /// Encode the confirmed height so that the entries of a script are sorted
+    /// by the byte order as: unconfirmed ones first, and then from the newest to the oldest.
+    fn encode_height(confirmed_height: Option<u32>) -> [u8; 4] {
+        confirmed_height.map_or(0u32, |h| u32::MAX - h).to_be_bytes()
+    }
+    fn decode_height(buf: &[u8]) -> Option<u32> {
+        let mut tmp = [0u8; 4];
+        tmp.copy_from_slice(buf);
+        match u32::from_be_bytes(tmp) {
+            0 => None,
+            h => Some(u32::MAX - h),
+        }
+    }
 }
 
 impl ConstantSize for AddressIndexDBKey {
-    const LEN: usize = 64;
+    const LEN: usize = 68;
 }
 
 impl Serialize for AddressIndexDBKey {
     fn serialize(&self) -> Vec<u8> {
-        [self.wscript_hash.as_ref(), &consensus_encode(&self.txid)].concat()
+        [
+            self.wscript_hash.as_ref(),
+            &Self::encode_height(self.confirmed_height),
+            &consensus_encode(&self.txid),
+        ].concat()
     }
 }
 
@@ -25,51 +46,22 @@ impl Deserialize for AddressIndexDBKey {
         let mut wscript_hash = [0u8; 32];
         wscript_hash.copy_from_slice(&buf[0..32]);
         let wscript_hash = WScriptHash::from_inner(wscript_hash);
-        let txid = consensus_decode(&buf[32..64]);
+        let confirmed_height = Self::decode_height(&buf[32..36]);
+        let txid = consensus_decode(&buf[36..68]);
         AddressIndexDBKey {
             wscript_hash,
+            confirmed_height,
             txid,
         }
     }
 }
 
-#[derive(Debug, Clone, Copy)]
-pub struct AddressIndexDBValue {
-    confirmed_height: Option<u32>,
-}
-
-impl ConstantSize for AddressIndexDBValue {
-    const LEN: usize = 4;
-}
-
-impl From<Option<u32>> for AddressIndexDBValue {
-    fn from(confirmed_height: Option<u32>) -> Self {
-        Self { confirmed_height }
-    }
-}
-
-impl Serialize for AddressIndexDBValue {
-    fn serialize(&self) -> Vec<u8> {
-        let confirmed_height: i32 = self.confirmed_height.map_or(-1i32, |h| h as i32);
-        confirmed_height.to_le_bytes().to_vec()
-    }
-}
-
-impl Deserialize for AddressIndexDBValue {
-    fn deserialize(buf: &[u8]) -> Self {
-        let confirmed_height = bytes_to_i32(buf);
-        Self {
-            confirmed_height: if confirmed_height < 0 { None } else { Some(confirmed_height as u32) },
-        }
-    }
-}
-
 #[derive(Debug)]
 pub struct AddressIndexDB {
-    db: RocksDB<AddressIndexDBKey, AddressIndexDBValue>,
+    db: RocksDB<AddressIndexDBKey, Empty>,
 }
 
-/// The database which stores (wscript_hash, txid) tuple.
+/// The database which stores (wscript_hash, confirmed_height, txid) tuple.
 impl AddressIndexDB {
     pub fn get_path(coin: &str) -> String {
         format!("{}/{}/address_index", data_dir(), coin)
@@ -80,54 +72,96 @@ impl AddressIndexDB {
             db: RocksDB::new(&path, temporary),
         }
     }
+    /// Returns all txids related to `script_pubkey` (unconfirmed ones first, and then from the newest to the oldest).
     pub fn get(&self, script_pubkey: &Script) -> Vec<Txid> {
-        let wscript_hash = script_pubkey.wscript_hash();
-        let mut txids = self.db.prefix_iter(wscript_hash.as_ref().to_vec())
-            .map(|(key, value)| (key.txid, value.confirmed_height))
-            .collect::<Vec<(bitcoin::Txid, Option<u32>)>>();
-        txids.sort_by(|a, b| {
-            if a.1 == b.1 {
-                return std::cmp::Ordering::Equal;
-            }
-            if a.1.is_none() {
-                return std::cmp::Ordering::Less;
-            }
-            if b.1.is_none() {
-                return std::cmp::Ordering::Greater;
-            }
-            b.1.unwrap().cmp(&a.1.unwrap())
-        });
-        txids.iter().map(|d| d.0).collect()
-    }
-    pub fn put(&self, script_pubkey: &Script, txid: &Txid, confirmed_height: Option<u32>) {
-        let key = AddressIndexDBKey {
-            wscript_hash: script_pubkey.wscript_hash(),
-            txid: *txid,
+        self.get_after(script_pubkey, None, usize::MAX)
+    }
+    /// Returns at most `limit` txids related to `script_pubkey` in the same order as `get()`.
+    /// If `after` (the confirmed height and the txid of the last entry of the previous page) is given,
+    /// the result starts from the entry right after it.
+    pub fn get_after(&self, script_pubkey: &Script, after: Option<(Option<u32>, Txid)>, limit: usize) -> Vec<Txid> {
+        self.get_after_by_hash(&script_pubkey.wscript_hash(), after, limit)
+    }
+    /// Same as `get_after()`, but the script is specified by its SHA256 hash.
+    pub fn get_after_by_hash(&self, wscript_hash: &WScriptHash, after: Option<(Option<u32>, Txid)>, limit: usize) -> Vec<Txid> {
+        let wscript_hash = *wscript_hash;
+        let prefix = wscript_hash.as_ref().to_vec();
+        let iter = match after {
+            Some((confirmed_height, txid)) => {
+                let key = AddressIndexDBKey {
+                    wscript_hash,
+                    confirmed_height,
+                    txid,
+                };
+                // Appending a zero byte yields the smallest key which is greater than `key`.
+                let from = [key.serialize(), vec![0u8]].concat();
+                self.db.prefix_iter_from(prefix, from)
+            },
+            None => self.db.prefix_iter(prefix),
         };
-        self.db.put(&key, &confirmed_height.into());
+        iter.take(limit).map(|(key, _value)| key.txid).collect()
+    }
+    /// Returns the scripts `tx` is indexed under and the number of entries consumed from `previous_utxos`.
+    ///
+    /// P2PK scripts are also indexed under their P2PKH script,
+    /// and bare multisig scripts under the P2PKH script of each participant.
+    fn indexed_scripts(tx: &bitcoin::Transaction, previous_utxos: &[UtxoEntry]) -> (Vec<Script>, usize) {
+        let previous_utxo_count = tx.input.iter().filter(|vin| !vin.previous_output.is_null()).count();
+        let scripts = previous_utxos[..previous_utxo_count].iter().map(|utxo| &utxo.script_pubkey)
+            .chain(tx.output.iter().map(|vout| &vout.script_pubkey))
+            .flat_map(|script_pubkey| {
+                let aliases = p2pk_to_p2pkh(script_pubkey).into_iter().chain(multisig_to_p2pkhs(script_pubkey));
+                std::iter::once(script_pubkey.clone()).chain(aliases)
+            })
+            .collect();
+        (scripts, previous_utxo_count)
     }
     pub fn process_tx(&self, tx: &bitcoin::Transaction, previous_utxos: &[UtxoEntry], height: Option<u32>) -> usize {
-        let mut previous_utxo_index = 0;
+        let mut batch = rocks_db::WriteBatch::<AddressIndexDBKey, Empty>::default();
         let txid = tx.txid();
-        // Process vins.
-        for vin in tx.input.iter() {
-            if !vin.previous_output.is_null() {
-                // Fetch transaction from `previous_output`.
-                self.put(&previous_utxos[previous_utxo_index].script_pubkey, &txid, height);
-                previous_utxo_index += 1;
+        let (scripts, previous_utxo_count) = Self::indexed_scripts(tx, previous_utxos);
+        for script_pubkey in scripts.iter() {
+            let wscript_hash = script_pubkey.wscript_hash();
+            // Remove the entry which was put when the transaction was in the mempool.
+            if height.is_some() {
+                batch.delete(&AddressIndexDBKey {
+                    wscript_hash,
+                    confirmed_height: None,
+                    txid,
+                });
             }
+            batch.put(&AddressIndexDBKey {
+                wscript_hash,
+                confirmed_height: height,
+                txid,
+            }, &Empty {});
         }
-        // Process vouts.
-        for vout in tx.output.iter() {
-            self.put(&vout.script_pubkey, &txid, height);
-        }
-        previous_utxo_index
+        self.db.write(batch).unwrap();
+        previous_utxo_count
     }
     pub fn process_block(&self, height: u32, block: &Block, previous_utxos: &[UtxoEntry]) {
         let mut previous_utxo_index = 0;
         for tx in block.txdata.iter() {
-            previous_utxo_index += self.process_tx(&tx, &previous_utxos[previous_utxo_index..], Some(height));
+            previous_utxo_index += self.process_tx(tx, &previous_utxos[previous_utxo_index..], Some(height));
+        }
+    }
+    /// Remove the entries put by `process_block()` for the block disconnected by a reorg.
+    pub fn reorg_block(&self, height: u32, block: &Block, previous_utxos: &[UtxoEntry]) {
+        let mut batch = rocks_db::WriteBatch::<AddressIndexDBKey, Empty>::default();
+        let mut previous_utxo_index = 0;
+        for tx in block.txdata.iter() {
+            let txid = tx.txid();
+            let (scripts, previous_utxo_count) = Self::indexed_scripts(tx, &previous_utxos[previous_utxo_index..]);
+            previous_utxo_index += previous_utxo_count;
+            for script_pubkey in scripts.iter() {
+                batch.delete(&AddressIndexDBKey {
+                    wscript_hash: script_pubkey.wscript_hash(),
+                    confirmed_height: Some(height),
+                    txid,
+                });
+            }
         }
+        self.db.write(batch).unwrap();
     }
 }
 
@@ -140,8 +174,9 @@ mod tests {
         let mut entries = addr_index_db.db.iter().map(|(key, _value)| key).collect::<Vec<AddressIndexDBKey>>();
         entries.sort();
         for entry in entries.iter() {
-            println!("        AddressIndexDBKey {{ wscript_hash: WScriptHash::from_inner([{}]), txid: consensus_decode(&hex::decode(\"{}\").unwrap()), }},",
+            println!("        AddressIndexDBKey {{ wscript_hash: WScriptHash::from_inner([{}]), confirmed_height: {:?}, txid: consensus_decode(&hex::decode(\"{}\").unwrap()), }},",
                 entry.wscript_hash.as_ref().iter().map(|b| ["0x", &hex::encode([*b])].concat()).collect::<Vec<String>>().join(","),
+                entry.confirmed_height,
                 hex::encode(consensus_encode(&entry.txid)));
         }
     }
@@ -150,7 +185,7 @@ mod tests {
         let addr_index_db = AddressIndexDB::new("test/address_index", true);
         let mut utxo_db = UtxoDB::new("test/address_index", true);
         for (height, block) in fixtures::regtest_blocks().iter().enumerate() {
-            let prev_utxos = utxo_db.process_block(&block, false);
+            let prev_utxos = utxo_db.process_block(height as u32, &block, false);
             addr_index_db.process_block(height as u32, &block, &prev_utxos);
         }
         print_addr_index_db(&addr_index_db);
@@ -167,4 +202,71 @@ mod tests {
             }
         }
     }
+    #[test]
+    fn addr_index_db_pagination() {
+        let addr_index_db = AddressIndexDB::new("test/address_index_pagination", true);
+        let mut utxo_db = UtxoDB::new("test/address_index_pagination", true);
+        let blocks = fixtures::regtest_blocks();
+        for (height, block) in blocks.iter().enumerate() {
+            let prev_utxos = utxo_db.process_block(height as u32, &block, false);
+            addr_index_db.process_block(height as u32, &block, &prev_utxos);
+        }
+        // Put an unconfirmed transaction which pays to the same script as the last coinbase.
+        let script_pubkey = &blocks.last().unwrap().txdata[0].output[0].script_pubkey;
+        let mut unconfirmed_tx = blocks.last().unwrap().txdata[0].clone();
+        unconfirmed_tx.lock_time += 1;
+        addr_index_db.process_tx(&unconfirmed_tx, &[], None);
+        let txids = addr_index_db.get(&script_pubkey);
+        assert!(txids.len() > 2);
+        // Unconfirmed transactions come first.
+        assert_eq!(txids[0], unconfirmed_tx.txid());
+        // Then, from the newest to the oldest.
+        assert!(blocks.last().unwrap().txdata.iter().any(|tx| tx.txid() == txids[1]));
+        // Walk through the pages.
+        let mut paged = Vec::new();
+        let mut after = None;
+        loop {
+            let page = addr_index_db.get_after(&script_pubkey, after, 2);
+            assert!(page.len() <= 2);
+            if page.is_empty() {
+                break;
+            }
+            let last = *page.last().unwrap();
+            let confirmed_height = addr_index_db.db.iter()
+                .find(|(key, _value)| key.txid == last)
+                .map(|(key, _value)| key.confirmed_height)
+                .unwrap();
+            after = Some((confirmed_height, last));
+            paged.extend(page);
+        }
+        assert_eq!(paged, txids);
+        // The mempool entry is replaced when the transaction gets confirmed.
+        addr_index_db.process_tx(&unconfirmed_tx, &[], Some(blocks.len() as u32));
+        let txids_confirmed = addr_index_db.get(&script_pubkey);
+        assert_eq!(txids_confirmed.len(), txids.len());
+        assert_eq!(txids_confirmed[0], unconfirmed_tx.txid());
+    }
+    #[test]
+    fn addr_index_db_reorg() {
+        let addr_index_db = AddressIndexDB::new("test/address_index_reorg", true);
+        let mut utxo_db = UtxoDB::new("test/address_index_reorg", true);
+        let blocks = fixtures::regtest_blocks();
+        let mut last_prev_utxos = Vec::new();
+        for (height, block) in blocks.iter().enumerate() {
+            last_prev_utxos = utxo_db.process_block(height as u32, block, false);
+            addr_index_db.process_block(height as u32, block, &last_prev_utxos);
+        }
+        let height = (blocks.len() - 1) as u32;
+        let last_block = blocks.last().unwrap();
+        let script_pubkey = &last_block.txdata[0].output[0].script_pubkey;
+        let txids = addr_index_db.get(script_pubkey);
+        // Disconnect the last block.
+        addr_index_db.reorg_block(height, last_block, &last_prev_utxos);
+        assert!(addr_index_db.db.iter().all(|(key, _value)| key.confirmed_height != Some(height)));
+        let txids_reorged = addr_index_db.get(script_pubkey);
+        assert!(txids_reorged.iter().all(|txid| last_block.txdata.iter().all(|tx| tx.txid() != *txid)));
+        // Re-mine the transactions at the next height, which should not be listed twice.
+        addr_index_db.process_block(height + 1, last_block, &last_prev_utxos);
+        assert_eq!(addr_index_db.get(script_pubkey).len(), txids.len());
+    }
 }
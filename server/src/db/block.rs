@@ -141,6 +141,11 @@ impl BlockContentDB {
     pub fn get(&self, block_hash: &BlockHash) -> Option<BlockContentDBValue> {
         self.db.get(&BlockHashDBValue { block_hash: *block_hash })
     }
+    /// Returns the block header without decoding txids.
+    pub fn get_header(&self, block_hash: &BlockHash) -> Option<BlockHeader> {
+        self.db.get_raw(&BlockHashDBValue { block_hash: *block_hash })
+            .map(|buf| consensus_decode(&buf[4..4+BLOCK_HEADER_LEN]))
+    }
 }
 
 #[derive(Debug)]
@@ -170,6 +175,9 @@ impl BlockDB {
     pub fn get_by_hash(&self, block_hash: &BlockHash) -> Option<BlockContentDBValue> {
         self.content_db.get(block_hash)
     }
+    pub fn get_header(&self, height: u32) -> Option<BlockHeader> {
+        self.content_db.get_header(&self.hash_db.get(height)?)
+    }
 }
 
 #[cfg(test)]
@@ -184,7 +192,9 @@ mod tests {
         }
         for (height, block) in blocks.iter().enumerate() {
             assert_eq!(block_db.get(height as u32), Some(BlockContentDBValue::new(height as u32, &block)));
+            assert_eq!(block_db.get_header(height as u32), Some(block.header));
         }
         assert_eq!(block_db.get(blocks.len() as u32), None);
+        assert_eq!(block_db.get_header(blocks.len() as u32), None);
     }
 }
@@ -0,0 +1,266 @@
+use crate::*;
+use bitcoin::{Block, BlockHash};
+use crate::db::utxo::UtxoEntry;
+use crate::db::block::BlockHashDBValue;
+use crate::rocks_db::{Serialize, Deserialize};
+
+const WITNESS_SCALE_FACTOR: u64 = bitcoin::blockdata::constants::WITNESS_SCALE_FACTOR as u64;
+
+/// The percentiles of fee rates stored in `BlockStatsDBValue` (same as Bitcoin Core's `getblockstats`).
+pub const FEERATE_PERCENTILES: [u64; 5] = [10, 25, 50, 75, 90];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BlockStatsDBValue {
+    pub height: u32,
+    /// The number of transactions (including coinbase).
+    pub txs: u32,
+    /// The number of inputs (excluding coinbase).
+    pub ins: u32,
+    /// The number of outputs (including coinbase).
+    pub outs: u32,
+    /// The total amount of inputs (excluding coinbase).
+    pub total_in: u64,
+    /// The total amount of outputs (excluding coinbase).
+    pub total_out: u64,
+    pub totalfee: u64,
+    /// The block subsidy by the subsidy schedule (the coinbase transaction may claim less).
+    pub subsidy: u64,
+    /// Fee rates are in sat/vB (excluding coinbase).
+    pub minfeerate: u64,
+    pub maxfeerate: u64,
+    /// Weighted by transaction weight, at `FEERATE_PERCENTILES`.
+    pub feerate_percentiles: [u64; 5],
+    /// The number of segwit transactions (excluding coinbase).
+    pub swtxs: u32,
+    /// The number of transactions which spend or create P2TR outputs.
+    pub taproottxs: u32,
+    /// The increase of the number of UTXOs (unspendable outputs are excluded).
+    pub utxo_increase: i64,
+}
+
+impl BlockStatsDBValue {
+    pub fn new(height: u32, block: &Block, previous_utxos: &[UtxoEntry], config: &Config) -> Self {
+        let mut ins = 0u32;
+        let mut outs = 0u32;
+        let mut total_in = 0u64;
+        let mut total_out = 0u64;
+        let mut totalfee = 0u64;
+        let mut swtxs = 0u32;
+        let mut taproottxs = 0u32;
+        let mut utxos = 0i64;
+        // (fee rate, weight)
+        let mut feerates = Vec::new();
+        let mut total_weight = 0u64;
+        let mut previous_utxo_index = 0;
+        for tx in block.txdata.iter() {
+            outs += tx.output.len() as u32;
+            let tx_total_out: u64 = tx.output.iter().map(|output| output.value).sum();
+            utxos += tx.output.iter().filter(|output| !output.script_pubkey.is_provably_unspendable()).count() as i64;
+            let mut taproot = tx.output.iter().any(|output| is_p2tr(&output.script_pubkey));
+            if tx.is_coin_base() {
+                if taproot {
+                    taproottxs += 1;
+                }
+                continue;
+            }
+            let mut tx_total_in = 0u64;
+            for _vin in tx.input.iter() {
+                let previous_utxo = &previous_utxos[previous_utxo_index];
+                tx_total_in += previous_utxo.value;
+                taproot |= is_p2tr(&previous_utxo.script_pubkey);
+                previous_utxo_index += 1;
+            }
+            ins += tx.input.len() as u32;
+            total_in += tx_total_in;
+            total_out += tx_total_out;
+            if taproot {
+                taproottxs += 1;
+            }
+            if tx.input.iter().any(|vin| !vin.witness.is_empty()) {
+                swtxs += 1;
+            }
+            let fee = tx_total_in.saturating_sub(tx_total_out);
+            totalfee += fee;
+            let weight = tx.get_weight() as u64;
+            feerates.push((fee * WITNESS_SCALE_FACTOR / weight, weight));
+            total_weight += weight;
+        }
+        feerates.sort_unstable();
+        Self {
+            height,
+            txs: block.txdata.len() as u32,
+            ins,
+            outs,
+            total_in,
+            total_out,
+            totalfee,
+            subsidy: get_block_subsidy(height, config),
+            minfeerate: feerates.first().map_or(0, |(feerate, _weight)| *feerate),
+            maxfeerate: feerates.last().map_or(0, |(feerate, _weight)| *feerate),
+            feerate_percentiles: Self::percentiles_by_weight(&feerates, total_weight),
+            swtxs,
+            taproottxs,
+            utxo_increase: utxos - ins as i64,
+        }
+    }
+    /// Compute weighted percentiles in the same manner as Bitcoin Core (`feerates` should be sorted).
+    fn percentiles_by_weight(feerates: &[(u64, u64)], total_weight: u64) -> [u64; 5] {
+        let mut result = [0u64; 5];
+        if feerates.is_empty() {
+            return result;
+        }
+        let mut next = 0;
+        let mut cumulative_weight = 0u64;
+        for (feerate, weight) in feerates.iter() {
+            cumulative_weight += weight;
+            while next < FEERATE_PERCENTILES.len() && cumulative_weight * 100 >= total_weight * FEERATE_PERCENTILES[next] {
+                result[next] = *feerate;
+                next += 1;
+            }
+        }
+        // Fill the remaining percentiles with the last value.
+        for r in result.iter_mut().skip(next) {
+            *r = feerates.last().unwrap().0;
+        }
+        result
+    }
+}
+
+impl Serialize for BlockStatsDBValue {
+    fn serialize(&self) -> Vec<u8> {
+        let mut buf = Vec::new();
+        write_u32(&mut buf, self.height);
+        write_u32(&mut buf, self.txs);
+        write_u32(&mut buf, self.ins);
+        write_u32(&mut buf, self.outs);
+        write_u64(&mut buf, self.total_in);
+        write_u64(&mut buf, self.total_out);
+        write_u64(&mut buf, self.totalfee);
+        write_u64(&mut buf, self.subsidy);
+        write_u64(&mut buf, self.minfeerate);
+        write_u64(&mut buf, self.maxfeerate);
+        for feerate in self.feerate_percentiles.iter() {
+            write_u64(&mut buf, *feerate);
+        }
+        write_u32(&mut buf, self.swtxs);
+        write_u32(&mut buf, self.taproottxs);
+        write_u64(&mut buf, self.utxo_increase as u64);
+        buf
+    }
+}
+
+impl Deserialize for BlockStatsDBValue {
+    fn deserialize(buf: &[u8]) -> Self {
+        let mut r = buf;
+        let height = read_u32(&mut r);
+        let txs = read_u32(&mut r);
+        let ins = read_u32(&mut r);
+        let outs = read_u32(&mut r);
+        let total_in = read_u64(&mut r);
+        let total_out = read_u64(&mut r);
+        let totalfee = read_u64(&mut r);
+        let subsidy = read_u64(&mut r);
+        let minfeerate = read_u64(&mut r);
+        let maxfeerate = read_u64(&mut r);
+        let mut feerate_percentiles = [0u64; 5];
+        for feerate in feerate_percentiles.iter_mut() {
+            *feerate = read_u64(&mut r);
+        }
+        let swtxs = read_u32(&mut r);
+        let taproottxs = read_u32(&mut r);
+        let utxo_increase = read_u64(&mut r) as i64;
+        Self {
+            height,
+            txs,
+            ins,
+            outs,
+            total_in,
+            total_out,
+            totalfee,
+            subsidy,
+            minfeerate,
+            maxfeerate,
+            feerate_percentiles,
+            swtxs,
+            taproottxs,
+            utxo_increase,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct BlockStatsDB {
+    /// Stores (block_hash, BlockStatsDBValue).
+    db: RocksDB<BlockHashDBValue, BlockStatsDBValue>,
+    config: Config,
+}
+
+impl BlockStatsDB {
+    pub fn path(coin: &str) -> String {
+        format!("{}/{}/block_stats", data_dir(), coin)
+    }
+    pub fn new(coin: &str, config: &Config, temporary: bool) -> Self {
+        let path = Self::path(coin);
+        Self {
+            db: RocksDB::new(&path, temporary),
+            config: config.clone(),
+        }
+    }
+    pub fn get(&self, block_hash: &BlockHash) -> Option<BlockStatsDBValue> {
+        self.db.get(&BlockHashDBValue { block_hash: *block_hash })
+    }
+    pub fn process_block(&self, height: u32, block: &Block, previous_utxos: &[UtxoEntry]) {
+        let value = BlockStatsDBValue::new(height, block, previous_utxos, &self.config);
+        self.db.put(&BlockHashDBValue { block_hash: block.block_hash() }, &value);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::db::utxo::UtxoDB;
+    use super::*;
+    #[test]
+    fn block_stats_db() {
+        let block_stats_db = BlockStatsDB::new("test/block_stats", &config_example("rbtc"), true);
+        let mut utxo_db = UtxoDB::new("test/block_stats", true);
+        let blocks = fixtures::regtest_blocks();
+        for (height, block) in blocks.iter().enumerate() {
+            let prev_utxos = utxo_db.process_block(height as u32, &block, false);
+            block_stats_db.process_block(height as u32, &block, &prev_utxos);
+        }
+        let stats = block_stats_db.get(&blocks[102].block_hash()).unwrap();
+        assert_eq!(stats, BlockStatsDBValue {
+            height: 102,
+            txs: 3,
+            ins: 3,
+            outs: 6,
+            total_in: 5_000_000_000 + 4_899_985_900 + 100_000_000,
+            total_out: 4_899_985_900 + 100_000_000 + 4_900_000_000 + 99_965_100,
+            totalfee: 14_100 + 20_800,
+            subsidy: 5_000_000_000,
+            minfeerate: 100,
+            maxfeerate: 100,
+            feerate_percentiles: [100, 100, 100, 100, 100],
+            swtxs: 2,
+            taproottxs: 0,
+            utxo_increase: 2,
+        });
+        assert_eq!(BlockStatsDBValue::deserialize(&stats.serialize()), stats);
+        // Blocks with only a coinbase transaction.
+        let stats = block_stats_db.get(&blocks[1].block_hash()).unwrap();
+        assert_eq!(stats.txs, 1);
+        assert_eq!(stats.ins, 0);
+        assert_eq!(stats.totalfee, 0);
+        assert_eq!(stats.feerate_percentiles, [0; 5]);
+        assert_eq!(block_stats_db.get(&fixtures::regtest_reorged_block().block_hash()), None);
+        // The subsidy follows the schedule even if the coinbase transaction claims less.
+        let mut block = blocks[1].clone();
+        block.txdata[0].output[0].value = 0;
+        assert_eq!(BlockStatsDBValue::new(1, &block, &[], &config_example("rbtc")).subsidy, 5_000_000_000);
+    }
+    #[test]
+    fn percentiles_by_weight() {
+        let feerates = [(1, 100), (2, 100), (3, 100), (4, 700)];
+        assert_eq!(BlockStatsDBValue::percentiles_by_weight(&feerates, 1000), [1, 3, 4, 4, 4]);
+    }
+}
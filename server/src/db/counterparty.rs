@@ -0,0 +1,164 @@
+use crate::*;
+use bitcoin::{Block, Txid};
+use crate::rocks_db::{Serialize, Deserialize, ConstantSize, Empty};
+use crate::counterparty::CounterpartyMessage;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CounterpartyDBKey {
+    pub asset_id: u64,                      // + 8 =  8.
+    pub height: u32,                        // + 4 = 12.
+    pub txid: Txid,                         // +32 = 44.
+}
+
+impl ConstantSize for CounterpartyDBKey {
+    const LEN: usize = 44;
+}
+
+impl Serialize for CounterpartyDBKey {
+    fn serialize(&self) -> Vec<u8> {
+        [
+            &self.asset_id.to_be_bytes()[..],
+            // Sort from the newest to the oldest.
+            &(u32::MAX - self.height).to_be_bytes(),
+            &consensus_encode(&self.txid),
+        ].concat()
+    }
+}
+
+impl Deserialize for CounterpartyDBKey {
+    fn deserialize(buf: &[u8]) -> Self {
+        let mut asset_id = [0u8; 8];
+        asset_id.copy_from_slice(&buf[0..8]);
+        let mut height = [0u8; 4];
+        height.copy_from_slice(&buf[8..12]);
+        let txid = consensus_decode(&buf[12..44]);
+        Self {
+            asset_id: u64::from_be_bytes(asset_id),
+            height: u32::MAX - u32::from_be_bytes(height),
+            txid,
+        }
+    }
+}
+
+/// The database which indexes Counterparty transactions by the assets they refer to.
+#[derive(Debug)]
+pub struct CounterpartyDB {
+    db: RocksDB<CounterpartyDBKey, Empty>,
+    config: Config,
+}
+
+impl CounterpartyDB {
+    pub fn get_path(coin: &str) -> String {
+        format!("{}/{}/counterparty", data_dir(), coin)
+    }
+    pub fn new(coin: &str, config: &Config, temporary: bool) -> Self {
+        let path = Self::get_path(coin);
+        Self {
+            db: RocksDB::new(&path, temporary),
+            config: config.clone(),
+        }
+    }
+    /// Returns at most `limit` (height, txid) pairs of transactions which refer to the asset (from the newest to the oldest).
+    /// If `after` (the height and the txid of the last entry of the previous page) is given,
+    /// the result starts from the entry right after it.
+    pub fn get_after(&self, asset_id: u64, after: Option<(u32, Txid)>, limit: usize) -> Vec<(u32, Txid)> {
+        let prefix = asset_id.to_be_bytes().to_vec();
+        let iter = match after {
+            Some((height, txid)) => {
+                let key = CounterpartyDBKey {
+                    asset_id,
+                    height,
+                    txid,
+                };
+                // Appending a zero byte yields the smallest key which is greater than `key`.
+                self.db.prefix_iter_from(prefix, [key.serialize(), vec![0u8]].concat())
+            },
+            None => self.db.prefix_iter(prefix),
+        };
+        iter.take(limit).map(|(key, _value)| (key.height, key.txid)).collect()
+    }
+    fn keys(&self, height: u32, block: &Block) -> Vec<CounterpartyDBKey> {
+        let mut keys = Vec::new();
+        for tx in block.txdata.iter() {
+            let message = match CounterpartyMessage::from_tx(tx, &self.config) {
+                Some(message) => message,
+                None => continue,
+            };
+            let txid = tx.txid();
+            for asset_id in message.asset_ids() {
+                keys.push(CounterpartyDBKey { asset_id, height, txid });
+            }
+        }
+        keys
+    }
+    pub fn process_block(&self, height: u32, block: &Block) {
+        let mut batch = rocks_db::WriteBatch::<CounterpartyDBKey, Empty>::default();
+        for key in self.keys(height, block).iter() {
+            batch.put(key, &Empty {});
+        }
+        self.db.write(batch).unwrap();
+    }
+    /// Revert `process_block()` for the block disconnected by a reorg.
+    pub fn reorg_block(&self, height: u32, block: &Block) {
+        let mut batch = rocks_db::WriteBatch::<CounterpartyDBKey, Empty>::default();
+        for key in self.keys(height, block).iter() {
+            batch.delete(key);
+        }
+        self.db.write(batch).unwrap();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bitcoin::{OutPoint, TxIn, TxOut};
+    use crate::counterparty::encode_op_return;
+    use super::*;
+    #[test]
+    fn key() {
+        let key = CounterpartyDBKey {
+            asset_id: 0x1234,
+            height: 102,
+            txid: Default::default(),
+        };
+        let buf = key.serialize();
+        assert_eq!(buf.len(), CounterpartyDBKey::LEN);
+        assert_eq!(CounterpartyDBKey::deserialize(&buf), key);
+    }
+    #[test]
+    fn counterparty_db() {
+        let config = config_example("rbtc");
+        let counterparty_db = CounterpartyDB::new("test/counterparty", &config, true);
+        let mut blocks = fixtures::regtest_blocks();
+        // The regtest chain has no Counterparty transactions.
+        for (height, block) in blocks.iter().enumerate() {
+            counterparty_db.process_block(height as u32, block);
+        }
+        assert!(counterparty_db.get_after(1, None, usize::MAX).is_empty());
+        // Orders of XCP (the asset id one) for the asset id 0x1234 in the following blocks.
+        let order = [&[10u8][..], &1u64.to_be_bytes(), &100u64.to_be_bytes(), &0x1234u64.to_be_bytes(), &200u64.to_be_bytes()].concat();
+        let mut expected = Vec::new();
+        for (i, block) in blocks.iter_mut().enumerate().skip(1).take(3) {
+            let previous_output = OutPoint { txid: block.txdata[0].txid(), vout: 0 };
+            let tx = bitcoin::Transaction {
+                version: 1,
+                lock_time: 0,
+                input: vec![TxIn { previous_output, script_sig: Script::new(), sequence: 0xffffffff, witness: vec![] }],
+                output: vec![TxOut { value: 0, script_pubkey: encode_op_return(&previous_output.txid, b"CNTRPRTY", &order) }],
+            };
+            let height = (1000 + i) as u32;
+            expected.insert(0, (height, tx.txid()));
+            block.txdata.push(tx);
+            counterparty_db.process_block(height, block);
+        }
+        assert_eq!(counterparty_db.get_after(1, None, usize::MAX), expected);
+        assert_eq!(counterparty_db.get_after(0x1234, None, usize::MAX), expected);
+        assert!(counterparty_db.get_after(0x1235, None, usize::MAX).is_empty());
+        // Pagination.
+        assert_eq!(counterparty_db.get_after(1, None, 2), expected[0..2].to_vec());
+        assert_eq!(counterparty_db.get_after(1, Some(expected[1]), 2), expected[2..].to_vec());
+        // Reorg the last block.
+        counterparty_db.reorg_block(1003, &blocks[3]);
+        assert_eq!(counterparty_db.get_after(1, None, usize::MAX), expected[1..].to_vec());
+        assert_eq!(counterparty_db.get_after(0x1234, None, usize::MAX), expected[1..].to_vec());
+    }
+}
@@ -0,0 +1,289 @@
+use crate::*;
+use bitcoin::{Block, Txid};
+use crate::rocks_db::{Serialize, Deserialize};
+use crate::db::block::BlockDB;
+use crate::db::tx::TxDB;
+
+/// The maximum number of leading bytes of OP_RETURN data which are indexed (the standard size of OP_RETURN data).
+pub const OP_RETURN_PREFIX_LEN: usize = 80;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OpReturnDBKey {
+    /// The first `OP_RETURN_PREFIX_LEN` bytes of data.
+    pub prefix: Vec<u8>,
+    pub height: u32,
+    pub txid: Txid,
+    pub vout: u32,
+}
+
+impl OpReturnDBKey {
+    pub fn new(data: &[u8], height: u32, txid: Txid, vout: u32) -> Self {
+        Self {
+            prefix: data[..data.len().min(OP_RETURN_PREFIX_LEN)].to_vec(),
+            height,
+            txid,
+            vout,
+        }
+    }
+}
+
+/// Escape zero bytes (0x00 -> 0x00 0xff) so that the prefix is terminated by 0x00 0x00.
+///
+/// The escaped bytes keep the order of the original bytes, and the escaped form of `a` is a prefix of that of `b`
+/// if and only if `a` is a prefix of `b`, which allows exact prefix searches.
+fn escape(data: &[u8]) -> Vec<u8> {
+    let mut buf = Vec::with_capacity(data.len() + 2);
+    for byte in data.iter() {
+        buf.push(*byte);
+        if *byte == 0 {
+            buf.push(0xff);
+        }
+    }
+    buf
+}
+
+impl Serialize for OpReturnDBKey {
+    fn serialize(&self) -> Vec<u8> {
+        [
+            &escape(&self.prefix)[..],
+            &[0u8, 0u8],
+            // Sort from the newest to the oldest.
+            &(u32::MAX - self.height).to_be_bytes(),
+            &consensus_encode(&self.txid),
+            &self.vout.to_be_bytes(),
+        ].concat()
+    }
+}
+
+impl Deserialize for OpReturnDBKey {
+    fn deserialize(buf: &[u8]) -> Self {
+        // Unescape the prefix until the terminator (0x00 0x00).
+        let mut prefix = Vec::new();
+        let mut i = 0;
+        while !(buf[i] == 0 && buf[i + 1] == 0) {
+            prefix.push(buf[i]);
+            i += if buf[i] == 0 { 2 } else { 1 };
+        }
+        let buf = &buf[i + 2..];
+        let mut height = [0u8; 4];
+        height.copy_from_slice(&buf[0..4]);
+        let txid = consensus_decode(&buf[4..36]);
+        let mut vout = [0u8; 4];
+        vout.copy_from_slice(&buf[36..40]);
+        Self {
+            prefix,
+            height: u32::MAX - u32::from_be_bytes(height),
+            txid,
+            vout: u32::from_be_bytes(vout),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OpReturnDBValue {
+    pub script_pubkey: Script,
+}
+
+impl Serialize for OpReturnDBValue {
+    fn serialize(&self) -> Vec<u8> {
+        self.script_pubkey.to_bytes()
+    }
+}
+
+impl Deserialize for OpReturnDBValue {
+    fn deserialize(buf: &[u8]) -> Self {
+        Self {
+            script_pubkey: Script::from(buf.to_vec()),
+        }
+    }
+}
+
+/// The database which indexes OP_RETURN outputs by the leading bytes of their data.
+#[derive(Debug)]
+pub struct OpReturnDB {
+    coin: String,
+    db: RocksDB<OpReturnDBKey, OpReturnDBValue>,
+    synced_height: Option<u32>,
+}
+
+impl OpReturnDB {
+    pub fn get_path(coin: &str) -> String {
+        format!("{}/{}/op_return", data_dir(), coin)
+    }
+    fn synced_height_path(coin: &str) -> String {
+        format!("{}.synced_height", Self::get_path(coin))
+    }
+    pub fn new(coin: &str, temporary: bool) -> Self {
+        let path = Self::get_path(coin);
+        let db = RocksDB::new(&path, temporary);
+        let synced_height = if temporary {
+            None
+        } else {
+            std::fs::read_to_string(Self::synced_height_path(coin)).ok().and_then(|s| s.parse().ok())
+        };
+        Self {
+            coin: coin.to_string(),
+            db,
+            synced_height,
+        }
+    }
+    /// The height up to which the blocks are indexed (None if no block is indexed yet).
+    pub fn synced_height(&self) -> Option<u32> {
+        self.synced_height
+    }
+    fn set_synced_height(&mut self, height: Option<u32>) {
+        match height {
+            Some(height) => std::fs::write(Self::synced_height_path(&self.coin), height.to_string()).unwrap(),
+            None => { let _ = std::fs::remove_file(Self::synced_height_path(&self.coin)); },
+        }
+        self.synced_height = height;
+    }
+    /// Returns at most `limit` OP_RETURN outputs whose data start with `prefix` (at most `OP_RETURN_PREFIX_LEN` bytes).
+    ///
+    /// The entries are sorted by the first `OP_RETURN_PREFIX_LEN` bytes of data, and then from the newest to the oldest.
+    /// If `after` (the key of the last entry of the previous page) is given, the result starts from the entry right after it.
+    pub fn get_after(&self, prefix: &[u8], after: Option<&OpReturnDBKey>, limit: usize) -> Vec<(OpReturnDBKey, OpReturnDBValue)> {
+        assert!(prefix.len() <= OP_RETURN_PREFIX_LEN);
+        let key_prefix = escape(prefix);
+        let iter = match after {
+            // Appending a zero byte yields the smallest key which is greater than `after`.
+            Some(after) => self.db.prefix_iter_from(key_prefix, [after.serialize(), vec![0u8]].concat()),
+            None => self.db.prefix_iter(key_prefix),
+        };
+        iter.take(limit).collect()
+    }
+    fn keys(height: u32, block: &Block) -> Vec<(OpReturnDBKey, OpReturnDBValue)> {
+        let mut keys = Vec::new();
+        for tx in block.txdata.iter() {
+            let txid = tx.txid();
+            for (vout, output) in tx.output.iter().enumerate() {
+                if let Some(data) = op_return_data(&output.script_pubkey) {
+                    keys.push((
+                        OpReturnDBKey::new(&data, height, txid, vout as u32),
+                        OpReturnDBValue { script_pubkey: output.script_pubkey.clone() }));
+                }
+            }
+        }
+        keys
+    }
+    pub fn process_block(&mut self, height: u32, block: &Block) {
+        let mut batch = rocks_db::WriteBatch::<OpReturnDBKey, OpReturnDBValue>::default();
+        for (key, value) in Self::keys(height, block).iter() {
+            batch.put(key, value);
+        }
+        self.db.write(batch).unwrap();
+        self.set_synced_height(Some(height));
+    }
+    /// Revert `process_block()` for the block disconnected by a reorg.
+    pub fn reorg_block(&mut self, height: u32, block: &Block) {
+        let mut batch = rocks_db::WriteBatch::<OpReturnDBKey, OpReturnDBValue>::default();
+        for (key, _value) in Self::keys(height, block).iter() {
+            batch.delete(key);
+        }
+        self.db.write(batch).unwrap();
+        self.set_synced_height(height.checked_sub(1));
+    }
+    /// Index the stored blocks which are not indexed yet, up to `height`.
+    pub fn backfill(&mut self, height: u32, block_db: &BlockDB, tx_db: &TxDB) {
+        let start = self.synced_height.map_or(0, |synced_height| synced_height + 1);
+        for h in start..=height {
+            let block_content = block_db.get(h).expect("Failed to get the block.");
+            let (block, _previous_utxos) = tx_db.get_block(&block_content).expect("Failed to get the transactions of the block.");
+            self.process_block(h, &block);
+            if h % 10_000 == 0 {
+                println!("OP_RETURN index backfilled up to height = {}.", to_locale_string(h));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::db::utxo::UtxoDB;
+    use super::*;
+    #[test]
+    fn key() {
+        let key = OpReturnDBKey::new(&[0xaa, 0x00, 0xa9, 0xed, 0x00], 102, Default::default(), 1);
+        assert_eq!(key.prefix, [0xaa, 0x00, 0xa9, 0xed, 0x00]);
+        assert_eq!(OpReturnDBKey::deserialize(&key.serialize()), key);
+        assert_eq!(OpReturnDBKey::new(&[0x01; 100], 0, Default::default(), 0).prefix, [0x01; OP_RETURN_PREFIX_LEN]);
+        // The escaped prefix of the data [0x01] is not a prefix of the key of [0x01, 0x00] and vice versa.
+        let key = OpReturnDBKey::new(&[0x01], 0, Default::default(), 0).serialize();
+        assert!(!key.starts_with(&escape(&[0x01, 0x00])));
+        let key = OpReturnDBKey::new(&[0x01, 0x00], 0, Default::default(), 0).serialize();
+        assert!(key.starts_with(&escape(&[0x01])));
+        assert!(key.starts_with(&escape(&[0x01, 0x00])));
+    }
+    #[test]
+    fn op_return_db() {
+        let mut op_return_db = OpReturnDB::new("test/op_return", true);
+        let blocks = fixtures::regtest_blocks();
+        for (height, block) in blocks.iter().enumerate() {
+            op_return_db.process_block(height as u32, block);
+        }
+        assert_eq!(op_return_db.synced_height(), Some(blocks.len() as u32 - 1));
+        // Witness commitments.
+        let commitment = [0xaa, 0x21, 0xa9, 0xed];
+        let commitments = blocks.iter().enumerate().flat_map(|(height, block)| {
+            block.txdata[0].output.iter().enumerate()
+                .filter(|(_vout, output)| matches!(op_return_data(&output.script_pubkey), Some(data) if data.starts_with(&commitment)))
+                .map(move |(vout, _output)| (height as u32, block.txdata[0].txid(), vout as u32))
+        }).collect::<Vec<_>>();
+        assert!(!commitments.is_empty());
+        let entries = op_return_db.get_after(&commitment, None, usize::MAX);
+        assert_eq!(entries.len(), commitments.len());
+        for (key, value) in entries.iter() {
+            assert!(commitments.contains(&(key.height, key.txid, key.vout)));
+            assert_eq!(op_return_protocol(&value.script_pubkey), Some("witness_commitment"));
+        }
+        // The whole data.
+        let data = op_return_data(&entries[0].1.script_pubkey).unwrap();
+        assert_eq!(op_return_db.get_after(&data, None, usize::MAX), entries[0..1].to_vec());
+        // Longer than the data.
+        assert!(op_return_db.get_after(&[data, vec![0x00]].concat(), None, usize::MAX).is_empty());
+        // Not found.
+        assert!(op_return_db.get_after(&[0xaa, 0x21, 0xa9, 0xee], None, usize::MAX).is_empty());
+        // Walk through the pages.
+        let mut paged = Vec::new();
+        let mut after = None;
+        loop {
+            let page = op_return_db.get_after(&commitment[0..2], after.as_ref(), 2);
+            assert!(page.len() <= 2);
+            if page.is_empty() {
+                break;
+            }
+            after = Some(page.last().unwrap().0.clone());
+            paged.extend(page);
+        }
+        assert_eq!(paged, entries);
+        // Reorg the last block.
+        let height = blocks.len() as u32 - 1;
+        op_return_db.reorg_block(height, blocks.last().unwrap());
+        assert_eq!(op_return_db.synced_height(), Some(height - 1));
+        let entries = entries.into_iter().filter(|(key, _value)| key.height != height).collect::<Vec<_>>();
+        assert_eq!(op_return_db.get_after(&commitment, None, usize::MAX), entries);
+    }
+    #[test]
+    fn op_return_db_backfill() {
+        let mut op_return_db = OpReturnDB::new("test/op_return_backfill", true);
+        let mut expected = OpReturnDB::new("test/op_return_backfill/expected", true);
+        let block_db = BlockDB::new("test/op_return_backfill", true);
+        let tx_db = TxDB::new("test/op_return_backfill", true);
+        let mut utxo_db = UtxoDB::new("test/op_return_backfill", true);
+        let blocks = fixtures::regtest_blocks();
+        for (height, block) in blocks.iter().enumerate() {
+            let prev_utxos = utxo_db.process_block(height as u32, block, false);
+            tx_db.process_block(height as u32, block, &prev_utxos);
+            block_db.put(height as u32, block);
+            expected.process_block(height as u32, block);
+            // Only index the first blocks.
+            if height < 10 {
+                op_return_db.process_block(height as u32, block);
+            }
+        }
+        let synced_height = blocks.len() as u32 - 1;
+        op_return_db.backfill(synced_height, &block_db, &tx_db);
+        assert_eq!(op_return_db.synced_height(), Some(synced_height));
+        assert_eq!(op_return_db.get_after(&[], None, usize::MAX), expected.get_after(&[], None, usize::MAX));
+    }
+}
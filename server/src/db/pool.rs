@@ -0,0 +1,236 @@
+use std::collections::BTreeMap;
+use crate::*;
+use bitcoin::{Block, BlockHash};
+use crate::db::block::BlockHashDBValue;
+use crate::rocks_db::{Serialize, Deserialize};
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
+pub struct PoolInfo {
+    pub name: String,
+    pub link: Option<String>,
+}
+
+/// Mining pool definitions (in the same format as the community maintained `pools.json`).
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Deserialize)]
+pub struct PoolDefinitions {
+    /// Maps a tag included in coinbase scriptSig to a pool.
+    #[serde(default)]
+    pub coinbase_tags: BTreeMap<String, PoolInfo>,
+    /// Maps a payout address of coinbase outputs to a pool.
+    #[serde(default)]
+    pub payout_addresses: BTreeMap<String, PoolInfo>,
+}
+
+impl std::str::FromStr for PoolDefinitions {
+    type Err = serde_json::Error;
+    fn from_str(json: &str) -> Result<Self, Self::Err> {
+        serde_json::from_str(json)
+    }
+}
+
+impl PoolDefinitions {
+    /// Load the file specified by `pools_file` in the config, or the bundled definitions if not specified.
+    pub fn load(config: &Config) -> Self {
+        match &config.pools_file {
+            Some(path) => {
+                let json = std::fs::read_to_string(path).expect("Failed to read pool definitions file.");
+                json.parse().expect("Failed to parse pool definitions file.")
+            },
+            None => include_str!("../../pools.json").parse().expect("Failed to parse bundled pool definitions."),
+        }
+    }
+    /// Find the pool which mined the block.
+    /// Payout addresses take priority over coinbase tags, and the longest matching tag wins.
+    pub fn find(&self, coinbase_script_sig: &[u8], payout_address: Option<&str>) -> Option<&PoolInfo> {
+        if let Some(pool) = payout_address.and_then(|address| self.payout_addresses.get(address)) {
+            return Some(pool);
+        }
+        // Tags are matched against the raw bytes, so that non-ASCII (UTF-8) tags match too.
+        self.coinbase_tags.iter()
+            .filter(|(tag, _pool)| !tag.is_empty() && coinbase_script_sig.windows(tag.len()).any(|window| window == tag.as_bytes()))
+            .max_by_key(|(tag, _pool)| tag.len())
+            .map(|(_tag, pool)| pool)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PoolDBValue {
+    /// The raw coinbase scriptSig, which pools are matched against.
+    pub coinbase_script_sig: Vec<u8>,
+    /// Printable ASCII characters in the coinbase scriptSig (for display, not stored).
+    pub coinbase_tag: String,
+    /// The address of the first coinbase output which has one.
+    pub payout_address: Option<String>,
+    /// Resolved by `PoolDB::get()` from the current pool definitions (not stored).
+    pub pool: Option<PoolInfo>,
+}
+
+fn coinbase_tag(coinbase_script_sig: &[u8]) -> String {
+    coinbase_script_sig.iter()
+        .filter(|c| (0x20..0x7f).contains(*c))
+        .map(|c| *c as char)
+        .collect()
+}
+
+impl PoolDBValue {
+    pub fn new(block: &Block, pools: &PoolDefinitions, config: &Config) -> Self {
+        let coinbase = &block.txdata[0];
+        let coinbase_script_sig = coinbase.input[0].script_sig.to_bytes();
+        let payout_address = coinbase.output.iter()
+            .find_map(|output| script_to_address_string(&output.script_pubkey, config));
+        let pool = pools.find(&coinbase_script_sig, payout_address.as_deref()).cloned();
+        Self {
+            coinbase_tag: coinbase_tag(&coinbase_script_sig),
+            coinbase_script_sig,
+            payout_address,
+            pool,
+        }
+    }
+}
+
+fn write_str(buf: &mut Vec<u8>, s: &str) {
+    write_u32(buf, s.len() as u32);
+    write_arr(buf, s.as_bytes());
+}
+
+fn read_str(r: &mut &[u8]) -> String {
+    let len = read_u32(r) as usize;
+    String::from_utf8(read_vec(r, len)).expect("Failed to decode string.")
+}
+
+/// Empty strings are used to represent `None` (a valid name, link or address is never empty).
+fn write_opt_str(buf: &mut Vec<u8>, s: &Option<String>) {
+    write_str(buf, s.as_deref().unwrap_or(""));
+}
+
+fn read_opt_str(r: &mut &[u8]) -> Option<String> {
+    Some(read_str(r)).filter(|s| !s.is_empty())
+}
+
+impl Serialize for PoolDBValue {
+    fn serialize(&self) -> Vec<u8> {
+        let mut buf = Vec::new();
+        write_u32(&mut buf, self.coinbase_script_sig.len() as u32);
+        write_arr(&mut buf, &self.coinbase_script_sig);
+        write_opt_str(&mut buf, &self.payout_address);
+        buf
+    }
+}
+
+impl Deserialize for PoolDBValue {
+    fn deserialize(buf: &[u8]) -> Self {
+        let mut r = buf;
+        let len = read_u32(&mut r) as usize;
+        let coinbase_script_sig = read_vec(&mut r, len);
+        let payout_address = read_opt_str(&mut r);
+        Self {
+            coinbase_tag: coinbase_tag(&coinbase_script_sig),
+            coinbase_script_sig,
+            payout_address,
+            pool: None,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct PoolDB {
+    /// Stores (block_hash, PoolDBValue).
+    db: RocksDB<BlockHashDBValue, PoolDBValue>,
+    config: Config,
+    pools: PoolDefinitions,
+}
+
+impl PoolDB {
+    pub fn path(coin: &str) -> String {
+        format!("{}/{}/pool", data_dir(), coin)
+    }
+    pub fn new(coin: &str, config: &Config, temporary: bool) -> Self {
+        let path = Self::path(coin);
+        Self {
+            db: RocksDB::new(&path, temporary),
+            config: config.clone(),
+            pools: PoolDefinitions::load(config),
+        }
+    }
+    /// The pool is resolved at read time, so that updated definitions (`pools_file`) apply to past blocks.
+    pub fn get(&self, block_hash: &BlockHash) -> Option<PoolDBValue> {
+        self.db.get(&BlockHashDBValue { block_hash: *block_hash }).map(|mut value| {
+            value.pool = self.pools.find(&value.coinbase_script_sig, value.payout_address.as_deref()).cloned();
+            value
+        })
+    }
+    pub fn process_block(&self, block: &Block) {
+        let value = PoolDBValue::new(block, &self.pools, &self.config);
+        self.db.put(&BlockHashDBValue { block_hash: block.block_hash() }, &value);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+    use bitcoin::Script;
+    use super::*;
+    const POOLS: &str = r#"{
+        "coinbase_tags": {
+            "/slush/": { "name": "Braiins Pool", "link": "https://braiins.com/" },
+            "Mined by AntPool": { "name": "AntPool", "link": "https://www.antpool.com/" },
+            "AntPool": { "name": "AntPool (short)" },
+            "七彩神仙鱼": { "name": "F2Pool", "link": "https://www.f2pool.com/" }
+        },
+        "payout_addresses": {
+            "1KFHE7w8BhaENAswwryaoccDb6qcT6DbYY": { "name": "F2Pool", "link": "https://www.f2pool.com/" }
+        }
+    }"#;
+    #[test]
+    fn bundled_pool_definitions() {
+        let pools = PoolDefinitions::load(&config_example("btc"));
+        assert!(!pools.coinbase_tags.is_empty());
+        assert!(!pools.payout_addresses.is_empty());
+    }
+    #[test]
+    fn find_pool() {
+        let pools = PoolDefinitions::from_str(POOLS).unwrap();
+        assert_eq!(pools.find(b"\x03\x01\x02\x03/slush/", None).unwrap().name, "Braiins Pool");
+        // The longest matching tag wins.
+        assert_eq!(pools.find(b"\x03\x01\x02\x03Mined by AntPool", None).unwrap().name, "AntPool");
+        assert_eq!(pools.find(b"\x03\x01\x02\x03AntPool", None).unwrap().link, None);
+        // Non-ASCII tags match the raw bytes.
+        assert_eq!(pools.find("\x03\x01\x02\x03七彩神仙鱼".as_bytes(), None).unwrap().name, "F2Pool");
+        // Fragments separated by binary bytes do not match.
+        assert_eq!(pools.find(b"/sl\x00ush/", None), None);
+        // Payout addresses take priority over coinbase tags.
+        assert_eq!(pools.find(b"/slush/", Some("1KFHE7w8BhaENAswwryaoccDb6qcT6DbYY")).unwrap().name, "F2Pool");
+        assert_eq!(pools.find(b"unknown", Some("1P5ZEDWTKTFGxQjZphgWPQUpe554WKDfHQ")), None);
+        assert!(PoolDefinitions::from_str("{").is_err());
+    }
+    #[test]
+    fn pool_db() {
+        let config = config_example("rbtc");
+        let pool_db = PoolDB::new("test/pool", &config, true);
+        let blocks = fixtures::regtest_blocks();
+        for block in blocks.iter() {
+            pool_db.process_block(&block);
+        }
+        let value = pool_db.get(&blocks[1].block_hash()).unwrap();
+        assert_eq!(value.pool, None);
+        assert_eq!(value.payout_address, script_to_address_string(&blocks[1].txdata[0].output[0].script_pubkey, &config));
+        assert_eq!(pool_db.get(&fixtures::regtest_reorged_block().block_hash()), None);
+        // Attribution by coinbase tag.
+        let mut block = blocks[1].clone();
+        block.txdata[0].input[0].script_sig = Script::from_str("03010203072f736c7573682f").unwrap();
+        let value = PoolDBValue::new(&block, &PoolDefinitions::from_str(POOLS).unwrap(), &config);
+        assert_eq!(value.coinbase_script_sig, hex::decode("03010203072f736c7573682f").unwrap());
+        assert_eq!(value.coinbase_tag, "/slush/");
+        assert_eq!(value.pool.as_ref().unwrap().name, "Braiins Pool");
+        assert_eq!(PoolDBValue::deserialize(&value.serialize()), PoolDBValue { pool: None, ..value.clone() });
+        // Changing the definitions re-attributes the stored blocks.
+        let mut pool_db = PoolDB::new("test/pool_reattribution", &config, true);
+        pool_db.pools = PoolDefinitions::default();
+        pool_db.process_block(&block);
+        assert_eq!(pool_db.get(&block.block_hash()).unwrap().pool, None);
+        pool_db.pools = PoolDefinitions::from_str(POOLS).unwrap();
+        assert_eq!(pool_db.get(&block.block_hash()).unwrap().pool, value.pool);
+        let value = PoolDBValue { coinbase_script_sig: vec![], coinbase_tag: "".to_string(), payout_address: None, pool: None };
+        assert_eq!(PoolDBValue::deserialize(&value.serialize()), value);
+    }
+}
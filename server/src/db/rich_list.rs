@@ -1,8 +1,11 @@
 use crate::*;
 use std::mem::size_of;
+use std::collections::hash_map::RandomState;
+use std::hash::BuildHasher;
 use core::ops::Range;
 use indexmap::IndexMap;
 use bitcoin::{Block, Script};
+use crate::rocks_db::{Serialize, Deserialize, Empty, RocksDBPrefixIterator};
 use crate::db::utxo::UtxoEntry;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -11,9 +14,585 @@ pub struct RichListEntry {
     pub value: u64,
 }
 
+/// The lower bounds of the balance buckets (in satoshis): < 0.001, 0.001 - 0.01, ..., >= 1,000,000 coins.
+pub const RICH_LIST_BUCKETS: [u64; 11] = [
+    0,
+    100_000,
+    1_000_000,
+    10_000_000,
+    100_000_000,
+    1_000_000_000,
+    10_000_000_000,
+    100_000_000_000,
+    1_000_000_000_000,
+    10_000_000_000_000,
+    100_000_000_000_000,
+];
+
+fn bucket_index(value: u64) -> usize {
+    RICH_LIST_BUCKETS.iter().rposition(|min| value >= *min).unwrap()
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RichListBucket {
+    /// The number of addresses whose balance falls in the bucket.
+    pub count: u32,
+    /// The sum of the balances in the bucket.
+    pub value: u64,
+}
+
+/// Public key hashes of well-known P2PKH addresses which nobody can spend from.
+const BURN_PUBKEY_HASHES: [[u8; 20]; 3] = [
+    // 1CounterpartyXXXXXXXXXXXXXXXUWLpVr.
+    [0x81, 0x88, 0x95, 0xf3, 0xdc, 0x2c, 0x17, 0x86, 0x29, 0xd3, 0xd2, 0xd8, 0xfa, 0x3e, 0xc4, 0xa3, 0xf8, 0x17, 0x98, 0x21],
+    // 1BitcoinEaterAddressDontSendf59kuE.
+    [0x75, 0x9d, 0x66, 0x77, 0x09, 0x1e, 0x97, 0x3b, 0x9e, 0x9d, 0x99, 0xf1, 0x9c, 0x68, 0xfb, 0xf4, 0x3e, 0x3f, 0x05, 0xf9],
+    // 1111111111111111111114oLvT2.
+    [0x00; 20],
+];
+
+/// Returns true if `script_pubkey` is provably unspendable or a well-known burn address.
+pub fn is_burn_script(script_pubkey: &Script) -> bool {
+    if script_pubkey.is_p2pkh() {
+        let pubkey_hash = &script_pubkey.as_bytes()[3..23];
+        return BURN_PUBKEY_HASHES.iter().any(|burn| burn[..] == *pubkey_hash);
+    }
+    is_unspendable(script_pubkey)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RichListScriptType {
+    P2pkh,
+    P2sh,
+    P2wpkh,
+    P2wsh,
+    P2tr,
+}
+
+impl RichListScriptType {
+    const ALL: [Self; 5] = [Self::P2pkh, Self::P2sh, Self::P2wpkh, Self::P2wsh, Self::P2tr];
+    fn matches(&self, script_pubkey: &Script) -> bool {
+        match self {
+            Self::P2pkh  => script_pubkey.is_p2pkh(),
+            Self::P2sh   => script_pubkey.is_p2sh(),
+            Self::P2wpkh => script_pubkey.is_v0_p2wpkh(),
+            Self::P2wsh  => script_pubkey.is_v0_p2wsh(),
+            Self::P2tr   => is_p2tr(script_pubkey),
+        }
+    }
+}
+
+/// Parse the script type in the same notation as `ScriptPubKey.type` of the REST API.
+impl std::str::FromStr for RichListScriptType {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "pubkeyhash"         => Ok(Self::P2pkh),
+            "scripthash"         => Ok(Self::P2sh),
+            "witnesspubkeyhash"  => Ok(Self::P2wpkh),
+            "witnessscripthash"  => Ok(Self::P2wsh),
+            "witness_v1_taproot" => Ok(Self::P2tr),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RichListFilter {
+    /// Only include scripts of the type.
+    pub script_type: Option<RichListScriptType>,
+    /// Only include scripts which can be represented as an address.
+    pub address_only: bool,
+    /// Exclude unspendable scripts and well-known burn addresses.
+    pub exclude_burn: bool,
+}
+
+/// Scripts are partitioned into categories of (script type, burn or not), and filters select a union of categories.
+/// Script types are `RichListScriptType::ALL`, followed by other scripts with an address and ones without an address.
+const CATEGORIES: usize = (RichListScriptType::ALL.len() + 2) * 2;
+const OTHER_ADDRESS: usize = RichListScriptType::ALL.len();
+
+/// P2PK outputs are counted under their P2PKH script so that each address has a single entry.
+pub fn entry_script(script_pubkey: &Script) -> std::borrow::Cow<'_, Script> {
+    match p2pk_to_p2pkh(script_pubkey) {
+        Some(p2pkh) => std::borrow::Cow::Owned(p2pkh),
+        None => std::borrow::Cow::Borrowed(script_pubkey),
+    }
+}
+
+fn category_of(script_pubkey: &Script) -> usize {
+    let script_type = match RichListScriptType::ALL.iter().position(|t| t.matches(script_pubkey)) {
+        Some(i) => i,
+        None if script_pubkey.is_witness_program() => OTHER_ADDRESS,
+        None => OTHER_ADDRESS + 1,
+    };
+    script_type * 2 + is_burn_script(script_pubkey) as usize
+}
+
+impl RichListFilter {
+    /// Returns the categories matching the filter.
+    fn categories(&self) -> Vec<usize> {
+        (0..CATEGORIES).filter(|category| {
+            let (script_type, burn) = (category / 2, category % 2 == 1);
+            if self.exclude_burn && burn {
+                return false;
+            }
+            match self.script_type {
+                Some(t) => RichListScriptType::ALL.get(script_type) == Some(&t),
+                None => !self.address_only || script_type <= OTHER_ADDRESS,
+            }
+        }).collect()
+    }
+}
+
+/// Represents an empty (sub)tree.
+const NIL: u32 = u32::MAX;
+
+/// A node of a treap (a randomized balanced binary search tree) which is ordered by (value descending, script ascending).
+#[derive(Debug, Clone)]
+struct RichListNode {
+    value: u64,
+    left: u32,
+    right: u32,
+    /// The category of the script (see `category_of()`).
+    category: u8,
+    /// The number of nodes of each category in the subtree.
+    counts: [u32; CATEGORIES],
+}
+
+impl RichListNode {
+    fn new(value: u64, category: usize) -> Self {
+        let mut counts = [0; CATEGORIES];
+        counts[category] = 1;
+        Self {
+            value,
+            left: NIL,
+            right: NIL,
+            category: category as u8,
+            counts,
+        }
+    }
+}
+
+/// The set of categories selected by a filter.
 #[derive(Debug, Clone)]
+struct RichListCategories {
+    categories: Vec<usize>,
+    contains: [bool; CATEGORIES],
+}
+
+impl RichListCategories {
+    fn new(categories: &[usize]) -> Self {
+        let mut contains = [false; CATEGORIES];
+        for category in categories.iter() {
+            contains[*category] = true;
+        }
+        Self {
+            categories: categories.to_vec(),
+            contains,
+        }
+    }
+    fn contains(&self, category: u8) -> bool {
+        self.contains[category as usize]
+    }
+    /// The number of nodes of the categories in the subtree whose counts are `counts`.
+    fn count(&self, counts: &[u32; CATEGORIES]) -> usize {
+        self.categories.iter().map(|category| counts[*category] as usize).sum()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RichListBalanceKey {
+    script_pubkey: Script,
+}
+
+impl Serialize for RichListBalanceKey {
+    fn serialize(&self) -> Vec<u8> {
+        self.script_pubkey.to_bytes()
+    }
+}
+
+impl Deserialize for RichListBalanceKey {
+    fn deserialize(buf: &[u8]) -> Self {
+        Self {
+            script_pubkey: Script::from(buf.to_vec()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RichListBalanceValue {
+    value: u64,
+}
+
+impl Serialize for RichListBalanceValue {
+    fn serialize(&self) -> Vec<u8> {
+        self.value.to_le_bytes().to_vec()
+    }
+}
+
+impl Deserialize for RichListBalanceValue {
+    fn deserialize(buf: &[u8]) -> Self {
+        Self {
+            value: bytes_to_u64(buf),
+        }
+    }
+}
+
+/// Serialized as !value (big endian) || script, so that keys are ordered by (value descending, script ascending).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RichListIndexKey {
+    value: u64,
+    script_pubkey: Script,
+}
+
+impl RichListIndexKey {
+    fn new(script_pubkey: &Script, value: u64) -> Self {
+        Self {
+            value,
+            script_pubkey: script_pubkey.clone(),
+        }
+    }
+}
+
+impl Serialize for RichListIndexKey {
+    fn serialize(&self) -> Vec<u8> {
+        [(!self.value).to_be_bytes().to_vec(), self.script_pubkey.to_bytes()].concat()
+    }
+}
+
+impl Deserialize for RichListIndexKey {
+    fn deserialize(buf: &[u8]) -> Self {
+        let mut value = [0u8; 8];
+        value.copy_from_slice(&buf[0..8]);
+        Self {
+            value: !u64::from_be_bytes(value),
+            script_pubkey: Script::from(buf[8..].to_vec()),
+        }
+    }
+}
+
+/// The number of keys per page of the sorted index (pages are split at the double and merged at the quarter of it).
+#[cfg(not(test))]
+const PAGE_SIZE: u32 = 1024;
+/// Small pages to exercise splits and merges in tests.
+#[cfg(test)]
+const PAGE_SIZE: u32 = 4;
+
+/// A range of the sorted index from `first` up to the first key of the next page.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct RichListPage {
+    /// The serialized lower bound of the keys (the first page starts from the empty key).
+    first: Vec<u8>,
+    /// The number of keys of each category.
+    counts: [u32; CATEGORIES],
+}
+
+impl RichListPage {
+    fn new(first: Vec<u8>) -> Self {
+        Self {
+            first,
+            counts: [0; CATEGORIES],
+        }
+    }
+    fn len(&self) -> u32 {
+        self.counts.iter().sum()
+    }
+    fn count(&self, categories: &[usize]) -> u32 {
+        categories.iter().map(|category| self.counts[*category]).sum()
+    }
+}
+
+/// The disk-backed storage of the rich list used in the low-memory mode.
+///
+/// The sorted index is split into pages whose sizes are kept in memory,
+/// so ranks and offsets are resolved by summing up the sizes and scanning a single page.
+#[derive(Debug)]
+struct RichListDB {
+    path: String,
+    balances: RocksDB<RichListBalanceKey, RichListBalanceValue>,
+    index: RocksDB<RichListIndexKey, Empty>,
+    /// The number of scripts.
+    len: usize,
+    /// The number of indexed scripts of each category.
+    counts: [usize; CATEGORIES],
+    pages: Vec<RichListPage>,
+    /// The height the balances are synced to (`None` if they should be loaded from the UTXO database).
+    synced_height: Option<u32>,
+}
+
+impl RichListDB {
+    fn meta_path(path: &str) -> String {
+        format!("{}/meta", path)
+    }
+    /// Opens the databases and restores the state saved by `save_meta()` if the balances are synced to `synced_height`.
+    /// Otherwise the databases are wiped to be loaded from scratch.
+    fn new(coin: &str, synced_height: Option<u32>, temporary: bool) -> (Self, Vec<RichListBucket>) {
+        let path = format!("{}/{}/rich_list", data_dir(), coin);
+        let meta = match std::fs::read(Self::meta_path(&path)) {
+            Ok(buf) if !temporary => Some(Self::deserialize_meta(&buf)),
+            _ => None,
+        };
+        let meta = meta.filter(|(height, _len, _buckets, _pages)| Some(*height) == synced_height);
+        if meta.is_none() && std::path::Path::new(&path).exists() {
+            std::fs::remove_dir_all(&path).expect("Failed to remove the rich list database.");
+        }
+        let mut db = Self {
+            balances: RocksDB::new(&format!("{}/balance", path), temporary),
+            index: RocksDB::new(&format!("{}/index", path), temporary),
+            path,
+            len: 0,
+            counts: [0; CATEGORIES],
+            pages: vec![RichListPage::new(Vec::new())],
+            synced_height: None,
+        };
+        let buckets = match meta {
+            Some((height, len, buckets, pages)) => {
+                for page in pages.iter() {
+                    for (category, count) in page.counts.iter().enumerate() {
+                        db.counts[category] += *count as usize;
+                    }
+                }
+                db.len = len;
+                db.pages = pages;
+                db.synced_height = Some(height);
+                buckets
+            },
+            None => vec![RichListBucket::default(); RICH_LIST_BUCKETS.len()],
+        };
+        (db, buckets)
+    }
+    /// Serialized as synced_height || len || buckets || pages.
+    fn serialize_meta(&self, buckets: &[RichListBucket]) -> Vec<u8> {
+        let mut buf = Vec::new();
+        write_u32(&mut buf, self.synced_height.unwrap());
+        write_usize(&mut buf, self.len);
+        write_u32(&mut buf, buckets.len() as u32);
+        for bucket in buckets.iter() {
+            write_u32(&mut buf, bucket.count);
+            write_u64(&mut buf, bucket.value);
+        }
+        write_u32(&mut buf, self.pages.len() as u32);
+        for page in self.pages.iter() {
+            write_u32(&mut buf, page.first.len() as u32);
+            write_arr(&mut buf, &page.first);
+            for count in page.counts.iter() {
+                write_u32(&mut buf, *count);
+            }
+        }
+        buf
+    }
+    fn deserialize_meta(buf: &[u8]) -> (u32, usize, Vec<RichListBucket>, Vec<RichListPage>) {
+        let mut r = buf;
+        let synced_height = read_u32(&mut r);
+        let len = read_usize(&mut r);
+        let buckets = (0..read_u32(&mut r)).map(|_| RichListBucket {
+            count: read_u32(&mut r),
+            value: read_u64(&mut r),
+        }).collect();
+        let pages = (0..read_u32(&mut r)).map(|_| {
+            let first_len = read_u32(&mut r) as usize;
+            let mut page = RichListPage::new(read_vec(&mut r, first_len));
+            for count in page.counts.iter_mut() {
+                *count = read_u32(&mut r);
+            }
+            page
+        }).collect();
+        (synced_height, len, buckets, pages)
+    }
+    /// Record that the balances are synced to `height` (written to a temporary file first to be atomic).
+    fn save_meta(&mut self, height: u32, buckets: &[RichListBucket]) {
+        self.synced_height = Some(height);
+        let meta_path = Self::meta_path(&self.path);
+        let tmp_path = format!("{}.tmp", meta_path);
+        std::fs::write(&tmp_path, self.serialize_meta(buckets)).expect("Failed to write the rich list metadata.");
+        std::fs::rename(&tmp_path, &meta_path).expect("Failed to write the rich list metadata.");
+    }
+    fn get(&self, script_pubkey: &Script) -> Option<u64> {
+        self.balances.get(&RichListBalanceKey { script_pubkey: script_pubkey.clone() }).map(|balance| balance.value)
+    }
+    /// Update the balance of `script_pubkey` (the entry is removed if `value` is zero).
+    fn set(&mut self, script_pubkey: &Script, value: u64, indexed: bool) {
+        let key = RichListBalanceKey { script_pubkey: script_pubkey.clone() };
+        match self.get(script_pubkey) {
+            Some(old) => {
+                if indexed {
+                    self.delete_index(&RichListIndexKey::new(script_pubkey, old));
+                }
+            },
+            None => self.len += 1,
+        }
+        if value == 0 {
+            self.balances.delete(&key);
+            self.len -= 1;
+            return;
+        }
+        self.balances.put(&key, &RichListBalanceValue { value });
+        if indexed {
+            self.insert_index(&RichListIndexKey::new(script_pubkey, value));
+        }
+    }
+    /// Returns the page which contains `key`.
+    fn locate(&self, key: &[u8]) -> usize {
+        self.pages.partition_point(|page| page.first.as_slice() <= key) - 1
+    }
+    fn insert_index(&mut self, key: &RichListIndexKey) {
+        let category = category_of(&key.script_pubkey);
+        self.index.put(key, &Empty {});
+        self.counts[category] += 1;
+        let i = self.locate(&key.serialize());
+        self.pages[i].counts[category] += 1;
+        if self.pages[i].len() > 2 * PAGE_SIZE {
+            self.split_page(i);
+        }
+    }
+    fn delete_index(&mut self, key: &RichListIndexKey) {
+        let category = category_of(&key.script_pubkey);
+        self.index.delete(key);
+        self.counts[category] -= 1;
+        let i = self.locate(&key.serialize());
+        self.pages[i].counts[category] -= 1;
+        if self.pages[i].len() < PAGE_SIZE / 4 && self.pages.len() > 1 {
+            // Merge with the next page (or the previous one for the last page).
+            let i = if i + 1 < self.pages.len() { i } else { i - 1 };
+            let next = self.pages.remove(i + 1);
+            for (count, next_count) in self.pages[i].counts.iter_mut().zip(next.counts.iter()) {
+                *count += next_count;
+            }
+            if self.pages[i].len() > 2 * PAGE_SIZE {
+                self.split_page(i);
+            }
+        }
+    }
+    /// Split the page `i` into the first `PAGE_SIZE` keys and the others.
+    fn split_page(&mut self, i: usize) {
+        let mut left = RichListPage::new(self.pages[i].first.clone());
+        let mut iter = self.index.prefix_iter_from(Vec::new(), left.first.clone());
+        for (key, _) in iter.by_ref().take(PAGE_SIZE as usize) {
+            left.counts[category_of(&key.script_pubkey)] += 1;
+        }
+        let (first, _) = iter.next().unwrap();
+        let mut right = RichListPage::new(first.serialize());
+        for (category, count) in right.counts.iter_mut().enumerate() {
+            *count = self.pages[i].counts[category] - left.counts[category];
+        }
+        self.pages[i] = left;
+        self.pages.insert(i + 1, right);
+    }
+    /// Build the sorted index and its pages from the balances.
+    fn build_index(&mut self) {
+        // Flush the index in chunks to bound the memory usage.
+        const BATCH_SIZE: usize = 100_000;
+        let mut batch = rocks_db::WriteBatch::<RichListIndexKey, Empty>::default();
+        let mut batch_len = 0;
+        for (key, balance) in self.balances.iter() {
+            batch.put(&RichListIndexKey::new(&key.script_pubkey, balance.value), &Empty {});
+            batch_len += 1;
+            if batch_len == BATCH_SIZE {
+                self.index.write(std::mem::take(&mut batch)).unwrap();
+                batch_len = 0;
+            }
+        }
+        self.index.write(batch).unwrap();
+        let mut pages = vec![RichListPage::new(Vec::new())];
+        for (key, _) in self.index.iter() {
+            if pages.last().unwrap().len() == PAGE_SIZE {
+                pages.push(RichListPage::new(key.serialize()));
+            }
+            let category = category_of(&key.script_pubkey);
+            pages.last_mut().unwrap().counts[category] += 1;
+            self.counts[category] += 1;
+        }
+        self.pages = pages;
+    }
+    /// Iterate over the entries of `categories` starting from the `k`-th (starting from zero) one.
+    fn iter_from(&self, categories: &[usize], mut k: usize) -> RichListDBIter<'_> {
+        let mut iter = RichListDBIter {
+            db: self,
+            categories: categories.to_vec(),
+            page: self.pages.len(),
+            left: 0,
+            iter: None,
+        };
+        for (i, page) in self.pages.iter().enumerate() {
+            let count = page.count(categories) as usize;
+            if k < count {
+                iter.seek(i);
+                if k > 0 {
+                    iter.nth(k - 1);
+                }
+                break;
+            }
+            k -= count;
+        }
+        iter
+    }
+    fn rank(&self, script_pubkey: &Script, value: u64, categories: &[usize]) -> usize {
+        let key = RichListIndexKey::new(script_pubkey, value).serialize();
+        let i = self.locate(&key);
+        let before = self.pages[..i].iter().map(|page| page.count(categories) as usize).sum::<usize>();
+        before + self.index.prefix_iter_from(Vec::new(), self.pages[i].first.clone())
+            .take_while(|(index_key, _)| index_key.serialize() < key)
+            .filter(|(index_key, _)| categories.contains(&category_of(&index_key.script_pubkey)))
+            .count()
+    }
+}
+
+/// Iterates over the sorted index skipping the pages which have no entry of `categories`.
+struct RichListDBIter<'a> {
+    db: &'a RichListDB,
+    categories: Vec<usize>,
+    /// The current page.
+    page: usize,
+    /// The number of entries of `categories` left in the current page.
+    left: u32,
+    iter: Option<RocksDBPrefixIterator<'a, RichListIndexKey, Empty>>,
+}
+
+impl<'a> RichListDBIter<'a> {
+    fn seek(&mut self, page: usize) {
+        self.page = page;
+        self.left = self.db.pages[page].count(&self.categories);
+        self.iter = Some(self.db.index.prefix_iter_from(Vec::new(), self.db.pages[page].first.clone()));
+    }
+}
+
+impl<'a> Iterator for RichListDBIter<'a> {
+    type Item = (Script, u64);
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.left == 0 {
+                let next = (self.page + 1..self.db.pages.len()).find(|i| self.db.pages[*i].count(&self.categories) > 0)?;
+                self.seek(next);
+            }
+            let (key, _) = self.iter.as_mut()?.next()?;
+            if self.categories.contains(&category_of(&key.script_pubkey)) {
+                self.left -= 1;
+                return Some((key.script_pubkey, key.value));
+            }
+        }
+    }
+}
+
+/// The rich list which supports updating balances, rank-of-script and select-by-rank in O(log n).
+///
+/// All the entries are kept in a single treap whose nodes count the nodes of each category in their subtree,
+/// so ranks and offsets among the entries matching a filter are resolved by a single descent
+/// (O(C log n) where C = `CATEGORIES` is a constant).
+#[derive(Debug)]
 pub struct RichList {
-    map: IndexMap<Script, u64>,
+    /// Nodes are referred by their index of the map.
+    map: IndexMap<Script, RichListNode>,
+    /// The root node of the treap.
+    root: u32,
+    /// Trees are built lazily by `finalize()` to make the bulk loading fast.
+    built: bool,
+    /// Randomizes the priorities of nodes.
+    hasher: RandomState,
+    /// Keeps the balances and the sorted index on disk instead of `map` in the low-memory mode.
+    disk: Option<RichListDB>,
+    /// Buckets corresponding to `RICH_LIST_BUCKETS`, updated on every balance change.
+    buckets: Vec<RichListBucket>,
 }
 
 impl Default for RichList {
@@ -26,49 +605,331 @@ impl RichList {
     pub fn new() -> Self {
         Self {
             map: IndexMap::new(),
+            root: NIL,
+            built: false,
+            hasher: RandomState::new(),
+            disk: None,
+            buckets: vec![RichListBucket::default(); RICH_LIST_BUCKETS.len()],
+        }
+    }
+    /// Construct the disk-backed rich list, which is reused if it is synced to `synced_height` and wiped otherwise.
+    pub fn new_on_disk(coin: &str, synced_height: Option<u32>, temporary: bool) -> Self {
+        let (disk, buckets) = RichListDB::new(coin, synced_height, temporary);
+        Self {
+            built: disk.synced_height.is_some(),
+            disk: Some(disk),
+            buckets,
+            ..Self::new()
+        }
+    }
+    /// The height the disk-backed rich list is synced to (always `None` for the in-memory one).
+    pub fn synced_height(&self) -> Option<u32> {
+        self.disk.as_ref().and_then(|disk| disk.synced_height)
+    }
+    /// Persist the state of the disk-backed rich list synced to `height` (no-op for the in-memory one).
+    pub fn set_synced_height(&mut self, height: u32) {
+        if let Some(disk) = &mut self.disk {
+            disk.save_meta(height, &self.buckets);
         }
     }
     pub fn is_empty(&self) -> bool {
-        self.map.is_empty()
+        self.len() == 0
     }
     pub fn len(&self) -> usize {
-        self.map.len()
+        match &self.disk {
+            Some(disk) => disk.len,
+            None => self.map.len(),
+        }
     }
+    /// The capacity of the in-memory map.
     pub fn capacity(&self) -> usize {
         self.map.capacity()
     }
+    /// The approximate size of the in-memory map.
     pub fn size(&self) -> usize {
-        self.map.iter().map(|(script, _val)| script.len() + size_of::<u64>()).sum()
+        self.map.iter().map(|(script, _node)| script.len() + size_of::<RichListNode>()).sum()
     }
     pub fn shrink_to_fit(&mut self) {
         self.map.shrink_to_fit();
     }
+    pub fn buckets(&self) -> &[RichListBucket] {
+        &self.buckets
+    }
+    /// Move a balance from the bucket of `old` to the one of `new` (zero means no balance).
+    fn update_buckets(&mut self, old: u64, new: u64) {
+        if old > 0 {
+            let bucket = &mut self.buckets[bucket_index(old)];
+            bucket.count -= 1;
+            bucket.value -= old;
+        }
+        if new > 0 {
+            let bucket = &mut self.buckets[bucket_index(new)];
+            bucket.count += 1;
+            bucket.value += new;
+        }
+    }
+    fn node(&self, i: u32) -> &RichListNode {
+        self.map.get_index(i as usize).unwrap().1
+    }
+    fn node_mut(&mut self, i: u32) -> &mut RichListNode {
+        self.map.get_index_mut(i as usize).unwrap().1
+    }
+    /// The number of nodes of each category in the tree `t`.
+    fn counts(&self, t: u32) -> [u32; CATEGORIES] {
+        if t == NIL { [0; CATEGORIES] } else { self.node(t).counts }
+    }
+    fn update_size(&mut self, t: u32) {
+        let node = self.node(t);
+        let (left, right) = (self.counts(node.left), self.counts(node.right));
+        let mut counts = [0; CATEGORIES];
+        counts[node.category as usize] = 1;
+        for category in 0..CATEGORIES {
+            counts[category] += left[category] + right[category];
+        }
+        self.node_mut(t).counts = counts;
+    }
+    fn priority(&self, i: u32) -> u64 {
+        self.hasher.hash_one(self.map.get_index(i as usize).unwrap().0)
+    }
+    /// Returns true if the node `a` precedes the node `b`.
+    fn less(&self, a: u32, b: u32) -> bool {
+        let (script_a, node_a) = self.map.get_index(a as usize).unwrap();
+        let (script_b, node_b) = self.map.get_index(b as usize).unwrap();
+        node_a.value > node_b.value || (node_a.value == node_b.value && script_a < script_b)
+    }
+    /// Split the tree `t` into the nodes preceding `x` and the others.
+    fn split(&mut self, t: u32, x: u32) -> (u32, u32) {
+        if t == NIL {
+            return (NIL, NIL);
+        }
+        if self.less(t, x) {
+            let (l, r) = self.split(self.node(t).right, x);
+            self.node_mut(t).right = l;
+            self.update_size(t);
+            (t, r)
+        } else {
+            let (l, r) = self.split(self.node(t).left, x);
+            self.node_mut(t).left = r;
+            self.update_size(t);
+            (l, t)
+        }
+    }
+    /// Merge the trees `a` and `b` (every node of `a` should precede the ones of `b`).
+    fn merge(&mut self, a: u32, b: u32) -> u32 {
+        if a == NIL {
+            return b;
+        }
+        if b == NIL {
+            return a;
+        }
+        if self.priority(a) > self.priority(b) {
+            let right = self.merge(self.node(a).right, b);
+            self.node_mut(a).right = right;
+            self.update_size(a);
+            a
+        } else {
+            let left = self.merge(a, self.node(b).left);
+            self.node_mut(b).left = left;
+            self.update_size(b);
+            b
+        }
+    }
+    /// Remove the node `x` from the tree `t` and returns the new root.
+    fn erase(&mut self, t: u32, x: u32) -> u32 {
+        if t == x {
+            let node = self.node(t);
+            return self.merge(node.left, node.right);
+        }
+        if self.less(t, x) {
+            let right = self.erase(self.node(t).right, x);
+            self.node_mut(t).right = right;
+        } else {
+            let left = self.erase(self.node(t).left, x);
+            self.node_mut(t).left = left;
+        }
+        self.update_size(t);
+        t
+    }
+    fn insert_node(&mut self, x: u32) {
+        let category = category_of(self.map.get_index(x as usize).unwrap().0);
+        let value = self.node(x).value;
+        *self.node_mut(x) = RichListNode::new(value, category);
+        let (l, r) = self.split(self.root, x);
+        let l = self.merge(l, x);
+        self.root = self.merge(l, r);
+    }
+    fn erase_node(&mut self, x: u32) {
+        self.root = self.erase(self.root, x);
+    }
+    /// Remove the entry `i` (which is not in any tree) from the map.
+    fn swap_remove(&mut self, i: u32) {
+        let last = (self.map.len() - 1) as u32;
+        if !self.built || i == last {
+            self.map.swap_remove_index(i as usize);
+            return;
+        }
+        // The last entry is moved to `i`, so we need to update the link from its parent.
+        let mut parent = NIL;
+        let mut t = self.root;
+        while t != last {
+            parent = t;
+            t = if self.less(last, t) { self.node(t).left } else { self.node(t).right };
+        }
+        self.map.swap_remove_index(i as usize);
+        if parent == NIL {
+            self.root = i;
+        } else if self.node(parent).left == last {
+            self.node_mut(parent).left = i;
+        } else {
+            self.node_mut(parent).right = i;
+        }
+    }
+    /// Returns the number of nodes of `categories` which precede the node `x`.
+    fn rank(&self, categories: &RichListCategories, x: u32) -> usize {
+        let mut rank = 0;
+        let mut t = self.root;
+        while t != NIL {
+            let node = self.node(t);
+            if self.less(t, x) {
+                rank += categories.count(&self.counts(node.left)) + categories.contains(node.category) as usize;
+                t = node.right;
+            } else {
+                t = node.left;
+            }
+        }
+        rank
+    }
+    /// Returns the `k`-th (starting from zero) node of `categories`,
+    /// and the path to it consisting of the nodes which follow it (for `RichListIter`).
+    fn select(&self, categories: &RichListCategories, mut k: usize) -> (Option<u32>, Vec<u32>) {
+        let mut path = Vec::new();
+        let mut t = self.root;
+        while t != NIL {
+            let node = self.node(t);
+            let left_count = categories.count(&self.counts(node.left));
+            let matches = categories.contains(node.category);
+            if k < left_count {
+                path.push(t);
+                t = node.left;
+            } else if k == left_count && matches {
+                path.push(t);
+                return (Some(t), path);
+            } else {
+                k -= left_count + matches as usize;
+                t = node.right;
+            }
+        }
+        (None, Vec::new())
+    }
     pub fn push(&mut self, utxo: &UtxoEntry) {
-        let value = self.map.get(&utxo.script_pubkey).unwrap_or(&0u64) + utxo.value;
-        self.map.insert(utxo.script_pubkey.clone(), value);
+        let script_pubkey = entry_script(&utxo.script_pubkey);
+        if let Some(disk) = &mut self.disk {
+            let old = disk.get(&script_pubkey).unwrap_or(0);
+            disk.set(&script_pubkey, old + utxo.value, self.built);
+            self.update_buckets(old, old + utxo.value);
+            return;
+        }
+        match self.map.get_full(&*script_pubkey) {
+            Some((i, _script, node)) => {
+                let i = i as u32;
+                let old = node.value;
+                let value = old + utxo.value;
+                self.update_buckets(old, value);
+                if self.built {
+                    self.erase_node(i);
+                }
+                self.node_mut(i).value = value;
+                if self.built {
+                    self.insert_node(i);
+                }
+            },
+            None => {
+                self.update_buckets(0, utxo.value);
+                let category = category_of(&script_pubkey);
+                let (i, _) = self.map.insert_full(script_pubkey.into_owned(), RichListNode::new(utxo.value, category));
+                if self.built {
+                    self.insert_node(i as u32);
+                }
+            },
+        }
     }
     pub fn remove(&mut self, script_pubkey: &Script, value: u64) {
+        let script_pubkey = &*entry_script(script_pubkey);
+        if let Some(disk) = &mut self.disk {
+            if value != 0 {
+                let old = disk.get(script_pubkey).unwrap();
+                disk.set(script_pubkey, old - value, self.built);
+                self.update_buckets(old, old - value);
+            }
+            return;
+        }
         if value != 0 {
-            let v = self.map.get_mut(script_pubkey).unwrap();
-            *v -= value;
+            let i = self.map.get_index_of(script_pubkey).unwrap() as u32;
+            if self.built {
+                self.erase_node(i);
+            }
+            let old = self.node(i).value;
+            self.update_buckets(old, old - value);
+            let node = self.node_mut(i);
+            node.value -= value;
             // Remove the entry if the value is zero.
-            if *v == 0 {
-                self.map.remove(script_pubkey);
+            if node.value == 0 {
+                self.swap_remove(i);
+            } else if self.built {
+                self.insert_node(i);
             }
         }
     }
+    /// Iterate over (script_pubkey, value) pairs in the descending order of values.
+    pub fn iter(&self) -> Box<dyn Iterator<Item = (Script, u64)> + '_> {
+        self.iter_from(&RichListFilter::default().categories(), 0)
+    }
+    /// Iterate over the entries of `categories` starting from the `k`-th (starting from zero) one.
+    fn iter_from(&self, categories: &[usize], k: usize) -> Box<dyn Iterator<Item = (Script, u64)> + '_> {
+        match &self.disk {
+            Some(disk) => Box::new(disk.iter_from(categories, k)),
+            None => Box::new(RichListIter::new_from(self, categories, k).map(|(script_pubkey, value)| (script_pubkey.clone(), value))),
+        }
+    }
     pub fn get_index_of(&self, script_pubkey: &Script) -> Option<usize> {
-        self.map.get_index_of(script_pubkey)
+        self.get_filtered_index_of(script_pubkey, &RichListFilter::default())
     }
     pub fn get_in_range_as_rest(&self, range: Range<usize>, config: &Config) -> Vec<Option<chainseeker::RichListEntry>> {
-        range.map(|i| {
-            self.map.get_index(i).map(|(script_pubkey, value)| {
-                chainseeker::RichListEntry {
-                    script_pub_key: create_script_pub_key(script_pubkey, config),
-                    value: *value,
-                }
+        self.get_filtered_in_range_as_rest(range, &RichListFilter::default(), config)
+    }
+    pub fn filtered_len(&self, filter: &RichListFilter) -> usize {
+        if let Some(disk) = &self.disk {
+            return filter.categories().iter().map(|category| disk.counts[*category]).sum();
+        }
+        RichListCategories::new(&filter.categories()).count(&self.counts(self.root))
+    }
+    /// Returns the rank (starting from zero) of `script_pubkey` among the entries matching `filter`.
+    pub fn get_filtered_index_of(&self, script_pubkey: &Script, filter: &RichListFilter) -> Option<usize> {
+        let script_pubkey = &*entry_script(script_pubkey);
+        let categories = filter.categories();
+        if !categories.contains(&category_of(script_pubkey)) {
+            return None;
+        }
+        if let Some(disk) = &self.disk {
+            let value = disk.get(script_pubkey)?;
+            return Some(disk.rank(script_pubkey, value, &categories));
+        }
+        let x = self.map.get_index_of(script_pubkey)? as u32;
+        Some(self.rank(&RichListCategories::new(&categories), x))
+    }
+    pub fn get_filtered_in_range_as_rest(&self, range: Range<usize>, filter: &RichListFilter, config: &Config) -> Vec<Option<chainseeker::RichListEntry>> {
+        let len = range.len();
+        let mut entries = self.iter_from(&filter.categories(), range.start)
+            .map(|(script_pubkey, value)| {
+                Some(chainseeker::RichListEntry {
+                    script_pub_key: create_script_pub_key(&script_pubkey, config),
+                    value,
+                })
             })
-        }).collect()
+            .take(len)
+            .collect::<Vec<_>>();
+        entries.resize(len, None);
+        entries
     }
     pub fn process_block(&mut self, block: &Block, previous_utxos: &[UtxoEntry]) {
         // Process vouts.
@@ -102,16 +963,115 @@ impl RichList {
         }
         self.finalize();
     }
+    /// Revert `process_block()` for the block disconnected by a reorg.
+    pub fn reorg_block(&mut self, block: &Block, previous_utxos: &[UtxoEntry]) {
+        for previous_utxo in previous_utxos.iter() {
+            if previous_utxo.value > 0 {
+                self.push(previous_utxo);
+            }
+        }
+        for tx in block.txdata.iter() {
+            for output in tx.output.iter() {
+                self.remove(&output.script_pubkey, output.value);
+            }
+        }
+    }
+    /// Build the trees (if not yet built). Later updates maintain the trees incrementally.
     pub fn finalize(&mut self) {
-        self.map.par_sort_by(|_k1, v1, _k2, v2| v2.cmp(v1));
+        if self.built {
+            return;
+        }
+        if let Some(disk) = &mut self.disk {
+            disk.build_index();
+            self.built = true;
+            return;
+        }
+        self.map.par_sort_by(|k1, v1, k2, v2| v2.value.cmp(&v1.value).then_with(|| k1.cmp(k2)));
+        // Build the treap from the sorted nodes by keeping the rightmost path in a stack.
+        let mut stack: Vec<(u32, u64)> = Vec::new();
+        for i in 0..self.map.len() {
+            let i = i as u32;
+            let category = category_of(self.map.get_index(i as usize).unwrap().0);
+            let value = self.node(i).value;
+            *self.node_mut(i) = RichListNode::new(value, category);
+            let priority = self.priority(i);
+            let mut left = NIL;
+            while let Some((top, top_priority)) = stack.last().copied() {
+                if top_priority > priority {
+                    break;
+                }
+                left = top;
+                stack.pop();
+            }
+            self.node_mut(i).left = left;
+            if let Some((top, _)) = stack.last().copied() {
+                self.node_mut(top).right = i;
+            }
+            stack.push((i, priority));
+        }
+        self.root = stack.first().map_or(NIL, |(root, _)| *root);
+        self.update_sizes(self.root);
+        self.built = true;
+    }
+    fn update_sizes(&mut self, t: u32) {
+        if t == NIL {
+            return;
+        }
+        let node = self.node(t);
+        let (left, right) = (node.left, node.right);
+        self.update_sizes(left);
+        self.update_sizes(right);
+        self.update_size(t);
+    }
+}
+
+/// Iterates over the entries of the union of categories in the descending order of values.
+pub struct RichListIter<'a> {
+    rich_list: &'a RichList,
+    categories: RichListCategories,
+    /// The stack to traverse the treap in-order (skipping the subtrees which have no entry of `categories`).
+    stack: Vec<u32>,
+}
+
+impl<'a> RichListIter<'a> {
+    /// Start from the `k`-th (starting from zero) entry.
+    fn new_from(rich_list: &'a RichList, categories: &[usize], k: usize) -> Self {
+        let categories = RichListCategories::new(categories);
+        let (_, stack) = rich_list.select(&categories, k);
+        Self {
+            rich_list,
+            categories,
+            stack,
+        }
+    }
+    fn push_left(&mut self, mut t: u32) {
+        while t != NIL && self.categories.count(&self.rich_list.node(t).counts) > 0 {
+            self.stack.push(t);
+            t = self.rich_list.node(t).left;
+        }
+    }
+}
+
+impl<'a> Iterator for RichListIter<'a> {
+    type Item = (&'a Script, u64);
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let x = self.stack.pop()?;
+            let (script_pubkey, node) = self.rich_list.map.get_index(x as usize).unwrap();
+            self.push_left(node.right);
+            if self.categories.contains(node.category) {
+                return Some((script_pubkey, node.value));
+            }
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::collections::HashMap;
     use crate::db::utxo::UtxoDB;
     use super::*;
-    const JSON: &str = r#"[{"scriptPubKey":{"asm":"OP_0 OP_PUSHBYTES_20 97033ca70d45fe6d49310859e132a9df98f97625","hex":"001497033ca70d45fe6d49310859e132a9df98f97625","type":"witnesspubkeyhash","address":"bcrt1qjupnefcdghlx6jf3ppv7zv4fm7v0ja39dzzwvd"},"value":505000034900},{"scriptPubKey":{"asm":"OP_PUSHBYTES_65 04678afdb0fe5548271967f1a67130b7105cd6a828e03909a67962e0ea1f61deb649f6bc3f4cef38c4f35504e51ec112de5c384df7ba0b8d578a4c702b6bf11d5f OP_CHECKSIG","hex":"4104678afdb0fe5548271967f1a67130b7105cd6a828e03909a67962e0ea1f61deb649f6bc3f4cef38c4f35504e51ec112de5c384df7ba0b8d578a4c702b6bf11d5fac","type":"unknown","address":null},"value":5000000000},{"scriptPubKey":{"asm":"OP_0 OP_PUSHBYTES_20 13bb0bcb776f3e15fa8800877552044d4db27b96","hex":"001413bb0bcb776f3e15fa8800877552044d4db27b96","type":"witnesspubkeyhash","address":"bcrt1qzwashjmhdulpt75gqzrh25syf4xmy7uk6clm0p"},"value":4900000000},{"scriptPubKey":{"asm":"OP_0 OP_PUSHBYTES_20 261b6555a3cb5d3593c0275ff46f31c36e42a4c7","hex":"0014261b6555a3cb5d3593c0275ff46f31c36e42a4c7","type":"witnesspubkeyhash","address":"bcrt1qycdk24dredwnty7qya0lgme3cdhy9fx83qc9wd"},"value":99965100}]"#;
+    const JSON: &str = r#"[{"scriptPubKey":{"asm":"OP_0 OP_PUSHBYTES_20 97033ca70d45fe6d49310859e132a9df98f97625","hex":"001497033ca70d45fe6d49310859e132a9df98f97625","type":"witnesspubkeyhash","address":"bcrt1qjupnefcdghlx6jf3ppv7zv4fm7v0ja39dzzwvd"},"value":505000034900},{"scriptPubKey":{"asm":"OP_DUP OP_HASH160 OP_PUSHBYTES_20 62e907b15cbf27d5425399ebf6f0fb50ebb88f18 OP_EQUALVERIFY OP_CHECKSIG","hex":"76a91462e907b15cbf27d5425399ebf6f0fb50ebb88f1888ac","type":"pubkeyhash","address":"mpXwg4jMtRhuSpVq4xS3HFHmCmWp9NyGKt"},"value":5000000000},{"scriptPubKey":{"asm":"OP_0 OP_PUSHBYTES_20 13bb0bcb776f3e15fa8800877552044d4db27b96","hex":"001413bb0bcb776f3e15fa8800877552044d4db27b96","type":"witnesspubkeyhash","address":"bcrt1qzwashjmhdulpt75gqzrh25syf4xmy7uk6clm0p"},"value":4900000000},{"scriptPubKey":{"asm":"OP_0 OP_PUSHBYTES_20 261b6555a3cb5d3593c0275ff46f31c36e42a4c7","hex":"0014261b6555a3cb5d3593c0275ff46f31c36e42a4c7","type":"witnesspubkeyhash","address":"bcrt1qycdk24dredwnty7qya0lgme3cdhy9fx83qc9wd"},"value":99965100}]"#;
     #[allow(dead_code)]
     fn print_rich_list(rich_list: &RichList) {
         let entries = rich_list.get_in_range_as_rest(0..rich_list.len(), &config_example("rbtc"));
@@ -122,8 +1082,8 @@ mod tests {
         let mut rich_list: RichList = Default::default();
         assert!(rich_list.is_empty());
         let mut utxo_db = UtxoDB::new("test/rich_list", true);
-        for block in fixtures::regtest_blocks().iter() {
-            let prev_utxos = utxo_db.process_block(&block, false);
+        for (height, block) in fixtures::regtest_blocks().iter().enumerate() {
+            let prev_utxos = utxo_db.process_block(height as u32, &block, false);
             rich_list.process_block(&block, &prev_utxos);
         }
         rich_list.finalize();
@@ -133,11 +1093,221 @@ mod tests {
         assert!(!rich_list.is_empty());
         assert_eq!(rich_list.len(), entries.len());
         assert_eq!(rich_list.capacity(), entries.len());
-        assert_eq!(rich_list.size(), 165);
+        assert_eq!(rich_list.size(), 91 + entries.len() * size_of::<RichListNode>());
         assert_eq!(rich_list.get_in_range_as_rest(0..entries.len(), &config_example("rbtc")), entries);
         for (i, entry) in entries.iter().enumerate() {
             let script_pubkey = Script::from_hex(&entry.as_ref().unwrap().script_pub_key.hex).unwrap();
             assert_eq!(rich_list.get_index_of(&script_pubkey), Some(i));
         }
     }
+    /// Compare the rich list with the naive implementation.
+    fn assert_rich_list(rich_list: &RichList, balances: &HashMap<Script, u64>) {
+        let mut expected = balances.iter().map(|(script_pubkey, value)| (script_pubkey.clone(), *value)).collect::<Vec<_>>();
+        expected.sort_by(|(k1, v1), (k2, v2)| v2.cmp(v1).then_with(|| k1.cmp(k2)));
+        let actual = rich_list.iter().collect::<Vec<_>>();
+        assert_eq!(actual, expected);
+        assert_eq!(rich_list.len(), expected.len());
+        let mut buckets = vec![RichListBucket::default(); RICH_LIST_BUCKETS.len()];
+        for (_script_pubkey, value) in expected.iter() {
+            buckets[bucket_index(*value)].count += 1;
+            buckets[bucket_index(*value)].value += value;
+        }
+        assert_eq!(rich_list.buckets(), &buckets[..]);
+        for (i, (script_pubkey, _value)) in expected.iter().enumerate() {
+            assert_eq!(rich_list.get_index_of(script_pubkey), Some(i));
+        }
+        let filter = RichListFilter { address_only: true, exclude_burn: true, ..Default::default() };
+        let filtered = expected.iter()
+            .filter(|(script_pubkey, _value)| script_pubkey.is_p2pkh() || script_pubkey.is_p2sh() || script_pubkey.is_witness_program())
+            .filter(|(script_pubkey, _value)| !is_burn_script(script_pubkey))
+            .collect::<Vec<_>>();
+        assert_eq!(rich_list.filtered_len(&filter), filtered.len());
+        for (i, (script_pubkey, _value)) in filtered.iter().enumerate() {
+            assert_eq!(rich_list.get_filtered_index_of(script_pubkey, &filter), Some(i));
+        }
+        let config = config_example("rbtc");
+        for offset in [0, 1, filtered.len() / 2, filtered.len()].iter() {
+            let entries = rich_list.get_filtered_in_range_as_rest(*offset..offset+3, &filter, &config);
+            for (i, entry) in entries.iter().enumerate() {
+                assert_eq!(entry.as_ref().map(|entry| entry.value), filtered.get(offset + i).map(|(_script_pubkey, value)| *value));
+            }
+        }
+    }
+    /// Apply random updates to `rich_list` and compare it with the naive implementation.
+    fn check_incremental_updates(mut rich_list: RichList) {
+        // Scripts of various types (including a burn address).
+        let scripts = (0..64u8).map(|i| match i % 5 {
+            0 => Script::from_hex(&format!("76a914{}88ac", hex::encode([i; 20]))).unwrap(),
+            1 => Script::from_hex(&format!("a914{}87", hex::encode([i; 20]))).unwrap(),
+            2 => Script::from_hex(&format!("0014{}", hex::encode([i; 20]))).unwrap(),
+            3 => Script::from_hex(&format!("6a{}", hex::encode([i; 2]))).unwrap(),
+            _ => Script::from_hex("76a914818895f3dc2c178629d3d2d8fa3ec4a3f817982188ac").unwrap(),
+        }).collect::<Vec<_>>();
+        let utxo = |script_pubkey: &Script, value: u64| UtxoEntry {
+            script_pubkey: script_pubkey.clone(),
+            txid: Default::default(),
+            vout: 0,
+            value,
+        };
+        // A simple xorshift generator to make the test deterministic.
+        let mut seed = 88172645463325252u64;
+        let mut rand = move || {
+            seed ^= seed << 13;
+            seed ^= seed >> 7;
+            seed ^= seed << 17;
+            seed
+        };
+        let mut balances = HashMap::new();
+        // Bulk loading.
+        for _ in 0..100 {
+            let script_pubkey = &scripts[rand() as usize % scripts.len()];
+            let value = rand() % 4 + 1;
+            rich_list.push(&utxo(script_pubkey, value));
+            *balances.entry(script_pubkey.clone()).or_insert(0) += value;
+        }
+        rich_list.finalize();
+        assert_rich_list(&rich_list, &balances);
+        // Incremental updates (values are small so that ties happen).
+        for _ in 0..1000 {
+            let script_pubkey = &scripts[rand() as usize % scripts.len()];
+            let balance = balances.get(script_pubkey).copied().unwrap_or(0);
+            if balance > 0 && rand() % 2 == 0 {
+                let value = rand() % balance + 1;
+                rich_list.remove(script_pubkey, value);
+                if value == balance {
+                    balances.remove(script_pubkey);
+                } else {
+                    balances.insert(script_pubkey.clone(), balance - value);
+                }
+            } else {
+                let value = rand() % 4 + 1;
+                rich_list.push(&utxo(script_pubkey, value));
+                balances.insert(script_pubkey.clone(), balance + value);
+            }
+            assert_rich_list(&rich_list, &balances);
+        }
+    }
+    #[test]
+    fn buckets() {
+        assert_eq!(bucket_index(1), 0);
+        assert_eq!(bucket_index(99_999), 0);
+        assert_eq!(bucket_index(100_000), 1);
+        assert_eq!(bucket_index(5_000_000_000), 5);
+        assert_eq!(bucket_index(u64::MAX), RICH_LIST_BUCKETS.len() - 1);
+    }
+    #[test]
+    fn incremental_updates() {
+        check_incremental_updates(RichList::new());
+    }
+    #[test]
+    fn incremental_updates_on_disk() {
+        check_incremental_updates(RichList::new_on_disk("test/rich_list_on_disk", None, true));
+    }
+    #[test]
+    fn rich_list_on_disk_persistence() {
+        const COIN: &str = "test/rich_list_persistence";
+        // The persistent database is written to the data directory, so it is removed before and after the test.
+        let path = format!("{}/{}", data_dir(), COIN);
+        let remove_dir = || if std::path::Path::new(&path).exists() {
+            std::fs::remove_dir_all(&path).unwrap();
+        };
+        remove_dir();
+        let mut rich_list = RichList::new_on_disk(COIN, None, false);
+        let mut utxo_db = UtxoDB::new(COIN, true);
+        for (height, block) in fixtures::regtest_blocks().iter().enumerate() {
+            let prev_utxos = utxo_db.process_block(height as u32, block, false);
+            rich_list.process_block(block, &prev_utxos);
+        }
+        assert_eq!(rich_list.synced_height(), None);
+        rich_list.set_synced_height(102);
+        let entries = rich_list.iter().collect::<Vec<_>>();
+        let buckets = rich_list.buckets().to_vec();
+        drop(rich_list);
+        // Reopen at the synced height.
+        let rich_list = RichList::new_on_disk(COIN, Some(102), false);
+        assert_eq!(rich_list.synced_height(), Some(102));
+        assert_eq!(rich_list.len(), entries.len());
+        assert_eq!(rich_list.iter().collect::<Vec<_>>(), entries);
+        assert_eq!(rich_list.buckets(), &buckets[..]);
+        assert_eq!(rich_list.get_index_of(&entries[1].0), Some(1));
+        drop(rich_list);
+        // Wiped at another height.
+        let rich_list = RichList::new_on_disk(COIN, Some(103), true);
+        assert_eq!(rich_list.synced_height(), None);
+        assert!(rich_list.is_empty());
+        assert_eq!(rich_list.iter().count(), 0);
+        drop(rich_list);
+        drop(utxo_db);
+        remove_dir();
+    }
+    #[test]
+    fn filtered_rich_list() {
+        let config = config_example("rbtc");
+        let entries: Vec<Option<chainseeker::RichListEntry>> = serde_json::from_str(JSON).unwrap();
+        let scripts = entries.iter().map(|entry| Script::from_hex(&entry.as_ref().unwrap().script_pub_key.hex).unwrap()).collect::<Vec<_>>();
+        let mut rich_list = RichList::new();
+        for (entry, script_pubkey) in entries.iter().zip(scripts.iter()) {
+            rich_list.push(&UtxoEntry {
+                script_pubkey: script_pubkey.clone(),
+                txid: Default::default(),
+                vout: 0,
+                value: entry.as_ref().unwrap().value,
+            });
+        }
+        // Burn 1,000 BTC to 1CounterpartyXXXXXXXXXXXXXXXUWLpVr.
+        let burn = Script::from_hex("76a914818895f3dc2c178629d3d2d8fa3ec4a3f817982188ac").unwrap();
+        assert!(is_burn_script(&burn));
+        rich_list.push(&UtxoEntry { script_pubkey: burn.clone(), txid: Default::default(), vout: 0, value: 100_000_000_000 });
+        // A script without an address (OP_TRUE).
+        let anyone_can_spend = Script::from_hex("51").unwrap();
+        rich_list.push(&UtxoEntry { script_pubkey: anyone_can_spend.clone(), txid: Default::default(), vout: 0, value: 1 });
+        rich_list.finalize();
+        // No filter.
+        let filter = RichListFilter::default();
+        assert_eq!(rich_list.filtered_len(&filter), 6);
+        assert_eq!(rich_list.get_filtered_index_of(&burn, &filter), Some(1));
+        // Exclude burn scripts.
+        let filter = RichListFilter { exclude_burn: true, ..Default::default() };
+        assert_eq!(rich_list.filtered_len(&filter), 5);
+        assert_eq!(rich_list.get_filtered_index_of(&burn, &filter), None);
+        assert_eq!(rich_list.get_filtered_index_of(&scripts[1], &filter), Some(1));
+        assert_eq!(rich_list.get_filtered_in_range_as_rest(0..4, &filter, &config), entries);
+        // Scripts with an address.
+        let filter = RichListFilter { address_only: true, ..Default::default() };
+        assert_eq!(rich_list.filtered_len(&filter), 5);
+        assert_eq!(rich_list.get_filtered_index_of(&anyone_can_spend, &filter), None);
+        assert_eq!(rich_list.get_filtered_index_of(&scripts[1], &filter), Some(2));
+        let filter = RichListFilter { address_only: true, exclude_burn: true, ..Default::default() };
+        assert_eq!(rich_list.filtered_len(&filter), 4);
+        assert_eq!(rich_list.get_filtered_index_of(&scripts[2], &filter), Some(2));
+        assert_eq!(
+            rich_list.get_filtered_in_range_as_rest(2..5, &filter, &config),
+            vec![entries[2].clone(), entries[3].clone(), None]);
+        // Filter by script type.
+        let filter = RichListFilter { script_type: Some("witnesspubkeyhash".parse().unwrap()), ..Default::default() };
+        assert_eq!(rich_list.filtered_len(&filter), 3);
+        assert_eq!(rich_list.get_filtered_in_range_as_rest(0..3, &filter, &config), vec![entries[0].clone(), entries[2].clone(), entries[3].clone()]);
+        let filter = RichListFilter { script_type: Some("pubkeyhash".parse().unwrap()), exclude_burn: true, ..Default::default() };
+        assert_eq!(rich_list.filtered_len(&filter), 1);
+        assert!("unknown".parse::<RichListScriptType>().is_err());
+        // P2TR.
+        let p2tr = Script::from_hex(&format!("5120{}", "ab".repeat(32))).unwrap();
+        rich_list.push(&UtxoEntry { script_pubkey: p2tr.clone(), txid: Default::default(), vout: 0, value: 2 });
+        let filter = RichListFilter { script_type: Some("witness_v1_taproot".parse().unwrap()), ..Default::default() };
+        assert_eq!(rich_list.filtered_len(&filter), 1);
+        assert_eq!(rich_list.get_filtered_index_of(&p2tr, &filter), Some(0));
+        let filter = RichListFilter { address_only: true, ..Default::default() };
+        assert_eq!(rich_list.get_filtered_index_of(&p2tr, &filter), Some(5));
+        // P2PK coins are merged into the entry of the P2PKH address.
+        let p2pk = Script::from_hex("4104678afdb0fe5548271967f1a67130b7105cd6a828e03909a67962e0ea1f61deb649f6bc3f4cef38c4f35504e51ec112de5c384df7ba0b8d578a4c702b6bf11d5fac").unwrap();
+        assert_eq!(p2pk_to_p2pkh(&p2pk).as_ref(), Some(&scripts[1]));
+        let len = rich_list.len();
+        rich_list.push(&UtxoEntry { script_pubkey: p2pk.clone(), txid: Default::default(), vout: 0, value: 600_000_000_000 });
+        assert_eq!(rich_list.len(), len);
+        assert_eq!(rich_list.get_index_of(&p2pk), Some(0));
+        assert_eq!(rich_list.get_index_of(&scripts[1]), Some(0));
+        assert_eq!(rich_list.iter().next(), Some((scripts[1].clone(), 605_000_000_000)));
+        rich_list.remove(&p2pk, 600_000_000_000);
+        assert_eq!(rich_list.get_index_of(&p2pk), Some(2));
+    }
 }
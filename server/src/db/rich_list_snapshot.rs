@@ -0,0 +1,378 @@
+use crate::*;
+use bitcoin::hashes::Hash;
+use bitcoin::{Script, WScriptHash};
+use crate::db::rich_list::{RichList, RichListEntry, RichListBucket, entry_script};
+use crate::rocks_db::{Serialize, Deserialize, ConstantSize};
+
+/// Decodes a big endian `u32` (heights and indices are encoded in big endian so that the keys are sorted by them).
+fn be_bytes_to_u32(buf: &[u8]) -> u32 {
+    let mut tmp = [0u8; 4];
+    tmp.copy_from_slice(buf);
+    u32::from_be_bytes(tmp)
+}
+
+/// Heights are encoded in big endian so that the keys are sorted by height.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RichListSnapshotDBKey {
+    pub height: u32,
+}
+
+impl ConstantSize for RichListSnapshotDBKey {
+    const LEN: usize = 4;
+}
+
+impl Serialize for RichListSnapshotDBKey {
+    fn serialize(&self) -> Vec<u8> {
+        self.height.to_be_bytes().to_vec()
+    }
+}
+
+impl Deserialize for RichListSnapshotDBKey {
+    fn deserialize(buf: &[u8]) -> Self {
+        Self {
+            height: be_bytes_to_u32(&buf[0..4]),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RichListSnapshot {
+    pub height: u32,
+    /// The number of addresses in the rich list.
+    pub count: u32,
+    /// The sum of the balances of all addresses.
+    pub total_value: u64,
+    /// The number of top entries stored.
+    pub entries: u32,
+    /// Buckets corresponding to `RICH_LIST_BUCKETS`.
+    pub buckets: Vec<RichListBucket>,
+}
+
+impl RichListSnapshot {
+    /// The buckets are maintained incrementally by `RichList`, so this does not scan the rich list.
+    pub fn new(height: u32, rich_list: &RichList, size: usize) -> Self {
+        let buckets = rich_list.buckets().to_vec();
+        Self {
+            height,
+            count: rich_list.len() as u32,
+            total_value: buckets.iter().map(|bucket| bucket.value).sum(),
+            entries: std::cmp::min(size, rich_list.len()) as u32,
+            buckets,
+        }
+    }
+}
+
+impl Serialize for RichListSnapshot {
+    fn serialize(&self) -> Vec<u8> {
+        let mut buf = Vec::new();
+        write_u32(&mut buf, self.height);
+        write_u32(&mut buf, self.count);
+        write_u64(&mut buf, self.total_value);
+        write_u32(&mut buf, self.entries);
+        for bucket in self.buckets.iter() {
+            write_u32(&mut buf, bucket.count);
+            write_u64(&mut buf, bucket.value);
+        }
+        buf
+    }
+}
+
+impl Deserialize for RichListSnapshot {
+    fn deserialize(buf: &[u8]) -> Self {
+        let mut r = buf;
+        let height = read_u32(&mut r);
+        let count = read_u32(&mut r);
+        let total_value = read_u64(&mut r);
+        let entries = read_u32(&mut r);
+        let mut buckets = Vec::new();
+        while !r.is_empty() {
+            buckets.push(RichListBucket {
+                count: read_u32(&mut r),
+                value: read_u64(&mut r),
+            });
+        }
+        Self {
+            height,
+            count,
+            total_value,
+            entries,
+            buckets,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RichListSnapshotEntryKey {
+    pub height: u32,    // +4 = 4.
+    pub index: u32,     // +4 = 8.
+}
+
+impl ConstantSize for RichListSnapshotEntryKey {
+    const LEN: usize = 8;
+}
+
+impl Serialize for RichListSnapshotEntryKey {
+    fn serialize(&self) -> Vec<u8> {
+        [self.height.to_be_bytes(), self.index.to_be_bytes()].concat()
+    }
+}
+
+impl Deserialize for RichListSnapshotEntryKey {
+    fn deserialize(buf: &[u8]) -> Self {
+        Self {
+            height: be_bytes_to_u32(&buf[0..4]),
+            index: be_bytes_to_u32(&buf[4..8]),
+        }
+    }
+}
+
+impl Serialize for RichListEntry {
+    fn serialize(&self) -> Vec<u8> {
+        let mut buf = Vec::new();
+        write_u64(&mut buf, self.value);
+        write_arr(&mut buf, self.script_pubkey.as_bytes());
+        buf
+    }
+}
+
+impl Deserialize for RichListEntry {
+    fn deserialize(buf: &[u8]) -> Self {
+        let mut r = buf;
+        let value = read_u64(&mut r);
+        Self {
+            script_pubkey: Script::from(r.to_vec()),
+            value,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RichListRankKey {
+    pub wscript_hash: WScriptHash,  // +32 = 32.
+    pub height: u32,                // + 4 = 36.
+}
+
+impl ConstantSize for RichListRankKey {
+    const LEN: usize = 36;
+}
+
+impl Serialize for RichListRankKey {
+    fn serialize(&self) -> Vec<u8> {
+        [self.wscript_hash.as_ref(), &self.height.to_be_bytes()].concat()
+    }
+}
+
+impl Deserialize for RichListRankKey {
+    fn deserialize(buf: &[u8]) -> Self {
+        let mut wscript_hash = [0u8; 32];
+        wscript_hash.copy_from_slice(&buf[0..32]);
+        Self {
+            wscript_hash: WScriptHash::from_inner(wscript_hash),
+            height: be_bytes_to_u32(&buf[32..36]),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RichListRankValue {
+    /// The rank starting from one.
+    pub rank: u32,
+    pub value: u64,
+}
+
+impl Serialize for RichListRankValue {
+    fn serialize(&self) -> Vec<u8> {
+        let mut buf = Vec::new();
+        write_u32(&mut buf, self.rank);
+        write_u64(&mut buf, self.value);
+        buf
+    }
+}
+
+impl Deserialize for RichListRankValue {
+    fn deserialize(buf: &[u8]) -> Self {
+        let mut r = buf;
+        Self {
+            rank: read_u32(&mut r),
+            value: read_u64(&mut r),
+        }
+    }
+}
+
+/// An entry of the rank history of an address (`rank` and `value` are `None` if the address is not in the top entries).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RichListRankHistoryEntry {
+    pub height: u32,
+    pub rank: Option<u32>,
+    pub value: Option<u64>,
+}
+
+#[derive(Debug)]
+pub struct RichListSnapshotDB {
+    /// Stores (block_height, RichListSnapshot).
+    db: RocksDB<RichListSnapshotDBKey, RichListSnapshot>,
+    /// Stores ((block_height, index), RichListEntry) for the top entries.
+    entry_db: RocksDB<RichListSnapshotEntryKey, RichListEntry>,
+    /// Stores ((wscript_hash, block_height), RichListRankValue) for the top entries.
+    rank_db: RocksDB<RichListRankKey, RichListRankValue>,
+    config: Config,
+}
+
+impl RichListSnapshotDB {
+    pub fn path(coin: &str) -> String {
+        format!("{}/{}/rich_list_snapshot", data_dir(), coin)
+    }
+    pub fn new(coin: &str, config: &Config, temporary: bool) -> Self {
+        let path = Self::path(coin);
+        Self {
+            db: RocksDB::new(&format!("{}/summary", path), temporary),
+            entry_db: RocksDB::new(&format!("{}/entry", path), temporary),
+            rank_db: RocksDB::new(&format!("{}/rank", path), temporary),
+            config: config.clone(),
+        }
+    }
+    /// Returns true if a snapshot should be taken at `height`.
+    pub fn is_snapshot_height(&self, height: u32) -> bool {
+        // Snapshots are disabled if the interval is zero.
+        height.checked_rem(self.config.rich_list_snapshot_interval) == Some(0)
+    }
+    pub fn get(&self, height: u32) -> Option<RichListSnapshot> {
+        self.db.get(&RichListSnapshotDBKey { height })
+    }
+    /// Returns the snapshots in `from..=to` in the ascending order of heights.
+    pub fn get_in_range(&self, from: u32, to: u32) -> Vec<RichListSnapshot> {
+        let from = RichListSnapshotDBKey { height: from }.serialize();
+        self.db.prefix_iter_from(Vec::new(), from)
+            .take_while(|(key, _snapshot)| key.height <= to)
+            .map(|(_key, snapshot)| snapshot)
+            .collect()
+    }
+    /// Returns `limit` entries starting from `offset` of the snapshot at `height` (`None` for entries out of range).
+    pub fn get_entries(&self, height: u32, offset: u32, limit: u32) -> Vec<Option<RichListEntry>> {
+        let prefix = RichListSnapshotDBKey { height }.serialize();
+        let from = RichListSnapshotEntryKey { height, index: offset }.serialize();
+        let mut entries = self.entry_db.prefix_iter_from(prefix, from)
+            .take(limit as usize)
+            .map(|(_key, entry)| Some(entry))
+            .collect::<Vec<_>>();
+        entries.resize(limit as usize, None);
+        entries
+    }
+    /// Returns the rank of `script_pubkey` in every snapshot in `from..=to`.
+    pub fn get_rank_history(&self, script_pubkey: &Script, from: u32, to: u32) -> Vec<RichListRankHistoryEntry> {
+        let wscript_hash = entry_script(script_pubkey).wscript_hash();
+        let prefix = wscript_hash.as_ref().to_vec();
+        let start = RichListRankKey { wscript_hash, height: from }.serialize();
+        let mut ranks = self.rank_db.prefix_iter_from(prefix, start)
+            .take_while(|(key, _value)| key.height <= to)
+            .peekable();
+        self.get_in_range(from, to).iter().map(|snapshot| {
+            match ranks.next_if(|(key, _value)| key.height == snapshot.height) {
+                Some((_key, value)) => RichListRankHistoryEntry {
+                    height: snapshot.height,
+                    rank: Some(value.rank),
+                    value: Some(value.value),
+                },
+                None => RichListRankHistoryEntry {
+                    height: snapshot.height,
+                    rank: None,
+                    value: None,
+                },
+            }
+        }).collect()
+    }
+    /// Remove the snapshot at `height` (if exists).
+    fn delete(&self, height: u32) {
+        let mut entry_batch = rocks_db::WriteBatch::<RichListSnapshotEntryKey, RichListEntry>::default();
+        let mut rank_batch = rocks_db::WriteBatch::<RichListRankKey, RichListRankValue>::default();
+        for (key, entry) in self.entry_db.prefix_iter(RichListSnapshotDBKey { height }.serialize()) {
+            entry_batch.delete(&key);
+            rank_batch.delete(&RichListRankKey { wscript_hash: entry.script_pubkey.wscript_hash(), height });
+        }
+        self.entry_db.write(entry_batch).unwrap();
+        self.rank_db.write(rank_batch).unwrap();
+        self.db.delete(&RichListSnapshotDBKey { height });
+    }
+    /// Take a snapshot of `rich_list` at `height` (replaces the existing one on reorgs).
+    pub fn put(&self, height: u32, rich_list: &RichList) {
+        self.delete(height);
+        let snapshot = RichListSnapshot::new(height, rich_list, self.config.rich_list_snapshot_size as usize);
+        let mut entry_batch = rocks_db::WriteBatch::<RichListSnapshotEntryKey, RichListEntry>::default();
+        let mut rank_batch = rocks_db::WriteBatch::<RichListRankKey, RichListRankValue>::default();
+        for (index, (script_pubkey, value)) in rich_list.iter().take(snapshot.entries as usize).enumerate() {
+            let index = index as u32;
+            rank_batch.put(&RichListRankKey { wscript_hash: script_pubkey.wscript_hash(), height }, &RichListRankValue {
+                rank: index + 1,
+                value,
+            });
+            entry_batch.put(&RichListSnapshotEntryKey { height, index }, &RichListEntry {
+                script_pubkey,
+                value,
+            });
+        }
+        self.entry_db.write(entry_batch).unwrap();
+        self.rank_db.write(rank_batch).unwrap();
+        self.db.put(&RichListSnapshotDBKey { height }, &snapshot);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::db::utxo::UtxoDB;
+    use super::*;
+    #[test]
+    fn key_encoding() {
+        let key = RichListSnapshotEntryKey { height: 0x01020304, index: 0x05060708 };
+        assert_eq!(key.serialize(), vec![1, 2, 3, 4, 5, 6, 7, 8]);
+        assert_eq!(RichListSnapshotEntryKey::deserialize(&key.serialize()), key);
+        let key = RichListRankKey { wscript_hash: Script::new().wscript_hash(), height: 0x01020304 };
+        assert_eq!(key.serialize()[32..], [1, 2, 3, 4]);
+        assert_eq!(RichListRankKey::deserialize(&key.serialize()), key);
+    }
+    #[test]
+    fn rich_list_snapshot_db() {
+        let mut config = config_example("rbtc");
+        config.rich_list_snapshot_interval = 50;
+        config.rich_list_snapshot_size = 2;
+        let snapshot_db = RichListSnapshotDB::new("test/rich_list_snapshot", &config, true);
+        let mut utxo_db = UtxoDB::new("test/rich_list_snapshot", true);
+        let mut rich_list = RichList::new();
+        // Process blocks up to height = 100.
+        for (height, block) in fixtures::regtest_blocks().iter().take(101).enumerate() {
+            let height = height as u32;
+            let prev_utxos = utxo_db.process_block(height, &block, false);
+            rich_list.process_block(&block, &prev_utxos);
+            if snapshot_db.is_snapshot_height(height) {
+                snapshot_db.put(height, &rich_list);
+            }
+        }
+        let heights = snapshot_db.get_in_range(0, u32::MAX).iter().map(|snapshot| snapshot.height).collect::<Vec<u32>>();
+        assert_eq!(heights, vec![0, 50, 100]);
+        assert_eq!(snapshot_db.get(1), None);
+        // The latest snapshot.
+        let snapshot = snapshot_db.get(100).unwrap();
+        assert_eq!(snapshot.count, rich_list.len() as u32);
+        assert_eq!(snapshot.entries, 2);
+        assert_eq!(snapshot.total_value, rich_list.iter().map(|(_script_pubkey, value)| value).sum::<u64>());
+        assert_eq!(snapshot.buckets.iter().map(|bucket| bucket.count).sum::<u32>(), snapshot.count);
+        assert_eq!(snapshot.buckets.iter().map(|bucket| bucket.value).sum::<u64>(), snapshot.total_value);
+        assert_eq!(RichListSnapshot::deserialize(&snapshot.serialize()), snapshot);
+        let entries = snapshot_db.get_entries(100, 0, 3);
+        let top = rich_list.iter().next().unwrap();
+        assert_eq!(entries[0], Some(RichListEntry { script_pubkey: top.0.clone(), value: top.1 }));
+        assert!(entries[1].is_some());
+        assert_eq!(entries[2], None);
+        assert_eq!(snapshot_db.get_entries(100, 1, 1), entries[1..2].to_vec());
+        // Rank history.
+        let history = snapshot_db.get_rank_history(&top.0, 0, u32::MAX);
+        assert_eq!(history.len(), 3);
+        assert_eq!(history[2], RichListRankHistoryEntry { height: 100, rank: Some(1), value: Some(top.1) });
+        assert_eq!(snapshot_db.get_rank_history(&top.0, 51, 100).len(), 1);
+        assert!(snapshot_db.get_rank_history(&Script::new(), 0, u32::MAX).iter().all(|entry| entry.rank.is_none()));
+        // Overwrite a snapshot (on reorgs).
+        snapshot_db.put(100, &RichList::new());
+        assert_eq!(snapshot_db.get(100).unwrap().count, 0);
+        assert_eq!(snapshot_db.get_entries(100, 0, 1), vec![None]);
+        assert_eq!(snapshot_db.get_rank_history(&top.0, 100, 100)[0].rank, None);
+    }
+}
@@ -0,0 +1,251 @@
+use crate::*;
+use bitcoin::{Block, BlockHash};
+use crate::db::{BlockDB, TxDB};
+use crate::db::utxo::UtxoEntry;
+use crate::rocks_db::{Serialize, Deserialize};
+
+/// The blocks whose coinbase outputs are overwritten by later duplicate coinbase transactions and thus can never be spent (BIP30).
+/// Matches `IsBIP30Unspendable()` of Bitcoin Core.
+const BIP30_UNSPENDABLE_BLOCKS: [(u32, &str); 2] = [
+    // Overwritten by the coinbase transaction of height = 91880.
+    (91722, "00000000000271a2dc26e7667f8419f2e15416dc6955e5a6c6cdf3f2574dd08e"),
+    // Overwritten by the coinbase transaction of height = 91842.
+    (91812, "00000000000af0aed4792b1acee3d966af36cf5def14935db8de83d6f9306f2f"),
+];
+
+fn is_bip30_unspendable(height: u32, block_hash: &BlockHash) -> bool {
+    let block_hash = block_hash.to_string();
+    BIP30_UNSPENDABLE_BLOCKS.iter().any(|(h, hash)| *h == height && *hash == block_hash)
+}
+
+/// Cumulative issuance statistics up to (and including) `height`. All amounts are in satoshis.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SupplyDBValue {
+    pub height: u32,
+    /// The sum of block subsidies defined by the subsidy schedule.
+    pub total_subsidy: u64,
+    pub total_fees: u64,
+    /// The outputs of the genesis block cannot be spent.
+    pub unspendable_genesis_block: u64,
+    /// Coinbase outputs overwritten by duplicate coinbase transactions.
+    pub unspendable_bip30: u64,
+    /// Outputs with unspendable scripts (e.g. OP_RETURN).
+    pub unspendable_scripts: u64,
+    /// Subsidy and fees which are not claimed by coinbase transactions.
+    pub unspendable_unclaimed_rewards: u64,
+}
+
+impl SupplyDBValue {
+    pub fn total_unspendable(&self) -> u64 {
+        self.unspendable_genesis_block + self.unspendable_bip30 + self.unspendable_scripts + self.unspendable_unclaimed_rewards
+    }
+    /// The amount of coins which can be spent (equals the total amount of the UTXO set).
+    pub fn circulating(&self) -> u64 {
+        self.total_subsidy - self.total_unspendable()
+    }
+    /// Compute the statistics of `block` on top of `prev` (the statistics of the previous block).
+    pub fn new(prev: &Self, height: u32, block: &Block, previous_utxos: &[UtxoEntry], config: &Config) -> Self {
+        let subsidy = get_block_subsidy(height, config);
+        let mut ret = Self {
+            height,
+            total_subsidy: prev.total_subsidy + subsidy,
+            ..prev.clone()
+        };
+        if height == 0 {
+            ret.unspendable_genesis_block += subsidy;
+            return ret;
+        }
+        let bip30_unspendable = is_bip30_unspendable(height, &block.block_hash());
+        let mut fees = 0u64;
+        let mut coinbase_out = 0u64;
+        let mut previous_utxo_index = 0;
+        for tx in block.txdata.iter() {
+            let tx_total_out: u64 = tx.output.iter().map(|output| output.value).sum();
+            if tx.is_coin_base() {
+                coinbase_out += tx_total_out;
+                if bip30_unspendable {
+                    ret.unspendable_bip30 += tx_total_out;
+                    continue;
+                }
+            } else {
+                let tx_total_in: u64 = previous_utxos[previous_utxo_index..previous_utxo_index+tx.input.len()].iter()
+                    .map(|utxo| utxo.value)
+                    .sum();
+                previous_utxo_index += tx.input.len();
+                fees += tx_total_in.saturating_sub(tx_total_out);
+            }
+            ret.unspendable_scripts += tx.output.iter()
+                .filter(|output| is_unspendable(&output.script_pubkey))
+                .map(|output| output.value)
+                .sum::<u64>();
+        }
+        ret.total_fees += fees;
+        ret.unspendable_unclaimed_rewards += (subsidy + fees).saturating_sub(coinbase_out);
+        ret
+    }
+}
+
+impl Serialize for SupplyDBValue {
+    fn serialize(&self) -> Vec<u8> {
+        let mut buf = Vec::new();
+        write_u32(&mut buf, self.height);
+        write_u64(&mut buf, self.total_subsidy);
+        write_u64(&mut buf, self.total_fees);
+        write_u64(&mut buf, self.unspendable_genesis_block);
+        write_u64(&mut buf, self.unspendable_bip30);
+        write_u64(&mut buf, self.unspendable_scripts);
+        write_u64(&mut buf, self.unspendable_unclaimed_rewards);
+        buf
+    }
+}
+
+impl Deserialize for SupplyDBValue {
+    fn deserialize(buf: &[u8]) -> Self {
+        let mut r = buf;
+        Self {
+            height: read_u32(&mut r),
+            total_subsidy: read_u64(&mut r),
+            total_fees: read_u64(&mut r),
+            unspendable_genesis_block: read_u64(&mut r),
+            unspendable_bip30: read_u64(&mut r),
+            unspendable_scripts: read_u64(&mut r),
+            unspendable_unclaimed_rewards: read_u64(&mut r),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct SupplyDB {
+    /// Stores (block_height, SupplyDBValue).
+    db: RocksDB<u32, SupplyDBValue>,
+    config: Config,
+}
+
+impl SupplyDB {
+    pub fn path(coin: &str) -> String {
+        format!("{}/{}/supply", data_dir(), coin)
+    }
+    pub fn new(coin: &str, config: &Config, temporary: bool) -> Self {
+        let path = Self::path(coin);
+        Self {
+            db: RocksDB::new(&path, temporary),
+            config: config.clone(),
+        }
+    }
+    pub fn get(&self, height: u32) -> Option<SupplyDBValue> {
+        self.db.get(&height)
+    }
+    /// Computes the missing entries up to `height` from the stored blocks
+    /// (the entries are missing if the database was synced before the supply was tracked).
+    pub fn backfill(&self, height: u32, block_db: &BlockDB, tx_db: &TxDB) {
+        // The entries are written in the order of height, so the missing ones form a suffix.
+        let start = match (0..=height).rev().find(|h| self.get(*h).is_some()) {
+            Some(h) => h + 1,
+            None => 0,
+        };
+        for h in start..=height {
+            let block_content = block_db.get(h).expect("Failed to get the block.");
+            let (block, previous_utxos) = tx_db.get_block(&block_content).expect("Failed to get the transactions of the block.");
+            self.process_block(h, &block, &previous_utxos);
+            if h % 10_000 == 0 {
+                println!("Supply backfilled up to height = {}.", to_locale_string(h));
+            }
+        }
+    }
+    pub fn process_block(&self, height: u32, block: &Block, previous_utxos: &[UtxoEntry]) {
+        let prev = if height > 0 {
+            self.get(height - 1).expect("Failed to get the supply of the previous block (run SupplyDB::backfill() first).")
+        } else {
+            SupplyDBValue::default()
+        };
+        let value = SupplyDBValue::new(&prev, height, block, previous_utxos, &self.config);
+        self.db.put(&height, &value);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+    use crate::db::utxo::UtxoDB;
+    use super::*;
+    #[test]
+    fn supply_db() {
+        let config = config_example("rbtc");
+        let supply_db = SupplyDB::new("test/supply", &config, true);
+        let mut utxo_db = UtxoDB::new("test/supply", true);
+        let blocks = fixtures::regtest_blocks();
+        for (height, block) in blocks.iter().enumerate() {
+            let prev_utxos = utxo_db.process_block(height as u32, &block, false);
+            supply_db.process_block(height as u32, &block, &prev_utxos);
+            // The circulating supply should match the total amount of the UTXO set.
+            assert_eq!(supply_db.get(height as u32).unwrap().circulating(), utxo_db.stats.total_amount);
+        }
+        let genesis = supply_db.get(0).unwrap();
+        assert_eq!(genesis.total_subsidy, 5_000_000_000);
+        assert_eq!(genesis.unspendable_genesis_block, 5_000_000_000);
+        assert_eq!(genesis.circulating(), 0);
+        let supply = supply_db.get(102).unwrap();
+        assert_eq!(supply, SupplyDBValue {
+            height: 102,
+            total_subsidy: 103 * 5_000_000_000,
+            total_fees: 14_100 + 20_800,
+            unspendable_genesis_block: 5_000_000_000,
+            unspendable_bip30: 0,
+            unspendable_scripts: 0,
+            unspendable_unclaimed_rewards: 0,
+        });
+        assert_eq!(SupplyDBValue::deserialize(&supply.serialize()), supply);
+        assert_eq!(supply_db.get(103), None);
+        // Unclaimed rewards and unspendable outputs.
+        let mut block = blocks[1].clone();
+        block.txdata[0].output[0].value = 4_000_000_000;
+        block.txdata[0].output[1].value = 600_000_000;
+        let supply = SupplyDBValue::new(&genesis, 1, &block, &[], &config);
+        assert_eq!(supply.unspendable_unclaimed_rewards, 400_000_000);
+        assert_eq!(supply.unspendable_scripts, 600_000_000);
+        assert_eq!(supply.circulating(), 4_000_000_000);
+        // Halving.
+        assert_eq!(SupplyDBValue::new(&genesis, 150, &blocks[1], &[], &config).unspendable_unclaimed_rewards, 0);
+        assert_eq!(SupplyDBValue::new(&genesis, 150, &blocks[1], &[], &config).total_subsidy, 7_500_000_000);
+    }
+    #[test]
+    fn bip30_unspendable() {
+        let hash = |hash: &str| BlockHash::from_str(hash).unwrap();
+        assert!(is_bip30_unspendable(91722, &hash("00000000000271a2dc26e7667f8419f2e15416dc6955e5a6c6cdf3f2574dd08e")));
+        assert!(is_bip30_unspendable(91812, &hash("00000000000af0aed4792b1acee3d966af36cf5def14935db8de83d6f9306f2f")));
+        // The blocks containing the duplicate coinbase transactions (`IsBIP30Repeat()` of Bitcoin Core), whose outputs are spendable.
+        assert!(!is_bip30_unspendable(91842, &hash("00000000000a4d0a398161ffc163c503763b1f4360639393e0e4c8e300e0caec")));
+        assert!(!is_bip30_unspendable(91880, &hash("00000000000743f190a18c5577a3c2d2a1f610ae9601ac046a38084ccb7cd721")));
+        // The height should match too.
+        assert!(!is_bip30_unspendable(91723, &hash("00000000000271a2dc26e7667f8419f2e15416dc6955e5a6c6cdf3f2574dd08e")));
+    }
+    #[test]
+    fn supply_db_backfill() {
+        let config = config_example("rbtc");
+        let supply_db = SupplyDB::new("test/supply_backfill", &config, true);
+        let expected = SupplyDB::new("test/supply_backfill/expected", &config, true);
+        let block_db = BlockDB::new("test/supply_backfill", true);
+        let tx_db = TxDB::new("test/supply_backfill", true);
+        let mut utxo_db = UtxoDB::new("test/supply_backfill", true);
+        let blocks = fixtures::regtest_blocks();
+        for (height, block) in blocks.iter().enumerate() {
+            let prev_utxos = utxo_db.process_block(height as u32, block, false);
+            tx_db.process_block(height as u32, block, &prev_utxos);
+            block_db.put(height as u32, block);
+            expected.process_block(height as u32, block, &prev_utxos);
+            // Only track the supply of the first blocks.
+            if height < 10 {
+                supply_db.process_block(height as u32, block, &prev_utxos);
+            }
+        }
+        let synced_height = blocks.len() as u32 - 1;
+        supply_db.backfill(synced_height, &block_db, &tx_db);
+        for height in 0..=synced_height {
+            assert_eq!(supply_db.get(height), expected.get(height));
+        }
+        // Backfill from genesis.
+        let supply_db = SupplyDB::new("test/supply_backfill/genesis", &config, true);
+        supply_db.backfill(synced_height, &block_db, &tx_db);
+        assert_eq!(supply_db.get(synced_height), expected.get(synced_height));
+    }
+}
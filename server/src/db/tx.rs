@@ -1,7 +1,8 @@
 use crate::*;
-use bitcoin::{Transaction, Txid, TxOut, Block};
-use bitcoin::blockdata::constants::WITNESS_SCALE_FACTOR;
+use bitcoin::{Transaction, Txid, TxOut, Block, WScriptHash};
+use crate::db::block::{BlockDB, BlockContentDBValue};
 use crate::db::utxo::UtxoEntry;
+use crate::counterparty::CounterpartyMessage;
 use crate::rocks_db::{Serialize, Deserialize};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -51,6 +52,16 @@ impl TxDBValue {
         }
         (confirmed_height, tx, previous_txouts)
     }
+    /// Returns the SHA256 hashes of the scripts of the outputs and the spent outputs.
+    pub fn wscript_hashes(&self) -> impl Iterator<Item = WScriptHash> + '_ {
+        self.tx.output.iter().chain(self.previous_txouts.iter()).map(|txout| txout.script_pubkey.wscript_hash())
+    }
+    /// True if an output or a spent output has the script whose SHA256 hash is `wscript_hash`.
+    ///
+    /// Unlike the address index, the P2PKH script of a P2PK or multisig output does not match it.
+    pub fn touches(&self, wscript_hash: &WScriptHash) -> bool {
+        self.wscript_hashes().any(|hash| hash == *wscript_hash)
+    }
 }
 
 impl Serialize for TxDBValue {
@@ -133,7 +144,40 @@ impl TxDB {
     pub fn get(&self, txid: &Txid) -> Option<TxDBValue> {
         self.db.get(&TxDBKey { txid: *txid })
     }
-    pub fn get_as_rest(&self, txid: &Txid, config: &Config) -> Option<chainseeker::Transaction> {
+    /// Reassembles a stored block and the outputs spent by it (in the order of `UtxoDB::process_block()`).
+    pub fn get_block(&self, block_content: &BlockContentDBValue) -> Option<(Block, Vec<UtxoEntry>)> {
+        let mut txdata = Vec::new();
+        let mut previous_utxos = Vec::new();
+        for txid in block_content.txids.iter() {
+            let value = self.get(txid)?;
+            let prevouts = value.tx.input.iter().filter(|vin| !vin.previous_output.is_null());
+            for (vin, txout) in prevouts.zip(value.previous_txouts) {
+                previous_utxos.push(UtxoEntry {
+                    script_pubkey: txout.script_pubkey,
+                    txid: vin.previous_output.txid,
+                    vout: vin.previous_output.vout,
+                    value: txout.value,
+                });
+            }
+            txdata.push(value.tx);
+        }
+        let block = Block {
+            header: block_content.block_header,
+            txdata,
+        };
+        Some((block, previous_utxos))
+    }
+    /// Returns the confirmed height of the transaction without decoding it.
+    pub fn get_confirmed_height(&self, txid: &Txid) -> Option<Option<u32>> {
+        self.db.get_raw(&TxDBKey { txid: *txid }).map(|buf| {
+            let confirmed_height = bytes_to_i32(&buf[0..4]);
+            if confirmed_height >= 0 { Some(confirmed_height as u32) } else { None }
+        })
+    }
+    /// Returns the transaction in the REST format.
+    /// `block_db` is used to fill the block hash and the block time.
+    /// The number of confirmations is computed only if `synced_height` is given.
+    pub fn get_as_rest(&self, txid: &Txid, block_db: &BlockDB, synced_height: Option<u32>, config: &Config) -> Option<chainseeker::Transaction> {
         //let begin_get = std::time::Instant::now();
         let buf = self.db.get_raw(&TxDBKey { txid: *txid });
         //println!("Transaction got in {}us.", begin_get.elapsed().as_micros());
@@ -142,34 +186,52 @@ impl TxDB {
             let (confirmed_height, rawtx, previous_txouts) = TxDBValue::deserialize_as_rawtx(&buf);
             let tx: Transaction = consensus_decode(&rawtx);
             let mut input_value = 0;
+            let mut resolved = true;
             let mut vin = Vec::new();
             let mut previous_txout_index = 0;
             for input in tx.input.iter() {
                 if input.previous_output.is_null() {
                     vin.push(create_vin(input, &None, config));
-                } else {
-                    input_value += previous_txouts[previous_txout_index].value;
-                    vin.push(create_vin(input, &Some(previous_txouts[previous_txout_index].clone()), config));
-                    previous_txout_index += 1;
+                    continue;
+                }
+                let previous_txout = previous_txouts.get(previous_txout_index).cloned();
+                match &previous_txout {
+                    Some(previous_txout) => input_value += previous_txout.value,
+                    None => resolved = false,
                 }
+                vin.push(create_vin(input, &previous_txout, config));
+                previous_txout_index += 1;
             }
             let output_value: u64 = tx.output.iter().map(|output| output.value).sum();
+            let coinbase = tx.is_coin_base();
+            let vsize = get_vsize(&tx);
+            // Coinbase transactions do not pay fees, and the fee is unknown unless all the previous outputs are resolved.
+            let fee = if coinbase || !resolved { None } else { input_value.checked_sub(output_value) };
+            let block_header = confirmed_height.and_then(|height| block_db.get_header(height));
             let tx = chainseeker::Transaction {
                 confirmed_height,
                 hex: hex::encode(&rawtx),
                 txid: tx.txid().to_string(),
                 hash: tx.wtxid().to_string(),
                 size: tx.get_size(),
-                // TODO: waiting for upstream merge.
-                //vsize: tx.get_vsize(),
-                vsize: (tx.get_weight() + WITNESS_SCALE_FACTOR - 1) / WITNESS_SCALE_FACTOR,
+                vsize,
                 weight: tx.get_weight(),
                 version: tx.version,
                 locktime: tx.lock_time,
                 vin,
                 vout: tx.output.iter().enumerate().map(|(n, vout)| create_vout(vout, n, config)).collect(),
-                // TODO: compute for coinbase transactions!
-                fee: (input_value as i64) - (output_value as i64),
+                fee,
+                fee_rate: fee.map(|fee| fee as f64 / vsize as f64),
+                // BIP125: any input with nSequence < 0xfffffffe signals replaceability.
+                rbf: !coinbase && tx.input.iter().any(|input| input.sequence < 0xfffffffe),
+                coinbase,
+                block_hash: block_header.map(|block_header| block_header.block_hash().to_string()),
+                block_time: block_header.map(|block_header| block_header.time),
+                confirmations: synced_height.map(|synced_height| match confirmed_height {
+                    Some(confirmed_height) => (synced_height + 1).saturating_sub(confirmed_height),
+                    None => 0,
+                }),
+                counterparty: CounterpartyMessage::from_tx(&tx, config).map(|message| create_counterparty(&message, config)),
             };
             //println!("Transaction converted in {}us.", begin_convert.elapsed().as_micros());
             Some(tx)
@@ -226,6 +288,7 @@ mod tests {
         let tx = &fixtures::regtest_blocks()[0].txdata[0];
         let tx_db = TxDB::new("test/tx/unconfirmed", true);
         tx_db.put_tx(&tx, None).unwrap();
+        assert_eq!(tx_db.get_confirmed_height(&tx.txid()), Some(None));
         assert_eq!(
             tx_db.get(&tx.txid()).unwrap(),
             TxDBValue {
@@ -234,6 +297,22 @@ mod tests {
                 previous_txouts: Vec::new(),
             },
         );
+        let block_db = BlockDB::new("test/tx/unconfirmed", true);
+        let tx = tx_db.get_as_rest(&tx.txid(), &block_db, Some(10), &config_example("rbtc")).unwrap();
+        assert!(tx.coinbase);
+        assert_eq!(tx.fee, None);
+        assert_eq!(tx.fee_rate, None);
+        assert_eq!(tx.block_hash, None);
+        assert_eq!(tx.block_time, None);
+        assert_eq!(tx.confirmations, Some(0));
+        // The fee is unknown if the previous outputs are not resolved.
+        let tx = &fixtures::regtest_blocks()[102].txdata[1];
+        tx_db.put(&tx.txid(), &TxDBValue { confirmed_height: None, tx: tx.clone(), previous_txouts: Vec::new() });
+        let tx = tx_db.get_as_rest(&tx.txid(), &block_db, None, &config_example("rbtc")).unwrap();
+        assert!(!tx.coinbase);
+        assert_eq!(tx.fee, None);
+        assert_eq!(tx.fee_rate, None);
+        assert_eq!(tx.confirmations, None);
     }
     #[test]
     fn put_confirmed() {
@@ -242,7 +321,7 @@ mod tests {
         let tx_db = TxDB::new("test/tx/confirmed", true);
         let mut previous_utxos_vec = Vec::new();
         for (height, block) in blocks.iter().enumerate() {
-            let previous_utxos = utxo_db.process_block(&block, true);
+            let previous_utxos = utxo_db.process_block(height as u32, &block, true);
             tx_db.process_block(height as u32, &block, &previous_utxos);
             previous_utxos_vec.push(previous_utxos);
         }
@@ -257,6 +336,7 @@ mod tests {
                 let mut previous_txout_index = 0;
                 let value = tx_db.get(&tx.txid()).unwrap();
                 assert_eq!(value.confirmed_height, Some(height as u32));
+                assert_eq!(tx_db.get_confirmed_height(&tx.txid()), Some(Some(height as u32)));
                 assert_eq!(value.tx, *tx);
                 for vin in tx.input.iter() {
                     if !vin.previous_output.is_null() {
@@ -270,6 +350,8 @@ mod tests {
                     }
                 }
             }
+            let block_content = BlockContentDBValue::new(height as u32, block);
+            assert_eq!(tx_db.get_block(&block_content), Some((block.clone(), previous_utxos_vec[height].clone())));
         }
     }
 }
@@ -1,6 +1,7 @@
 use crate::*;
 use bitcoin::{Block, Txid, Script, Transaction};
 use crate::rocks_db::{Serialize, Deserialize, RocksDBIterator};
+use crate::muhash::MuHash3072;
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub struct UtxoEntry {
@@ -37,22 +38,108 @@ impl Deserialize for UtxoDBKey {
 pub struct UtxoDBValue {
     script_pubkey: Script,
     value: u64,
+    height: u32,
+    coinbase: bool,
+}
+
+impl UtxoDBValue {
+    /// Returns true if the output is a member of the UTXO set of Bitcoin Core
+    /// (the outputs of the genesis block and unspendable outputs are never added to it).
+    fn in_utxo_set(&self) -> bool {
+        self.height > 0 && !is_unspendable(&self.script_pubkey)
+    }
 }
 
 impl Serialize for UtxoDBValue {
     fn serialize(&self) -> Vec<u8> {
-        [consensus_encode(&self.script_pubkey), self.value.to_le_bytes().to_vec()].concat()
+        let code = (self.height << 1) | self.coinbase as u32;
+        [consensus_encode(&self.script_pubkey), self.value.to_le_bytes().to_vec(), code.to_le_bytes().to_vec()].concat()
     }
 }
 
 impl Deserialize for UtxoDBValue {
     fn deserialize(buf: &[u8]) -> Self {
-        let script_pubkey_len = buf.len() - 8;
+        let script_pubkey_len = buf.len() - 12;
         let script_pubkey = consensus_decode(&buf[0..script_pubkey_len]);
-        let value = bytes_to_u64(&buf[script_pubkey_len..]);
+        let value = bytes_to_u64(&buf[script_pubkey_len..script_pubkey_len+8]);
+        let code = bytes_to_u32(&buf[script_pubkey_len+8..]);
         Self {
             script_pubkey,
             value,
+            height: code >> 1,
+            coinbase: code & 1 == 1,
+        }
+    }
+}
+
+/// Statistics of the UTXO set, comparable to Bitcoin Core's `gettxoutsetinfo muhash`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct UtxoSetStats {
+    pub txouts: u64,
+    pub bogosize: u64,
+    pub total_amount: u64,
+    pub muhash: MuHash3072,
+}
+
+impl UtxoSetStats {
+    /// Serialize an UTXO in the same manner as Bitcoin Core's `TxOutSer()`.
+    fn muhash_element(key: &UtxoDBKey, value: &UtxoDBValue) -> Vec<u8> {
+        let code = (value.height << 1) | value.coinbase as u32;
+        [
+            consensus_encode(&key.txid),
+            key.vout.to_le_bytes().to_vec(),
+            code.to_le_bytes().to_vec(),
+            value.value.to_le_bytes().to_vec(),
+            consensus_encode(&value.script_pubkey),
+        ].concat()
+    }
+    /// Same as Bitcoin Core's `GetBogoSize()`.
+    fn bogosize(value: &UtxoDBValue) -> u64 {
+        32 + 4 + 4 + 8 + 2 + value.script_pubkey.len() as u64
+    }
+    fn add(&mut self, key: &UtxoDBKey, value: &UtxoDBValue) {
+        if !value.in_utxo_set() {
+            return;
+        }
+        self.txouts += 1;
+        self.bogosize += Self::bogosize(value);
+        self.total_amount += value.value;
+        self.muhash.insert(&Self::muhash_element(key, value));
+    }
+    fn remove(&mut self, key: &UtxoDBKey, value: &UtxoDBValue) {
+        if !value.in_utxo_set() {
+            return;
+        }
+        self.txouts -= 1;
+        self.bogosize -= Self::bogosize(value);
+        self.total_amount -= value.value;
+        self.muhash.remove(&Self::muhash_element(key, value));
+    }
+}
+
+impl Serialize for UtxoSetStats {
+    fn serialize(&self) -> Vec<u8> {
+        let mut buf = Vec::new();
+        write_u64(&mut buf, self.txouts);
+        write_u64(&mut buf, self.bogosize);
+        write_u64(&mut buf, self.total_amount);
+        write_arr(&mut buf, &self.muhash.to_bytes());
+        buf
+    }
+}
+
+impl Deserialize for UtxoSetStats {
+    fn deserialize(buf: &[u8]) -> Self {
+        let mut r = buf;
+        let txouts = read_u64(&mut r);
+        let bogosize = read_u64(&mut r);
+        let total_amount = read_u64(&mut r);
+        let muhash = MuHash3072::from_bytes(r);
+        Self {
+            txouts,
+            bogosize,
+            total_amount,
+            muhash,
         }
     }
 }
@@ -89,8 +176,10 @@ impl<'a> Iterator for UtxoDBIterator<'a> {
 pub struct UtxoDB {
     /// Stores:
     ///     key   = txid || vout
-    ///     value = script_pubkey || value
+    ///     value = script_pubkey || value || (height << 1 | coinbase)
     pub db: RocksDB<UtxoDBKey, UtxoDBValue>,
+    /// The statistics of the current UTXO set (should be restored by the caller on startup).
+    pub stats: UtxoSetStats,
 }
 
 impl UtxoDB {
@@ -98,6 +187,7 @@ impl UtxoDB {
         let path = Self::get_path(coin);
         Self {
             db: RocksDB::new(&path, temporary),
+            stats: UtxoSetStats::default(),
         }
     }
     fn get_path(coin: &str) -> String {
@@ -108,19 +198,43 @@ impl UtxoDB {
             iter: self.db.iter(),
         }
     }
-    pub fn process_block(&mut self, block: &Block, no_panic: bool) -> Vec<UtxoEntry> {
+    /// Iterate UTXO entries along with their confirmed height and coinbase flag.
+    pub fn iter_with_height(&self) -> impl Iterator<Item = (UtxoEntry, u32, bool)> + '_ {
+        self.db.iter().map(|(key, value)| {
+            let (height, coinbase) = (value.height, value.coinbase);
+            ((key, value).into(), height, coinbase)
+        })
+    }
+    /// Recompute the statistics from scratch by scanning the whole database.
+    pub fn compute_stats(&self) -> UtxoSetStats {
+        let mut stats = UtxoSetStats::default();
+        for (key, value) in self.db.iter() {
+            stats.add(&key, &value);
+        }
+        stats
+    }
+    pub fn process_block(&mut self, height: u32, block: &Block, no_panic: bool) -> Vec<UtxoEntry> {
         let mut inserts = std::collections::HashMap::new();
         // Process vouts.
         for tx in block.txdata.iter() {
             let txid = tx.txid();
+            let coinbase = tx.is_coin_base();
             for (vout, output) in tx.output.iter().enumerate() {
                 let key = UtxoDBKey {
                     txid,
                     vout: vout as u32,
                 };
+                // Duplicate coinbase transactions (BIP30) overwrite the existing outputs.
+                if coinbase {
+                    if let Some(value) = self.db.get(&key) {
+                        self.stats.remove(&key, &value);
+                    }
+                }
                 let value = UtxoDBValue {
                     script_pubkey: output.script_pubkey.clone(),
                     value: output.value,
+                    height,
+                    coinbase,
                 };
                 inserts.insert(key, value);
             }
@@ -141,6 +255,7 @@ impl UtxoDB {
                         match self.db.get(&key) {
                             Some(value) => {
                                 batch.delete(&key);
+                                self.stats.remove(&key, &value);
                                 value
                             },
                             None => {
@@ -151,6 +266,8 @@ impl UtxoDB {
                                 UtxoDBValue {
                                     script_pubkey: Script::new(),
                                     value: 0,
+                                    height: 0,
+                                    coinbase: false,
                                 }
                             },
                         }
@@ -161,11 +278,13 @@ impl UtxoDB {
         }
         for (key, value) in inserts.iter() {
             batch.put(&key, &value);
+            self.stats.add(key, value);
         }
         self.db.write(batch).unwrap();
         previous_utxos
     }
-    pub fn reorg_block(&mut self, block: &Block, prev_txs: &[Transaction]) {
+    /// Revert `block`. `prev_txs` should contain the transactions (and their confirmed heights) spent by each input.
+    pub fn reorg_block(&mut self, block: &Block, prev_txs: &[(u32, Transaction)]) {
         // Process vins.
         let mut prev_tx_offset = 0;
         for tx in block.txdata.iter() {
@@ -177,7 +296,7 @@ impl UtxoDB {
                         txid: *txid,
                         vout,
                     };
-                    let prev_tx = &prev_txs[prev_tx_offset];
+                    let (prev_height, prev_tx) = &prev_txs[prev_tx_offset];
                     prev_tx_offset += 1;
                     let prev_out = &prev_tx.output[vout as usize];
                     let script_pubkey = &prev_out.script_pubkey;
@@ -185,8 +304,11 @@ impl UtxoDB {
                     let value = UtxoDBValue {
                         script_pubkey: (*script_pubkey).clone(),
                         value,
+                        height: *prev_height,
+                        coinbase: prev_tx.is_coin_base(),
                     };
                     self.db.put(&key, &value);
+                    self.stats.add(&key, &value);
                 }
             }
         }
@@ -198,6 +320,9 @@ impl UtxoDB {
                     txid,
                     vout: vout as u32,
                 };
+                if let Some(value) = self.db.get(&key) {
+                    self.stats.remove(&key, &value);
+                }
                 self.db.delete(&key);
             }
         }
@@ -219,11 +344,11 @@ mod tests {
             utxo.value);
         }
     }
-    fn find_tx(blocks: &[Block], txid: &Txid) -> Transaction {
-        for block in blocks.iter() {
+    fn find_tx(blocks: &[Block], txid: &Txid) -> (u32, Transaction) {
+        for (height, block) in blocks.iter().enumerate() {
             for tx in block.txdata.iter() {
                 if tx.txid() == *txid {
-                    return (*tx).clone();
+                    return (height as u32, (*tx).clone());
                 }
             }
         }
@@ -233,8 +358,10 @@ mod tests {
     fn utxo_db() {
         let blocks = fixtures::regtest_blocks();
         let mut utxo_db = UtxoDB::new("test/utxo", true);
-        for block in blocks.iter() {
-            utxo_db.process_block(&block, false);
+        let mut stats = Vec::new();
+        for (height, block) in blocks.iter().enumerate() {
+            utxo_db.process_block(height as u32, &block, false);
+            stats.push(utxo_db.stats.clone());
         }
         println!("BEFORE");
         print_utxo_db(&utxo_db);
@@ -243,6 +370,18 @@ mod tests {
         utxos_test.sort();
         let utxos = fixtures::utxos_before_reorg();
         assert_eq!(utxos_test, utxos);
+        // Test UTXO set statistics BEFORE reorg.
+        let computed_stats = utxo_db.compute_stats();
+        assert_eq!(utxo_db.stats.txouts, computed_stats.txouts);
+        assert_eq!(utxo_db.stats.bogosize, computed_stats.bogosize);
+        assert_eq!(utxo_db.stats.total_amount, computed_stats.total_amount);
+        assert_eq!(utxo_db.stats.muhash.finalize(), computed_stats.muhash.finalize());
+        // The outputs of the genesis block and OP_RETURN outputs are excluded.
+        let expected = utxos.iter().filter(|utxo| {
+            utxo.txid != blocks[0].txdata[0].txid() && !utxo.script_pubkey.is_op_return()
+        }).collect::<Vec<&UtxoEntry>>();
+        assert_eq!(utxo_db.stats.txouts, expected.len() as u64);
+        assert_eq!(utxo_db.stats.total_amount, expected.iter().map(|utxo| utxo.value).sum::<u64>());
         // Test UTXO database AFTER reorg.
         let reorged_block = fixtures::regtest_reorged_block();
         // Find previous transactions.
@@ -258,12 +397,23 @@ mod tests {
             }
         }
         utxo_db.reorg_block(&blocks.last().unwrap(), &prev_txs);
-        utxo_db.process_block(&reorged_block, false);
+        // Reverting the block restores the previous statistics.
+        let previous_stats = &stats[stats.len() - 2];
+        assert_eq!(utxo_db.stats.txouts, previous_stats.txouts);
+        assert_eq!(utxo_db.stats.total_amount, previous_stats.total_amount);
+        assert_eq!(utxo_db.stats.muhash.finalize(), previous_stats.muhash.finalize());
+        utxo_db.process_block((blocks.len() - 1) as u32, &reorged_block, false);
         println!("AFTER");
         print_utxo_db(&utxo_db);
         let mut utxos_test = utxo_db.iter().collect::<Vec<UtxoEntry>>();
         utxos_test.sort();
         let utxos = fixtures::utxos_after_reorg();
         assert_eq!(utxos_test, utxos);
+        // Test UTXO set statistics AFTER reorg.
+        let computed_stats = utxo_db.compute_stats();
+        assert_eq!(utxo_db.stats.txouts, computed_stats.txouts);
+        assert_eq!(utxo_db.stats.total_amount, computed_stats.total_amount);
+        assert_eq!(utxo_db.stats.muhash.finalize(), computed_stats.muhash.finalize());
+        assert_eq!(UtxoSetStats::deserialize(&utxo_db.stats.serialize()), utxo_db.stats);
     }
 }
@@ -1,17 +1,216 @@
 use crate::*;
 use indexmap::IndexMap;
-use bitcoin::{Txid, Script, Block, WScriptHash};
+use bitcoin::hashes::Hash;
+use bitcoin::{Txid, Script, Block, Transaction, WScriptHash};
+use crate::rocks_db::{Serialize, Deserialize};
 use crate::db::utxo::UtxoEntry;
 
+/// Write `n` in the LEB128 encoding (7 bits per byte, the most significant bit marks continuation).
+fn write_varint(buf: &mut Vec<u8>, mut n: u64) {
+    while n >= 0x80 {
+        buf.push((n as u8) | 0x80);
+        n >>= 7;
+    }
+    buf.push(n as u8);
+}
+
+fn read_varint(r: &mut &[u8]) -> u64 {
+    let mut n = 0u64;
+    let mut shift = 0;
+    loop {
+        let b = r[0];
+        *r = &r[1..];
+        n |= ((b & 0x7f) as u64) << shift;
+        if b & 0x80 == 0 {
+            return n;
+        }
+        shift += 7;
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct UtxoServerValue {
-    pub txid: Txid,  // +32 = 32
-    pub vout: u32,   // + 4 = 36
+    pub txid: Txid,
+    pub vout: u32,
+    code: u32,  // height << 2 | p2pk << 1 | coinbase
+    pub value: u64,
+}
+
+impl UtxoServerValue {
+    pub fn new(txid: Txid, vout: u32, value: u64, height: u32, coinbase: bool, p2pk: bool) -> Self {
+        Self {
+            txid,
+            vout,
+            code: (height << 2) | (p2pk as u32) << 1 | coinbase as u32,
+            value,
+        }
+    }
+    /// The height of the block which contains the transaction.
+    pub fn height(&self) -> u32 {
+        self.code >> 2
+    }
+    /// True if the entry is a P2PK output which is indexed under its P2PKH script.
+    pub fn p2pk(&self) -> bool {
+        self.code & 2 == 2
+    }
+    pub fn coinbase(&self) -> bool {
+        self.code & 1 == 1
+    }
+    /// Serialized as txid || varint(vout) || varint(code) || varint(value) (typically 40 bytes or less).
+    fn write_compact(&self, buf: &mut Vec<u8>) {
+        buf.extend(self.txid.as_ref());
+        write_varint(buf, self.vout as u64);
+        write_varint(buf, self.code as u64);
+        write_varint(buf, self.value);
+    }
+    fn read_compact(r: &mut &[u8]) -> Self {
+        let txid = Txid::from_slice(&r[0..32]).unwrap();
+        *r = &r[32..];
+        Self {
+            txid,
+            vout: read_varint(r) as u32,
+            code: read_varint(r) as u32,
+            value: read_varint(r),
+        }
+    }
+}
+
+/// Decodes the entries of a script encoded by `UtxoServerValue::write_compact()`.
+fn read_compact_values(mut r: &[u8]) -> Vec<UtxoServerValue> {
+    let mut values = Vec::new();
+    while !r.is_empty() {
+        values.push(UtxoServerValue::read_compact(&mut r));
+    }
+    values
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UtxoServerDBKey {
+    wscript_hash: WScriptHash,
+    txid: Txid,
+    vout: u32,
+}
+
+impl Serialize for UtxoServerDBKey {
+    fn serialize(&self) -> Vec<u8> {
+        [self.wscript_hash.to_vec(), self.txid.to_vec(), self.vout.to_le_bytes().to_vec()].concat()
+    }
+}
+
+impl Deserialize for UtxoServerDBKey {
+    fn deserialize(buf: &[u8]) -> Self {
+        Self {
+            wscript_hash: WScriptHash::from_slice(&buf[0..32]).unwrap(),
+            txid: consensus_decode(&buf[32..64]),
+            vout: bytes_to_u32(&buf[64..68]),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UtxoServerDBValue {
+    code: u32,
+    value: u64,
+}
+
+impl Serialize for UtxoServerDBValue {
+    fn serialize(&self) -> Vec<u8> {
+        let mut buf = Vec::new();
+        write_varint(&mut buf, self.code as u64);
+        write_varint(&mut buf, self.value);
+        buf
+    }
+}
+
+impl Deserialize for UtxoServerDBValue {
+    fn deserialize(buf: &[u8]) -> Self {
+        let mut r = buf;
+        Self {
+            code: read_varint(&mut r) as u32,
+            value: read_varint(&mut r),
+        }
+    }
+}
+
+/// The disk-backed storage of the UTXO server used in the low-memory mode.
+#[derive(Debug)]
+struct UtxoServerDB {
+    path: String,
+    db: RocksDB<UtxoServerDBKey, UtxoServerDBValue>,
+    /// The number of scripts.
+    len: usize,
+    /// The height the entries are synced to (`None` if they should be loaded from the UTXO database).
+    synced_height: Option<u32>,
+}
+
+impl UtxoServerDB {
+    fn meta_path(path: &str) -> String {
+        format!("{}.meta", path)
+    }
+    /// Opens the database if it is synced to `synced_height`, otherwise it is wiped to be loaded from scratch.
+    fn new(coin: &str, synced_height: Option<u32>, temporary: bool) -> Self {
+        let path = format!("{}/{}/utxo_server", data_dir(), coin);
+        // Serialized as synced_height || len.
+        let meta = match std::fs::read(Self::meta_path(&path)) {
+            Ok(buf) if !temporary => {
+                let mut r = &buf[..];
+                Some((read_u32(&mut r), read_usize(&mut r)))
+            },
+            _ => None,
+        };
+        let meta = meta.filter(|(height, _len)| Some(*height) == synced_height);
+        if meta.is_none() && std::path::Path::new(&path).exists() {
+            std::fs::remove_dir_all(&path).expect("Failed to remove the UTXO server database.");
+            // The metadata is stale.
+            let _ = std::fs::remove_file(Self::meta_path(&path));
+        }
+        Self {
+            db: RocksDB::new(&path, temporary),
+            path,
+            len: meta.map_or(0, |(_height, len)| len),
+            synced_height: meta.map(|(height, _len)| height),
+        }
+    }
+    /// Record that the entries are synced to `height` (written to a temporary file first to be atomic).
+    fn save_meta(&mut self, height: u32) {
+        self.synced_height = Some(height);
+        let mut buf = Vec::new();
+        write_u32(&mut buf, height);
+        write_usize(&mut buf, self.len);
+        let meta_path = Self::meta_path(&self.path);
+        let tmp_path = format!("{}.tmp", meta_path);
+        std::fs::write(&tmp_path, buf).expect("Failed to write the UTXO server metadata.");
+        std::fs::rename(&tmp_path, &meta_path).expect("Failed to write the UTXO server metadata.");
+    }
+    fn has_script(&self, wscript_hash: &WScriptHash) -> bool {
+        self.db.prefix_iter(wscript_hash.to_vec()).next().is_some()
+    }
+    fn put(&mut self, key: &UtxoServerDBKey, value: &UtxoServerDBValue) {
+        if !self.has_script(&key.wscript_hash) {
+            self.len += 1;
+        }
+        self.db.put(key, value);
+    }
+    fn delete(&mut self, key: &UtxoServerDBKey) {
+        // Zero-value outputs are not loaded from the UTXO database.
+        if self.db.get(key).is_none() {
+            return;
+        }
+        self.db.delete(key);
+        if !self.has_script(&key.wscript_hash) {
+            self.len -= 1;
+        }
+    }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug)]
 pub struct UtxoServer {
-    db: IndexMap<WScriptHash, Vec<UtxoServerValue>>,
+    /// Stores the entries of each script concatenated in the compact encoding of `UtxoServerValue::write_compact()`.
+    db: IndexMap<WScriptHash, Vec<u8>>,
+    /// Stores the entries on disk instead of `db` in the low-memory mode:
+    ///     key   = wscript_hash || txid || vout
+    ///     value = varint(height << 2 | p2pk << 1 | coinbase) || varint(value)
+    disk: Option<UtxoServerDB>,
 }
 
 impl Default for UtxoServer {
@@ -24,57 +223,127 @@ impl UtxoServer {
     pub fn new() -> Self {
         Self {
             db: IndexMap::new(),
+            disk: None,
+        }
+    }
+    /// Construct the disk-backed server, which is reused if it is synced to `synced_height` and wiped otherwise.
+    pub fn new_on_disk(coin: &str, synced_height: Option<u32>, temporary: bool) -> Self {
+        Self {
+            db: IndexMap::new(),
+            disk: Some(UtxoServerDB::new(coin, synced_height, temporary)),
+        }
+    }
+    /// The height the disk-backed server is synced to (always `None` for the in-memory one).
+    pub fn synced_height(&self) -> Option<u32> {
+        self.disk.as_ref().and_then(|disk| disk.synced_height)
+    }
+    /// Persist the state of the disk-backed server synced to `height` (no-op for the in-memory one).
+    pub fn set_synced_height(&mut self, height: u32) {
+        if let Some(disk) = &mut self.disk {
+            disk.save_meta(height);
         }
     }
     pub fn is_empty(&self) -> bool {
-        self.db.is_empty()
+        self.len() == 0
     }
+    /// The number of scripts.
     pub fn len(&self) -> usize {
-        self.db.len()
+        match &self.disk {
+            Some(disk) => disk.len,
+            None => self.db.len(),
+        }
     }
     pub fn capacity(&self) -> usize {
         self.db.capacity()
     }
     pub fn size(&self) -> usize {
-        self.db.iter().map(|(script, value)| script.len() + 36 * value.len()).sum()
+        self.db.iter().map(|(script, values)| script.len() + values.len()).sum()
     }
     pub fn shrink_to_fit(&mut self) {
         self.db.shrink_to_fit();
     }
-    pub fn iter(&self) -> indexmap::map::Iter<WScriptHash, Vec<UtxoServerValue>> {
-        self.db.iter()
+    pub fn iter(&self) -> impl Iterator<Item = (&WScriptHash, Vec<UtxoServerValue>)> {
+        self.db.iter().map(|(wscript_hash, values)| (wscript_hash, read_compact_values(values)))
     }
     pub fn get(&self, script_pubkey: &Script) -> Vec<UtxoServerValue> {
-        self.db.get(&script_pubkey.wscript_hash()).map_or_else(Vec::new, |values| (*values).clone())
+        self.get_by_hash(&script_pubkey.wscript_hash())
     }
-    pub fn push(&mut self, utxo: &UtxoEntry) {
-        let v = UtxoServerValue {
-            txid: utxo.txid,
-            vout: utxo.vout,
-        };
-        let wscript_hash = utxo.script_pubkey.wscript_hash();
-        match self.db.get_mut(&wscript_hash) {
-            Some(values) => values.push(v),
-            None => assert!(self.db.insert(wscript_hash, vec![v]).is_none()),
+    /// Same as `get()`, but the script is specified by its SHA256 hash.
+    pub fn get_by_hash(&self, wscript_hash: &WScriptHash) -> Vec<UtxoServerValue> {
+        match &self.disk {
+            Some(disk) => disk.db.prefix_iter(wscript_hash.to_vec()).map(|(key, value)| {
+                UtxoServerValue {
+                    txid: key.txid,
+                    vout: key.vout,
+                    code: value.code,
+                    value: value.value,
+                }
+            }).collect(),
+            None => self.db.get(wscript_hash).map_or_else(Vec::new, |values| read_compact_values(values)),
+        }
+    }
+    pub fn push(&mut self, utxo: &UtxoEntry, height: u32, coinbase: bool) {
+        let v = UtxoServerValue::new(utxo.txid, utxo.vout, utxo.value, height, coinbase, false);
+        self.insert(utxo.script_pubkey.wscript_hash(), v);
+        // P2PK outputs are also indexed under their P2PKH script.
+        if let Some(p2pkh) = p2pk_to_p2pkh(&utxo.script_pubkey) {
+            let v = UtxoServerValue::new(utxo.txid, utxo.vout, utxo.value, height, coinbase, true);
+            self.insert(p2pkh.wscript_hash(), v);
         }
     }
+    fn insert(&mut self, wscript_hash: WScriptHash, v: UtxoServerValue) {
+        if let Some(disk) = &mut self.disk {
+            let key = UtxoServerDBKey {
+                wscript_hash,
+                txid: v.txid,
+                vout: v.vout,
+            };
+            disk.put(&key, &UtxoServerDBValue { code: v.code, value: v.value });
+            return;
+        }
+        v.write_compact(self.db.entry(wscript_hash).or_default());
+    }
     fn remove(&mut self, script_pubkey: &Script, txid: &Txid, vout: u32) {
-        let values = self.db.get_mut(&script_pubkey.wscript_hash()).unwrap();
-        *values = values.iter().filter(|&utxo_value| {
-            !(utxo_value.txid == *txid && utxo_value.vout == vout)
-        }).cloned().collect();
+        self.delete(script_pubkey.wscript_hash(), txid, vout);
+        if let Some(p2pkh) = p2pk_to_p2pkh(script_pubkey) {
+            self.delete(p2pkh.wscript_hash(), txid, vout);
+        }
     }
-    pub fn process_block(&mut self, block: &Block, previous_utxos: &[UtxoEntry]) {
+    fn delete(&mut self, wscript_hash: WScriptHash, txid: &Txid, vout: u32) {
+        if let Some(disk) = &mut self.disk {
+            disk.delete(&UtxoServerDBKey {
+                wscript_hash,
+                txid: *txid,
+                vout,
+            });
+            return;
+        }
+        // Zero-value outputs are not loaded from the UTXO database.
+        if let Some(values) = self.db.get_mut(&wscript_hash) {
+            let mut r = &values[..];
+            while !r.is_empty() {
+                let begin = values.len() - r.len();
+                let utxo_value = UtxoServerValue::read_compact(&mut r);
+                if utxo_value.txid == *txid && utxo_value.vout == vout {
+                    let end = values.len() - r.len();
+                    values.drain(begin..end);
+                    break;
+                }
+            }
+        }
+    }
+    pub fn process_block(&mut self, height: u32, block: &Block, previous_utxos: &[UtxoEntry]) {
         // Process vouts.
         for tx in block.txdata.iter() {
             let txid = tx.txid();
+            let coinbase = tx.is_coin_base();
             for (vout, output) in tx.output.iter().enumerate() {
                 self.push(&UtxoEntry {
                     script_pubkey: output.script_pubkey.clone(),
                     txid,
                     vout: vout as u32,
                     value: output.value,
-                });
+                }, height, coinbase);
             }
         }
         // Process vins.
@@ -89,6 +358,20 @@ impl UtxoServer {
             }
         }
     }
+    /// Revert `process_block()` for the block disconnected by a reorg.
+    ///
+    /// `prev_txs` holds the confirmed height and the transaction of each entry of `previous_utxos`.
+    pub fn reorg_block(&mut self, block: &Block, previous_utxos: &[UtxoEntry], prev_txs: &[(u32, Transaction)]) {
+        for (utxo, (height, prev_tx)) in previous_utxos.iter().zip(prev_txs.iter()) {
+            self.push(utxo, *height, prev_tx.is_coin_base());
+        }
+        for tx in block.txdata.iter() {
+            let txid = tx.txid();
+            for (vout, output) in tx.output.iter().enumerate() {
+                self.remove(&output.script_pubkey, &txid, vout as u32);
+            }
+        }
+    }
 }
 
 #[cfg(test)]
@@ -100,9 +383,13 @@ mod tests {
         for (wscript_hash, values) in utxo_server.iter() {
             println!("        (WScriptHash::from_hex(\"{}\").unwrap(), vec![", hex::encode(wscript_hash));
             for value in values.iter() {
-                println!("            UtxoServerValue {{ txid: consensus_decode(&hex::decode(\"{}\").unwrap()), vout: {}, }},",
+                println!("            UtxoServerValue::new(consensus_decode(&hex::decode(\"{}\").unwrap()), {}, {}, {}, {}, {}),",
                     hex::encode(consensus_encode(&value.txid)),
-                    value.vout);
+                    value.vout,
+                    value.value,
+                    value.height(),
+                    value.coinbase(),
+                    value.p2pk());
             }
             println!("        ]),");
         }
@@ -113,9 +400,9 @@ mod tests {
         assert!(utxo_server.is_empty());
         let mut utxo_db = UtxoDB::new("test/utxo_server", true);
         let blocks = fixtures::regtest_blocks();
-        for block in blocks.iter() {
-            let prev_utxos = utxo_db.process_block(&block, false);
-            utxo_server.process_block(&block, &prev_utxos);
+        for (height, block) in blocks.iter().enumerate() {
+            let prev_utxos = utxo_db.process_block(height as u32, &block, false);
+            utxo_server.process_block(height as u32, &block, &prev_utxos);
         }
         utxo_server.shrink_to_fit();
         print_utxo_server(&utxo_server);
@@ -123,11 +410,77 @@ mod tests {
         let entries = fixtures::utxo_server_entries();
         assert_eq!(utxo_server.len(), entries.len());
         assert_eq!(utxo_server.capacity(), entries.len());
-        assert_eq!(utxo_server.size(), 7672);
+        assert_eq!(utxo_server.size(), 8096);
         for (i, (wscript_hash, value)) in utxo_server.iter().enumerate() {
             assert_eq!(*wscript_hash, entries[i].0);
-            assert_eq!(*value, entries[i].1);
+            assert_eq!(value, entries[i].1);
         }
         assert_eq!(utxo_server.get(&blocks[0].txdata[0].output[0].script_pubkey).len(), 1);
     }
+    #[test]
+    fn compact_encoding() {
+        for n in [0, 1, 0x7f, 0x80, 5_000_000_000, u64::MAX].iter() {
+            let mut buf = Vec::new();
+            write_varint(&mut buf, *n);
+            assert_eq!(read_varint(&mut &buf[..]), *n);
+        }
+        let value = UtxoServerValue::new(Txid::default(), 1, 5_000_000_000, 700_000, true, false);
+        let mut buf = Vec::new();
+        value.write_compact(&mut buf);
+        assert_eq!(buf.len(), 32 + 1 + 4 + 5);
+        assert_eq!(read_compact_values(&buf), vec![value.clone()]);
+        let db_value = UtxoServerDBValue { code: value.code, value: value.value };
+        assert_eq!(db_value.serialize().len(), 9);
+        assert_eq!(UtxoServerDBValue::deserialize(&db_value.serialize()), db_value);
+    }
+    #[tokio::test]
+    async fn utxo_server_on_disk() {
+        // The persistent database is written to the data directory, so it is removed before and after the test.
+        let path = format!("{}/test/utxo_server_on_disk", data_dir());
+        let remove_dir = || if std::path::Path::new(&path).exists() {
+            std::fs::remove_dir_all(&path).unwrap();
+        };
+        remove_dir();
+        let mut utxo_server = UtxoServer::new();
+        let mut utxo_server_on_disk = UtxoServer::new_on_disk("test/utxo_server_on_disk", None, false);
+        assert!(utxo_server_on_disk.is_empty());
+        let mut utxo_db = UtxoDB::new("test/utxo_server_on_disk", true);
+        let blocks = fixtures::regtest_blocks();
+        for (height, block) in blocks.iter().enumerate() {
+            let prev_utxos = utxo_db.process_block(height as u32, block, false);
+            utxo_server.process_block(height as u32, block, &prev_utxos);
+            utxo_server_on_disk.process_block(height as u32, block, &prev_utxos);
+        }
+        assert!(!utxo_server_on_disk.is_empty());
+        assert_eq!(utxo_server_on_disk.len(), utxo_server.iter().filter(|(_wscript_hash, values)| !values.is_empty()).count());
+        assert_eq!(utxo_server_on_disk.size(), 0);
+        for block in blocks.iter() {
+            for tx in block.txdata.iter() {
+                for output in tx.output.iter() {
+                    let mut expected = utxo_server.get(&output.script_pubkey);
+                    expected.sort_by_key(|value| (value.txid, value.vout));
+                    assert_eq!(utxo_server_on_disk.get(&output.script_pubkey), expected);
+                }
+            }
+        }
+        // Reopen at the synced height.
+        assert_eq!(utxo_server_on_disk.synced_height(), None);
+        utxo_server_on_disk.set_synced_height(102);
+        let len = utxo_server_on_disk.len();
+        drop(utxo_server_on_disk);
+        let utxo_server_on_disk = UtxoServer::new_on_disk("test/utxo_server_on_disk", Some(102), false);
+        assert_eq!(utxo_server_on_disk.synced_height(), Some(102));
+        assert_eq!(utxo_server_on_disk.len(), len);
+        let script_pubkey = &blocks[0].txdata[0].output[0].script_pubkey;
+        assert_eq!(utxo_server_on_disk.get(script_pubkey), utxo_server.get(script_pubkey));
+        drop(utxo_server_on_disk);
+        // Wiped at another height.
+        let utxo_server_on_disk = UtxoServer::new_on_disk("test/utxo_server_on_disk", Some(103), true);
+        assert_eq!(utxo_server_on_disk.synced_height(), None);
+        assert!(utxo_server_on_disk.is_empty());
+        assert!(utxo_server_on_disk.get(script_pubkey).is_empty());
+        drop(utxo_server_on_disk);
+        drop(utxo_db);
+        remove_dir();
+    }
 }
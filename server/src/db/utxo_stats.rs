@@ -0,0 +1,47 @@
+use crate::*;
+use crate::db::utxo::UtxoSetStats;
+
+#[derive(Debug)]
+pub struct UtxoStatsDB {
+    /// Stores (block_height, UtxoSetStats).
+    db: RocksDB<u32, UtxoSetStats>,
+}
+
+impl UtxoStatsDB {
+    pub fn path(coin: &str) -> String {
+        format!("{}/{}/utxo_stats", data_dir(), coin)
+    }
+    pub fn new(coin: &str, temporary: bool) -> Self {
+        let path = Self::path(coin);
+        Self {
+            db: RocksDB::new(&path, temporary),
+        }
+    }
+    pub fn put(&self, height: u32, stats: &UtxoSetStats) {
+        self.db.put(&height, stats);
+    }
+    pub fn get(&self, height: u32) -> Option<UtxoSetStats> {
+        self.db.get(&height)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::db::utxo::UtxoDB;
+    use super::*;
+    #[test]
+    fn utxo_stats_db() {
+        let utxo_stats_db = UtxoStatsDB::new("test/utxo_stats", true);
+        let mut utxo_db = UtxoDB::new("test/utxo_stats", true);
+        let blocks = fixtures::regtest_blocks();
+        for (height, block) in blocks.iter().enumerate() {
+            utxo_db.process_block(height as u32, &block, false);
+            utxo_stats_db.put(height as u32, &utxo_db.stats);
+        }
+        assert_eq!(utxo_stats_db.get(0).unwrap().txouts, 0);
+        assert_eq!(utxo_stats_db.get(1).unwrap().txouts, 1);
+        assert_eq!(utxo_stats_db.get(1).unwrap().total_amount, 5_000_000_000);
+        assert_eq!(utxo_stats_db.get((blocks.len() - 1) as u32), Some(utxo_db.stats));
+        assert_eq!(utxo_stats_db.get(blocks.len() as u32), None);
+    }
+}
@@ -0,0 +1,539 @@
+//! Electrum server protocol (line-delimited JSON-RPC over TCP).
+//!
+//! See also: <https://electrumx-spesmilo.readthedocs.io/en/latest/protocol-methods.html>.
+
+use crate::*;
+use std::sync::Arc;
+use std::collections::{HashMap, HashSet};
+use tokio::sync::{broadcast, RwLock};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+use serde_json::{json, Value};
+use bitcoin::hashes::{sha256, Hash};
+use bitcoin::hashes::hex::FromHex;
+use bitcoin::{BlockHash, Txid, WScriptHash};
+use bitcoincore_rpc::RpcApi;
+use crate::db::Database;
+use crate::db::utxo_server::UtxoServerValue;
+use ZeroMQMessage::*;
+
+/// The version of the Electrum protocol implemented.
+const PROTOCOL_VERSION: &str = "1.4";
+/// The maximum length of a line of request in bytes (the same as ElectrumX).
+const MAX_REQUEST_LEN: usize = 1_000_000;
+/// The maximum number of headers returned by `blockchain.block.headers`.
+const MAX_HEADERS: u32 = 2016;
+
+/// JSON-RPC error codes.
+const METHOD_NOT_FOUND: i32 = -32601;
+const INVALID_PARAMS: i32 = -32602;
+const BAD_REQUEST: i32 = 1;
+const DAEMON_ERROR: i32 = 2;
+
+type RpcResult = Result<Value, (i32, String)>;
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ElectrumHistoryEntry {
+    /// The confirmed height (0 for unconfirmed transactions, or -1 if they spend unconfirmed outputs).
+    pub height: i32,
+    pub tx_hash: String,
+    /// The fee (only for unconfirmed transactions).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fee: Option<u64>,
+}
+
+/// Decode an Electrum script hash (the SHA256 of the script in the reversed byte order).
+pub fn decode_scripthash(scripthash: &str) -> Option<WScriptHash> {
+    let mut buf = hex::decode(scripthash).ok()?;
+    buf.reverse();
+    WScriptHash::from_slice(&buf).ok()
+}
+
+pub fn encode_scripthash(wscript_hash: &WScriptHash) -> String {
+    let mut buf = wscript_hash.to_vec();
+    buf.reverse();
+    hex::encode(buf)
+}
+
+/// Compute the status of a script hash from its history (null if the history is empty).
+pub fn history_status(history: &[ElectrumHistoryEntry]) -> Option<String> {
+    if history.is_empty() {
+        return None;
+    }
+    let status = history.iter().map(|entry| format!("{}:{}:", entry.tx_hash, entry.height)).collect::<String>();
+    Some(hex::encode(sha256::Hash::hash(status.as_bytes()).into_inner()))
+}
+
+/// The state of a client connection.
+#[derive(Debug, Default)]
+struct Session {
+    headers_subscribed: bool,
+    /// The subscribed script hashes and their last statuses.
+    scripthashes: HashMap<WScriptHash, Option<String>>,
+    /// The subscribed script hashes which have unconfirmed transactions
+    /// (their statuses may be changed by a block which does not touch them).
+    unconfirmed: HashSet<WScriptHash>,
+    /// The tip the statuses were last computed at.
+    tip: Option<(u32, BlockHash)>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ElectrumServer {
+    db: Database,
+    stop: Arc<RwLock<bool>>,
+    ready: Arc<RwLock<bool>>,
+}
+
+impl ElectrumServer {
+    pub fn new(db: Database) -> Self {
+        Self {
+            db,
+            stop: Arc::new(RwLock::new(false)),
+            ready: Arc::new(RwLock::new(false)),
+        }
+    }
+    fn param<'a>(params: &'a [Value], index: usize, name: &str) -> Result<&'a str, (i32, String)> {
+        params.get(index).and_then(Value::as_str).ok_or_else(|| (INVALID_PARAMS, format!("\"{}\" is missing or not a string", name)))
+    }
+    fn u32_param(params: &[Value], index: usize, name: &str) -> Result<u32, (i32, String)> {
+        match params.get(index).and_then(Value::as_u64) {
+            Some(value) if value <= u32::MAX as u64 => Ok(value as u32),
+            _ => Err((INVALID_PARAMS, format!("\"{}\" is missing or not an integer", name))),
+        }
+    }
+    fn scripthash_param(params: &[Value]) -> Result<WScriptHash, (i32, String)> {
+        decode_scripthash(Self::param(params, 0, "scripthash")?).ok_or_else(|| (BAD_REQUEST, "invalid script hash".to_string()))
+    }
+    fn txid_param(params: &[Value]) -> Result<Txid, (i32, String)> {
+        Txid::from_hex(Self::param(params, 0, "tx_hash")?).map_err(|_| (BAD_REQUEST, "invalid tx hash".to_string()))
+    }
+    /// Checkpoints (the `cp_height` parameter) are not supported.
+    fn no_checkpoint(params: &[Value], index: usize) -> Result<(), (i32, String)> {
+        match params.get(index).and_then(Value::as_u64).unwrap_or(0) {
+            0 => Ok(()),
+            _ => Err((BAD_REQUEST, "checkpoints are not supported".to_string())),
+        }
+    }
+    async fn tip(&self) -> Option<(u32, BlockHash)> {
+        let height = self.db.synced_height_db.read().await.get()?;
+        Some((height, self.db.block_db.read().await.get_header(height)?.block_hash()))
+    }
+    async fn header(&self, height: u32) -> RpcResult {
+        match self.db.block_db.read().await.get_header(height) {
+            Some(block_header) => Ok(json!({ "height": height, "hex": hex::encode(consensus_encode(&block_header)) })),
+            None => Err((BAD_REQUEST, format!("height {} out of range", height))),
+        }
+    }
+    async fn tip_header(&self) -> RpcResult {
+        match self.db.synced_height_db.read().await.get() {
+            Some(height) => self.header(height).await,
+            None => Err((BAD_REQUEST, "no block synced yet".to_string())),
+        }
+    }
+    /// Returns the history of the script hash (confirmed transactions from the oldest, and then unconfirmed ones).
+    async fn history(&self, wscript_hash: &WScriptHash) -> Result<Vec<ElectrumHistoryEntry>, (i32, String)> {
+        // Unconfirmed transactions come first, and then from the newest to the oldest.
+        let txids = self.db.addr_index_db.read().await.get_after_by_hash(wscript_hash, None, usize::MAX);
+        let mut confirmed = Vec::new();
+        let mut unconfirmed = Vec::new();
+        let tx_db = self.db.tx_db.read().await;
+        for txid in txids.iter() {
+            let value = tx_db.get(txid).ok_or_else(|| (DAEMON_ERROR, format!("failed to resolve transaction {}", txid)))?;
+            // Skip transactions indexed only under the P2PKH script of a P2PK or multisig output.
+            if !value.touches(wscript_hash) {
+                continue;
+            }
+            match value.confirmed_height {
+                Some(height) => confirmed.push((height, *txid)),
+                None => {
+                    let input_value: u64 = value.previous_txouts.iter().map(|txout| txout.value).sum();
+                    let output_value: u64 = value.tx.output.iter().map(|txout| txout.value).sum();
+                    let has_unconfirmed_parent = value.tx.input.iter()
+                        .any(|input| tx_db.get_confirmed_height(&input.previous_output.txid) == Some(None));
+                    unconfirmed.push(ElectrumHistoryEntry {
+                        height: if has_unconfirmed_parent { -1 } else { 0 },
+                        tx_hash: txid.to_string(),
+                        fee: Some(input_value.saturating_sub(output_value)),
+                    });
+                },
+            }
+        }
+        drop(tx_db);
+        confirmed.reverse();
+        // Transactions confirmed in the same block are ordered by their position in the block.
+        let block_db = self.db.block_db.read().await;
+        let mut begin = 0;
+        while begin < confirmed.len() {
+            let height = confirmed[begin].0;
+            let end = begin + confirmed[begin..].iter().take_while(|(h, _)| *h == height).count();
+            if end - begin > 1 {
+                let block_txids = block_db.get(height)
+                    .ok_or_else(|| (DAEMON_ERROR, format!("failed to resolve block at height {}", height)))?
+                    .txids;
+                confirmed[begin..end].sort_by_key(|(_, txid)| block_txids.iter().position(|block_txid| block_txid == txid));
+            }
+            begin = end;
+        }
+        let mut history = confirmed.into_iter()
+            .map(|(height, txid)| ElectrumHistoryEntry { height: height as i32, tx_hash: txid.to_string(), fee: None })
+            .collect::<Vec<ElectrumHistoryEntry>>();
+        history.extend(unconfirmed);
+        Ok(history)
+    }
+    /// Returns the status of a subscribed script hash and records whether it has unconfirmed transactions.
+    async fn subscription_status(&self, wscript_hash: &WScriptHash, session: &mut Session) -> Result<Option<String>, (i32, String)> {
+        let history = self.history(wscript_hash).await?;
+        if history.iter().any(|entry| entry.height <= 0) {
+            session.unconfirmed.insert(*wscript_hash);
+        } else {
+            session.unconfirmed.remove(wscript_hash);
+        }
+        Ok(history_status(&history))
+    }
+    /// Returns the confirmed outputs of the script hash (excluding P2PK outputs indexed under their P2PKH script).
+    async fn utxos(&self, wscript_hash: &WScriptHash) -> Vec<UtxoServerValue> {
+        let utxos = self.db.utxo_server.read().await.get_by_hash(wscript_hash);
+        utxos.into_iter().filter(|utxo| !utxo.p2pk()).collect()
+    }
+    /// Returns the confirmed balance and the change by unconfirmed transactions.
+    async fn balance(&self, wscript_hash: &WScriptHash) -> Result<(u64, i64), (i32, String)> {
+        let confirmed = self.utxos(wscript_hash).await.iter().map(|utxo| utxo.value).sum();
+        let txids = self.db.addr_index_db.read().await.get_after_by_hash(wscript_hash, None, usize::MAX);
+        let tx_db = self.db.tx_db.read().await;
+        let mut unconfirmed = 0i64;
+        for txid in txids.iter() {
+            let value = tx_db.get(txid).ok_or_else(|| (DAEMON_ERROR, format!("failed to resolve transaction {}", txid)))?;
+            // Unconfirmed transactions come first.
+            if value.confirmed_height.is_some() {
+                break;
+            }
+            for txout in value.tx.output.iter().filter(|txout| txout.script_pubkey.wscript_hash() == *wscript_hash) {
+                unconfirmed += txout.value as i64;
+            }
+            for txout in value.previous_txouts.iter().filter(|txout| txout.script_pubkey.wscript_hash() == *wscript_hash) {
+                unconfirmed -= txout.value as i64;
+            }
+        }
+        Ok((confirmed, unconfirmed))
+    }
+    async fn call(&self, method: &str, params: &[Value], session: &mut Session) -> RpcResult {
+        match method {
+            "server.version" => Ok(json!([format!("chainseeker {}", env!("CARGO_PKG_VERSION")), PROTOCOL_VERSION])),
+            "server.ping" => Ok(Value::Null),
+            "server.peers.subscribe" => Ok(json!([])),
+            "server.features" => {
+                let genesis_hash = self.db.block_db.read().await.get_header(0).map(|block_header| block_header.block_hash().to_string());
+                Ok(json!({
+                    "genesis_hash": genesis_hash,
+                    "hosts": {},
+                    "protocol_max": PROTOCOL_VERSION,
+                    "protocol_min": PROTOCOL_VERSION,
+                    "pruning": null,
+                    "server_version": format!("chainseeker {}", env!("CARGO_PKG_VERSION")),
+                    "hash_function": "sha256",
+                }))
+            },
+            "blockchain.headers.subscribe" => {
+                session.headers_subscribed = true;
+                self.tip_header().await
+            },
+            "blockchain.block.header" => {
+                let height = Self::u32_param(params, 0, "height")?;
+                Self::no_checkpoint(params, 1)?;
+                self.header(height).await
+            },
+            "blockchain.block.headers" => {
+                let start_height = Self::u32_param(params, 0, "start_height")?;
+                let count = Self::u32_param(params, 1, "count")?.min(MAX_HEADERS);
+                Self::no_checkpoint(params, 2)?;
+                let block_db = self.db.block_db.read().await;
+                let mut headers = Vec::new();
+                for height in start_height..start_height.saturating_add(count) {
+                    match block_db.get_header(height) {
+                        Some(block_header) => headers.push(hex::encode(consensus_encode(&block_header))),
+                        None => break,
+                    }
+                }
+                Ok(json!({ "count": headers.len(), "hex": headers.concat(), "max": MAX_HEADERS }))
+            },
+            "blockchain.estimatefee" => {
+                // Bitcoin Core does not estimate for more than 1008 blocks.
+                let target = Self::u32_param(params, 0, "number")?.min(1008) as u16;
+                match get_rpc(&self.db.config).estimate_smart_fee(target, None) {
+                    // BTC/kB, or -1 if no estimate is available.
+                    Ok(res) => Ok(json!(res.fee_rate.map_or(-1f64, |fee_rate| fee_rate.as_btc()))),
+                    Err(err) => Err((DAEMON_ERROR, format!("estimatesmartfee RPC error: {}", err))),
+                }
+            },
+            "blockchain.relayfee" => match get_rpc(&self.db.config).call::<Value>("getnetworkinfo", &[]) {
+                Ok(network_info) => Ok(network_info["relayfee"].clone()),
+                Err(err) => Err((DAEMON_ERROR, format!("getnetworkinfo RPC error: {}", err))),
+            },
+            "blockchain.scripthash.get_history" => {
+                let history = self.history(&Self::scripthash_param(params)?).await?;
+                Ok(json!(history))
+            },
+            "blockchain.scripthash.get_balance" => {
+                let (confirmed, unconfirmed) = self.balance(&Self::scripthash_param(params)?).await?;
+                Ok(json!({ "confirmed": confirmed, "unconfirmed": unconfirmed }))
+            },
+            "blockchain.scripthash.listunspent" => {
+                // Only confirmed outputs are listed.
+                let utxos = self.utxos(&Self::scripthash_param(params)?).await;
+                Ok(json!(utxos.iter().map(|utxo| json!({
+                    "tx_hash": utxo.txid.to_string(),
+                    "tx_pos": utxo.vout,
+                    "height": utxo.height(),
+                    "value": utxo.value,
+                })).collect::<Vec<Value>>()))
+            },
+            "blockchain.scripthash.subscribe" => {
+                let wscript_hash = Self::scripthash_param(params)?;
+                let status = self.subscription_status(&wscript_hash, session).await?;
+                session.scripthashes.insert(wscript_hash, status.clone());
+                Ok(json!(status))
+            },
+            "blockchain.scripthash.unsubscribe" => {
+                let wscript_hash = Self::scripthash_param(params)?;
+                session.unconfirmed.remove(&wscript_hash);
+                Ok(json!(session.scripthashes.remove(&wscript_hash).is_some()))
+            },
+            "blockchain.transaction.get" => {
+                let txid = Self::txid_param(params)?;
+                if params.get(1).and_then(Value::as_bool).unwrap_or(false) {
+                    return Err((BAD_REQUEST, "verbose transactions are not supported".to_string()));
+                }
+                match self.db.tx_db.read().await.get(&txid) {
+                    Some(value) => Ok(json!(hex::encode(consensus_encode(&value.tx)))),
+                    None => Err((BAD_REQUEST, format!("transaction {} not found", txid))),
+                }
+            },
+            "blockchain.transaction.broadcast" => {
+                let hex = Self::param(params, 0, "raw_tx")?;
+                match get_rpc(&self.db.config).send_raw_transaction(hex) {
+                    Ok(txid) => Ok(json!(txid.to_string())),
+                    Err(err) => Err((BAD_REQUEST, format!("the transaction was rejected by network rules: {}", err))),
+                }
+            },
+            "blockchain.transaction.get_merkle" => {
+                let txid = Self::txid_param(params)?;
+                let height = Self::u32_param(params, 1, "height")?;
+                let txids = match self.db.block_db.read().await.get(height) {
+                    Some(block_content) => block_content.txids,
+                    None => return Err((BAD_REQUEST, format!("height {} out of range", height))),
+                };
+                match txids.iter().position(|block_txid| *block_txid == txid) {
+                    Some(pos) => Ok(json!({
+                        "block_height": height,
+                        "merkle": merkle_branch(&txids, pos).iter().map(|hash| hash.to_string()).collect::<Vec<String>>(),
+                        "pos": pos,
+                    })),
+                    None => Err((BAD_REQUEST, format!("transaction {} not in block at height {}", txid, height))),
+                }
+            },
+            _ => Err((METHOD_NOT_FOUND, format!("unknown method \"{}\"", method))),
+        }
+    }
+    async fn handle_request(&self, request: &Value, session: &mut Session) -> Value {
+        let id = request.get("id").cloned().unwrap_or(Value::Null);
+        let result = match request.get("method").and_then(Value::as_str) {
+            Some(method) => {
+                let params = request.get("params").and_then(Value::as_array).cloned().unwrap_or_default();
+                self.call(method, &params, session).await
+            },
+            None => Err((BAD_REQUEST, "\"method\" is missing".to_string())),
+        };
+        match result {
+            Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+            Err((code, message)) => json!({ "jsonrpc": "2.0", "id": id, "error": { "code": code, "message": message } }),
+        }
+    }
+    /// Handle a line of request (a single request or a batch of requests).
+    async fn handle_line(&self, line: &str, session: &mut Session) -> Value {
+        match serde_json::from_str::<Value>(line) {
+            Ok(Value::Array(requests)) => {
+                let mut responses = Vec::new();
+                for request in requests.iter() {
+                    responses.push(self.handle_request(request, session).await);
+                }
+                Value::Array(responses)
+            },
+            Ok(request) => self.handle_request(&request, session).await,
+            Err(_) => json!({ "jsonrpc": "2.0", "id": Value::Null, "error": { "code": BAD_REQUEST, "message": "invalid JSON" } }),
+        }
+    }
+    /// Returns the script hashes touched by the blocks after `last_tip` up to `tip`,
+    /// or None if they are unknown (e.g. `last_tip` was reorged out).
+    async fn blocks_touched(&self, last_tip: Option<(u32, BlockHash)>, tip: Option<(u32, BlockHash)>) -> Option<HashSet<WScriptHash>> {
+        let ((last_height, last_block_hash), (height, _)) = (last_tip?, tip?);
+        let block_db = self.db.block_db.read().await;
+        if height < last_height || block_db.get_header(last_height)?.block_hash() != last_block_hash {
+            return None;
+        }
+        let tx_db = self.db.tx_db.read().await;
+        let mut touched = HashSet::new();
+        for height in (last_height + 1)..=height {
+            for txid in block_db.get(height)?.txids.iter() {
+                touched.extend(tx_db.get(txid)?.wscript_hashes());
+            }
+        }
+        Some(touched)
+    }
+    /// Returns notifications to send on a new block or transaction.
+    ///
+    /// Only the statuses of the subscribed script hashes which may have been changed are recomputed.
+    /// `message` is None if some events were dropped, in which case all of them are recomputed.
+    async fn notifications(&self, message: Option<&ZeroMQMessage>, session: &mut Session) -> Vec<Value> {
+        let mut notifications = Vec::new();
+        // None if any script hash may have been touched.
+        let touched = match message {
+            Some(HashBlock(_)) | None => {
+                if session.headers_subscribed {
+                    if let Ok(header) = self.tip_header().await {
+                        notifications.push(json!({ "jsonrpc": "2.0", "method": "blockchain.headers.subscribe", "params": [header] }));
+                    }
+                }
+                let tip = self.tip().await;
+                let touched = match message {
+                    Some(_) => self.blocks_touched(session.tip, tip).await,
+                    None => None,
+                };
+                session.tip = tip;
+                touched.map(|touched| touched.union(&session.unconfirmed).copied().collect())
+            },
+            Some(RawTx(tx)) => Some(match self.db.tx_db.read().await.get(&tx.txid()) {
+                Some(value) => value.wscript_hashes().collect(),
+                None => tx.output.iter().map(|txout| txout.script_pubkey.wscript_hash()).collect(),
+            }),
+            Some(Init) => Some(HashSet::new()),
+        };
+        let wscript_hashes = session.scripthashes.keys()
+            .filter(|wscript_hash| match &touched {
+                Some(touched) => touched.contains(*wscript_hash),
+                None => true,
+            })
+            .copied()
+            .collect::<Vec<WScriptHash>>();
+        for wscript_hash in wscript_hashes.iter() {
+            let status = match self.subscription_status(wscript_hash, session).await {
+                Ok(status) => status,
+                Err(_) => continue,
+            };
+            if session.scripthashes.insert(*wscript_hash, status.clone()) != Some(status.clone()) {
+                notifications.push(json!({
+                    "jsonrpc": "2.0",
+                    "method": "blockchain.scripthash.subscribe",
+                    "params": [encode_scripthash(wscript_hash), status],
+                }));
+            }
+        }
+        notifications
+    }
+    async fn handle_connection(&self, stream: TcpStream, mut receiver: broadcast::Receiver<ZeroMQMessage>) {
+        let (read, mut write) = stream.into_split();
+        let mut reader = BufReader::new(read);
+        let mut line = Vec::new();
+        let mut session = Session {
+            tip: self.tip().await,
+            ..Session::default()
+        };
+        loop {
+            // Read at most one byte more than the limit to detect too long requests.
+            let mut limited_reader = (&mut reader).take((MAX_REQUEST_LEN + 1 - line.len()) as u64);
+            let responses = tokio::select! {
+                read = limited_reader.read_until(b'\n', &mut line) => match read {
+                    Ok(0) | Err(_) => break,
+                    // The limit is reached or the connection is closed in the middle of a line.
+                    Ok(_) if line.last() != Some(&b'\n') => {
+                        if line.len() <= MAX_REQUEST_LEN {
+                            continue;
+                        }
+                        let error = json!({ "jsonrpc": "2.0", "id": Value::Null, "error": { "code": BAD_REQUEST, "message": "request too large" } });
+                        let _ = write.write_all(format!("{}\n", error).as_bytes()).await;
+                        break;
+                    },
+                    Ok(_) => {
+                        let request = std::mem::take(&mut line);
+                        match std::str::from_utf8(&request) {
+                            Ok(request) if request.trim().is_empty() => continue,
+                            Ok(request) => vec![self.handle_line(request, &mut session).await],
+                            Err(_) => vec![json!({ "jsonrpc": "2.0", "id": Value::Null, "error": { "code": BAD_REQUEST, "message": "invalid UTF-8" } })],
+                        }
+                    },
+                },
+                message = receiver.recv() => match message {
+                    Ok(message) => self.notifications(Some(&message), &mut session).await,
+                    Err(broadcast::error::RecvError::Lagged(_)) => self.notifications(None, &mut session).await,
+                    Err(broadcast::error::RecvError::Closed) => break,
+                },
+            };
+            for response in responses.iter() {
+                if write.write_all(format!("{}\n", response).as_bytes()).await.is_err() {
+                    return;
+                }
+            }
+        }
+    }
+    pub async fn run(&self, endpoint: &str, events: broadcast::Sender<ZeroMQMessage>) {
+        let stop = self.stop.clone();
+        tokio::spawn(async move {
+            tokio::signal::ctrl_c().await.expect("Failed to install CTRL+C signal handler.");
+            *stop.write().await = true;
+        });
+        let listener = TcpListener::bind(endpoint).await.unwrap();
+        println!("ElectrumServer: listening on {}", endpoint);
+        *self.ready.write().await = true;
+        loop {
+            if *self.stop.read().await {
+                break;
+            }
+            let accepted = tokio::time::timeout(std::time::Duration::from_millis(100), listener.accept()).await;
+            if let Ok(Ok((stream, addr))) = accepted {
+                println!("ElectrumServer: new connection from {}.", addr);
+                let server = self.clone();
+                let receiver = events.subscribe();
+                tokio::spawn(async move {
+                    server.handle_connection(stream, receiver).await;
+                });
+            }
+        }
+        println!("ElectrumServer stopped.");
+    }
+    pub async fn ready(&self) -> bool {
+        *self.ready.read().await
+    }
+    pub async fn wait_for_ready(&self) {
+        while !self.ready().await {
+            tokio::time::sleep(std::time::Duration::from_millis(1)).await;
+        }
+    }
+    pub async fn stop(&self) {
+        *self.stop.write().await = true;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+    use super::*;
+    #[test]
+    fn scripthash() {
+        // The example comes from the Electrum protocol documentation (P2PKH of 1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa).
+        let script = Script::from_str("76a91462e907b15cbf27d5425399ebf6f0fb50ebb88f1888ac").unwrap();
+        let scripthash = "8b01df4e368ea28f8dc0423bcf7a4923e3a12d307c875e47a0cfbf90b5c39161";
+        assert_eq!(encode_scripthash(&script.wscript_hash()), scripthash);
+        assert_eq!(decode_scripthash(scripthash), Some(script.wscript_hash()));
+        assert_eq!(decode_scripthash("8b01df"), None);
+        assert_eq!(decode_scripthash("xx"), None);
+    }
+    #[test]
+    fn history_status() {
+        assert_eq!(super::history_status(&[]), None);
+        let history = [
+            ElectrumHistoryEntry { height: 1, tx_hash: "0c66a655559673689f753645ff5df20aaa4609716e6fcbcd22aa3a04fe962f59".to_string(), fee: None },
+            ElectrumHistoryEntry { height: 0, tx_hash: "e6a6a7db6faadbfc0815fd3a78c3b5d15cb733dde8aca0e911b6f565ea24ee73".to_string(), fee: Some(14100) },
+        ];
+        // sha256("0c66...2f59:1:e6a6...ee73:0:").
+        assert_eq!(super::history_status(&history).as_deref(), Some("074522e266369073d8dbb9efecd50f21d53894de65eb91d9c966d3cb46e259a1"));
+    }
+}
@@ -0,0 +1,846 @@
+//! Esplora-compatible REST API.
+//!
+//! See also: <https://github.com/Blockstream/esplora/blob/master/API.md>.
+//!
+//! Not supported (they return 404): `/tx/:txid/outspend/:vout` and `/tx/:txid/outspends`
+//! (no spending transaction index), `/tx/:txid/merkleblock-proof`, `/block/:hash/raw`,
+//! `/mempool*`, `/asset*` and `/address-prefix/:prefix`.
+//!
+//! Transactions and UTXOs of an address are those of its exact script,
+//! i.e. P2PK outputs are not listed under the P2PKH address of the public key.
+
+use std::collections::BTreeMap;
+use std::convert::Infallible;
+use hyper::{Body, Request, Response, StatusCode};
+use routerify::prelude::*;
+use routerify::Router;
+use bitcoin::hashes::Hash;
+use bitcoin::hashes::hex::FromHex;
+use bitcoin::{BlockHash, Script, Txid, TxOut, WScriptHash};
+use bitcoincore_rpc::RpcApi;
+use crate::*;
+use crate::db::Database;
+use crate::db::block::{BlockDB, BlockContentDBValue};
+use crate::db::tx::TxDBValue;
+
+/// The number of confirmed transactions per page.
+const TXS_PER_PAGE: usize = 25;
+/// The maximum number of unconfirmed transactions returned for an address.
+const MAX_MEMPOOL_TXS: usize = 50;
+/// The number of blocks returned by `/blocks`.
+const BLOCKS_PER_PAGE: u32 = 10;
+/// The confirmation targets of `/fee-estimates`.
+const FEE_ESTIMATE_TARGETS: [u16; 28] = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 144, 504, 1008];
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct EsploraTxStatus {
+    pub confirmed: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub block_height: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub block_hash: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub block_time: Option<u32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct EsploraVout {
+    pub scriptpubkey: String,
+    pub scriptpubkey_asm: String,
+    pub scriptpubkey_type: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scriptpubkey_address: Option<String>,
+    pub value: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct EsploraVin {
+    pub txid: String,
+    pub vout: u32,
+    /// The spent output (null for coinbase inputs).
+    pub prevout: Option<EsploraVout>,
+    pub scriptsig: String,
+    pub scriptsig_asm: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub witness: Option<Vec<String>>,
+    pub is_coinbase: bool,
+    pub sequence: u32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub inner_redeemscript_asm: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub inner_witnessscript_asm: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct EsploraTransaction {
+    pub txid: String,
+    pub version: i32,
+    pub locktime: u32,
+    pub vin: Vec<EsploraVin>,
+    pub vout: Vec<EsploraVout>,
+    pub size: usize,
+    pub weight: usize,
+    /// The fee in satoshis (zero for coinbase transactions).
+    pub fee: u64,
+    pub status: EsploraTxStatus,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct EsploraUtxo {
+    pub txid: String,
+    pub vout: u32,
+    pub status: EsploraTxStatus,
+    pub value: u64,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct EsploraAddressStats {
+    pub funded_txo_count: u32,
+    pub funded_txo_sum: u64,
+    pub spent_txo_count: u32,
+    pub spent_txo_sum: u64,
+    pub tx_count: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct EsploraAddress {
+    /// Set by `/address/:address`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub address: Option<String>,
+    /// Set by `/scripthash/:hash`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scripthash: Option<String>,
+    pub chain_stats: EsploraAddressStats,
+    pub mempool_stats: EsploraAddressStats,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct EsploraMerkleProof {
+    pub block_height: u32,
+    /// The merkle branch from the bottom to the top.
+    pub merkle: Vec<String>,
+    /// The index of the transaction in the block.
+    pub pos: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct EsploraBlock {
+    pub id: String,
+    pub height: u32,
+    pub version: i32,
+    pub timestamp: u32,
+    pub tx_count: usize,
+    pub size: u32,
+    pub weight: u32,
+    pub merkle_root: String,
+    /// The hash of the previous block (null for the genesis block).
+    pub previousblockhash: Option<String>,
+    /// The median time of the last 11 blocks.
+    pub mediantime: u32,
+    pub nonce: u32,
+    pub bits: u32,
+    pub difficulty: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct EsploraBlockStatus {
+    pub in_best_chain: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub height: Option<u32>,
+    /// The hash of the next block in the best chain.
+    pub next_best: Option<String>,
+}
+
+/// Returns the script type in the Esplora format (e.g. "p2pkh", "v0_p2wpkh").
+pub fn esplora_script_type(script_pubkey: &Script) -> &'static str {
+    if script_pubkey.is_empty() {
+        "empty"
+    } else if script_pubkey.is_op_return() {
+        "op_return"
+    } else if script_pubkey.is_p2pk() {
+        "p2pk"
+    } else if script_pubkey.is_p2pkh() {
+        "p2pkh"
+    } else if script_pubkey.is_p2sh() {
+        "p2sh"
+    } else if script_pubkey.is_v0_p2wpkh() {
+        "v0_p2wpkh"
+    } else if script_pubkey.is_v0_p2wsh() {
+        "v0_p2wsh"
+    } else if is_p2tr(script_pubkey) {
+        "v1_p2tr"
+    } else if parse_multisig(script_pubkey).is_some() {
+        "multisig"
+    } else {
+        "unknown"
+    }
+}
+
+pub fn create_esplora_vout(txout: &TxOut, config: &Config) -> EsploraVout {
+    EsploraVout {
+        scriptpubkey: hex::encode(txout.script_pubkey.as_bytes()),
+        scriptpubkey_asm: txout.script_pubkey.asm(),
+        scriptpubkey_type: esplora_script_type(&txout.script_pubkey).to_string(),
+        // P2PK outputs do not have an address.
+        scriptpubkey_address: if txout.script_pubkey.is_p2pk() { None } else { script_to_address_string(&txout.script_pubkey, config) },
+        value: txout.value,
+    }
+}
+
+pub fn create_esplora_tx_status(confirmed_height: Option<u32>, block_db: &BlockDB) -> EsploraTxStatus {
+    let block_header = confirmed_height.and_then(|height| block_db.get_header(height));
+    EsploraTxStatus {
+        confirmed: confirmed_height.is_some(),
+        block_height: confirmed_height,
+        block_hash: block_header.map(|block_header| block_header.block_hash().to_string()),
+        block_time: block_header.map(|block_header| block_header.time),
+    }
+}
+
+pub fn create_esplora_tx(value: &TxDBValue, block_db: &BlockDB, config: &Config) -> EsploraTransaction {
+    let tx = &value.tx;
+    // Coinbase inputs have no previous outputs.
+    let mut previous_txouts = value.previous_txouts.iter();
+    let vin = tx.input.iter().map(|input| {
+        let is_coinbase = input.previous_output.is_null();
+        let prevout = if is_coinbase { None } else { previous_txouts.next() };
+        let info = classify_input(input, prevout.map(|txout| &txout.script_pubkey));
+        EsploraVin {
+            txid: input.previous_output.txid.to_string(),
+            vout: input.previous_output.vout,
+            prevout: prevout.map(|txout| create_esplora_vout(txout, config)),
+            scriptsig: hex::encode(input.script_sig.as_bytes()),
+            scriptsig_asm: input.script_sig.asm(),
+            witness: if input.witness.is_empty() { None } else { Some(input.witness.iter().map(hex::encode).collect()) },
+            is_coinbase,
+            sequence: input.sequence,
+            inner_redeemscript_asm: info.redeem_script.map(|script| Script::from(script).asm()),
+            inner_witnessscript_asm: info.witness_script.map(|script| Script::from(script).asm()),
+        }
+    }).collect();
+    let input_value: u64 = value.previous_txouts.iter().map(|txout| txout.value).sum();
+    let output_value: u64 = tx.output.iter().map(|txout| txout.value).sum();
+    EsploraTransaction {
+        txid: tx.txid().to_string(),
+        version: tx.version,
+        locktime: tx.lock_time,
+        vin,
+        vout: tx.output.iter().map(|txout| create_esplora_vout(txout, config)).collect(),
+        size: tx.get_size(),
+        weight: tx.get_weight(),
+        fee: if tx.is_coin_base() { 0 } else { input_value.saturating_sub(output_value) },
+        status: create_esplora_tx_status(value.confirmed_height, block_db),
+    }
+}
+
+pub fn create_esplora_block(block_content: &BlockContentDBValue, block_db: &BlockDB, config: &Config) -> EsploraBlock {
+    let block_header = &block_content.block_header;
+    let height = block_content.height;
+    let mut times = (height.saturating_sub(10)..height).filter_map(|height| block_db.get_header(height))
+        .map(|block_header| block_header.time)
+        .chain(std::iter::once(block_header.time))
+        .collect::<Vec<u32>>();
+    times.sort_unstable();
+    EsploraBlock {
+        id: block_header.block_hash().to_string(),
+        height,
+        version: block_header.version,
+        timestamp: block_header.time,
+        tx_count: block_content.txids.len(),
+        size: block_content.size,
+        weight: block_content.weight,
+        merkle_root: block_header.merkle_root.to_string(),
+        previousblockhash: if height == 0 { None } else { Some(block_header.prev_blockhash.to_string()) },
+        mediantime: times[times.len() / 2],
+        nonce: block_header.nonce,
+        bits: block_header.bits,
+        difficulty: get_difficulty(block_header, config),
+    }
+}
+
+impl EsploraAddressStats {
+    /// Add the outputs funded and spent by the transaction to the statistics of the script.
+    pub fn add_tx(&mut self, wscript_hash: &WScriptHash, value: &TxDBValue) {
+        let matches = |txout: &&TxOut| txout.script_pubkey.wscript_hash() == *wscript_hash;
+        let mut touched = false;
+        for txout in value.tx.output.iter().filter(matches) {
+            self.funded_txo_count += 1;
+            self.funded_txo_sum += txout.value;
+            touched = true;
+        }
+        for txout in value.previous_txouts.iter().filter(matches) {
+            self.spent_txo_count += 1;
+            self.spent_txo_sum += txout.value;
+            touched = true;
+        }
+        if touched {
+            self.tx_count += 1;
+        }
+    }
+}
+
+/// The router which serves the Esplora-compatible REST API.
+#[derive(Debug)]
+pub struct EsploraServer {}
+
+impl EsploraServer {
+    fn text<T: ToString>(body: T, cacheable: bool) -> Response<Body> {
+        HttpServer::response_with_content_type(&StatusCode::OK, "text/plain", body.to_string(), cacheable)
+    }
+    fn error(status: &StatusCode, msg: &str) -> Response<Body> {
+        HttpServer::response_with_content_type(status, "text/plain", msg.to_string(), false)
+    }
+    fn not_found(msg: &str) -> Response<Body> {
+        Self::error(&StatusCode::NOT_FOUND, msg)
+    }
+    fn bad_request(msg: &str) -> Response<Body> {
+        Self::error(&StatusCode::BAD_REQUEST, msg)
+    }
+    fn internal_error(msg: &str) -> Response<Body> {
+        Self::error(&StatusCode::INTERNAL_SERVER_ERROR, msg)
+    }
+    async fn tip_height(db: &Database) -> Result<u32, Response<Body>> {
+        db.synced_height_db.read().await.get().ok_or_else(|| Self::not_found("No block synced yet"))
+    }
+    /// Helper function for `/block/:hash/*` APIs.
+    async fn block_content(req: &Request<Body>) -> Result<BlockContentDBValue, Response<Body>> {
+        let db = req.data::<Database>().unwrap();
+        let block_hash = match BlockHash::from_hex(req.param("hash").unwrap()) {
+            Ok(block_hash) => block_hash,
+            Err(_) => return Err(Self::bad_request("Invalid hex string")),
+        };
+        db.block_db.read().await.get_by_hash(&block_hash).ok_or_else(|| Self::not_found("Block not found"))
+    }
+    /// Helper function for `/tx/:txid/*` APIs.
+    async fn tx(req: &Request<Body>) -> Result<TxDBValue, Response<Body>> {
+        let db = req.data::<Database>().unwrap();
+        let txid = match Txid::from_hex(req.param("txid").unwrap()) {
+            Ok(txid) => txid,
+            Err(_) => return Err(Self::bad_request("Invalid hex string")),
+        };
+        db.tx_db.read().await.get(&txid).ok_or_else(|| Self::not_found("Transaction not found"))
+    }
+    /// Resolve `txids` into transactions.
+    async fn txs(db: &Database, txids: &[Txid]) -> Result<Vec<TxDBValue>, Response<Body>> {
+        let tx_db = db.tx_db.read().await;
+        let mut txs = Vec::new();
+        for txid in txids.iter() {
+            match tx_db.get(txid) {
+                Some(tx) => txs.push(tx),
+                None => return Err(Self::internal_error(&format!("Failed to resolve transaction: {}", txid))),
+            }
+        }
+        Ok(txs)
+    }
+    async fn txs_json(db: &Database, txs: &[TxDBValue]) -> Response<Body> {
+        let block_db = db.block_db.read().await;
+        let txs = txs.iter().map(|tx| create_esplora_tx(tx, &block_db, &db.config)).collect::<Vec<EsploraTransaction>>();
+        HttpServer::json(&txs, false)
+    }
+    /// Helper function for `/address/:address/*` and `/scripthash/:hash/*` APIs.
+    async fn script_hash(req: &Request<Body>) -> Result<WScriptHash, Response<Body>> {
+        let db = req.data::<Database>().unwrap();
+        match req.param("address") {
+            Some(address) => match address_string_to_script(address, &db.config) {
+                Ok(script) => Ok(script.wscript_hash()),
+                Err(err) => Err(Self::bad_request(&err)),
+            },
+            None => WScriptHash::from_hex(req.param("hash").unwrap()).map_err(|_| Self::bad_request("Invalid hex string")),
+        }
+    }
+    /// Returns at most `limit` transactions related to the script in the order of `AddressIndexDB::get_after()`.
+    ///
+    /// The address index also lists transactions under the P2PKH script of P2PK and multisig outputs,
+    /// so the entries are fetched page by page and the transactions which do not touch the script are skipped.
+    /// If `unconfirmed_only` is true, stops at the first confirmed transaction.
+    async fn script_txs(db: &Database, wscript_hash: &WScriptHash, mut after: Option<(Option<u32>, Txid)>, limit: usize, unconfirmed_only: bool)
+        -> Result<Vec<TxDBValue>, Response<Body>>
+    {
+        let mut txs = Vec::new();
+        loop {
+            let txids = db.addr_index_db.read().await.get_after_by_hash(wscript_hash, after, TXS_PER_PAGE);
+            for (txid, tx) in txids.iter().zip(Self::txs(db, &txids).await?) {
+                if unconfirmed_only && tx.confirmed_height.is_some() {
+                    return Ok(txs);
+                }
+                after = Some((tx.confirmed_height, *txid));
+                if tx.touches(wscript_hash) {
+                    txs.push(tx);
+                    if txs.len() >= limit {
+                        return Ok(txs);
+                    }
+                }
+            }
+            if txids.len() < TXS_PER_PAGE {
+                return Ok(txs);
+            }
+        }
+    }
+    /// Returns at most `MAX_MEMPOOL_TXS` unconfirmed transactions related to the script.
+    async fn mempool_txs(db: &Database, wscript_hash: &WScriptHash) -> Result<Vec<TxDBValue>, Response<Body>> {
+        // Unconfirmed transactions come first.
+        Self::script_txs(db, wscript_hash, None, MAX_MEMPOOL_TXS, true).await
+    }
+    /// Returns a page of confirmed transactions related to the script (from the newest to the oldest).
+    async fn chain_txs(db: &Database, wscript_hash: &WScriptHash, last_seen: Option<Txid>) -> Result<Vec<TxDBValue>, Response<Body>> {
+        let after = match last_seen {
+            Some(txid) => match db.tx_db.read().await.get_confirmed_height(&txid) {
+                Some(Some(height)) => (Some(height), txid),
+                _ => return Err(Self::bad_request("Invalid last seen txid")),
+            },
+            // The largest key of unconfirmed transactions, so that the page starts from the newest confirmed one.
+            None => (None, Txid::from_inner([0xff; 32])),
+        };
+        Self::script_txs(db, wscript_hash, Some(after), TXS_PER_PAGE, false).await
+    }
+    /// `/blocks/tip/height` endpoint.
+    async fn tip_height_handler(req: Request<Body>) -> Result<Response<Body>, Infallible> {
+        let db = req.data::<Database>().unwrap();
+        match Self::tip_height(db).await {
+            Ok(height) => Ok(Self::text(height, false)),
+            Err(res) => Ok(res),
+        }
+    }
+    /// `/blocks/tip/hash` endpoint.
+    async fn tip_hash_handler(req: Request<Body>) -> Result<Response<Body>, Infallible> {
+        let db = req.data::<Database>().unwrap();
+        let height = match Self::tip_height(db).await {
+            Ok(height) => height,
+            Err(res) => return Ok(res),
+        };
+        match db.block_db.read().await.get_header(height) {
+            Some(block_header) => Ok(Self::text(block_header.block_hash(), false)),
+            None => Ok(Self::not_found("Block not found")),
+        }
+    }
+    /// `/blocks` and `/blocks/:start_height` endpoints.
+    async fn blocks_handler(req: Request<Body>) -> Result<Response<Body>, Infallible> {
+        let db = req.data::<Database>().unwrap();
+        let start_height = match req.param("start_height") {
+            Some(start_height) => match start_height.parse::<u32>() {
+                Ok(start_height) => start_height,
+                Err(_) => return Ok(Self::bad_request("Invalid height")),
+            },
+            None => match Self::tip_height(db).await {
+                Ok(height) => height,
+                Err(res) => return Ok(res),
+            },
+        };
+        let block_db = db.block_db.read().await;
+        let blocks = (start_height.saturating_sub(BLOCKS_PER_PAGE - 1)..=start_height).rev()
+            .filter_map(|height| block_db.get(height))
+            .map(|block_content| create_esplora_block(&block_content, &block_db, &db.config))
+            .collect::<Vec<EsploraBlock>>();
+        Ok(HttpServer::json(&blocks, false))
+    }
+    /// `/block-height/:height` endpoint.
+    async fn block_height_handler(req: Request<Body>) -> Result<Response<Body>, Infallible> {
+        let db = req.data::<Database>().unwrap();
+        let height = match req.param("height").unwrap().parse::<u32>() {
+            Ok(height) => height,
+            Err(_) => return Ok(Self::bad_request("Invalid height")),
+        };
+        match db.block_db.read().await.get_header(height) {
+            Some(block_header) => Ok(Self::text(block_header.block_hash(), false)),
+            None => Ok(Self::not_found("Block not found")),
+        }
+    }
+    /// `/block/:hash` endpoint.
+    async fn block_handler(req: Request<Body>) -> Result<Response<Body>, Infallible> {
+        let db = req.data::<Database>().unwrap();
+        match Self::block_content(&req).await {
+            Ok(block_content) => {
+                let block_db = db.block_db.read().await;
+                Ok(HttpServer::json(create_esplora_block(&block_content, &block_db, &db.config), true))
+            },
+            Err(res) => Ok(res),
+        }
+    }
+    /// `/block/:hash/status` endpoint.
+    async fn block_status_handler(req: Request<Body>) -> Result<Response<Body>, Infallible> {
+        let db = req.data::<Database>().unwrap();
+        let block_content = match Self::block_content(&req).await {
+            Ok(block_content) => block_content,
+            Err(res) => return Ok(res),
+        };
+        let block_db = db.block_db.read().await;
+        let block_hash = block_content.block_header.block_hash();
+        let in_best_chain = matches!(block_db.get_header(block_content.height), Some(block_header) if block_header.block_hash() == block_hash);
+        let status = EsploraBlockStatus {
+            in_best_chain,
+            height: if in_best_chain { Some(block_content.height) } else { None },
+            next_best: if in_best_chain {
+                block_db.get_header(block_content.height + 1).map(|block_header| block_header.block_hash().to_string())
+            } else {
+                None
+            },
+        };
+        Ok(HttpServer::json(&status, false))
+    }
+    /// `/block/:hash/header` endpoint.
+    async fn block_header_handler(req: Request<Body>) -> Result<Response<Body>, Infallible> {
+        match Self::block_content(&req).await {
+            Ok(block_content) => Ok(Self::text(hex::encode(consensus_encode(&block_content.block_header)), true)),
+            Err(res) => Ok(res),
+        }
+    }
+    /// `/block/:hash/txids` endpoint.
+    async fn block_txids_handler(req: Request<Body>) -> Result<Response<Body>, Infallible> {
+        match Self::block_content(&req).await {
+            Ok(block_content) => {
+                let txids = block_content.txids.iter().map(|txid| txid.to_string()).collect::<Vec<String>>();
+                Ok(HttpServer::json(&txids, true))
+            },
+            Err(res) => Ok(res),
+        }
+    }
+    /// `/block/:hash/txid/:index` endpoint.
+    async fn block_txid_handler(req: Request<Body>) -> Result<Response<Body>, Infallible> {
+        let block_content = match Self::block_content(&req).await {
+            Ok(block_content) => block_content,
+            Err(res) => return Ok(res),
+        };
+        let txid = req.param("index").unwrap().parse::<usize>().ok().and_then(|index| block_content.txids.get(index));
+        match txid {
+            Some(txid) => Ok(Self::text(txid, true)),
+            None => Ok(Self::not_found("Transaction not found")),
+        }
+    }
+    /// `/block/:hash/txs` and `/block/:hash/txs/:start_index` endpoints.
+    async fn block_txs_handler(req: Request<Body>) -> Result<Response<Body>, Infallible> {
+        let db = req.data::<Database>().unwrap();
+        let block_content = match Self::block_content(&req).await {
+            Ok(block_content) => block_content,
+            Err(res) => return Ok(res),
+        };
+        let start_index = match req.param("start_index").map(|start_index| start_index.parse::<usize>()) {
+            Some(Ok(start_index)) if start_index % TXS_PER_PAGE == 0 => start_index,
+            Some(Ok(_)) => return Ok(Self::bad_request(&format!("start index must be a multiple of {}", TXS_PER_PAGE))),
+            Some(Err(_)) => return Ok(Self::bad_request("Invalid start index")),
+            None => 0,
+        };
+        let txids = block_content.txids.iter().skip(start_index).take(TXS_PER_PAGE).copied().collect::<Vec<Txid>>();
+        match Self::txs(db, &txids).await {
+            Ok(txs) => Ok(Self::txs_json(db, &txs).await),
+            Err(res) => Ok(res),
+        }
+    }
+    /// `/tx/:txid` endpoint.
+    async fn tx_handler(req: Request<Body>) -> Result<Response<Body>, Infallible> {
+        let db = req.data::<Database>().unwrap();
+        match Self::tx(&req).await {
+            Ok(tx) => {
+                let block_db = db.block_db.read().await;
+                Ok(HttpServer::json(create_esplora_tx(&tx, &block_db, &db.config), false))
+            },
+            Err(res) => Ok(res),
+        }
+    }
+    /// `/tx/:txid/status` endpoint.
+    async fn tx_status_handler(req: Request<Body>) -> Result<Response<Body>, Infallible> {
+        let db = req.data::<Database>().unwrap();
+        match Self::tx(&req).await {
+            Ok(tx) => {
+                let block_db = db.block_db.read().await;
+                Ok(HttpServer::json(create_esplora_tx_status(tx.confirmed_height, &block_db), false))
+            },
+            Err(res) => Ok(res),
+        }
+    }
+    /// `/tx/:txid/hex` endpoint.
+    async fn tx_hex_handler(req: Request<Body>) -> Result<Response<Body>, Infallible> {
+        match Self::tx(&req).await {
+            Ok(tx) => Ok(Self::text(hex::encode(consensus_encode(&tx.tx)), true)),
+            Err(res) => Ok(res),
+        }
+    }
+    /// `/tx/:txid/raw` endpoint.
+    async fn tx_raw_handler(req: Request<Body>) -> Result<Response<Body>, Infallible> {
+        match Self::tx(&req).await {
+            Ok(tx) => Ok(HttpServer::response_with_content_type(&StatusCode::OK, "application/octet-stream", consensus_encode(&tx.tx), true)),
+            Err(res) => Ok(res),
+        }
+    }
+    /// `/tx/:txid/merkle-proof` endpoint.
+    async fn tx_merkle_proof_handler(req: Request<Body>) -> Result<Response<Body>, Infallible> {
+        let db = req.data::<Database>().unwrap();
+        let tx = match Self::tx(&req).await {
+            Ok(tx) => tx,
+            Err(res) => return Ok(res),
+        };
+        let block_height = match tx.confirmed_height {
+            Some(height) => height,
+            None => return Ok(Self::not_found("Transaction not confirmed")),
+        };
+        let txid = tx.tx.txid();
+        let txids = match db.block_db.read().await.get(block_height) {
+            Some(block_content) => block_content.txids,
+            None => return Ok(Self::not_found("Block not found")),
+        };
+        let pos = match txids.iter().position(|block_txid| *block_txid == txid) {
+            Some(pos) => pos,
+            None => return Ok(Self::not_found("Transaction not found in the block")),
+        };
+        Ok(HttpServer::json(EsploraMerkleProof {
+            block_height,
+            merkle: merkle_branch(&txids, pos).iter().map(|hash| hash.to_string()).collect(),
+            pos,
+        }, false))
+    }
+    /// `POST /tx` endpoint (the body is the raw transaction in hex).
+    async fn post_tx_handler(req: Request<Body>) -> Result<Response<Body>, Infallible> {
+        let rpc = get_rpc(&req.data::<Database>().unwrap().config);
+        let hex = match hyper::body::to_bytes(req.into_body()).await.map(|hex| String::from_utf8(hex.to_vec())) {
+            Ok(Ok(hex)) => hex,
+            _ => return Ok(Self::bad_request("Failed to parse input")),
+        };
+        match rpc.send_raw_transaction(hex.trim()) {
+            Ok(txid) => Ok(Self::text(txid, false)),
+            Err(err) => Ok(Self::bad_request(&format!("sendrawtransaction RPC error: {}", err))),
+        }
+    }
+    /// `/fee-estimates` endpoint (the fee rates in sat/vB keyed by the confirmation target).
+    ///
+    /// The targets Bitcoin Core cannot estimate for (e.g. for lack of data) are omitted.
+    async fn fee_estimates_handler(req: Request<Body>) -> Result<Response<Body>, Infallible> {
+        let rpc = get_rpc(&req.data::<Database>().unwrap().config);
+        let mut estimates = BTreeMap::new();
+        for target in FEE_ESTIMATE_TARGETS.iter() {
+            match rpc.estimate_smart_fee(*target, None) {
+                // BTC/kvB to sat/vB.
+                Ok(res) => if let Some(fee_rate) = res.fee_rate {
+                    estimates.insert(*target, fee_rate.as_sat() as f64 / 1000f64);
+                },
+                Err(err) => return Ok(Self::internal_error(&format!("estimatesmartfee RPC error: {}", err))),
+            }
+        }
+        Ok(HttpServer::json(&estimates, false))
+    }
+    /// `/address/:address` and `/scripthash/:hash` endpoints.
+    async fn address_handler(req: Request<Body>) -> Result<Response<Body>, Infallible> {
+        let db = req.data::<Database>().unwrap();
+        let wscript_hash = match Self::script_hash(&req).await {
+            Ok(wscript_hash) => wscript_hash,
+            Err(res) => return Ok(res),
+        };
+        // Fetch the transactions page by page so that addresses with many transactions do not exhaust the memory.
+        let mut chain_stats = EsploraAddressStats::default();
+        let mut mempool_stats = EsploraAddressStats::default();
+        let mut after = None;
+        loop {
+            let txids = db.addr_index_db.read().await.get_after_by_hash(&wscript_hash, after, TXS_PER_PAGE);
+            let txs = match Self::txs(db, &txids).await {
+                Ok(txs) => txs,
+                Err(res) => return Ok(res),
+            };
+            for (txid, tx) in txids.iter().zip(txs) {
+                let stats = if tx.confirmed_height.is_some() { &mut chain_stats } else { &mut mempool_stats };
+                stats.add_tx(&wscript_hash, &tx);
+                after = Some((tx.confirmed_height, *txid));
+            }
+            if txids.len() < TXS_PER_PAGE {
+                break;
+            }
+        }
+        Ok(HttpServer::json(EsploraAddress {
+            address: req.param("address").map(|address| address.to_string()),
+            scripthash: req.param("hash").map(|hash| hash.to_string()),
+            chain_stats,
+            mempool_stats,
+        }, false))
+    }
+    /// `/address/:address/txs` endpoint (unconfirmed transactions and the first page of confirmed ones).
+    async fn address_txs_handler(req: Request<Body>) -> Result<Response<Body>, Infallible> {
+        let db = req.data::<Database>().unwrap();
+        let wscript_hash = match Self::script_hash(&req).await {
+            Ok(wscript_hash) => wscript_hash,
+            Err(res) => return Ok(res),
+        };
+        let mempool_txs = match Self::mempool_txs(db, &wscript_hash).await {
+            Ok(txs) => txs,
+            Err(res) => return Ok(res),
+        };
+        let chain_txs = match Self::chain_txs(db, &wscript_hash, None).await {
+            Ok(txs) => txs,
+            Err(res) => return Ok(res),
+        };
+        Ok(Self::txs_json(db, &[mempool_txs, chain_txs].concat()).await)
+    }
+    /// `/address/:address/txs/chain` and `/address/:address/txs/chain/:last_seen_txid` endpoints.
+    async fn address_txs_chain_handler(req: Request<Body>) -> Result<Response<Body>, Infallible> {
+        let db = req.data::<Database>().unwrap();
+        let wscript_hash = match Self::script_hash(&req).await {
+            Ok(wscript_hash) => wscript_hash,
+            Err(res) => return Ok(res),
+        };
+        let last_seen = match req.param("last_seen_txid").map(|txid| Txid::from_hex(txid)) {
+            Some(Ok(txid)) => Some(txid),
+            Some(Err(_)) => return Ok(Self::bad_request("Invalid hex string")),
+            None => None,
+        };
+        match Self::chain_txs(db, &wscript_hash, last_seen).await {
+            Ok(txs) => Ok(Self::txs_json(db, &txs).await),
+            Err(res) => Ok(res),
+        }
+    }
+    /// `/address/:address/txs/mempool` endpoint.
+    async fn address_txs_mempool_handler(req: Request<Body>) -> Result<Response<Body>, Infallible> {
+        let db = req.data::<Database>().unwrap();
+        let wscript_hash = match Self::script_hash(&req).await {
+            Ok(wscript_hash) => wscript_hash,
+            Err(res) => return Ok(res),
+        };
+        match Self::mempool_txs(db, &wscript_hash).await {
+            Ok(txs) => Ok(Self::txs_json(db, &txs).await),
+            Err(res) => Ok(res),
+        }
+    }
+    /// `/address/:address/utxo` endpoint.
+    async fn address_utxo_handler(req: Request<Body>) -> Result<Response<Body>, Infallible> {
+        let db = req.data::<Database>().unwrap();
+        let wscript_hash = match Self::script_hash(&req).await {
+            Ok(wscript_hash) => wscript_hash,
+            Err(res) => return Ok(res),
+        };
+        let values = db.utxo_server.read().await.get_by_hash(&wscript_hash);
+        let block_db = db.block_db.read().await;
+        // Skip P2PK outputs indexed under their P2PKH script.
+        let utxos = values.iter().filter(|utxo| !utxo.p2pk()).map(|utxo| EsploraUtxo {
+            txid: utxo.txid.to_string(),
+            vout: utxo.vout,
+            status: create_esplora_tx_status(Some(utxo.height()), &block_db),
+            value: utxo.value,
+        }).collect::<Vec<EsploraUtxo>>();
+        Ok(HttpServer::json(&utxos, false))
+    }
+    /// Build the router (to be mounted by `HttpServer` under `Config::esplora_path`).
+    pub fn router(db: Database) -> Router<Body, Infallible> {
+        Router::builder()
+            .data(db)
+            .get("/blocks/tip/height", Self::tip_height_handler)
+            .get("/blocks/tip/hash", Self::tip_hash_handler)
+            .get("/blocks", Self::blocks_handler)
+            .get("/blocks/:start_height", Self::blocks_handler)
+            .get("/block-height/:height", Self::block_height_handler)
+            .get("/block/:hash", Self::block_handler)
+            .get("/block/:hash/status", Self::block_status_handler)
+            .get("/block/:hash/header", Self::block_header_handler)
+            .get("/block/:hash/txids", Self::block_txids_handler)
+            .get("/block/:hash/txid/:index", Self::block_txid_handler)
+            .get("/block/:hash/txs", Self::block_txs_handler)
+            .get("/block/:hash/txs/:start_index", Self::block_txs_handler)
+            .get("/tx/:txid", Self::tx_handler)
+            .get("/tx/:txid/status", Self::tx_status_handler)
+            .get("/tx/:txid/hex", Self::tx_hex_handler)
+            .get("/tx/:txid/raw", Self::tx_raw_handler)
+            .get("/tx/:txid/merkle-proof", Self::tx_merkle_proof_handler)
+            .post("/tx", Self::post_tx_handler)
+            .get("/fee-estimates", Self::fee_estimates_handler)
+            .get("/address/:address", Self::address_handler)
+            .get("/address/:address/txs", Self::address_txs_handler)
+            .get("/address/:address/txs/chain", Self::address_txs_chain_handler)
+            .get("/address/:address/txs/chain/:last_seen_txid", Self::address_txs_chain_handler)
+            .get("/address/:address/txs/mempool", Self::address_txs_mempool_handler)
+            .get("/address/:address/utxo", Self::address_utxo_handler)
+            .get("/scripthash/:hash", Self::address_handler)
+            .get("/scripthash/:hash/txs", Self::address_txs_handler)
+            .get("/scripthash/:hash/txs/chain", Self::address_txs_chain_handler)
+            .get("/scripthash/:hash/txs/chain/:last_seen_txid", Self::address_txs_chain_handler)
+            .get("/scripthash/:hash/txs/mempool", Self::address_txs_mempool_handler)
+            .get("/scripthash/:hash/utxo", Self::address_utxo_handler)
+            .build()
+            .unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+    use crate::db::tx::TxDB;
+    use super::*;
+    #[test]
+    fn esplora_script_type() {
+        let cases = [
+            ("", "empty"),
+            ("6a0401020304", "op_return"),
+            ("76a91497033ca70d45fe6d49310859e132a9df98f9762588ac", "p2pkh"),
+            ("a91497033ca70d45fe6d49310859e132a9df98f9762587", "p2sh"),
+            ("001497033ca70d45fe6d49310859e132a9df98f97625", "v0_p2wpkh"),
+            ("00201863143c14c5166804bd19203356da136c985678cd4d27a1b8c6329604903262", "v0_p2wsh"),
+            ("2103b0da749730dc9b4b1f4a14d6902877a92541f5368778853d9c4a0cb7802dcfb2ac", "p2pk"),
+            ("51", "unknown"),
+        ];
+        for (script, expected) in cases.iter() {
+            assert_eq!(super::esplora_script_type(&Script::from_str(script).unwrap()), *expected, "{}", script);
+        }
+    }
+    #[test]
+    fn esplora_tx() {
+        let tx_db = TxDB::new("test/esplora", true);
+        let block_db = BlockDB::new("test/esplora", true);
+        let blocks = fixtures::regtest_blocks();
+        for (height, block) in blocks.iter().enumerate() {
+            block_db.put(height as u32, block);
+            for tx in block.txdata.iter() {
+                tx_db.put_tx(tx, Some(height as u32)).unwrap();
+            }
+        }
+        let config = config_example("rbtc");
+        // Coinbase transaction.
+        let coinbase = create_esplora_tx(&tx_db.get(&blocks[102].txdata[0].txid()).unwrap(), &block_db, &config);
+        assert!(coinbase.vin[0].is_coinbase);
+        assert_eq!(coinbase.vin[0].prevout, None);
+        assert_eq!(coinbase.fee, 0);
+        assert_eq!(coinbase.status, EsploraTxStatus {
+            confirmed: true,
+            block_height: Some(102),
+            block_hash: Some(blocks[102].block_hash().to_string()),
+            block_time: Some(blocks[102].header.time),
+        });
+        // Non-coinbase transaction.
+        let tx = create_esplora_tx(&tx_db.get(&blocks[102].txdata[1].txid()).unwrap(), &block_db, &config);
+        assert_eq!(tx.txid, "e6a6a7db6faadbfc0815fd3a78c3b5d15cb733dde8aca0e911b6f565ea24ee73");
+        assert_eq!(tx.fee, 14100);
+        let prevout = tx.vin[0].prevout.as_ref().unwrap();
+        assert_eq!(prevout.value, 5000000000);
+        assert_eq!(prevout.scriptpubkey_type, "v0_p2wpkh");
+        assert_eq!(prevout.scriptpubkey_address.as_deref(), Some("bcrt1qjupnefcdghlx6jf3ppv7zv4fm7v0ja39dzzwvd"));
+        assert_eq!(tx.vin[0].witness.as_ref().unwrap().len(), 2);
+        // P2PK outputs do not have an address.
+        let p2pk = TxOut {
+            value: 5000000000,
+            script_pubkey: Script::from_str("2103b0da749730dc9b4b1f4a14d6902877a92541f5368778853d9c4a0cb7802dcfb2ac").unwrap(),
+        };
+        assert_eq!(create_esplora_vout(&p2pk, &config).scriptpubkey_address, None);
+        // Address stats.
+        let script = address_string_to_script("bcrt1qjupnefcdghlx6jf3ppv7zv4fm7v0ja39dzzwvd", &config).unwrap();
+        let value = tx_db.get(&blocks[102].txdata[1].txid()).unwrap();
+        assert!(value.touches(&script.wscript_hash()));
+        let mut stats = EsploraAddressStats::default();
+        stats.add_tx(&script.wscript_hash(), &value);
+        assert_eq!(stats, EsploraAddressStats {
+            funded_txo_count: 0,
+            funded_txo_sum: 0,
+            spent_txo_count: 1,
+            spent_txo_sum: 5000000000,
+            tx_count: 1,
+        });
+        // The P2PKH script of a P2PK output does not match it.
+        let p2pkh = p2pk_to_p2pkh(&p2pk.script_pubkey).unwrap();
+        let mut value = value;
+        value.tx.output.push(p2pk);
+        assert!(!value.touches(&p2pkh.wscript_hash()));
+        let mut stats = EsploraAddressStats::default();
+        stats.add_tx(&p2pkh.wscript_hash(), &value);
+        assert_eq!(stats, EsploraAddressStats::default());
+        // Block.
+        let block = create_esplora_block(&block_db.get(102).unwrap(), &block_db, &config);
+        assert_eq!(block.id, blocks[102].block_hash().to_string());
+        assert_eq!(block.tx_count, blocks[102].txdata.len());
+        assert_eq!(block.previousblockhash, Some(blocks[101].block_hash().to_string()));
+        let mut times = blocks[92..=102].iter().map(|block| block.header.time).collect::<Vec<u32>>();
+        times.sort_unstable();
+        assert_eq!(block.mediantime, times[5]);
+        assert_eq!(create_esplora_block(&block_db.get(0).unwrap(), &block_db, &config).previousblockhash, None);
+    }
+}
@@ -3,219 +3,220 @@ use bitcoin::hashes::Hash;
 use bitcoin::WScriptHash;
 use crate::db::address_index::AddressIndexDBKey;
 
-pub fn addr_index_db() -> [AddressIndexDBKey; 212] {
+pub fn addr_index_db() -> [AddressIndexDBKey; 213] {
     [
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x19,0x6e,0x36,0x61,0xab,0xa2,0x91,0xf5,0x65,0xf6,0xa8,0x7e,0xf2,0x37,0x29,0x3e,0xba,0xbe,0x4e,0x11,0x45,0x85,0xec,0x3a,0x3c,0x32,0x63,0xcc,0x4b,0xda,0x40,0x94]), txid: consensus_decode(&hex::decode("bcae0a12d28e9c28dfeaf2cbb914f9b414946049b7e304a4a219b73f6c0403b9").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x1d,0xe8,0xed,0xdb,0x92,0x64,0xd4,0x11,0x1c,0x76,0xf9,0x4d,0x23,0xf5,0xd5,0xea,0xef,0xcc,0x73,0x9f,0x85,0xd4,0x96,0xde,0x05,0xd2,0x77,0xeb,0x74,0xe3,0xd4,0x1b]), txid: consensus_decode(&hex::decode("2d813aded53c0f2f5daa2f9f5d1dc9c7e6af5496afd82c22f3acbb9a044e7029").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x1d,0xe8,0xed,0xdb,0x92,0x64,0xd4,0x11,0x1c,0x76,0xf9,0x4d,0x23,0xf5,0xd5,0xea,0xef,0xcc,0x73,0x9f,0x85,0xd4,0x96,0xde,0x05,0xd2,0x77,0xeb,0x74,0xe3,0xd4,0x1b]), txid: consensus_decode(&hex::decode("73ee24ea65f5b611e9a0ace8dd33b75cd1b5c3783afd1508fcdbaa6fdba7a6e6").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x33,0x18,0x53,0x7d,0xfb,0x31,0x35,0xdf,0x9f,0x3d,0x95,0x0d,0xbd,0xf8,0xa7,0xae,0x68,0xdd,0x7c,0x7d,0xfe,0xf6,0x1e,0xd1,0x79,0x63,0xff,0x80,0xf3,0x85,0x04,0x74]), txid: consensus_decode(&hex::decode("3ba3edfd7a7b12b27ac72c3e67768f617fc81bc3888a51323a9fb8aa4b1e5e4a").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x39,0x13,0x51,0x13,0x5e,0x1e,0x11,0x80,0x5c,0xf0,0x38,0x20,0xd2,0x89,0x28,0xbf,0x78,0xe4,0xc6,0x5f,0x76,0xec,0x4c,0xe0,0x92,0xa1,0x83,0xc6,0xa1,0xd3,0x19,0x6c]), txid: consensus_decode(&hex::decode("2d813aded53c0f2f5daa2f9f5d1dc9c7e6af5496afd82c22f3acbb9a044e7029").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0x0a,0x2a,0xef,0x00,0x33,0x92,0xb3,0xcb,0xc1,0x1a,0x24,0xa7,0x8d,0x7b,0xae,0x83,0x9d,0x3e,0x3d,0xc3,0x6a,0x53,0xeb,0x55,0x6b,0x1e,0xf1,0xe8,0x15,0x5a,0x14]), txid: consensus_decode(&hex::decode("2d813aded53c0f2f5daa2f9f5d1dc9c7e6af5496afd82c22f3acbb9a044e7029").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), txid: consensus_decode(&hex::decode("015cbb7aee592ab4b8881fde2c8036d61cb783c31c53d5efd4cb83dc291ef22f").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), txid: consensus_decode(&hex::decode("053057a6cc0e2bb74ac02bb7305d08985f80d4f30985afca7a2ed1678ab9530a").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), txid: consensus_decode(&hex::decode("0782a2a7de4c46db3e041c63ee28aeab483947d6825e1315eca6e1cf02f3c906").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), txid: consensus_decode(&hex::decode("07a182ced74903e62fff155415a3ca26af0f71f2fc22de78194114cf19279eaa").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), txid: consensus_decode(&hex::decode("087029de3113d9295dff2770d48d68f2880bf734efb8c5cc53ff73ae4a963ac3").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), txid: consensus_decode(&hex::decode("08bbc21c87bf538339c86d117e78307c471df8a4b9d54cd3843d7058ab1e5ef5").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), txid: consensus_decode(&hex::decode("0b2b470b2dfe711153e042886611d976846954b0dea9ce125b7d5b0afdeb9abf").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), txid: consensus_decode(&hex::decode("0f328bad7eca1c56b343ff2523eab6b12c24e6be8861cbcf90a4e849a7df95de").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), txid: consensus_decode(&hex::decode("13af981ed9cecb66d175ad5eccb6eac7706e5affde549f89c5bb393429f36e5b").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), txid: consensus_decode(&hex::decode("145464574465129a8f3b111bca6932f86b4930bc202cf8bf60e1e22619c35b6b").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), txid: consensus_decode(&hex::decode("174a08ae859446ce66df56ad4ebab88152ffe007caa176bd9d7c5e0a86c0c848").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), txid: consensus_decode(&hex::decode("18e9139ba76040fa1f4ce4b1982bd3b731581e3e6313263d2594c9e354ebb728").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), txid: consensus_decode(&hex::decode("1c702a5d3dcfddfddaca47bb2fa5e3264254d2c3caed312361b0cc922be34e1e").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), txid: consensus_decode(&hex::decode("200e465574e472bd6b0900b8ca7095f3342b3d43553bb4754e8611ff1725f2ae").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), txid: consensus_decode(&hex::decode("220dd0b71c8f1fd379a69cc532a0352d79746c03fcaa05656415aa2ef1213aa7").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), txid: consensus_decode(&hex::decode("2576f2f598674c26024db883e10f087102f9f4a91c93a6f8ea06c61f38443e77").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), txid: consensus_decode(&hex::decode("2764bc934ee5afd4323899205293101aec0e7d4065a2901b7190482c3efb33d4").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), txid: consensus_decode(&hex::decode("278b9c78c9e4c17a9555c4e06ba6fe2768f2b9787407236c9d7681fedc7ddba9").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), txid: consensus_decode(&hex::decode("28a37fd0e952c0a548a6aa3fafa94d6463a29f040c7f9aabcfa3f693d9c123d5").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), txid: consensus_decode(&hex::decode("29421f89bf24dcd2c258528543c361152d0d9e2bffdeb72cf681c58d5a20235f").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), txid: consensus_decode(&hex::decode("297842b6b2314843043892a8f49fb17b0ebadfc454c76d68f5a8cac1f23faf6d").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), txid: consensus_decode(&hex::decode("2a2a902d9cbfdd63b4c60d7ed2aefcc4fb9b5b4ccfa76464d0ebbd7fc8b25051").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), txid: consensus_decode(&hex::decode("2e7ba427bc64bf63817d79ec82397093e4e056ae2933d38e88ebf63990162ed6").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), txid: consensus_decode(&hex::decode("2fed5ae153b255cbd5e1bcae9e5cc93951ea80805b592ce7c2fe2a9795c1c941").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), txid: consensus_decode(&hex::decode("30e78bf44071ed68796f4d74cfce3c8c439445dfa4869cc097e858843ffb80d8").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), txid: consensus_decode(&hex::decode("343057fa8d3bc9ccfc3ba087bb705ee47564e3769c316de83b20470a6ab8abbb").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), txid: consensus_decode(&hex::decode("35295128120a4c1f90b70fb88f9b4bef400c55008b5d6b76186aec659500a7b2").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), txid: consensus_decode(&hex::decode("37bcd587fc3f7ec7988029f9229f2955059eefc653ab6a7a79f0c03fc7f77c60").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), txid: consensus_decode(&hex::decode("3c53dbaf709a0695dead0011aada19cbf5d36577fb288d1cd2f114982482a46d").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), txid: consensus_decode(&hex::decode("3e49c8cc7174650189aa87353112765c33d07e88e696d47c846e3ed996853d25").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), txid: consensus_decode(&hex::decode("40e3d56b5f08e9d37016a14f1ad2ee9c5a9cd41bee0d2372169d079b46a1f737").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), txid: consensus_decode(&hex::decode("463a6fda64e67c0f640bb6396b443d3bacf9108d33d6b47ddebe61bce8b7e286").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), txid: consensus_decode(&hex::decode("4812fa27abdcd5e550fc4de2846ce644e604a14051a488a67dbb67e52e2cffec").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), txid: consensus_decode(&hex::decode("4909a37755da7b1d912cb4b914539b82cf50327e993f4daabb8b4feb1e84adfb").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), txid: consensus_decode(&hex::decode("4b737b656b2ad2476b4c37aa7250ff35a400a7004f04228ea23ddd55061108fb").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), txid: consensus_decode(&hex::decode("51f975af8c24d4f484f182e2fb2c71685038716459e0ac303948895fab17f3fc").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), txid: consensus_decode(&hex::decode("527d0f70f7f49e6acecb806ab7cc46fd994beb801a93e8b0524aff97162b4766").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), txid: consensus_decode(&hex::decode("592f96fe043aaa22cdcb6f6e710946aa0af25dff4536759f6873965555a6660c").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), txid: consensus_decode(&hex::decode("5c25e3685dc7d48ad0c45db8c9a9ef27e820655ca0e5e560e1ebb80b649fdcbe").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), txid: consensus_decode(&hex::decode("5fcd9f523df6c145be014836ec289c71bf435f7455e5e2eda1f6d40c2d2e9fdf").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), txid: consensus_decode(&hex::decode("6126c5af0b3871e3ef74443c642b46249b9f70a28d1063abeaec99e13118d01c").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), txid: consensus_decode(&hex::decode("69cb27faacc0dfceb4d9d9444d20b16a9fc00f61b7b29937c6f6d97b99f9b2d0").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), txid: consensus_decode(&hex::decode("6d9449a6e647bcc01943a15bfd990dff3688cb9de9c45d77147bbf79aab61124").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), txid: consensus_decode(&hex::decode("6f79afe378fffa9516c389175c55200178350d99a0bd1cbc2df8bde3e7f9a126").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), txid: consensus_decode(&hex::decode("715da11118f8fb545c17c8a6bc4ca99a99d85e331bdf604c6cb017ee3dca7c0b").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), txid: consensus_decode(&hex::decode("7233a59cb35f2cdfb1625018eb2365a19c0a44be9e2bdc7b0e47c30761916bd4").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), txid: consensus_decode(&hex::decode("73ee24ea65f5b611e9a0ace8dd33b75cd1b5c3783afd1508fcdbaa6fdba7a6e6").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), txid: consensus_decode(&hex::decode("75989e266f4ad3da241c5d4df9687682b997e4140bb54252777ede43009b6f10").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), txid: consensus_decode(&hex::decode("770bed1e8af9d80d7eb03e142c9896e834f99197563c286ca2f39c1e0d113960").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), txid: consensus_decode(&hex::decode("78ae6a5198b31017415a32fb66fda209690e24390ba6e5a1dde98ff15cf52d55").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), txid: consensus_decode(&hex::decode("7a355418ac6593fb3b00e2815f45b2ae3037a985121e6a1c204c1882c1fea083").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), txid: consensus_decode(&hex::decode("7cd8c012b690ec71db991bf958d1ca0c3337484550f2d60ea9daad9fc950259f").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), txid: consensus_decode(&hex::decode("7fc4490fcbd798a74b73512a917831a54047ed709b6314e825d16c860cef1b6f").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), txid: consensus_decode(&hex::decode("84334d4a4bcdf994b3e563a0e925bd5bc7cc6bf40496c3236941c30233a56977").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), txid: consensus_decode(&hex::decode("86900e7e035f24d1560cae42e418302aaaf5ae22571a6f088b94fa7e074215d7").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), txid: consensus_decode(&hex::decode("870d140b7a90b318e8c8c7724c6287749830b03b232157609998cebcb1f1d0ff").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), txid: consensus_decode(&hex::decode("882d41608b5f541784653b8b3a9087fdc7e19b08b0846203cead62b8992afabb").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), txid: consensus_decode(&hex::decode("94336c1c8fd573c7ad4e3d7fa6592a1b001c237e8faab14d5780474fd4d6fe62").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), txid: consensus_decode(&hex::decode("944879603fd1b6ef4421014ce7c1cf7df5dd740e5dda91a13d508636ac3ebc6c").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), txid: consensus_decode(&hex::decode("9871f4cf79e18e726b91be146fcd48ff2890e64a3554d405a99b6f57dbf13ebf").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), txid: consensus_decode(&hex::decode("9a43afe88077c1cee17dff96695bf5b25a6b18e8499d772278d8f4e27942651f").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), txid: consensus_decode(&hex::decode("9b42ec0f6fd154686696d283a51dbb98da1978837507835465d09b7125cd9469").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), txid: consensus_decode(&hex::decode("9c06ab041f6f614f7ae457ac78a47d7235574c98a93aa4e0260a5157c1fd065f").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), txid: consensus_decode(&hex::decode("9e42c74de2f66e75e2763043ebdab929697fe55e8b182a2c983fa689f9729a54").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), txid: consensus_decode(&hex::decode("a0dbaafc7a7265538d25d1accc3e6780051d112734d37a26861eee54cdc0860a").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), txid: consensus_decode(&hex::decode("a12ab55ede98f3c11456de21223b16c8debe881df46e18d0c4ea65b07e8bc610").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), txid: consensus_decode(&hex::decode("a5e761b565759fe46f37fb834917dde6cf96112194af119f87530357a59e463a").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), txid: consensus_decode(&hex::decode("a99f8a5d6b4509237825a8e41f06ed4d30127beac86ddbcb8765a0fecd205c43").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), txid: consensus_decode(&hex::decode("b1340de3e38d57929cb91b0ea5e8472553abfb9d42301b6fc5d615264c90dd54").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), txid: consensus_decode(&hex::decode("b26c6e7744522d9c0409c51ecf5b94b4fa3bd02fa7bd59f371c1a42e6a068fba").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), txid: consensus_decode(&hex::decode("b28e12e66480f7f728396794f4f939f713dc9d44c831016d48e7464de660d703").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), txid: consensus_decode(&hex::decode("b2d6a3b9a6a14ff57fb9aa781400d59d257638ba50e3535259dfe5b29f3ffd4f").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), txid: consensus_decode(&hex::decode("b430a6a5cb2b9f443d558b6c52932765d07cfabc7a1a52c396b5f585112beb17").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), txid: consensus_decode(&hex::decode("b566a843c89b61539052b76633ea7d8d68c3a632bef4305a2c2c73750475087f").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), txid: consensus_decode(&hex::decode("bc42c2659e6ffc902a06c42113bd49fcb66a4e20efa7660a51d9a8ed1959c654").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), txid: consensus_decode(&hex::decode("bcae0a12d28e9c28dfeaf2cbb914f9b414946049b7e304a4a219b73f6c0403b9").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), txid: consensus_decode(&hex::decode("bd5039f0b8f2bbd6183321d3344c8a3856074b05ce32398a50623fec6220f196").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), txid: consensus_decode(&hex::decode("c0349e38082c76e62ed5b845a396868ab5f8613268356bb2469aa1ca7f94fed2").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), txid: consensus_decode(&hex::decode("c03b9cbeb95ec612e1a6604e9873556c634225732a06ae1cab84646eeea2ed40").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), txid: consensus_decode(&hex::decode("c5793751e9cbcc8f438cb835fc3f27cfd914e74ed23b3a3218af30f989234af5").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), txid: consensus_decode(&hex::decode("c91685ce3d48b042f060fa5031ab809c659354dff52fbafc35e9814ba8d3ffed").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), txid: consensus_decode(&hex::decode("caa44cf93d8c6206f45eabc74227049bcb41a7a65c8dfa4cfd2fa964d65eee02").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), txid: consensus_decode(&hex::decode("cc62211cc737742ad1bb1a12edc386df4bf84ada597a219ed08a3b84fc3eac92").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), txid: consensus_decode(&hex::decode("ce3bdbd3e48a4ea80bcbc207a722716702bb1ae6af978280b672b1280d07c1f7").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), txid: consensus_decode(&hex::decode("d5ad56b153c0cb74ae2c6d5c56a8d3c7a6ed00e21de74b308410a9bac776c5d1").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), txid: consensus_decode(&hex::decode("d6ad95b24ed67bb3288b03f06bd50ea3a89ecfce426fc8fa9a06ed163b3b433e").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), txid: consensus_decode(&hex::decode("dad8dd787ad1bf2fa568fc4afdffaa00d55bdf0721f67d907feb0a4bb9952e19").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), txid: consensus_decode(&hex::decode("dd6c1eb0c671c7a1546a56c7e8591cee7d35eae31a290cdae2933e92c0751d7a").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), txid: consensus_decode(&hex::decode("dfd9683d487fd6407614bf6d7cd1731323bf865242effb499ac94e85005e61fb").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), txid: consensus_decode(&hex::decode("dff95f4cecc366483e628236698740088a73c60069b04679d3170ca9966de64d").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), txid: consensus_decode(&hex::decode("e0d7b388911af97c9d3b12c743dcbe7f03670472c731fce26ce30c3b5431010d").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), txid: consensus_decode(&hex::decode("e6c44f7320174e5637d53ed596e45d6794e8bc88ee0916ad1588a1def35559bf").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), txid: consensus_decode(&hex::decode("e844aead67c33a125534cb1c557b981126b2631c12b337dc4709789527ae10c3").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), txid: consensus_decode(&hex::decode("e8e4db3caca2d81a7869593015de85ae6086dd9ca51e5d1d8935f669d85cb234").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), txid: consensus_decode(&hex::decode("eaf157e7b6fb7b1a9b766ec690c20203bcc730d371ba773eb3ad37fa8e08b10f").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), txid: consensus_decode(&hex::decode("f20d7fab026f71a7fc565c9700bc69fda79c7a81d1b1ec88b57e5499eed6ab99").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), txid: consensus_decode(&hex::decode("f445c8e0595ff9f4da471312e885a0d025be07b0c7e3bf3fac8679fc97601ab7").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), txid: consensus_decode(&hex::decode("f7d515b84b6c1f63ff399fac718c5ef7ca32d0e0fd4adbe84ed9be496f4bdaf4").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), txid: consensus_decode(&hex::decode("f7fc7baf4ed63c83857aa0b2b3d466900e20e1277f17c34d2fc48c92671f7db1").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), txid: consensus_decode(&hex::decode("f82256bcc97b94d4c3e58c900e679566c2d32ef3c6adcda10cf5df73c202b261").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), txid: consensus_decode(&hex::decode("f9b4c23a394155e613607490eec659b26dc0d2b169f34aa177dcac3ea9fa34e9").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), txid: consensus_decode(&hex::decode("fb9e03098c010f7729d33b2f7c38ce6418002d88c577986f724eb30e2430c6cf").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), txid: consensus_decode(&hex::decode("fca5ed4637c9eb7b4a8d1d65384f25346053046c5d47e8bda2127bf2e2b71e17").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x51,0xc9,0xa4,0xdf,0x86,0xc7,0x6c,0xa7,0xc2,0xed,0xea,0xe6,0x38,0xe3,0xae,0xfa,0x95,0x98,0x7e,0xa3,0xa7,0x0d,0xf1,0x95,0xaa,0x6d,0x88,0xe3,0xe1,0xd1,0xe7,0x88]), txid: consensus_decode(&hex::decode("2d813aded53c0f2f5daa2f9f5d1dc9c7e6af5496afd82c22f3acbb9a044e7029").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x51,0xc9,0xa4,0xdf,0x86,0xc7,0x6c,0xa7,0xc2,0xed,0xea,0xe6,0x38,0xe3,0xae,0xfa,0x95,0x98,0x7e,0xa3,0xa7,0x0d,0xf1,0x95,0xaa,0x6d,0x88,0xe3,0xe1,0xd1,0xe7,0x88]), txid: consensus_decode(&hex::decode("73ee24ea65f5b611e9a0ace8dd33b75cd1b5c3783afd1508fcdbaa6fdba7a6e6").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), txid: consensus_decode(&hex::decode("015cbb7aee592ab4b8881fde2c8036d61cb783c31c53d5efd4cb83dc291ef22f").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), txid: consensus_decode(&hex::decode("053057a6cc0e2bb74ac02bb7305d08985f80d4f30985afca7a2ed1678ab9530a").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), txid: consensus_decode(&hex::decode("0782a2a7de4c46db3e041c63ee28aeab483947d6825e1315eca6e1cf02f3c906").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), txid: consensus_decode(&hex::decode("07a182ced74903e62fff155415a3ca26af0f71f2fc22de78194114cf19279eaa").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), txid: consensus_decode(&hex::decode("087029de3113d9295dff2770d48d68f2880bf734efb8c5cc53ff73ae4a963ac3").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), txid: consensus_decode(&hex::decode("08bbc21c87bf538339c86d117e78307c471df8a4b9d54cd3843d7058ab1e5ef5").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), txid: consensus_decode(&hex::decode("0b2b470b2dfe711153e042886611d976846954b0dea9ce125b7d5b0afdeb9abf").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), txid: consensus_decode(&hex::decode("0f328bad7eca1c56b343ff2523eab6b12c24e6be8861cbcf90a4e849a7df95de").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), txid: consensus_decode(&hex::decode("13af981ed9cecb66d175ad5eccb6eac7706e5affde549f89c5bb393429f36e5b").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), txid: consensus_decode(&hex::decode("145464574465129a8f3b111bca6932f86b4930bc202cf8bf60e1e22619c35b6b").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), txid: consensus_decode(&hex::decode("174a08ae859446ce66df56ad4ebab88152ffe007caa176bd9d7c5e0a86c0c848").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), txid: consensus_decode(&hex::decode("18e9139ba76040fa1f4ce4b1982bd3b731581e3e6313263d2594c9e354ebb728").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), txid: consensus_decode(&hex::decode("1c702a5d3dcfddfddaca47bb2fa5e3264254d2c3caed312361b0cc922be34e1e").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), txid: consensus_decode(&hex::decode("200e465574e472bd6b0900b8ca7095f3342b3d43553bb4754e8611ff1725f2ae").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), txid: consensus_decode(&hex::decode("220dd0b71c8f1fd379a69cc532a0352d79746c03fcaa05656415aa2ef1213aa7").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), txid: consensus_decode(&hex::decode("2576f2f598674c26024db883e10f087102f9f4a91c93a6f8ea06c61f38443e77").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), txid: consensus_decode(&hex::decode("2764bc934ee5afd4323899205293101aec0e7d4065a2901b7190482c3efb33d4").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), txid: consensus_decode(&hex::decode("278b9c78c9e4c17a9555c4e06ba6fe2768f2b9787407236c9d7681fedc7ddba9").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), txid: consensus_decode(&hex::decode("28a37fd0e952c0a548a6aa3fafa94d6463a29f040c7f9aabcfa3f693d9c123d5").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), txid: consensus_decode(&hex::decode("29421f89bf24dcd2c258528543c361152d0d9e2bffdeb72cf681c58d5a20235f").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), txid: consensus_decode(&hex::decode("297842b6b2314843043892a8f49fb17b0ebadfc454c76d68f5a8cac1f23faf6d").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), txid: consensus_decode(&hex::decode("2a2a902d9cbfdd63b4c60d7ed2aefcc4fb9b5b4ccfa76464d0ebbd7fc8b25051").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), txid: consensus_decode(&hex::decode("2e7ba427bc64bf63817d79ec82397093e4e056ae2933d38e88ebf63990162ed6").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), txid: consensus_decode(&hex::decode("2fed5ae153b255cbd5e1bcae9e5cc93951ea80805b592ce7c2fe2a9795c1c941").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), txid: consensus_decode(&hex::decode("30e78bf44071ed68796f4d74cfce3c8c439445dfa4869cc097e858843ffb80d8").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), txid: consensus_decode(&hex::decode("343057fa8d3bc9ccfc3ba087bb705ee47564e3769c316de83b20470a6ab8abbb").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), txid: consensus_decode(&hex::decode("35295128120a4c1f90b70fb88f9b4bef400c55008b5d6b76186aec659500a7b2").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), txid: consensus_decode(&hex::decode("37bcd587fc3f7ec7988029f9229f2955059eefc653ab6a7a79f0c03fc7f77c60").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), txid: consensus_decode(&hex::decode("3c53dbaf709a0695dead0011aada19cbf5d36577fb288d1cd2f114982482a46d").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), txid: consensus_decode(&hex::decode("3e49c8cc7174650189aa87353112765c33d07e88e696d47c846e3ed996853d25").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), txid: consensus_decode(&hex::decode("40e3d56b5f08e9d37016a14f1ad2ee9c5a9cd41bee0d2372169d079b46a1f737").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), txid: consensus_decode(&hex::decode("463a6fda64e67c0f640bb6396b443d3bacf9108d33d6b47ddebe61bce8b7e286").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), txid: consensus_decode(&hex::decode("4812fa27abdcd5e550fc4de2846ce644e604a14051a488a67dbb67e52e2cffec").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), txid: consensus_decode(&hex::decode("4909a37755da7b1d912cb4b914539b82cf50327e993f4daabb8b4feb1e84adfb").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), txid: consensus_decode(&hex::decode("4b737b656b2ad2476b4c37aa7250ff35a400a7004f04228ea23ddd55061108fb").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), txid: consensus_decode(&hex::decode("51f975af8c24d4f484f182e2fb2c71685038716459e0ac303948895fab17f3fc").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), txid: consensus_decode(&hex::decode("527d0f70f7f49e6acecb806ab7cc46fd994beb801a93e8b0524aff97162b4766").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), txid: consensus_decode(&hex::decode("592f96fe043aaa22cdcb6f6e710946aa0af25dff4536759f6873965555a6660c").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), txid: consensus_decode(&hex::decode("5c25e3685dc7d48ad0c45db8c9a9ef27e820655ca0e5e560e1ebb80b649fdcbe").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), txid: consensus_decode(&hex::decode("5fcd9f523df6c145be014836ec289c71bf435f7455e5e2eda1f6d40c2d2e9fdf").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), txid: consensus_decode(&hex::decode("6126c5af0b3871e3ef74443c642b46249b9f70a28d1063abeaec99e13118d01c").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), txid: consensus_decode(&hex::decode("69cb27faacc0dfceb4d9d9444d20b16a9fc00f61b7b29937c6f6d97b99f9b2d0").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), txid: consensus_decode(&hex::decode("6d9449a6e647bcc01943a15bfd990dff3688cb9de9c45d77147bbf79aab61124").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), txid: consensus_decode(&hex::decode("6f79afe378fffa9516c389175c55200178350d99a0bd1cbc2df8bde3e7f9a126").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), txid: consensus_decode(&hex::decode("715da11118f8fb545c17c8a6bc4ca99a99d85e331bdf604c6cb017ee3dca7c0b").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), txid: consensus_decode(&hex::decode("7233a59cb35f2cdfb1625018eb2365a19c0a44be9e2bdc7b0e47c30761916bd4").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), txid: consensus_decode(&hex::decode("75989e266f4ad3da241c5d4df9687682b997e4140bb54252777ede43009b6f10").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), txid: consensus_decode(&hex::decode("770bed1e8af9d80d7eb03e142c9896e834f99197563c286ca2f39c1e0d113960").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), txid: consensus_decode(&hex::decode("78ae6a5198b31017415a32fb66fda209690e24390ba6e5a1dde98ff15cf52d55").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), txid: consensus_decode(&hex::decode("7a355418ac6593fb3b00e2815f45b2ae3037a985121e6a1c204c1882c1fea083").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), txid: consensus_decode(&hex::decode("7cd8c012b690ec71db991bf958d1ca0c3337484550f2d60ea9daad9fc950259f").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), txid: consensus_decode(&hex::decode("7fc4490fcbd798a74b73512a917831a54047ed709b6314e825d16c860cef1b6f").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), txid: consensus_decode(&hex::decode("84334d4a4bcdf994b3e563a0e925bd5bc7cc6bf40496c3236941c30233a56977").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), txid: consensus_decode(&hex::decode("86900e7e035f24d1560cae42e418302aaaf5ae22571a6f088b94fa7e074215d7").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), txid: consensus_decode(&hex::decode("870d140b7a90b318e8c8c7724c6287749830b03b232157609998cebcb1f1d0ff").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), txid: consensus_decode(&hex::decode("882d41608b5f541784653b8b3a9087fdc7e19b08b0846203cead62b8992afabb").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), txid: consensus_decode(&hex::decode("94336c1c8fd573c7ad4e3d7fa6592a1b001c237e8faab14d5780474fd4d6fe62").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), txid: consensus_decode(&hex::decode("944879603fd1b6ef4421014ce7c1cf7df5dd740e5dda91a13d508636ac3ebc6c").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), txid: consensus_decode(&hex::decode("9871f4cf79e18e726b91be146fcd48ff2890e64a3554d405a99b6f57dbf13ebf").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), txid: consensus_decode(&hex::decode("9a43afe88077c1cee17dff96695bf5b25a6b18e8499d772278d8f4e27942651f").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), txid: consensus_decode(&hex::decode("9b42ec0f6fd154686696d283a51dbb98da1978837507835465d09b7125cd9469").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), txid: consensus_decode(&hex::decode("9c06ab041f6f614f7ae457ac78a47d7235574c98a93aa4e0260a5157c1fd065f").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), txid: consensus_decode(&hex::decode("9e42c74de2f66e75e2763043ebdab929697fe55e8b182a2c983fa689f9729a54").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), txid: consensus_decode(&hex::decode("a0dbaafc7a7265538d25d1accc3e6780051d112734d37a26861eee54cdc0860a").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), txid: consensus_decode(&hex::decode("a12ab55ede98f3c11456de21223b16c8debe881df46e18d0c4ea65b07e8bc610").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), txid: consensus_decode(&hex::decode("a5e761b565759fe46f37fb834917dde6cf96112194af119f87530357a59e463a").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), txid: consensus_decode(&hex::decode("a99f8a5d6b4509237825a8e41f06ed4d30127beac86ddbcb8765a0fecd205c43").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), txid: consensus_decode(&hex::decode("b1340de3e38d57929cb91b0ea5e8472553abfb9d42301b6fc5d615264c90dd54").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), txid: consensus_decode(&hex::decode("b26c6e7744522d9c0409c51ecf5b94b4fa3bd02fa7bd59f371c1a42e6a068fba").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), txid: consensus_decode(&hex::decode("b28e12e66480f7f728396794f4f939f713dc9d44c831016d48e7464de660d703").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), txid: consensus_decode(&hex::decode("b2d6a3b9a6a14ff57fb9aa781400d59d257638ba50e3535259dfe5b29f3ffd4f").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), txid: consensus_decode(&hex::decode("b430a6a5cb2b9f443d558b6c52932765d07cfabc7a1a52c396b5f585112beb17").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), txid: consensus_decode(&hex::decode("b566a843c89b61539052b76633ea7d8d68c3a632bef4305a2c2c73750475087f").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), txid: consensus_decode(&hex::decode("bc42c2659e6ffc902a06c42113bd49fcb66a4e20efa7660a51d9a8ed1959c654").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), txid: consensus_decode(&hex::decode("bd5039f0b8f2bbd6183321d3344c8a3856074b05ce32398a50623fec6220f196").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), txid: consensus_decode(&hex::decode("c0349e38082c76e62ed5b845a396868ab5f8613268356bb2469aa1ca7f94fed2").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), txid: consensus_decode(&hex::decode("c03b9cbeb95ec612e1a6604e9873556c634225732a06ae1cab84646eeea2ed40").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), txid: consensus_decode(&hex::decode("c5793751e9cbcc8f438cb835fc3f27cfd914e74ed23b3a3218af30f989234af5").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), txid: consensus_decode(&hex::decode("c91685ce3d48b042f060fa5031ab809c659354dff52fbafc35e9814ba8d3ffed").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), txid: consensus_decode(&hex::decode("caa44cf93d8c6206f45eabc74227049bcb41a7a65c8dfa4cfd2fa964d65eee02").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), txid: consensus_decode(&hex::decode("cc62211cc737742ad1bb1a12edc386df4bf84ada597a219ed08a3b84fc3eac92").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), txid: consensus_decode(&hex::decode("ce3bdbd3e48a4ea80bcbc207a722716702bb1ae6af978280b672b1280d07c1f7").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), txid: consensus_decode(&hex::decode("d5ad56b153c0cb74ae2c6d5c56a8d3c7a6ed00e21de74b308410a9bac776c5d1").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), txid: consensus_decode(&hex::decode("d6ad95b24ed67bb3288b03f06bd50ea3a89ecfce426fc8fa9a06ed163b3b433e").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), txid: consensus_decode(&hex::decode("dad8dd787ad1bf2fa568fc4afdffaa00d55bdf0721f67d907feb0a4bb9952e19").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), txid: consensus_decode(&hex::decode("dd6c1eb0c671c7a1546a56c7e8591cee7d35eae31a290cdae2933e92c0751d7a").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), txid: consensus_decode(&hex::decode("dfd9683d487fd6407614bf6d7cd1731323bf865242effb499ac94e85005e61fb").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), txid: consensus_decode(&hex::decode("dff95f4cecc366483e628236698740088a73c60069b04679d3170ca9966de64d").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), txid: consensus_decode(&hex::decode("e0d7b388911af97c9d3b12c743dcbe7f03670472c731fce26ce30c3b5431010d").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), txid: consensus_decode(&hex::decode("e6c44f7320174e5637d53ed596e45d6794e8bc88ee0916ad1588a1def35559bf").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), txid: consensus_decode(&hex::decode("e844aead67c33a125534cb1c557b981126b2631c12b337dc4709789527ae10c3").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), txid: consensus_decode(&hex::decode("e8e4db3caca2d81a7869593015de85ae6086dd9ca51e5d1d8935f669d85cb234").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), txid: consensus_decode(&hex::decode("eaf157e7b6fb7b1a9b766ec690c20203bcc730d371ba773eb3ad37fa8e08b10f").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), txid: consensus_decode(&hex::decode("f20d7fab026f71a7fc565c9700bc69fda79c7a81d1b1ec88b57e5499eed6ab99").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), txid: consensus_decode(&hex::decode("f445c8e0595ff9f4da471312e885a0d025be07b0c7e3bf3fac8679fc97601ab7").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), txid: consensus_decode(&hex::decode("f7d515b84b6c1f63ff399fac718c5ef7ca32d0e0fd4adbe84ed9be496f4bdaf4").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), txid: consensus_decode(&hex::decode("f7fc7baf4ed63c83857aa0b2b3d466900e20e1277f17c34d2fc48c92671f7db1").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), txid: consensus_decode(&hex::decode("f82256bcc97b94d4c3e58c900e679566c2d32ef3c6adcda10cf5df73c202b261").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), txid: consensus_decode(&hex::decode("f9b4c23a394155e613607490eec659b26dc0d2b169f34aa177dcac3ea9fa34e9").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), txid: consensus_decode(&hex::decode("fb9e03098c010f7729d33b2f7c38ce6418002d88c577986f724eb30e2430c6cf").unwrap()), },
-        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), txid: consensus_decode(&hex::decode("fca5ed4637c9eb7b4a8d1d65384f25346053046c5d47e8bda2127bf2e2b71e17").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x19,0x6e,0x36,0x61,0xab,0xa2,0x91,0xf5,0x65,0xf6,0xa8,0x7e,0xf2,0x37,0x29,0x3e,0xba,0xbe,0x4e,0x11,0x45,0x85,0xec,0x3a,0x3c,0x32,0x63,0xcc,0x4b,0xda,0x40,0x94]), confirmed_height: Some(102), txid: consensus_decode(&hex::decode("bcae0a12d28e9c28dfeaf2cbb914f9b414946049b7e304a4a219b73f6c0403b9").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x1d,0xe8,0xed,0xdb,0x92,0x64,0xd4,0x11,0x1c,0x76,0xf9,0x4d,0x23,0xf5,0xd5,0xea,0xef,0xcc,0x73,0x9f,0x85,0xd4,0x96,0xde,0x05,0xd2,0x77,0xeb,0x74,0xe3,0xd4,0x1b]), confirmed_height: Some(102), txid: consensus_decode(&hex::decode("2d813aded53c0f2f5daa2f9f5d1dc9c7e6af5496afd82c22f3acbb9a044e7029").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x1d,0xe8,0xed,0xdb,0x92,0x64,0xd4,0x11,0x1c,0x76,0xf9,0x4d,0x23,0xf5,0xd5,0xea,0xef,0xcc,0x73,0x9f,0x85,0xd4,0x96,0xde,0x05,0xd2,0x77,0xeb,0x74,0xe3,0xd4,0x1b]), confirmed_height: Some(102), txid: consensus_decode(&hex::decode("73ee24ea65f5b611e9a0ace8dd33b75cd1b5c3783afd1508fcdbaa6fdba7a6e6").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x33,0x18,0x53,0x7d,0xfb,0x31,0x35,0xdf,0x9f,0x3d,0x95,0x0d,0xbd,0xf8,0xa7,0xae,0x68,0xdd,0x7c,0x7d,0xfe,0xf6,0x1e,0xd1,0x79,0x63,0xff,0x80,0xf3,0x85,0x04,0x74]), confirmed_height: Some(0), txid: consensus_decode(&hex::decode("3ba3edfd7a7b12b27ac72c3e67768f617fc81bc3888a51323a9fb8aa4b1e5e4a").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x39,0x13,0x51,0x13,0x5e,0x1e,0x11,0x80,0x5c,0xf0,0x38,0x20,0xd2,0x89,0x28,0xbf,0x78,0xe4,0xc6,0x5f,0x76,0xec,0x4c,0xe0,0x92,0xa1,0x83,0xc6,0xa1,0xd3,0x19,0x6c]), confirmed_height: Some(102), txid: consensus_decode(&hex::decode("2d813aded53c0f2f5daa2f9f5d1dc9c7e6af5496afd82c22f3acbb9a044e7029").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0x0a,0x2a,0xef,0x00,0x33,0x92,0xb3,0xcb,0xc1,0x1a,0x24,0xa7,0x8d,0x7b,0xae,0x83,0x9d,0x3e,0x3d,0xc3,0x6a,0x53,0xeb,0x55,0x6b,0x1e,0xf1,0xe8,0x15,0x5a,0x14]), confirmed_height: Some(102), txid: consensus_decode(&hex::decode("2d813aded53c0f2f5daa2f9f5d1dc9c7e6af5496afd82c22f3acbb9a044e7029").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), confirmed_height: Some(1), txid: consensus_decode(&hex::decode("592f96fe043aaa22cdcb6f6e710946aa0af25dff4536759f6873965555a6660c").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), confirmed_height: Some(2), txid: consensus_decode(&hex::decode("dfd9683d487fd6407614bf6d7cd1731323bf865242effb499ac94e85005e61fb").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), confirmed_height: Some(3), txid: consensus_decode(&hex::decode("51f975af8c24d4f484f182e2fb2c71685038716459e0ac303948895fab17f3fc").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), confirmed_height: Some(4), txid: consensus_decode(&hex::decode("0f328bad7eca1c56b343ff2523eab6b12c24e6be8861cbcf90a4e849a7df95de").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), confirmed_height: Some(5), txid: consensus_decode(&hex::decode("d5ad56b153c0cb74ae2c6d5c56a8d3c7a6ed00e21de74b308410a9bac776c5d1").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), confirmed_height: Some(6), txid: consensus_decode(&hex::decode("220dd0b71c8f1fd379a69cc532a0352d79746c03fcaa05656415aa2ef1213aa7").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), confirmed_height: Some(7), txid: consensus_decode(&hex::decode("c91685ce3d48b042f060fa5031ab809c659354dff52fbafc35e9814ba8d3ffed").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), confirmed_height: Some(8), txid: consensus_decode(&hex::decode("4812fa27abdcd5e550fc4de2846ce644e604a14051a488a67dbb67e52e2cffec").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), confirmed_height: Some(9), txid: consensus_decode(&hex::decode("18e9139ba76040fa1f4ce4b1982bd3b731581e3e6313263d2594c9e354ebb728").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), confirmed_height: Some(10), txid: consensus_decode(&hex::decode("f20d7fab026f71a7fc565c9700bc69fda79c7a81d1b1ec88b57e5499eed6ab99").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), confirmed_height: Some(11), txid: consensus_decode(&hex::decode("caa44cf93d8c6206f45eabc74227049bcb41a7a65c8dfa4cfd2fa964d65eee02").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), confirmed_height: Some(12), txid: consensus_decode(&hex::decode("3c53dbaf709a0695dead0011aada19cbf5d36577fb288d1cd2f114982482a46d").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), confirmed_height: Some(13), txid: consensus_decode(&hex::decode("7cd8c012b690ec71db991bf958d1ca0c3337484550f2d60ea9daad9fc950259f").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), confirmed_height: Some(14), txid: consensus_decode(&hex::decode("ce3bdbd3e48a4ea80bcbc207a722716702bb1ae6af978280b672b1280d07c1f7").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), confirmed_height: Some(15), txid: consensus_decode(&hex::decode("a99f8a5d6b4509237825a8e41f06ed4d30127beac86ddbcb8765a0fecd205c43").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), confirmed_height: Some(16), txid: consensus_decode(&hex::decode("40e3d56b5f08e9d37016a14f1ad2ee9c5a9cd41bee0d2372169d079b46a1f737").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), confirmed_height: Some(17), txid: consensus_decode(&hex::decode("527d0f70f7f49e6acecb806ab7cc46fd994beb801a93e8b0524aff97162b4766").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), confirmed_height: Some(18), txid: consensus_decode(&hex::decode("9a43afe88077c1cee17dff96695bf5b25a6b18e8499d772278d8f4e27942651f").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), confirmed_height: Some(19), txid: consensus_decode(&hex::decode("9871f4cf79e18e726b91be146fcd48ff2890e64a3554d405a99b6f57dbf13ebf").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), confirmed_height: Some(20), txid: consensus_decode(&hex::decode("200e465574e472bd6b0900b8ca7095f3342b3d43553bb4754e8611ff1725f2ae").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), confirmed_height: Some(21), txid: consensus_decode(&hex::decode("715da11118f8fb545c17c8a6bc4ca99a99d85e331bdf604c6cb017ee3dca7c0b").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), confirmed_height: Some(22), txid: consensus_decode(&hex::decode("2a2a902d9cbfdd63b4c60d7ed2aefcc4fb9b5b4ccfa76464d0ebbd7fc8b25051").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), confirmed_height: Some(23), txid: consensus_decode(&hex::decode("6f79afe378fffa9516c389175c55200178350d99a0bd1cbc2df8bde3e7f9a126").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), confirmed_height: Some(24), txid: consensus_decode(&hex::decode("e8e4db3caca2d81a7869593015de85ae6086dd9ca51e5d1d8935f669d85cb234").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), confirmed_height: Some(25), txid: consensus_decode(&hex::decode("dff95f4cecc366483e628236698740088a73c60069b04679d3170ca9966de64d").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), confirmed_height: Some(26), txid: consensus_decode(&hex::decode("78ae6a5198b31017415a32fb66fda209690e24390ba6e5a1dde98ff15cf52d55").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), confirmed_height: Some(27), txid: consensus_decode(&hex::decode("7a355418ac6593fb3b00e2815f45b2ae3037a985121e6a1c204c1882c1fea083").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), confirmed_height: Some(28), txid: consensus_decode(&hex::decode("f9b4c23a394155e613607490eec659b26dc0d2b169f34aa177dcac3ea9fa34e9").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), confirmed_height: Some(29), txid: consensus_decode(&hex::decode("882d41608b5f541784653b8b3a9087fdc7e19b08b0846203cead62b8992afabb").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), confirmed_height: Some(30), txid: consensus_decode(&hex::decode("343057fa8d3bc9ccfc3ba087bb705ee47564e3769c316de83b20470a6ab8abbb").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), confirmed_height: Some(31), txid: consensus_decode(&hex::decode("2576f2f598674c26024db883e10f087102f9f4a91c93a6f8ea06c61f38443e77").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), confirmed_height: Some(32), txid: consensus_decode(&hex::decode("4b737b656b2ad2476b4c37aa7250ff35a400a7004f04228ea23ddd55061108fb").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), confirmed_height: Some(33), txid: consensus_decode(&hex::decode("7fc4490fcbd798a74b73512a917831a54047ed709b6314e825d16c860cef1b6f").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), confirmed_height: Some(34), txid: consensus_decode(&hex::decode("463a6fda64e67c0f640bb6396b443d3bacf9108d33d6b47ddebe61bce8b7e286").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), confirmed_height: Some(35), txid: consensus_decode(&hex::decode("94336c1c8fd573c7ad4e3d7fa6592a1b001c237e8faab14d5780474fd4d6fe62").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), confirmed_height: Some(36), txid: consensus_decode(&hex::decode("f82256bcc97b94d4c3e58c900e679566c2d32ef3c6adcda10cf5df73c202b261").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), confirmed_height: Some(37), txid: consensus_decode(&hex::decode("015cbb7aee592ab4b8881fde2c8036d61cb783c31c53d5efd4cb83dc291ef22f").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), confirmed_height: Some(38), txid: consensus_decode(&hex::decode("0782a2a7de4c46db3e041c63ee28aeab483947d6825e1315eca6e1cf02f3c906").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), confirmed_height: Some(39), txid: consensus_decode(&hex::decode("d6ad95b24ed67bb3288b03f06bd50ea3a89ecfce426fc8fa9a06ed163b3b433e").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), confirmed_height: Some(40), txid: consensus_decode(&hex::decode("e844aead67c33a125534cb1c557b981126b2631c12b337dc4709789527ae10c3").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), confirmed_height: Some(41), txid: consensus_decode(&hex::decode("4909a37755da7b1d912cb4b914539b82cf50327e993f4daabb8b4feb1e84adfb").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), confirmed_height: Some(42), txid: consensus_decode(&hex::decode("e6c44f7320174e5637d53ed596e45d6794e8bc88ee0916ad1588a1def35559bf").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), confirmed_height: Some(43), txid: consensus_decode(&hex::decode("bc42c2659e6ffc902a06c42113bd49fcb66a4e20efa7660a51d9a8ed1959c654").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), confirmed_height: Some(44), txid: consensus_decode(&hex::decode("b430a6a5cb2b9f443d558b6c52932765d07cfabc7a1a52c396b5f585112beb17").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), confirmed_height: Some(45), txid: consensus_decode(&hex::decode("053057a6cc0e2bb74ac02bb7305d08985f80d4f30985afca7a2ed1678ab9530a").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), confirmed_height: Some(46), txid: consensus_decode(&hex::decode("dad8dd787ad1bf2fa568fc4afdffaa00d55bdf0721f67d907feb0a4bb9952e19").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), confirmed_height: Some(47), txid: consensus_decode(&hex::decode("dd6c1eb0c671c7a1546a56c7e8591cee7d35eae31a290cdae2933e92c0751d7a").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), confirmed_height: Some(48), txid: consensus_decode(&hex::decode("a0dbaafc7a7265538d25d1accc3e6780051d112734d37a26861eee54cdc0860a").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), confirmed_height: Some(49), txid: consensus_decode(&hex::decode("e0d7b388911af97c9d3b12c743dcbe7f03670472c731fce26ce30c3b5431010d").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), confirmed_height: Some(50), txid: consensus_decode(&hex::decode("b1340de3e38d57929cb91b0ea5e8472553abfb9d42301b6fc5d615264c90dd54").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), confirmed_height: Some(51), txid: consensus_decode(&hex::decode("770bed1e8af9d80d7eb03e142c9896e834f99197563c286ca2f39c1e0d113960").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), confirmed_height: Some(52), txid: consensus_decode(&hex::decode("28a37fd0e952c0a548a6aa3fafa94d6463a29f040c7f9aabcfa3f693d9c123d5").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), confirmed_height: Some(53), txid: consensus_decode(&hex::decode("bd5039f0b8f2bbd6183321d3344c8a3856074b05ce32398a50623fec6220f196").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), confirmed_height: Some(54), txid: consensus_decode(&hex::decode("fca5ed4637c9eb7b4a8d1d65384f25346053046c5d47e8bda2127bf2e2b71e17").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), confirmed_height: Some(55), txid: consensus_decode(&hex::decode("75989e266f4ad3da241c5d4df9687682b997e4140bb54252777ede43009b6f10").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), confirmed_height: Some(56), txid: consensus_decode(&hex::decode("c03b9cbeb95ec612e1a6604e9873556c634225732a06ae1cab84646eeea2ed40").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), confirmed_height: Some(57), txid: consensus_decode(&hex::decode("2e7ba427bc64bf63817d79ec82397093e4e056ae2933d38e88ebf63990162ed6").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), confirmed_height: Some(58), txid: consensus_decode(&hex::decode("2764bc934ee5afd4323899205293101aec0e7d4065a2901b7190482c3efb33d4").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), confirmed_height: Some(59), txid: consensus_decode(&hex::decode("fb9e03098c010f7729d33b2f7c38ce6418002d88c577986f724eb30e2430c6cf").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), confirmed_height: Some(60), txid: consensus_decode(&hex::decode("08bbc21c87bf538339c86d117e78307c471df8a4b9d54cd3843d7058ab1e5ef5").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), confirmed_height: Some(61), txid: consensus_decode(&hex::decode("3e49c8cc7174650189aa87353112765c33d07e88e696d47c846e3ed996853d25").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), confirmed_height: Some(62), txid: consensus_decode(&hex::decode("9b42ec0f6fd154686696d283a51dbb98da1978837507835465d09b7125cd9469").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), confirmed_height: Some(63), txid: consensus_decode(&hex::decode("07a182ced74903e62fff155415a3ca26af0f71f2fc22de78194114cf19279eaa").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), confirmed_height: Some(64), txid: consensus_decode(&hex::decode("35295128120a4c1f90b70fb88f9b4bef400c55008b5d6b76186aec659500a7b2").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), confirmed_height: Some(65), txid: consensus_decode(&hex::decode("2fed5ae153b255cbd5e1bcae9e5cc93951ea80805b592ce7c2fe2a9795c1c941").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), confirmed_height: Some(66), txid: consensus_decode(&hex::decode("c5793751e9cbcc8f438cb835fc3f27cfd914e74ed23b3a3218af30f989234af5").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), confirmed_height: Some(67), txid: consensus_decode(&hex::decode("b26c6e7744522d9c0409c51ecf5b94b4fa3bd02fa7bd59f371c1a42e6a068fba").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), confirmed_height: Some(68), txid: consensus_decode(&hex::decode("f7d515b84b6c1f63ff399fac718c5ef7ca32d0e0fd4adbe84ed9be496f4bdaf4").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), confirmed_height: Some(69), txid: consensus_decode(&hex::decode("5fcd9f523df6c145be014836ec289c71bf435f7455e5e2eda1f6d40c2d2e9fdf").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), confirmed_height: Some(70), txid: consensus_decode(&hex::decode("145464574465129a8f3b111bca6932f86b4930bc202cf8bf60e1e22619c35b6b").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), confirmed_height: Some(71), txid: consensus_decode(&hex::decode("174a08ae859446ce66df56ad4ebab88152ffe007caa176bd9d7c5e0a86c0c848").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), confirmed_height: Some(72), txid: consensus_decode(&hex::decode("870d140b7a90b318e8c8c7724c6287749830b03b232157609998cebcb1f1d0ff").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), confirmed_height: Some(73), txid: consensus_decode(&hex::decode("087029de3113d9295dff2770d48d68f2880bf734efb8c5cc53ff73ae4a963ac3").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), confirmed_height: Some(74), txid: consensus_decode(&hex::decode("13af981ed9cecb66d175ad5eccb6eac7706e5affde549f89c5bb393429f36e5b").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), confirmed_height: Some(75), txid: consensus_decode(&hex::decode("84334d4a4bcdf994b3e563a0e925bd5bc7cc6bf40496c3236941c30233a56977").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), confirmed_height: Some(76), txid: consensus_decode(&hex::decode("6126c5af0b3871e3ef74443c642b46249b9f70a28d1063abeaec99e13118d01c").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), confirmed_height: Some(77), txid: consensus_decode(&hex::decode("9c06ab041f6f614f7ae457ac78a47d7235574c98a93aa4e0260a5157c1fd065f").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), confirmed_height: Some(78), txid: consensus_decode(&hex::decode("86900e7e035f24d1560cae42e418302aaaf5ae22571a6f088b94fa7e074215d7").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), confirmed_height: Some(79), txid: consensus_decode(&hex::decode("9e42c74de2f66e75e2763043ebdab929697fe55e8b182a2c983fa689f9729a54").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), confirmed_height: Some(80), txid: consensus_decode(&hex::decode("a5e761b565759fe46f37fb834917dde6cf96112194af119f87530357a59e463a").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), confirmed_height: Some(81), txid: consensus_decode(&hex::decode("0b2b470b2dfe711153e042886611d976846954b0dea9ce125b7d5b0afdeb9abf").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), confirmed_height: Some(82), txid: consensus_decode(&hex::decode("944879603fd1b6ef4421014ce7c1cf7df5dd740e5dda91a13d508636ac3ebc6c").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), confirmed_height: Some(83), txid: consensus_decode(&hex::decode("1c702a5d3dcfddfddaca47bb2fa5e3264254d2c3caed312361b0cc922be34e1e").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), confirmed_height: Some(84), txid: consensus_decode(&hex::decode("5c25e3685dc7d48ad0c45db8c9a9ef27e820655ca0e5e560e1ebb80b649fdcbe").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), confirmed_height: Some(85), txid: consensus_decode(&hex::decode("278b9c78c9e4c17a9555c4e06ba6fe2768f2b9787407236c9d7681fedc7ddba9").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), confirmed_height: Some(86), txid: consensus_decode(&hex::decode("b28e12e66480f7f728396794f4f939f713dc9d44c831016d48e7464de660d703").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), confirmed_height: Some(87), txid: consensus_decode(&hex::decode("69cb27faacc0dfceb4d9d9444d20b16a9fc00f61b7b29937c6f6d97b99f9b2d0").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), confirmed_height: Some(88), txid: consensus_decode(&hex::decode("a12ab55ede98f3c11456de21223b16c8debe881df46e18d0c4ea65b07e8bc610").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), confirmed_height: Some(89), txid: consensus_decode(&hex::decode("29421f89bf24dcd2c258528543c361152d0d9e2bffdeb72cf681c58d5a20235f").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), confirmed_height: Some(90), txid: consensus_decode(&hex::decode("f445c8e0595ff9f4da471312e885a0d025be07b0c7e3bf3fac8679fc97601ab7").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), confirmed_height: Some(91), txid: consensus_decode(&hex::decode("c0349e38082c76e62ed5b845a396868ab5f8613268356bb2469aa1ca7f94fed2").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), confirmed_height: Some(92), txid: consensus_decode(&hex::decode("f7fc7baf4ed63c83857aa0b2b3d466900e20e1277f17c34d2fc48c92671f7db1").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), confirmed_height: Some(93), txid: consensus_decode(&hex::decode("297842b6b2314843043892a8f49fb17b0ebadfc454c76d68f5a8cac1f23faf6d").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), confirmed_height: Some(94), txid: consensus_decode(&hex::decode("cc62211cc737742ad1bb1a12edc386df4bf84ada597a219ed08a3b84fc3eac92").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), confirmed_height: Some(95), txid: consensus_decode(&hex::decode("eaf157e7b6fb7b1a9b766ec690c20203bcc730d371ba773eb3ad37fa8e08b10f").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), confirmed_height: Some(96), txid: consensus_decode(&hex::decode("30e78bf44071ed68796f4d74cfce3c8c439445dfa4869cc097e858843ffb80d8").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), confirmed_height: Some(97), txid: consensus_decode(&hex::decode("b2d6a3b9a6a14ff57fb9aa781400d59d257638ba50e3535259dfe5b29f3ffd4f").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), confirmed_height: Some(98), txid: consensus_decode(&hex::decode("7233a59cb35f2cdfb1625018eb2365a19c0a44be9e2bdc7b0e47c30761916bd4").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), confirmed_height: Some(99), txid: consensus_decode(&hex::decode("37bcd587fc3f7ec7988029f9229f2955059eefc653ab6a7a79f0c03fc7f77c60").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), confirmed_height: Some(100), txid: consensus_decode(&hex::decode("6d9449a6e647bcc01943a15bfd990dff3688cb9de9c45d77147bbf79aab61124").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), confirmed_height: Some(101), txid: consensus_decode(&hex::decode("b566a843c89b61539052b76633ea7d8d68c3a632bef4305a2c2c73750475087f").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), confirmed_height: Some(102), txid: consensus_decode(&hex::decode("73ee24ea65f5b611e9a0ace8dd33b75cd1b5c3783afd1508fcdbaa6fdba7a6e6").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x3a,0xe8,0x14,0x51,0x54,0x62,0x4a,0xbd,0xb8,0x5b,0x83,0xd3,0x41,0xda,0x41,0x39,0xe8,0xaa,0xf0,0x2f,0x93,0xef,0xc9,0x63,0xb2,0x6a,0x56,0x19,0xd9,0xb1,0xdb,0xa8]), confirmed_height: Some(102), txid: consensus_decode(&hex::decode("bcae0a12d28e9c28dfeaf2cbb914f9b414946049b7e304a4a219b73f6c0403b9").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x51,0xc9,0xa4,0xdf,0x86,0xc7,0x6c,0xa7,0xc2,0xed,0xea,0xe6,0x38,0xe3,0xae,0xfa,0x95,0x98,0x7e,0xa3,0xa7,0x0d,0xf1,0x95,0xaa,0x6d,0x88,0xe3,0xe1,0xd1,0xe7,0x88]), confirmed_height: Some(102), txid: consensus_decode(&hex::decode("2d813aded53c0f2f5daa2f9f5d1dc9c7e6af5496afd82c22f3acbb9a044e7029").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x51,0xc9,0xa4,0xdf,0x86,0xc7,0x6c,0xa7,0xc2,0xed,0xea,0xe6,0x38,0xe3,0xae,0xfa,0x95,0x98,0x7e,0xa3,0xa7,0x0d,0xf1,0x95,0xaa,0x6d,0x88,0xe3,0xe1,0xd1,0xe7,0x88]), confirmed_height: Some(102), txid: consensus_decode(&hex::decode("73ee24ea65f5b611e9a0ace8dd33b75cd1b5c3783afd1508fcdbaa6fdba7a6e6").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), confirmed_height: Some(1), txid: consensus_decode(&hex::decode("592f96fe043aaa22cdcb6f6e710946aa0af25dff4536759f6873965555a6660c").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), confirmed_height: Some(2), txid: consensus_decode(&hex::decode("dfd9683d487fd6407614bf6d7cd1731323bf865242effb499ac94e85005e61fb").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), confirmed_height: Some(3), txid: consensus_decode(&hex::decode("51f975af8c24d4f484f182e2fb2c71685038716459e0ac303948895fab17f3fc").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), confirmed_height: Some(4), txid: consensus_decode(&hex::decode("0f328bad7eca1c56b343ff2523eab6b12c24e6be8861cbcf90a4e849a7df95de").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), confirmed_height: Some(5), txid: consensus_decode(&hex::decode("d5ad56b153c0cb74ae2c6d5c56a8d3c7a6ed00e21de74b308410a9bac776c5d1").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), confirmed_height: Some(6), txid: consensus_decode(&hex::decode("220dd0b71c8f1fd379a69cc532a0352d79746c03fcaa05656415aa2ef1213aa7").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), confirmed_height: Some(7), txid: consensus_decode(&hex::decode("c91685ce3d48b042f060fa5031ab809c659354dff52fbafc35e9814ba8d3ffed").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), confirmed_height: Some(8), txid: consensus_decode(&hex::decode("4812fa27abdcd5e550fc4de2846ce644e604a14051a488a67dbb67e52e2cffec").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), confirmed_height: Some(9), txid: consensus_decode(&hex::decode("18e9139ba76040fa1f4ce4b1982bd3b731581e3e6313263d2594c9e354ebb728").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), confirmed_height: Some(10), txid: consensus_decode(&hex::decode("f20d7fab026f71a7fc565c9700bc69fda79c7a81d1b1ec88b57e5499eed6ab99").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), confirmed_height: Some(11), txid: consensus_decode(&hex::decode("caa44cf93d8c6206f45eabc74227049bcb41a7a65c8dfa4cfd2fa964d65eee02").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), confirmed_height: Some(12), txid: consensus_decode(&hex::decode("3c53dbaf709a0695dead0011aada19cbf5d36577fb288d1cd2f114982482a46d").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), confirmed_height: Some(13), txid: consensus_decode(&hex::decode("7cd8c012b690ec71db991bf958d1ca0c3337484550f2d60ea9daad9fc950259f").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), confirmed_height: Some(14), txid: consensus_decode(&hex::decode("ce3bdbd3e48a4ea80bcbc207a722716702bb1ae6af978280b672b1280d07c1f7").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), confirmed_height: Some(15), txid: consensus_decode(&hex::decode("a99f8a5d6b4509237825a8e41f06ed4d30127beac86ddbcb8765a0fecd205c43").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), confirmed_height: Some(16), txid: consensus_decode(&hex::decode("40e3d56b5f08e9d37016a14f1ad2ee9c5a9cd41bee0d2372169d079b46a1f737").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), confirmed_height: Some(17), txid: consensus_decode(&hex::decode("527d0f70f7f49e6acecb806ab7cc46fd994beb801a93e8b0524aff97162b4766").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), confirmed_height: Some(18), txid: consensus_decode(&hex::decode("9a43afe88077c1cee17dff96695bf5b25a6b18e8499d772278d8f4e27942651f").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), confirmed_height: Some(19), txid: consensus_decode(&hex::decode("9871f4cf79e18e726b91be146fcd48ff2890e64a3554d405a99b6f57dbf13ebf").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), confirmed_height: Some(20), txid: consensus_decode(&hex::decode("200e465574e472bd6b0900b8ca7095f3342b3d43553bb4754e8611ff1725f2ae").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), confirmed_height: Some(21), txid: consensus_decode(&hex::decode("715da11118f8fb545c17c8a6bc4ca99a99d85e331bdf604c6cb017ee3dca7c0b").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), confirmed_height: Some(22), txid: consensus_decode(&hex::decode("2a2a902d9cbfdd63b4c60d7ed2aefcc4fb9b5b4ccfa76464d0ebbd7fc8b25051").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), confirmed_height: Some(23), txid: consensus_decode(&hex::decode("6f79afe378fffa9516c389175c55200178350d99a0bd1cbc2df8bde3e7f9a126").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), confirmed_height: Some(24), txid: consensus_decode(&hex::decode("e8e4db3caca2d81a7869593015de85ae6086dd9ca51e5d1d8935f669d85cb234").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), confirmed_height: Some(25), txid: consensus_decode(&hex::decode("dff95f4cecc366483e628236698740088a73c60069b04679d3170ca9966de64d").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), confirmed_height: Some(26), txid: consensus_decode(&hex::decode("78ae6a5198b31017415a32fb66fda209690e24390ba6e5a1dde98ff15cf52d55").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), confirmed_height: Some(27), txid: consensus_decode(&hex::decode("7a355418ac6593fb3b00e2815f45b2ae3037a985121e6a1c204c1882c1fea083").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), confirmed_height: Some(28), txid: consensus_decode(&hex::decode("f9b4c23a394155e613607490eec659b26dc0d2b169f34aa177dcac3ea9fa34e9").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), confirmed_height: Some(29), txid: consensus_decode(&hex::decode("882d41608b5f541784653b8b3a9087fdc7e19b08b0846203cead62b8992afabb").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), confirmed_height: Some(30), txid: consensus_decode(&hex::decode("343057fa8d3bc9ccfc3ba087bb705ee47564e3769c316de83b20470a6ab8abbb").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), confirmed_height: Some(31), txid: consensus_decode(&hex::decode("2576f2f598674c26024db883e10f087102f9f4a91c93a6f8ea06c61f38443e77").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), confirmed_height: Some(32), txid: consensus_decode(&hex::decode("4b737b656b2ad2476b4c37aa7250ff35a400a7004f04228ea23ddd55061108fb").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), confirmed_height: Some(33), txid: consensus_decode(&hex::decode("7fc4490fcbd798a74b73512a917831a54047ed709b6314e825d16c860cef1b6f").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), confirmed_height: Some(34), txid: consensus_decode(&hex::decode("463a6fda64e67c0f640bb6396b443d3bacf9108d33d6b47ddebe61bce8b7e286").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), confirmed_height: Some(35), txid: consensus_decode(&hex::decode("94336c1c8fd573c7ad4e3d7fa6592a1b001c237e8faab14d5780474fd4d6fe62").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), confirmed_height: Some(36), txid: consensus_decode(&hex::decode("f82256bcc97b94d4c3e58c900e679566c2d32ef3c6adcda10cf5df73c202b261").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), confirmed_height: Some(37), txid: consensus_decode(&hex::decode("015cbb7aee592ab4b8881fde2c8036d61cb783c31c53d5efd4cb83dc291ef22f").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), confirmed_height: Some(38), txid: consensus_decode(&hex::decode("0782a2a7de4c46db3e041c63ee28aeab483947d6825e1315eca6e1cf02f3c906").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), confirmed_height: Some(39), txid: consensus_decode(&hex::decode("d6ad95b24ed67bb3288b03f06bd50ea3a89ecfce426fc8fa9a06ed163b3b433e").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), confirmed_height: Some(40), txid: consensus_decode(&hex::decode("e844aead67c33a125534cb1c557b981126b2631c12b337dc4709789527ae10c3").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), confirmed_height: Some(41), txid: consensus_decode(&hex::decode("4909a37755da7b1d912cb4b914539b82cf50327e993f4daabb8b4feb1e84adfb").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), confirmed_height: Some(42), txid: consensus_decode(&hex::decode("e6c44f7320174e5637d53ed596e45d6794e8bc88ee0916ad1588a1def35559bf").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), confirmed_height: Some(43), txid: consensus_decode(&hex::decode("bc42c2659e6ffc902a06c42113bd49fcb66a4e20efa7660a51d9a8ed1959c654").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), confirmed_height: Some(44), txid: consensus_decode(&hex::decode("b430a6a5cb2b9f443d558b6c52932765d07cfabc7a1a52c396b5f585112beb17").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), confirmed_height: Some(45), txid: consensus_decode(&hex::decode("053057a6cc0e2bb74ac02bb7305d08985f80d4f30985afca7a2ed1678ab9530a").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), confirmed_height: Some(46), txid: consensus_decode(&hex::decode("dad8dd787ad1bf2fa568fc4afdffaa00d55bdf0721f67d907feb0a4bb9952e19").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), confirmed_height: Some(47), txid: consensus_decode(&hex::decode("dd6c1eb0c671c7a1546a56c7e8591cee7d35eae31a290cdae2933e92c0751d7a").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), confirmed_height: Some(48), txid: consensus_decode(&hex::decode("a0dbaafc7a7265538d25d1accc3e6780051d112734d37a26861eee54cdc0860a").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), confirmed_height: Some(49), txid: consensus_decode(&hex::decode("e0d7b388911af97c9d3b12c743dcbe7f03670472c731fce26ce30c3b5431010d").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), confirmed_height: Some(50), txid: consensus_decode(&hex::decode("b1340de3e38d57929cb91b0ea5e8472553abfb9d42301b6fc5d615264c90dd54").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), confirmed_height: Some(51), txid: consensus_decode(&hex::decode("770bed1e8af9d80d7eb03e142c9896e834f99197563c286ca2f39c1e0d113960").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), confirmed_height: Some(52), txid: consensus_decode(&hex::decode("28a37fd0e952c0a548a6aa3fafa94d6463a29f040c7f9aabcfa3f693d9c123d5").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), confirmed_height: Some(53), txid: consensus_decode(&hex::decode("bd5039f0b8f2bbd6183321d3344c8a3856074b05ce32398a50623fec6220f196").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), confirmed_height: Some(54), txid: consensus_decode(&hex::decode("fca5ed4637c9eb7b4a8d1d65384f25346053046c5d47e8bda2127bf2e2b71e17").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), confirmed_height: Some(55), txid: consensus_decode(&hex::decode("75989e266f4ad3da241c5d4df9687682b997e4140bb54252777ede43009b6f10").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), confirmed_height: Some(56), txid: consensus_decode(&hex::decode("c03b9cbeb95ec612e1a6604e9873556c634225732a06ae1cab84646eeea2ed40").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), confirmed_height: Some(57), txid: consensus_decode(&hex::decode("2e7ba427bc64bf63817d79ec82397093e4e056ae2933d38e88ebf63990162ed6").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), confirmed_height: Some(58), txid: consensus_decode(&hex::decode("2764bc934ee5afd4323899205293101aec0e7d4065a2901b7190482c3efb33d4").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), confirmed_height: Some(59), txid: consensus_decode(&hex::decode("fb9e03098c010f7729d33b2f7c38ce6418002d88c577986f724eb30e2430c6cf").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), confirmed_height: Some(60), txid: consensus_decode(&hex::decode("08bbc21c87bf538339c86d117e78307c471df8a4b9d54cd3843d7058ab1e5ef5").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), confirmed_height: Some(61), txid: consensus_decode(&hex::decode("3e49c8cc7174650189aa87353112765c33d07e88e696d47c846e3ed996853d25").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), confirmed_height: Some(62), txid: consensus_decode(&hex::decode("9b42ec0f6fd154686696d283a51dbb98da1978837507835465d09b7125cd9469").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), confirmed_height: Some(63), txid: consensus_decode(&hex::decode("07a182ced74903e62fff155415a3ca26af0f71f2fc22de78194114cf19279eaa").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), confirmed_height: Some(64), txid: consensus_decode(&hex::decode("35295128120a4c1f90b70fb88f9b4bef400c55008b5d6b76186aec659500a7b2").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), confirmed_height: Some(65), txid: consensus_decode(&hex::decode("2fed5ae153b255cbd5e1bcae9e5cc93951ea80805b592ce7c2fe2a9795c1c941").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), confirmed_height: Some(66), txid: consensus_decode(&hex::decode("c5793751e9cbcc8f438cb835fc3f27cfd914e74ed23b3a3218af30f989234af5").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), confirmed_height: Some(67), txid: consensus_decode(&hex::decode("b26c6e7744522d9c0409c51ecf5b94b4fa3bd02fa7bd59f371c1a42e6a068fba").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), confirmed_height: Some(68), txid: consensus_decode(&hex::decode("f7d515b84b6c1f63ff399fac718c5ef7ca32d0e0fd4adbe84ed9be496f4bdaf4").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), confirmed_height: Some(69), txid: consensus_decode(&hex::decode("5fcd9f523df6c145be014836ec289c71bf435f7455e5e2eda1f6d40c2d2e9fdf").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), confirmed_height: Some(70), txid: consensus_decode(&hex::decode("145464574465129a8f3b111bca6932f86b4930bc202cf8bf60e1e22619c35b6b").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), confirmed_height: Some(71), txid: consensus_decode(&hex::decode("174a08ae859446ce66df56ad4ebab88152ffe007caa176bd9d7c5e0a86c0c848").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), confirmed_height: Some(72), txid: consensus_decode(&hex::decode("870d140b7a90b318e8c8c7724c6287749830b03b232157609998cebcb1f1d0ff").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), confirmed_height: Some(73), txid: consensus_decode(&hex::decode("087029de3113d9295dff2770d48d68f2880bf734efb8c5cc53ff73ae4a963ac3").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), confirmed_height: Some(74), txid: consensus_decode(&hex::decode("13af981ed9cecb66d175ad5eccb6eac7706e5affde549f89c5bb393429f36e5b").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), confirmed_height: Some(75), txid: consensus_decode(&hex::decode("84334d4a4bcdf994b3e563a0e925bd5bc7cc6bf40496c3236941c30233a56977").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), confirmed_height: Some(76), txid: consensus_decode(&hex::decode("6126c5af0b3871e3ef74443c642b46249b9f70a28d1063abeaec99e13118d01c").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), confirmed_height: Some(77), txid: consensus_decode(&hex::decode("9c06ab041f6f614f7ae457ac78a47d7235574c98a93aa4e0260a5157c1fd065f").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), confirmed_height: Some(78), txid: consensus_decode(&hex::decode("86900e7e035f24d1560cae42e418302aaaf5ae22571a6f088b94fa7e074215d7").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), confirmed_height: Some(79), txid: consensus_decode(&hex::decode("9e42c74de2f66e75e2763043ebdab929697fe55e8b182a2c983fa689f9729a54").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), confirmed_height: Some(80), txid: consensus_decode(&hex::decode("a5e761b565759fe46f37fb834917dde6cf96112194af119f87530357a59e463a").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), confirmed_height: Some(81), txid: consensus_decode(&hex::decode("0b2b470b2dfe711153e042886611d976846954b0dea9ce125b7d5b0afdeb9abf").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), confirmed_height: Some(82), txid: consensus_decode(&hex::decode("944879603fd1b6ef4421014ce7c1cf7df5dd740e5dda91a13d508636ac3ebc6c").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), confirmed_height: Some(83), txid: consensus_decode(&hex::decode("1c702a5d3dcfddfddaca47bb2fa5e3264254d2c3caed312361b0cc922be34e1e").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), confirmed_height: Some(84), txid: consensus_decode(&hex::decode("5c25e3685dc7d48ad0c45db8c9a9ef27e820655ca0e5e560e1ebb80b649fdcbe").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), confirmed_height: Some(85), txid: consensus_decode(&hex::decode("278b9c78c9e4c17a9555c4e06ba6fe2768f2b9787407236c9d7681fedc7ddba9").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), confirmed_height: Some(86), txid: consensus_decode(&hex::decode("b28e12e66480f7f728396794f4f939f713dc9d44c831016d48e7464de660d703").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), confirmed_height: Some(87), txid: consensus_decode(&hex::decode("69cb27faacc0dfceb4d9d9444d20b16a9fc00f61b7b29937c6f6d97b99f9b2d0").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), confirmed_height: Some(88), txid: consensus_decode(&hex::decode("a12ab55ede98f3c11456de21223b16c8debe881df46e18d0c4ea65b07e8bc610").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), confirmed_height: Some(89), txid: consensus_decode(&hex::decode("29421f89bf24dcd2c258528543c361152d0d9e2bffdeb72cf681c58d5a20235f").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), confirmed_height: Some(90), txid: consensus_decode(&hex::decode("f445c8e0595ff9f4da471312e885a0d025be07b0c7e3bf3fac8679fc97601ab7").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), confirmed_height: Some(91), txid: consensus_decode(&hex::decode("c0349e38082c76e62ed5b845a396868ab5f8613268356bb2469aa1ca7f94fed2").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), confirmed_height: Some(92), txid: consensus_decode(&hex::decode("f7fc7baf4ed63c83857aa0b2b3d466900e20e1277f17c34d2fc48c92671f7db1").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), confirmed_height: Some(93), txid: consensus_decode(&hex::decode("297842b6b2314843043892a8f49fb17b0ebadfc454c76d68f5a8cac1f23faf6d").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), confirmed_height: Some(94), txid: consensus_decode(&hex::decode("cc62211cc737742ad1bb1a12edc386df4bf84ada597a219ed08a3b84fc3eac92").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), confirmed_height: Some(95), txid: consensus_decode(&hex::decode("eaf157e7b6fb7b1a9b766ec690c20203bcc730d371ba773eb3ad37fa8e08b10f").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), confirmed_height: Some(96), txid: consensus_decode(&hex::decode("30e78bf44071ed68796f4d74cfce3c8c439445dfa4869cc097e858843ffb80d8").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), confirmed_height: Some(97), txid: consensus_decode(&hex::decode("b2d6a3b9a6a14ff57fb9aa781400d59d257638ba50e3535259dfe5b29f3ffd4f").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), confirmed_height: Some(98), txid: consensus_decode(&hex::decode("7233a59cb35f2cdfb1625018eb2365a19c0a44be9e2bdc7b0e47c30761916bd4").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), confirmed_height: Some(99), txid: consensus_decode(&hex::decode("37bcd587fc3f7ec7988029f9229f2955059eefc653ab6a7a79f0c03fc7f77c60").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), confirmed_height: Some(100), txid: consensus_decode(&hex::decode("6d9449a6e647bcc01943a15bfd990dff3688cb9de9c45d77147bbf79aab61124").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x5c,0xf2,0x17,0xd4,0x08,0x8b,0x80,0x3b,0xb7,0x07,0x68,0x3b,0x3a,0x24,0xbb,0xb9,0x08,0xc5,0x71,0xce,0xd2,0x4f,0x7c,0x79,0x17,0xd7,0x03,0xd7,0x3f,0x6c,0x66,0x0e]), confirmed_height: Some(101), txid: consensus_decode(&hex::decode("b566a843c89b61539052b76633ea7d8d68c3a632bef4305a2c2c73750475087f").unwrap()), },
+        AddressIndexDBKey { wscript_hash: WScriptHash::from_inner([0x61,0x91,0xc3,0xb5,0x90,0xbf,0xcf,0xa0,0x47,0x5e,0x87,0x7c,0x30,0x2d,0xa1,0xe3,0x23,0x49,0x7a,0xcf,0x3b,0x42,0xc0,0x8d,0x8f,0xa2,0x8e,0x36,0x4e,0xdf,0x01,0x8b]), confirmed_height: Some(0), txid: consensus_decode(&hex::decode("3ba3edfd7a7b12b27ac72c3e67768f617fc81bc3888a51323a9fb8aa4b1e5e4a").unwrap()), },
     ]
 }
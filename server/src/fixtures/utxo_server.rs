@@ -2,229 +2,232 @@ use crate::*;
 use bitcoin::WScriptHash;
 use crate::db::utxo_server::UtxoServerValue;
 
-pub fn utxo_server_entries() -> [(WScriptHash, Vec<UtxoServerValue>); 8] {
+pub fn utxo_server_entries() -> [(WScriptHash, Vec<UtxoServerValue>); 9] {
     [
         (WScriptHash::from_hex("3318537dfb3135df9f3d950dbdf8a7ae68dd7c7dfef61ed17963ff80f3850474").unwrap(), vec![
-            UtxoServerValue { txid: consensus_decode(&hex::decode("3ba3edfd7a7b12b27ac72c3e67768f617fc81bc3888a51323a9fb8aa4b1e5e4a").unwrap()), vout: 0, },
+            UtxoServerValue::new(consensus_decode(&hex::decode("3ba3edfd7a7b12b27ac72c3e67768f617fc81bc3888a51323a9fb8aa4b1e5e4a").unwrap()), 0, 5000000000, 0, true, false),
+        ]),
+        (WScriptHash::from_hex("6191c3b590bfcfa0475e877c302da1e323497acf3b42c08d8fa28e364edf018b").unwrap(), vec![
+            UtxoServerValue::new(consensus_decode(&hex::decode("3ba3edfd7a7b12b27ac72c3e67768f617fc81bc3888a51323a9fb8aa4b1e5e4a").unwrap()), 0, 5000000000, 0, true, true),
         ]),
         (WScriptHash::from_hex("3ae8145154624abdb85b83d341da4139e8aaf02f93efc963b26a5619d9b1dba8").unwrap(), vec![
-            UtxoServerValue { txid: consensus_decode(&hex::decode("dfd9683d487fd6407614bf6d7cd1731323bf865242effb499ac94e85005e61fb").unwrap()), vout: 0, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("51f975af8c24d4f484f182e2fb2c71685038716459e0ac303948895fab17f3fc").unwrap()), vout: 0, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("0f328bad7eca1c56b343ff2523eab6b12c24e6be8861cbcf90a4e849a7df95de").unwrap()), vout: 0, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("d5ad56b153c0cb74ae2c6d5c56a8d3c7a6ed00e21de74b308410a9bac776c5d1").unwrap()), vout: 0, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("220dd0b71c8f1fd379a69cc532a0352d79746c03fcaa05656415aa2ef1213aa7").unwrap()), vout: 0, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("c91685ce3d48b042f060fa5031ab809c659354dff52fbafc35e9814ba8d3ffed").unwrap()), vout: 0, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("4812fa27abdcd5e550fc4de2846ce644e604a14051a488a67dbb67e52e2cffec").unwrap()), vout: 0, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("18e9139ba76040fa1f4ce4b1982bd3b731581e3e6313263d2594c9e354ebb728").unwrap()), vout: 0, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("f20d7fab026f71a7fc565c9700bc69fda79c7a81d1b1ec88b57e5499eed6ab99").unwrap()), vout: 0, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("caa44cf93d8c6206f45eabc74227049bcb41a7a65c8dfa4cfd2fa964d65eee02").unwrap()), vout: 0, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("3c53dbaf709a0695dead0011aada19cbf5d36577fb288d1cd2f114982482a46d").unwrap()), vout: 0, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("7cd8c012b690ec71db991bf958d1ca0c3337484550f2d60ea9daad9fc950259f").unwrap()), vout: 0, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("ce3bdbd3e48a4ea80bcbc207a722716702bb1ae6af978280b672b1280d07c1f7").unwrap()), vout: 0, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("a99f8a5d6b4509237825a8e41f06ed4d30127beac86ddbcb8765a0fecd205c43").unwrap()), vout: 0, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("40e3d56b5f08e9d37016a14f1ad2ee9c5a9cd41bee0d2372169d079b46a1f737").unwrap()), vout: 0, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("527d0f70f7f49e6acecb806ab7cc46fd994beb801a93e8b0524aff97162b4766").unwrap()), vout: 0, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("9a43afe88077c1cee17dff96695bf5b25a6b18e8499d772278d8f4e27942651f").unwrap()), vout: 0, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("9871f4cf79e18e726b91be146fcd48ff2890e64a3554d405a99b6f57dbf13ebf").unwrap()), vout: 0, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("200e465574e472bd6b0900b8ca7095f3342b3d43553bb4754e8611ff1725f2ae").unwrap()), vout: 0, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("715da11118f8fb545c17c8a6bc4ca99a99d85e331bdf604c6cb017ee3dca7c0b").unwrap()), vout: 0, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("2a2a902d9cbfdd63b4c60d7ed2aefcc4fb9b5b4ccfa76464d0ebbd7fc8b25051").unwrap()), vout: 0, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("6f79afe378fffa9516c389175c55200178350d99a0bd1cbc2df8bde3e7f9a126").unwrap()), vout: 0, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("e8e4db3caca2d81a7869593015de85ae6086dd9ca51e5d1d8935f669d85cb234").unwrap()), vout: 0, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("dff95f4cecc366483e628236698740088a73c60069b04679d3170ca9966de64d").unwrap()), vout: 0, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("78ae6a5198b31017415a32fb66fda209690e24390ba6e5a1dde98ff15cf52d55").unwrap()), vout: 0, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("7a355418ac6593fb3b00e2815f45b2ae3037a985121e6a1c204c1882c1fea083").unwrap()), vout: 0, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("f9b4c23a394155e613607490eec659b26dc0d2b169f34aa177dcac3ea9fa34e9").unwrap()), vout: 0, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("882d41608b5f541784653b8b3a9087fdc7e19b08b0846203cead62b8992afabb").unwrap()), vout: 0, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("343057fa8d3bc9ccfc3ba087bb705ee47564e3769c316de83b20470a6ab8abbb").unwrap()), vout: 0, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("2576f2f598674c26024db883e10f087102f9f4a91c93a6f8ea06c61f38443e77").unwrap()), vout: 0, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("4b737b656b2ad2476b4c37aa7250ff35a400a7004f04228ea23ddd55061108fb").unwrap()), vout: 0, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("7fc4490fcbd798a74b73512a917831a54047ed709b6314e825d16c860cef1b6f").unwrap()), vout: 0, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("463a6fda64e67c0f640bb6396b443d3bacf9108d33d6b47ddebe61bce8b7e286").unwrap()), vout: 0, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("94336c1c8fd573c7ad4e3d7fa6592a1b001c237e8faab14d5780474fd4d6fe62").unwrap()), vout: 0, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("f82256bcc97b94d4c3e58c900e679566c2d32ef3c6adcda10cf5df73c202b261").unwrap()), vout: 0, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("015cbb7aee592ab4b8881fde2c8036d61cb783c31c53d5efd4cb83dc291ef22f").unwrap()), vout: 0, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("0782a2a7de4c46db3e041c63ee28aeab483947d6825e1315eca6e1cf02f3c906").unwrap()), vout: 0, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("d6ad95b24ed67bb3288b03f06bd50ea3a89ecfce426fc8fa9a06ed163b3b433e").unwrap()), vout: 0, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("e844aead67c33a125534cb1c557b981126b2631c12b337dc4709789527ae10c3").unwrap()), vout: 0, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("4909a37755da7b1d912cb4b914539b82cf50327e993f4daabb8b4feb1e84adfb").unwrap()), vout: 0, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("e6c44f7320174e5637d53ed596e45d6794e8bc88ee0916ad1588a1def35559bf").unwrap()), vout: 0, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("bc42c2659e6ffc902a06c42113bd49fcb66a4e20efa7660a51d9a8ed1959c654").unwrap()), vout: 0, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("b430a6a5cb2b9f443d558b6c52932765d07cfabc7a1a52c396b5f585112beb17").unwrap()), vout: 0, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("053057a6cc0e2bb74ac02bb7305d08985f80d4f30985afca7a2ed1678ab9530a").unwrap()), vout: 0, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("dad8dd787ad1bf2fa568fc4afdffaa00d55bdf0721f67d907feb0a4bb9952e19").unwrap()), vout: 0, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("dd6c1eb0c671c7a1546a56c7e8591cee7d35eae31a290cdae2933e92c0751d7a").unwrap()), vout: 0, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("a0dbaafc7a7265538d25d1accc3e6780051d112734d37a26861eee54cdc0860a").unwrap()), vout: 0, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("e0d7b388911af97c9d3b12c743dcbe7f03670472c731fce26ce30c3b5431010d").unwrap()), vout: 0, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("b1340de3e38d57929cb91b0ea5e8472553abfb9d42301b6fc5d615264c90dd54").unwrap()), vout: 0, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("770bed1e8af9d80d7eb03e142c9896e834f99197563c286ca2f39c1e0d113960").unwrap()), vout: 0, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("28a37fd0e952c0a548a6aa3fafa94d6463a29f040c7f9aabcfa3f693d9c123d5").unwrap()), vout: 0, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("bd5039f0b8f2bbd6183321d3344c8a3856074b05ce32398a50623fec6220f196").unwrap()), vout: 0, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("fca5ed4637c9eb7b4a8d1d65384f25346053046c5d47e8bda2127bf2e2b71e17").unwrap()), vout: 0, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("75989e266f4ad3da241c5d4df9687682b997e4140bb54252777ede43009b6f10").unwrap()), vout: 0, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("c03b9cbeb95ec612e1a6604e9873556c634225732a06ae1cab84646eeea2ed40").unwrap()), vout: 0, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("2e7ba427bc64bf63817d79ec82397093e4e056ae2933d38e88ebf63990162ed6").unwrap()), vout: 0, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("2764bc934ee5afd4323899205293101aec0e7d4065a2901b7190482c3efb33d4").unwrap()), vout: 0, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("fb9e03098c010f7729d33b2f7c38ce6418002d88c577986f724eb30e2430c6cf").unwrap()), vout: 0, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("08bbc21c87bf538339c86d117e78307c471df8a4b9d54cd3843d7058ab1e5ef5").unwrap()), vout: 0, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("3e49c8cc7174650189aa87353112765c33d07e88e696d47c846e3ed996853d25").unwrap()), vout: 0, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("9b42ec0f6fd154686696d283a51dbb98da1978837507835465d09b7125cd9469").unwrap()), vout: 0, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("07a182ced74903e62fff155415a3ca26af0f71f2fc22de78194114cf19279eaa").unwrap()), vout: 0, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("35295128120a4c1f90b70fb88f9b4bef400c55008b5d6b76186aec659500a7b2").unwrap()), vout: 0, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("2fed5ae153b255cbd5e1bcae9e5cc93951ea80805b592ce7c2fe2a9795c1c941").unwrap()), vout: 0, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("c5793751e9cbcc8f438cb835fc3f27cfd914e74ed23b3a3218af30f989234af5").unwrap()), vout: 0, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("b26c6e7744522d9c0409c51ecf5b94b4fa3bd02fa7bd59f371c1a42e6a068fba").unwrap()), vout: 0, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("f7d515b84b6c1f63ff399fac718c5ef7ca32d0e0fd4adbe84ed9be496f4bdaf4").unwrap()), vout: 0, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("5fcd9f523df6c145be014836ec289c71bf435f7455e5e2eda1f6d40c2d2e9fdf").unwrap()), vout: 0, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("145464574465129a8f3b111bca6932f86b4930bc202cf8bf60e1e22619c35b6b").unwrap()), vout: 0, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("174a08ae859446ce66df56ad4ebab88152ffe007caa176bd9d7c5e0a86c0c848").unwrap()), vout: 0, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("870d140b7a90b318e8c8c7724c6287749830b03b232157609998cebcb1f1d0ff").unwrap()), vout: 0, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("087029de3113d9295dff2770d48d68f2880bf734efb8c5cc53ff73ae4a963ac3").unwrap()), vout: 0, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("13af981ed9cecb66d175ad5eccb6eac7706e5affde549f89c5bb393429f36e5b").unwrap()), vout: 0, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("84334d4a4bcdf994b3e563a0e925bd5bc7cc6bf40496c3236941c30233a56977").unwrap()), vout: 0, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("6126c5af0b3871e3ef74443c642b46249b9f70a28d1063abeaec99e13118d01c").unwrap()), vout: 0, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("9c06ab041f6f614f7ae457ac78a47d7235574c98a93aa4e0260a5157c1fd065f").unwrap()), vout: 0, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("86900e7e035f24d1560cae42e418302aaaf5ae22571a6f088b94fa7e074215d7").unwrap()), vout: 0, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("9e42c74de2f66e75e2763043ebdab929697fe55e8b182a2c983fa689f9729a54").unwrap()), vout: 0, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("a5e761b565759fe46f37fb834917dde6cf96112194af119f87530357a59e463a").unwrap()), vout: 0, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("0b2b470b2dfe711153e042886611d976846954b0dea9ce125b7d5b0afdeb9abf").unwrap()), vout: 0, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("944879603fd1b6ef4421014ce7c1cf7df5dd740e5dda91a13d508636ac3ebc6c").unwrap()), vout: 0, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("1c702a5d3dcfddfddaca47bb2fa5e3264254d2c3caed312361b0cc922be34e1e").unwrap()), vout: 0, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("5c25e3685dc7d48ad0c45db8c9a9ef27e820655ca0e5e560e1ebb80b649fdcbe").unwrap()), vout: 0, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("278b9c78c9e4c17a9555c4e06ba6fe2768f2b9787407236c9d7681fedc7ddba9").unwrap()), vout: 0, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("b28e12e66480f7f728396794f4f939f713dc9d44c831016d48e7464de660d703").unwrap()), vout: 0, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("69cb27faacc0dfceb4d9d9444d20b16a9fc00f61b7b29937c6f6d97b99f9b2d0").unwrap()), vout: 0, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("a12ab55ede98f3c11456de21223b16c8debe881df46e18d0c4ea65b07e8bc610").unwrap()), vout: 0, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("29421f89bf24dcd2c258528543c361152d0d9e2bffdeb72cf681c58d5a20235f").unwrap()), vout: 0, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("f445c8e0595ff9f4da471312e885a0d025be07b0c7e3bf3fac8679fc97601ab7").unwrap()), vout: 0, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("c0349e38082c76e62ed5b845a396868ab5f8613268356bb2469aa1ca7f94fed2").unwrap()), vout: 0, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("f7fc7baf4ed63c83857aa0b2b3d466900e20e1277f17c34d2fc48c92671f7db1").unwrap()), vout: 0, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("297842b6b2314843043892a8f49fb17b0ebadfc454c76d68f5a8cac1f23faf6d").unwrap()), vout: 0, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("cc62211cc737742ad1bb1a12edc386df4bf84ada597a219ed08a3b84fc3eac92").unwrap()), vout: 0, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("eaf157e7b6fb7b1a9b766ec690c20203bcc730d371ba773eb3ad37fa8e08b10f").unwrap()), vout: 0, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("30e78bf44071ed68796f4d74cfce3c8c439445dfa4869cc097e858843ffb80d8").unwrap()), vout: 0, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("b2d6a3b9a6a14ff57fb9aa781400d59d257638ba50e3535259dfe5b29f3ffd4f").unwrap()), vout: 0, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("7233a59cb35f2cdfb1625018eb2365a19c0a44be9e2bdc7b0e47c30761916bd4").unwrap()), vout: 0, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("37bcd587fc3f7ec7988029f9229f2955059eefc653ab6a7a79f0c03fc7f77c60").unwrap()), vout: 0, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("6d9449a6e647bcc01943a15bfd990dff3688cb9de9c45d77147bbf79aab61124").unwrap()), vout: 0, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("b566a843c89b61539052b76633ea7d8d68c3a632bef4305a2c2c73750475087f").unwrap()), vout: 0, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("bcae0a12d28e9c28dfeaf2cbb914f9b414946049b7e304a4a219b73f6c0403b9").unwrap()), vout: 0, },
+            UtxoServerValue::new(consensus_decode(&hex::decode("dfd9683d487fd6407614bf6d7cd1731323bf865242effb499ac94e85005e61fb").unwrap()), 0, 5000000000, 2, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("51f975af8c24d4f484f182e2fb2c71685038716459e0ac303948895fab17f3fc").unwrap()), 0, 5000000000, 3, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("0f328bad7eca1c56b343ff2523eab6b12c24e6be8861cbcf90a4e849a7df95de").unwrap()), 0, 5000000000, 4, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("d5ad56b153c0cb74ae2c6d5c56a8d3c7a6ed00e21de74b308410a9bac776c5d1").unwrap()), 0, 5000000000, 5, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("220dd0b71c8f1fd379a69cc532a0352d79746c03fcaa05656415aa2ef1213aa7").unwrap()), 0, 5000000000, 6, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("c91685ce3d48b042f060fa5031ab809c659354dff52fbafc35e9814ba8d3ffed").unwrap()), 0, 5000000000, 7, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("4812fa27abdcd5e550fc4de2846ce644e604a14051a488a67dbb67e52e2cffec").unwrap()), 0, 5000000000, 8, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("18e9139ba76040fa1f4ce4b1982bd3b731581e3e6313263d2594c9e354ebb728").unwrap()), 0, 5000000000, 9, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("f20d7fab026f71a7fc565c9700bc69fda79c7a81d1b1ec88b57e5499eed6ab99").unwrap()), 0, 5000000000, 10, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("caa44cf93d8c6206f45eabc74227049bcb41a7a65c8dfa4cfd2fa964d65eee02").unwrap()), 0, 5000000000, 11, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("3c53dbaf709a0695dead0011aada19cbf5d36577fb288d1cd2f114982482a46d").unwrap()), 0, 5000000000, 12, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("7cd8c012b690ec71db991bf958d1ca0c3337484550f2d60ea9daad9fc950259f").unwrap()), 0, 5000000000, 13, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("ce3bdbd3e48a4ea80bcbc207a722716702bb1ae6af978280b672b1280d07c1f7").unwrap()), 0, 5000000000, 14, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("a99f8a5d6b4509237825a8e41f06ed4d30127beac86ddbcb8765a0fecd205c43").unwrap()), 0, 5000000000, 15, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("40e3d56b5f08e9d37016a14f1ad2ee9c5a9cd41bee0d2372169d079b46a1f737").unwrap()), 0, 5000000000, 16, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("527d0f70f7f49e6acecb806ab7cc46fd994beb801a93e8b0524aff97162b4766").unwrap()), 0, 5000000000, 17, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("9a43afe88077c1cee17dff96695bf5b25a6b18e8499d772278d8f4e27942651f").unwrap()), 0, 5000000000, 18, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("9871f4cf79e18e726b91be146fcd48ff2890e64a3554d405a99b6f57dbf13ebf").unwrap()), 0, 5000000000, 19, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("200e465574e472bd6b0900b8ca7095f3342b3d43553bb4754e8611ff1725f2ae").unwrap()), 0, 5000000000, 20, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("715da11118f8fb545c17c8a6bc4ca99a99d85e331bdf604c6cb017ee3dca7c0b").unwrap()), 0, 5000000000, 21, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("2a2a902d9cbfdd63b4c60d7ed2aefcc4fb9b5b4ccfa76464d0ebbd7fc8b25051").unwrap()), 0, 5000000000, 22, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("6f79afe378fffa9516c389175c55200178350d99a0bd1cbc2df8bde3e7f9a126").unwrap()), 0, 5000000000, 23, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("e8e4db3caca2d81a7869593015de85ae6086dd9ca51e5d1d8935f669d85cb234").unwrap()), 0, 5000000000, 24, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("dff95f4cecc366483e628236698740088a73c60069b04679d3170ca9966de64d").unwrap()), 0, 5000000000, 25, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("78ae6a5198b31017415a32fb66fda209690e24390ba6e5a1dde98ff15cf52d55").unwrap()), 0, 5000000000, 26, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("7a355418ac6593fb3b00e2815f45b2ae3037a985121e6a1c204c1882c1fea083").unwrap()), 0, 5000000000, 27, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("f9b4c23a394155e613607490eec659b26dc0d2b169f34aa177dcac3ea9fa34e9").unwrap()), 0, 5000000000, 28, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("882d41608b5f541784653b8b3a9087fdc7e19b08b0846203cead62b8992afabb").unwrap()), 0, 5000000000, 29, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("343057fa8d3bc9ccfc3ba087bb705ee47564e3769c316de83b20470a6ab8abbb").unwrap()), 0, 5000000000, 30, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("2576f2f598674c26024db883e10f087102f9f4a91c93a6f8ea06c61f38443e77").unwrap()), 0, 5000000000, 31, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("4b737b656b2ad2476b4c37aa7250ff35a400a7004f04228ea23ddd55061108fb").unwrap()), 0, 5000000000, 32, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("7fc4490fcbd798a74b73512a917831a54047ed709b6314e825d16c860cef1b6f").unwrap()), 0, 5000000000, 33, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("463a6fda64e67c0f640bb6396b443d3bacf9108d33d6b47ddebe61bce8b7e286").unwrap()), 0, 5000000000, 34, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("94336c1c8fd573c7ad4e3d7fa6592a1b001c237e8faab14d5780474fd4d6fe62").unwrap()), 0, 5000000000, 35, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("f82256bcc97b94d4c3e58c900e679566c2d32ef3c6adcda10cf5df73c202b261").unwrap()), 0, 5000000000, 36, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("015cbb7aee592ab4b8881fde2c8036d61cb783c31c53d5efd4cb83dc291ef22f").unwrap()), 0, 5000000000, 37, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("0782a2a7de4c46db3e041c63ee28aeab483947d6825e1315eca6e1cf02f3c906").unwrap()), 0, 5000000000, 38, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("d6ad95b24ed67bb3288b03f06bd50ea3a89ecfce426fc8fa9a06ed163b3b433e").unwrap()), 0, 5000000000, 39, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("e844aead67c33a125534cb1c557b981126b2631c12b337dc4709789527ae10c3").unwrap()), 0, 5000000000, 40, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("4909a37755da7b1d912cb4b914539b82cf50327e993f4daabb8b4feb1e84adfb").unwrap()), 0, 5000000000, 41, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("e6c44f7320174e5637d53ed596e45d6794e8bc88ee0916ad1588a1def35559bf").unwrap()), 0, 5000000000, 42, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("bc42c2659e6ffc902a06c42113bd49fcb66a4e20efa7660a51d9a8ed1959c654").unwrap()), 0, 5000000000, 43, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("b430a6a5cb2b9f443d558b6c52932765d07cfabc7a1a52c396b5f585112beb17").unwrap()), 0, 5000000000, 44, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("053057a6cc0e2bb74ac02bb7305d08985f80d4f30985afca7a2ed1678ab9530a").unwrap()), 0, 5000000000, 45, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("dad8dd787ad1bf2fa568fc4afdffaa00d55bdf0721f67d907feb0a4bb9952e19").unwrap()), 0, 5000000000, 46, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("dd6c1eb0c671c7a1546a56c7e8591cee7d35eae31a290cdae2933e92c0751d7a").unwrap()), 0, 5000000000, 47, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("a0dbaafc7a7265538d25d1accc3e6780051d112734d37a26861eee54cdc0860a").unwrap()), 0, 5000000000, 48, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("e0d7b388911af97c9d3b12c743dcbe7f03670472c731fce26ce30c3b5431010d").unwrap()), 0, 5000000000, 49, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("b1340de3e38d57929cb91b0ea5e8472553abfb9d42301b6fc5d615264c90dd54").unwrap()), 0, 5000000000, 50, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("770bed1e8af9d80d7eb03e142c9896e834f99197563c286ca2f39c1e0d113960").unwrap()), 0, 5000000000, 51, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("28a37fd0e952c0a548a6aa3fafa94d6463a29f040c7f9aabcfa3f693d9c123d5").unwrap()), 0, 5000000000, 52, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("bd5039f0b8f2bbd6183321d3344c8a3856074b05ce32398a50623fec6220f196").unwrap()), 0, 5000000000, 53, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("fca5ed4637c9eb7b4a8d1d65384f25346053046c5d47e8bda2127bf2e2b71e17").unwrap()), 0, 5000000000, 54, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("75989e266f4ad3da241c5d4df9687682b997e4140bb54252777ede43009b6f10").unwrap()), 0, 5000000000, 55, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("c03b9cbeb95ec612e1a6604e9873556c634225732a06ae1cab84646eeea2ed40").unwrap()), 0, 5000000000, 56, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("2e7ba427bc64bf63817d79ec82397093e4e056ae2933d38e88ebf63990162ed6").unwrap()), 0, 5000000000, 57, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("2764bc934ee5afd4323899205293101aec0e7d4065a2901b7190482c3efb33d4").unwrap()), 0, 5000000000, 58, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("fb9e03098c010f7729d33b2f7c38ce6418002d88c577986f724eb30e2430c6cf").unwrap()), 0, 5000000000, 59, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("08bbc21c87bf538339c86d117e78307c471df8a4b9d54cd3843d7058ab1e5ef5").unwrap()), 0, 5000000000, 60, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("3e49c8cc7174650189aa87353112765c33d07e88e696d47c846e3ed996853d25").unwrap()), 0, 5000000000, 61, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("9b42ec0f6fd154686696d283a51dbb98da1978837507835465d09b7125cd9469").unwrap()), 0, 5000000000, 62, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("07a182ced74903e62fff155415a3ca26af0f71f2fc22de78194114cf19279eaa").unwrap()), 0, 5000000000, 63, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("35295128120a4c1f90b70fb88f9b4bef400c55008b5d6b76186aec659500a7b2").unwrap()), 0, 5000000000, 64, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("2fed5ae153b255cbd5e1bcae9e5cc93951ea80805b592ce7c2fe2a9795c1c941").unwrap()), 0, 5000000000, 65, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("c5793751e9cbcc8f438cb835fc3f27cfd914e74ed23b3a3218af30f989234af5").unwrap()), 0, 5000000000, 66, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("b26c6e7744522d9c0409c51ecf5b94b4fa3bd02fa7bd59f371c1a42e6a068fba").unwrap()), 0, 5000000000, 67, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("f7d515b84b6c1f63ff399fac718c5ef7ca32d0e0fd4adbe84ed9be496f4bdaf4").unwrap()), 0, 5000000000, 68, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("5fcd9f523df6c145be014836ec289c71bf435f7455e5e2eda1f6d40c2d2e9fdf").unwrap()), 0, 5000000000, 69, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("145464574465129a8f3b111bca6932f86b4930bc202cf8bf60e1e22619c35b6b").unwrap()), 0, 5000000000, 70, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("174a08ae859446ce66df56ad4ebab88152ffe007caa176bd9d7c5e0a86c0c848").unwrap()), 0, 5000000000, 71, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("870d140b7a90b318e8c8c7724c6287749830b03b232157609998cebcb1f1d0ff").unwrap()), 0, 5000000000, 72, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("087029de3113d9295dff2770d48d68f2880bf734efb8c5cc53ff73ae4a963ac3").unwrap()), 0, 5000000000, 73, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("13af981ed9cecb66d175ad5eccb6eac7706e5affde549f89c5bb393429f36e5b").unwrap()), 0, 5000000000, 74, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("84334d4a4bcdf994b3e563a0e925bd5bc7cc6bf40496c3236941c30233a56977").unwrap()), 0, 5000000000, 75, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("6126c5af0b3871e3ef74443c642b46249b9f70a28d1063abeaec99e13118d01c").unwrap()), 0, 5000000000, 76, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("9c06ab041f6f614f7ae457ac78a47d7235574c98a93aa4e0260a5157c1fd065f").unwrap()), 0, 5000000000, 77, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("86900e7e035f24d1560cae42e418302aaaf5ae22571a6f088b94fa7e074215d7").unwrap()), 0, 5000000000, 78, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("9e42c74de2f66e75e2763043ebdab929697fe55e8b182a2c983fa689f9729a54").unwrap()), 0, 5000000000, 79, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("a5e761b565759fe46f37fb834917dde6cf96112194af119f87530357a59e463a").unwrap()), 0, 5000000000, 80, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("0b2b470b2dfe711153e042886611d976846954b0dea9ce125b7d5b0afdeb9abf").unwrap()), 0, 5000000000, 81, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("944879603fd1b6ef4421014ce7c1cf7df5dd740e5dda91a13d508636ac3ebc6c").unwrap()), 0, 5000000000, 82, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("1c702a5d3dcfddfddaca47bb2fa5e3264254d2c3caed312361b0cc922be34e1e").unwrap()), 0, 5000000000, 83, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("5c25e3685dc7d48ad0c45db8c9a9ef27e820655ca0e5e560e1ebb80b649fdcbe").unwrap()), 0, 5000000000, 84, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("278b9c78c9e4c17a9555c4e06ba6fe2768f2b9787407236c9d7681fedc7ddba9").unwrap()), 0, 5000000000, 85, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("b28e12e66480f7f728396794f4f939f713dc9d44c831016d48e7464de660d703").unwrap()), 0, 5000000000, 86, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("69cb27faacc0dfceb4d9d9444d20b16a9fc00f61b7b29937c6f6d97b99f9b2d0").unwrap()), 0, 5000000000, 87, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("a12ab55ede98f3c11456de21223b16c8debe881df46e18d0c4ea65b07e8bc610").unwrap()), 0, 5000000000, 88, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("29421f89bf24dcd2c258528543c361152d0d9e2bffdeb72cf681c58d5a20235f").unwrap()), 0, 5000000000, 89, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("f445c8e0595ff9f4da471312e885a0d025be07b0c7e3bf3fac8679fc97601ab7").unwrap()), 0, 5000000000, 90, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("c0349e38082c76e62ed5b845a396868ab5f8613268356bb2469aa1ca7f94fed2").unwrap()), 0, 5000000000, 91, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("f7fc7baf4ed63c83857aa0b2b3d466900e20e1277f17c34d2fc48c92671f7db1").unwrap()), 0, 5000000000, 92, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("297842b6b2314843043892a8f49fb17b0ebadfc454c76d68f5a8cac1f23faf6d").unwrap()), 0, 5000000000, 93, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("cc62211cc737742ad1bb1a12edc386df4bf84ada597a219ed08a3b84fc3eac92").unwrap()), 0, 5000000000, 94, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("eaf157e7b6fb7b1a9b766ec690c20203bcc730d371ba773eb3ad37fa8e08b10f").unwrap()), 0, 5000000000, 95, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("30e78bf44071ed68796f4d74cfce3c8c439445dfa4869cc097e858843ffb80d8").unwrap()), 0, 5000000000, 96, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("b2d6a3b9a6a14ff57fb9aa781400d59d257638ba50e3535259dfe5b29f3ffd4f").unwrap()), 0, 5000000000, 97, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("7233a59cb35f2cdfb1625018eb2365a19c0a44be9e2bdc7b0e47c30761916bd4").unwrap()), 0, 5000000000, 98, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("37bcd587fc3f7ec7988029f9229f2955059eefc653ab6a7a79f0c03fc7f77c60").unwrap()), 0, 5000000000, 99, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("6d9449a6e647bcc01943a15bfd990dff3688cb9de9c45d77147bbf79aab61124").unwrap()), 0, 5000000000, 100, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("b566a843c89b61539052b76633ea7d8d68c3a632bef4305a2c2c73750475087f").unwrap()), 0, 5000000000, 101, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("bcae0a12d28e9c28dfeaf2cbb914f9b414946049b7e304a4a219b73f6c0403b9").unwrap()), 0, 5000034900, 102, true, false),
         ]),
         (WScriptHash::from_hex("5cf217d4088b803bb707683b3a24bbb908c571ced24f7c7917d703d73f6c660e").unwrap(), vec![
-            UtxoServerValue { txid: consensus_decode(&hex::decode("592f96fe043aaa22cdcb6f6e710946aa0af25dff4536759f6873965555a6660c").unwrap()), vout: 1, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("dfd9683d487fd6407614bf6d7cd1731323bf865242effb499ac94e85005e61fb").unwrap()), vout: 1, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("51f975af8c24d4f484f182e2fb2c71685038716459e0ac303948895fab17f3fc").unwrap()), vout: 1, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("0f328bad7eca1c56b343ff2523eab6b12c24e6be8861cbcf90a4e849a7df95de").unwrap()), vout: 1, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("d5ad56b153c0cb74ae2c6d5c56a8d3c7a6ed00e21de74b308410a9bac776c5d1").unwrap()), vout: 1, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("220dd0b71c8f1fd379a69cc532a0352d79746c03fcaa05656415aa2ef1213aa7").unwrap()), vout: 1, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("c91685ce3d48b042f060fa5031ab809c659354dff52fbafc35e9814ba8d3ffed").unwrap()), vout: 1, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("4812fa27abdcd5e550fc4de2846ce644e604a14051a488a67dbb67e52e2cffec").unwrap()), vout: 1, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("18e9139ba76040fa1f4ce4b1982bd3b731581e3e6313263d2594c9e354ebb728").unwrap()), vout: 1, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("f20d7fab026f71a7fc565c9700bc69fda79c7a81d1b1ec88b57e5499eed6ab99").unwrap()), vout: 1, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("caa44cf93d8c6206f45eabc74227049bcb41a7a65c8dfa4cfd2fa964d65eee02").unwrap()), vout: 1, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("3c53dbaf709a0695dead0011aada19cbf5d36577fb288d1cd2f114982482a46d").unwrap()), vout: 1, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("7cd8c012b690ec71db991bf958d1ca0c3337484550f2d60ea9daad9fc950259f").unwrap()), vout: 1, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("ce3bdbd3e48a4ea80bcbc207a722716702bb1ae6af978280b672b1280d07c1f7").unwrap()), vout: 1, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("a99f8a5d6b4509237825a8e41f06ed4d30127beac86ddbcb8765a0fecd205c43").unwrap()), vout: 1, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("40e3d56b5f08e9d37016a14f1ad2ee9c5a9cd41bee0d2372169d079b46a1f737").unwrap()), vout: 1, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("527d0f70f7f49e6acecb806ab7cc46fd994beb801a93e8b0524aff97162b4766").unwrap()), vout: 1, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("9a43afe88077c1cee17dff96695bf5b25a6b18e8499d772278d8f4e27942651f").unwrap()), vout: 1, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("9871f4cf79e18e726b91be146fcd48ff2890e64a3554d405a99b6f57dbf13ebf").unwrap()), vout: 1, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("200e465574e472bd6b0900b8ca7095f3342b3d43553bb4754e8611ff1725f2ae").unwrap()), vout: 1, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("715da11118f8fb545c17c8a6bc4ca99a99d85e331bdf604c6cb017ee3dca7c0b").unwrap()), vout: 1, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("2a2a902d9cbfdd63b4c60d7ed2aefcc4fb9b5b4ccfa76464d0ebbd7fc8b25051").unwrap()), vout: 1, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("6f79afe378fffa9516c389175c55200178350d99a0bd1cbc2df8bde3e7f9a126").unwrap()), vout: 1, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("e8e4db3caca2d81a7869593015de85ae6086dd9ca51e5d1d8935f669d85cb234").unwrap()), vout: 1, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("dff95f4cecc366483e628236698740088a73c60069b04679d3170ca9966de64d").unwrap()), vout: 1, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("78ae6a5198b31017415a32fb66fda209690e24390ba6e5a1dde98ff15cf52d55").unwrap()), vout: 1, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("7a355418ac6593fb3b00e2815f45b2ae3037a985121e6a1c204c1882c1fea083").unwrap()), vout: 1, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("f9b4c23a394155e613607490eec659b26dc0d2b169f34aa177dcac3ea9fa34e9").unwrap()), vout: 1, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("882d41608b5f541784653b8b3a9087fdc7e19b08b0846203cead62b8992afabb").unwrap()), vout: 1, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("343057fa8d3bc9ccfc3ba087bb705ee47564e3769c316de83b20470a6ab8abbb").unwrap()), vout: 1, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("2576f2f598674c26024db883e10f087102f9f4a91c93a6f8ea06c61f38443e77").unwrap()), vout: 1, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("4b737b656b2ad2476b4c37aa7250ff35a400a7004f04228ea23ddd55061108fb").unwrap()), vout: 1, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("7fc4490fcbd798a74b73512a917831a54047ed709b6314e825d16c860cef1b6f").unwrap()), vout: 1, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("463a6fda64e67c0f640bb6396b443d3bacf9108d33d6b47ddebe61bce8b7e286").unwrap()), vout: 1, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("94336c1c8fd573c7ad4e3d7fa6592a1b001c237e8faab14d5780474fd4d6fe62").unwrap()), vout: 1, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("f82256bcc97b94d4c3e58c900e679566c2d32ef3c6adcda10cf5df73c202b261").unwrap()), vout: 1, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("015cbb7aee592ab4b8881fde2c8036d61cb783c31c53d5efd4cb83dc291ef22f").unwrap()), vout: 1, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("0782a2a7de4c46db3e041c63ee28aeab483947d6825e1315eca6e1cf02f3c906").unwrap()), vout: 1, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("d6ad95b24ed67bb3288b03f06bd50ea3a89ecfce426fc8fa9a06ed163b3b433e").unwrap()), vout: 1, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("e844aead67c33a125534cb1c557b981126b2631c12b337dc4709789527ae10c3").unwrap()), vout: 1, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("4909a37755da7b1d912cb4b914539b82cf50327e993f4daabb8b4feb1e84adfb").unwrap()), vout: 1, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("e6c44f7320174e5637d53ed596e45d6794e8bc88ee0916ad1588a1def35559bf").unwrap()), vout: 1, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("bc42c2659e6ffc902a06c42113bd49fcb66a4e20efa7660a51d9a8ed1959c654").unwrap()), vout: 1, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("b430a6a5cb2b9f443d558b6c52932765d07cfabc7a1a52c396b5f585112beb17").unwrap()), vout: 1, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("053057a6cc0e2bb74ac02bb7305d08985f80d4f30985afca7a2ed1678ab9530a").unwrap()), vout: 1, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("dad8dd787ad1bf2fa568fc4afdffaa00d55bdf0721f67d907feb0a4bb9952e19").unwrap()), vout: 1, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("dd6c1eb0c671c7a1546a56c7e8591cee7d35eae31a290cdae2933e92c0751d7a").unwrap()), vout: 1, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("a0dbaafc7a7265538d25d1accc3e6780051d112734d37a26861eee54cdc0860a").unwrap()), vout: 1, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("e0d7b388911af97c9d3b12c743dcbe7f03670472c731fce26ce30c3b5431010d").unwrap()), vout: 1, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("b1340de3e38d57929cb91b0ea5e8472553abfb9d42301b6fc5d615264c90dd54").unwrap()), vout: 1, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("770bed1e8af9d80d7eb03e142c9896e834f99197563c286ca2f39c1e0d113960").unwrap()), vout: 1, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("28a37fd0e952c0a548a6aa3fafa94d6463a29f040c7f9aabcfa3f693d9c123d5").unwrap()), vout: 1, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("bd5039f0b8f2bbd6183321d3344c8a3856074b05ce32398a50623fec6220f196").unwrap()), vout: 1, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("fca5ed4637c9eb7b4a8d1d65384f25346053046c5d47e8bda2127bf2e2b71e17").unwrap()), vout: 1, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("75989e266f4ad3da241c5d4df9687682b997e4140bb54252777ede43009b6f10").unwrap()), vout: 1, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("c03b9cbeb95ec612e1a6604e9873556c634225732a06ae1cab84646eeea2ed40").unwrap()), vout: 1, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("2e7ba427bc64bf63817d79ec82397093e4e056ae2933d38e88ebf63990162ed6").unwrap()), vout: 1, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("2764bc934ee5afd4323899205293101aec0e7d4065a2901b7190482c3efb33d4").unwrap()), vout: 1, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("fb9e03098c010f7729d33b2f7c38ce6418002d88c577986f724eb30e2430c6cf").unwrap()), vout: 1, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("08bbc21c87bf538339c86d117e78307c471df8a4b9d54cd3843d7058ab1e5ef5").unwrap()), vout: 1, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("3e49c8cc7174650189aa87353112765c33d07e88e696d47c846e3ed996853d25").unwrap()), vout: 1, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("9b42ec0f6fd154686696d283a51dbb98da1978837507835465d09b7125cd9469").unwrap()), vout: 1, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("07a182ced74903e62fff155415a3ca26af0f71f2fc22de78194114cf19279eaa").unwrap()), vout: 1, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("35295128120a4c1f90b70fb88f9b4bef400c55008b5d6b76186aec659500a7b2").unwrap()), vout: 1, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("2fed5ae153b255cbd5e1bcae9e5cc93951ea80805b592ce7c2fe2a9795c1c941").unwrap()), vout: 1, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("c5793751e9cbcc8f438cb835fc3f27cfd914e74ed23b3a3218af30f989234af5").unwrap()), vout: 1, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("b26c6e7744522d9c0409c51ecf5b94b4fa3bd02fa7bd59f371c1a42e6a068fba").unwrap()), vout: 1, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("f7d515b84b6c1f63ff399fac718c5ef7ca32d0e0fd4adbe84ed9be496f4bdaf4").unwrap()), vout: 1, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("5fcd9f523df6c145be014836ec289c71bf435f7455e5e2eda1f6d40c2d2e9fdf").unwrap()), vout: 1, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("145464574465129a8f3b111bca6932f86b4930bc202cf8bf60e1e22619c35b6b").unwrap()), vout: 1, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("174a08ae859446ce66df56ad4ebab88152ffe007caa176bd9d7c5e0a86c0c848").unwrap()), vout: 1, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("870d140b7a90b318e8c8c7724c6287749830b03b232157609998cebcb1f1d0ff").unwrap()), vout: 1, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("087029de3113d9295dff2770d48d68f2880bf734efb8c5cc53ff73ae4a963ac3").unwrap()), vout: 1, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("13af981ed9cecb66d175ad5eccb6eac7706e5affde549f89c5bb393429f36e5b").unwrap()), vout: 1, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("84334d4a4bcdf994b3e563a0e925bd5bc7cc6bf40496c3236941c30233a56977").unwrap()), vout: 1, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("6126c5af0b3871e3ef74443c642b46249b9f70a28d1063abeaec99e13118d01c").unwrap()), vout: 1, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("9c06ab041f6f614f7ae457ac78a47d7235574c98a93aa4e0260a5157c1fd065f").unwrap()), vout: 1, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("86900e7e035f24d1560cae42e418302aaaf5ae22571a6f088b94fa7e074215d7").unwrap()), vout: 1, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("9e42c74de2f66e75e2763043ebdab929697fe55e8b182a2c983fa689f9729a54").unwrap()), vout: 1, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("a5e761b565759fe46f37fb834917dde6cf96112194af119f87530357a59e463a").unwrap()), vout: 1, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("0b2b470b2dfe711153e042886611d976846954b0dea9ce125b7d5b0afdeb9abf").unwrap()), vout: 1, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("944879603fd1b6ef4421014ce7c1cf7df5dd740e5dda91a13d508636ac3ebc6c").unwrap()), vout: 1, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("1c702a5d3dcfddfddaca47bb2fa5e3264254d2c3caed312361b0cc922be34e1e").unwrap()), vout: 1, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("5c25e3685dc7d48ad0c45db8c9a9ef27e820655ca0e5e560e1ebb80b649fdcbe").unwrap()), vout: 1, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("278b9c78c9e4c17a9555c4e06ba6fe2768f2b9787407236c9d7681fedc7ddba9").unwrap()), vout: 1, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("b28e12e66480f7f728396794f4f939f713dc9d44c831016d48e7464de660d703").unwrap()), vout: 1, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("69cb27faacc0dfceb4d9d9444d20b16a9fc00f61b7b29937c6f6d97b99f9b2d0").unwrap()), vout: 1, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("a12ab55ede98f3c11456de21223b16c8debe881df46e18d0c4ea65b07e8bc610").unwrap()), vout: 1, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("29421f89bf24dcd2c258528543c361152d0d9e2bffdeb72cf681c58d5a20235f").unwrap()), vout: 1, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("f445c8e0595ff9f4da471312e885a0d025be07b0c7e3bf3fac8679fc97601ab7").unwrap()), vout: 1, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("c0349e38082c76e62ed5b845a396868ab5f8613268356bb2469aa1ca7f94fed2").unwrap()), vout: 1, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("f7fc7baf4ed63c83857aa0b2b3d466900e20e1277f17c34d2fc48c92671f7db1").unwrap()), vout: 1, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("297842b6b2314843043892a8f49fb17b0ebadfc454c76d68f5a8cac1f23faf6d").unwrap()), vout: 1, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("cc62211cc737742ad1bb1a12edc386df4bf84ada597a219ed08a3b84fc3eac92").unwrap()), vout: 1, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("eaf157e7b6fb7b1a9b766ec690c20203bcc730d371ba773eb3ad37fa8e08b10f").unwrap()), vout: 1, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("30e78bf44071ed68796f4d74cfce3c8c439445dfa4869cc097e858843ffb80d8").unwrap()), vout: 1, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("b2d6a3b9a6a14ff57fb9aa781400d59d257638ba50e3535259dfe5b29f3ffd4f").unwrap()), vout: 1, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("7233a59cb35f2cdfb1625018eb2365a19c0a44be9e2bdc7b0e47c30761916bd4").unwrap()), vout: 1, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("37bcd587fc3f7ec7988029f9229f2955059eefc653ab6a7a79f0c03fc7f77c60").unwrap()), vout: 1, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("6d9449a6e647bcc01943a15bfd990dff3688cb9de9c45d77147bbf79aab61124").unwrap()), vout: 1, },
-            UtxoServerValue { txid: consensus_decode(&hex::decode("b566a843c89b61539052b76633ea7d8d68c3a632bef4305a2c2c73750475087f").unwrap()), vout: 1, },
+            UtxoServerValue::new(consensus_decode(&hex::decode("592f96fe043aaa22cdcb6f6e710946aa0af25dff4536759f6873965555a6660c").unwrap()), 1, 0, 1, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("dfd9683d487fd6407614bf6d7cd1731323bf865242effb499ac94e85005e61fb").unwrap()), 1, 0, 2, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("51f975af8c24d4f484f182e2fb2c71685038716459e0ac303948895fab17f3fc").unwrap()), 1, 0, 3, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("0f328bad7eca1c56b343ff2523eab6b12c24e6be8861cbcf90a4e849a7df95de").unwrap()), 1, 0, 4, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("d5ad56b153c0cb74ae2c6d5c56a8d3c7a6ed00e21de74b308410a9bac776c5d1").unwrap()), 1, 0, 5, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("220dd0b71c8f1fd379a69cc532a0352d79746c03fcaa05656415aa2ef1213aa7").unwrap()), 1, 0, 6, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("c91685ce3d48b042f060fa5031ab809c659354dff52fbafc35e9814ba8d3ffed").unwrap()), 1, 0, 7, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("4812fa27abdcd5e550fc4de2846ce644e604a14051a488a67dbb67e52e2cffec").unwrap()), 1, 0, 8, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("18e9139ba76040fa1f4ce4b1982bd3b731581e3e6313263d2594c9e354ebb728").unwrap()), 1, 0, 9, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("f20d7fab026f71a7fc565c9700bc69fda79c7a81d1b1ec88b57e5499eed6ab99").unwrap()), 1, 0, 10, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("caa44cf93d8c6206f45eabc74227049bcb41a7a65c8dfa4cfd2fa964d65eee02").unwrap()), 1, 0, 11, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("3c53dbaf709a0695dead0011aada19cbf5d36577fb288d1cd2f114982482a46d").unwrap()), 1, 0, 12, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("7cd8c012b690ec71db991bf958d1ca0c3337484550f2d60ea9daad9fc950259f").unwrap()), 1, 0, 13, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("ce3bdbd3e48a4ea80bcbc207a722716702bb1ae6af978280b672b1280d07c1f7").unwrap()), 1, 0, 14, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("a99f8a5d6b4509237825a8e41f06ed4d30127beac86ddbcb8765a0fecd205c43").unwrap()), 1, 0, 15, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("40e3d56b5f08e9d37016a14f1ad2ee9c5a9cd41bee0d2372169d079b46a1f737").unwrap()), 1, 0, 16, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("527d0f70f7f49e6acecb806ab7cc46fd994beb801a93e8b0524aff97162b4766").unwrap()), 1, 0, 17, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("9a43afe88077c1cee17dff96695bf5b25a6b18e8499d772278d8f4e27942651f").unwrap()), 1, 0, 18, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("9871f4cf79e18e726b91be146fcd48ff2890e64a3554d405a99b6f57dbf13ebf").unwrap()), 1, 0, 19, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("200e465574e472bd6b0900b8ca7095f3342b3d43553bb4754e8611ff1725f2ae").unwrap()), 1, 0, 20, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("715da11118f8fb545c17c8a6bc4ca99a99d85e331bdf604c6cb017ee3dca7c0b").unwrap()), 1, 0, 21, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("2a2a902d9cbfdd63b4c60d7ed2aefcc4fb9b5b4ccfa76464d0ebbd7fc8b25051").unwrap()), 1, 0, 22, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("6f79afe378fffa9516c389175c55200178350d99a0bd1cbc2df8bde3e7f9a126").unwrap()), 1, 0, 23, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("e8e4db3caca2d81a7869593015de85ae6086dd9ca51e5d1d8935f669d85cb234").unwrap()), 1, 0, 24, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("dff95f4cecc366483e628236698740088a73c60069b04679d3170ca9966de64d").unwrap()), 1, 0, 25, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("78ae6a5198b31017415a32fb66fda209690e24390ba6e5a1dde98ff15cf52d55").unwrap()), 1, 0, 26, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("7a355418ac6593fb3b00e2815f45b2ae3037a985121e6a1c204c1882c1fea083").unwrap()), 1, 0, 27, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("f9b4c23a394155e613607490eec659b26dc0d2b169f34aa177dcac3ea9fa34e9").unwrap()), 1, 0, 28, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("882d41608b5f541784653b8b3a9087fdc7e19b08b0846203cead62b8992afabb").unwrap()), 1, 0, 29, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("343057fa8d3bc9ccfc3ba087bb705ee47564e3769c316de83b20470a6ab8abbb").unwrap()), 1, 0, 30, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("2576f2f598674c26024db883e10f087102f9f4a91c93a6f8ea06c61f38443e77").unwrap()), 1, 0, 31, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("4b737b656b2ad2476b4c37aa7250ff35a400a7004f04228ea23ddd55061108fb").unwrap()), 1, 0, 32, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("7fc4490fcbd798a74b73512a917831a54047ed709b6314e825d16c860cef1b6f").unwrap()), 1, 0, 33, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("463a6fda64e67c0f640bb6396b443d3bacf9108d33d6b47ddebe61bce8b7e286").unwrap()), 1, 0, 34, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("94336c1c8fd573c7ad4e3d7fa6592a1b001c237e8faab14d5780474fd4d6fe62").unwrap()), 1, 0, 35, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("f82256bcc97b94d4c3e58c900e679566c2d32ef3c6adcda10cf5df73c202b261").unwrap()), 1, 0, 36, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("015cbb7aee592ab4b8881fde2c8036d61cb783c31c53d5efd4cb83dc291ef22f").unwrap()), 1, 0, 37, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("0782a2a7de4c46db3e041c63ee28aeab483947d6825e1315eca6e1cf02f3c906").unwrap()), 1, 0, 38, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("d6ad95b24ed67bb3288b03f06bd50ea3a89ecfce426fc8fa9a06ed163b3b433e").unwrap()), 1, 0, 39, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("e844aead67c33a125534cb1c557b981126b2631c12b337dc4709789527ae10c3").unwrap()), 1, 0, 40, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("4909a37755da7b1d912cb4b914539b82cf50327e993f4daabb8b4feb1e84adfb").unwrap()), 1, 0, 41, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("e6c44f7320174e5637d53ed596e45d6794e8bc88ee0916ad1588a1def35559bf").unwrap()), 1, 0, 42, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("bc42c2659e6ffc902a06c42113bd49fcb66a4e20efa7660a51d9a8ed1959c654").unwrap()), 1, 0, 43, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("b430a6a5cb2b9f443d558b6c52932765d07cfabc7a1a52c396b5f585112beb17").unwrap()), 1, 0, 44, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("053057a6cc0e2bb74ac02bb7305d08985f80d4f30985afca7a2ed1678ab9530a").unwrap()), 1, 0, 45, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("dad8dd787ad1bf2fa568fc4afdffaa00d55bdf0721f67d907feb0a4bb9952e19").unwrap()), 1, 0, 46, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("dd6c1eb0c671c7a1546a56c7e8591cee7d35eae31a290cdae2933e92c0751d7a").unwrap()), 1, 0, 47, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("a0dbaafc7a7265538d25d1accc3e6780051d112734d37a26861eee54cdc0860a").unwrap()), 1, 0, 48, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("e0d7b388911af97c9d3b12c743dcbe7f03670472c731fce26ce30c3b5431010d").unwrap()), 1, 0, 49, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("b1340de3e38d57929cb91b0ea5e8472553abfb9d42301b6fc5d615264c90dd54").unwrap()), 1, 0, 50, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("770bed1e8af9d80d7eb03e142c9896e834f99197563c286ca2f39c1e0d113960").unwrap()), 1, 0, 51, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("28a37fd0e952c0a548a6aa3fafa94d6463a29f040c7f9aabcfa3f693d9c123d5").unwrap()), 1, 0, 52, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("bd5039f0b8f2bbd6183321d3344c8a3856074b05ce32398a50623fec6220f196").unwrap()), 1, 0, 53, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("fca5ed4637c9eb7b4a8d1d65384f25346053046c5d47e8bda2127bf2e2b71e17").unwrap()), 1, 0, 54, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("75989e266f4ad3da241c5d4df9687682b997e4140bb54252777ede43009b6f10").unwrap()), 1, 0, 55, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("c03b9cbeb95ec612e1a6604e9873556c634225732a06ae1cab84646eeea2ed40").unwrap()), 1, 0, 56, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("2e7ba427bc64bf63817d79ec82397093e4e056ae2933d38e88ebf63990162ed6").unwrap()), 1, 0, 57, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("2764bc934ee5afd4323899205293101aec0e7d4065a2901b7190482c3efb33d4").unwrap()), 1, 0, 58, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("fb9e03098c010f7729d33b2f7c38ce6418002d88c577986f724eb30e2430c6cf").unwrap()), 1, 0, 59, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("08bbc21c87bf538339c86d117e78307c471df8a4b9d54cd3843d7058ab1e5ef5").unwrap()), 1, 0, 60, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("3e49c8cc7174650189aa87353112765c33d07e88e696d47c846e3ed996853d25").unwrap()), 1, 0, 61, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("9b42ec0f6fd154686696d283a51dbb98da1978837507835465d09b7125cd9469").unwrap()), 1, 0, 62, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("07a182ced74903e62fff155415a3ca26af0f71f2fc22de78194114cf19279eaa").unwrap()), 1, 0, 63, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("35295128120a4c1f90b70fb88f9b4bef400c55008b5d6b76186aec659500a7b2").unwrap()), 1, 0, 64, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("2fed5ae153b255cbd5e1bcae9e5cc93951ea80805b592ce7c2fe2a9795c1c941").unwrap()), 1, 0, 65, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("c5793751e9cbcc8f438cb835fc3f27cfd914e74ed23b3a3218af30f989234af5").unwrap()), 1, 0, 66, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("b26c6e7744522d9c0409c51ecf5b94b4fa3bd02fa7bd59f371c1a42e6a068fba").unwrap()), 1, 0, 67, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("f7d515b84b6c1f63ff399fac718c5ef7ca32d0e0fd4adbe84ed9be496f4bdaf4").unwrap()), 1, 0, 68, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("5fcd9f523df6c145be014836ec289c71bf435f7455e5e2eda1f6d40c2d2e9fdf").unwrap()), 1, 0, 69, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("145464574465129a8f3b111bca6932f86b4930bc202cf8bf60e1e22619c35b6b").unwrap()), 1, 0, 70, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("174a08ae859446ce66df56ad4ebab88152ffe007caa176bd9d7c5e0a86c0c848").unwrap()), 1, 0, 71, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("870d140b7a90b318e8c8c7724c6287749830b03b232157609998cebcb1f1d0ff").unwrap()), 1, 0, 72, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("087029de3113d9295dff2770d48d68f2880bf734efb8c5cc53ff73ae4a963ac3").unwrap()), 1, 0, 73, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("13af981ed9cecb66d175ad5eccb6eac7706e5affde549f89c5bb393429f36e5b").unwrap()), 1, 0, 74, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("84334d4a4bcdf994b3e563a0e925bd5bc7cc6bf40496c3236941c30233a56977").unwrap()), 1, 0, 75, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("6126c5af0b3871e3ef74443c642b46249b9f70a28d1063abeaec99e13118d01c").unwrap()), 1, 0, 76, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("9c06ab041f6f614f7ae457ac78a47d7235574c98a93aa4e0260a5157c1fd065f").unwrap()), 1, 0, 77, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("86900e7e035f24d1560cae42e418302aaaf5ae22571a6f088b94fa7e074215d7").unwrap()), 1, 0, 78, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("9e42c74de2f66e75e2763043ebdab929697fe55e8b182a2c983fa689f9729a54").unwrap()), 1, 0, 79, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("a5e761b565759fe46f37fb834917dde6cf96112194af119f87530357a59e463a").unwrap()), 1, 0, 80, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("0b2b470b2dfe711153e042886611d976846954b0dea9ce125b7d5b0afdeb9abf").unwrap()), 1, 0, 81, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("944879603fd1b6ef4421014ce7c1cf7df5dd740e5dda91a13d508636ac3ebc6c").unwrap()), 1, 0, 82, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("1c702a5d3dcfddfddaca47bb2fa5e3264254d2c3caed312361b0cc922be34e1e").unwrap()), 1, 0, 83, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("5c25e3685dc7d48ad0c45db8c9a9ef27e820655ca0e5e560e1ebb80b649fdcbe").unwrap()), 1, 0, 84, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("278b9c78c9e4c17a9555c4e06ba6fe2768f2b9787407236c9d7681fedc7ddba9").unwrap()), 1, 0, 85, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("b28e12e66480f7f728396794f4f939f713dc9d44c831016d48e7464de660d703").unwrap()), 1, 0, 86, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("69cb27faacc0dfceb4d9d9444d20b16a9fc00f61b7b29937c6f6d97b99f9b2d0").unwrap()), 1, 0, 87, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("a12ab55ede98f3c11456de21223b16c8debe881df46e18d0c4ea65b07e8bc610").unwrap()), 1, 0, 88, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("29421f89bf24dcd2c258528543c361152d0d9e2bffdeb72cf681c58d5a20235f").unwrap()), 1, 0, 89, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("f445c8e0595ff9f4da471312e885a0d025be07b0c7e3bf3fac8679fc97601ab7").unwrap()), 1, 0, 90, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("c0349e38082c76e62ed5b845a396868ab5f8613268356bb2469aa1ca7f94fed2").unwrap()), 1, 0, 91, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("f7fc7baf4ed63c83857aa0b2b3d466900e20e1277f17c34d2fc48c92671f7db1").unwrap()), 1, 0, 92, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("297842b6b2314843043892a8f49fb17b0ebadfc454c76d68f5a8cac1f23faf6d").unwrap()), 1, 0, 93, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("cc62211cc737742ad1bb1a12edc386df4bf84ada597a219ed08a3b84fc3eac92").unwrap()), 1, 0, 94, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("eaf157e7b6fb7b1a9b766ec690c20203bcc730d371ba773eb3ad37fa8e08b10f").unwrap()), 1, 0, 95, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("30e78bf44071ed68796f4d74cfce3c8c439445dfa4869cc097e858843ffb80d8").unwrap()), 1, 0, 96, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("b2d6a3b9a6a14ff57fb9aa781400d59d257638ba50e3535259dfe5b29f3ffd4f").unwrap()), 1, 0, 97, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("7233a59cb35f2cdfb1625018eb2365a19c0a44be9e2bdc7b0e47c30761916bd4").unwrap()), 1, 0, 98, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("37bcd587fc3f7ec7988029f9229f2955059eefc653ab6a7a79f0c03fc7f77c60").unwrap()), 1, 0, 99, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("6d9449a6e647bcc01943a15bfd990dff3688cb9de9c45d77147bbf79aab61124").unwrap()), 1, 0, 100, true, false),
+            UtxoServerValue::new(consensus_decode(&hex::decode("b566a843c89b61539052b76633ea7d8d68c3a632bef4305a2c2c73750475087f").unwrap()), 1, 0, 101, true, false),
         ]),
         (WScriptHash::from_hex("196e3661aba291f565f6a87ef237293ebabe4e114585ec3a3c3263cc4bda4094").unwrap(), vec![
-            UtxoServerValue { txid: consensus_decode(&hex::decode("bcae0a12d28e9c28dfeaf2cbb914f9b414946049b7e304a4a219b73f6c0403b9").unwrap()), vout: 1, },
+            UtxoServerValue::new(consensus_decode(&hex::decode("bcae0a12d28e9c28dfeaf2cbb914f9b414946049b7e304a4a219b73f6c0403b9").unwrap()), 1, 0, 102, true, false),
         ]),
         (WScriptHash::from_hex("1de8eddb9264d4111c76f94d23f5d5eaefcc739f85d496de05d277eb74e3d41b").unwrap(), vec![
         ]),
         (WScriptHash::from_hex("51c9a4df86c76ca7c2edeae638e3aefa95987ea3a70df195aa6d88e3e1d1e788").unwrap(), vec![
         ]),
         (WScriptHash::from_hex("3a0a2aef003392b3cbc11a24a78d7bae839d3e3dc36a53eb556b1ef1e8155a14").unwrap(), vec![
-            UtxoServerValue { txid: consensus_decode(&hex::decode("2d813aded53c0f2f5daa2f9f5d1dc9c7e6af5496afd82c22f3acbb9a044e7029").unwrap()), vout: 0, },
+            UtxoServerValue::new(consensus_decode(&hex::decode("2d813aded53c0f2f5daa2f9f5d1dc9c7e6af5496afd82c22f3acbb9a044e7029").unwrap()), 0, 4900000000, 102, false, false),
         ]),
         (WScriptHash::from_hex("391351135e1e11805cf03820d28928bf78e4c65f76ec4ce092a183c6a1d3196c").unwrap(), vec![
-            UtxoServerValue { txid: consensus_decode(&hex::decode("2d813aded53c0f2f5daa2f9f5d1dc9c7e6af5496afd82c22f3acbb9a044e7029").unwrap()), vout: 1, },
+            UtxoServerValue::new(consensus_decode(&hex::decode("2d813aded53c0f2f5daa2f9f5d1dc9c7e6af5496afd82c22f3acbb9a044e7029").unwrap()), 1, 99965100, 102, false, false),
         ]),
     ]
 }
@@ -0,0 +1,467 @@
+//! GraphQL API over blocks, transactions and addresses (at `POST /api/v1/graphql`).
+
+use crate::*;
+use std::convert::Infallible;
+use bitcoin::{Script, TxIn, TxOut, Txid};
+use bitcoin::hashes::hex::{FromHex, ToHex};
+use async_graphql::{Context, EmptyMutation, EmptySubscription, Error, Object, Result, Schema, SchemaBuilder};
+use hyper::{Body, Request, Response};
+use routerify::prelude::*;
+use crate::db::Database;
+use crate::db::block::BlockContentDBValue;
+use crate::db::tx::TxDBValue;
+
+/// The maximum depth of queries.
+pub const MAX_DEPTH: usize = 16;
+/// The maximum complexity of queries (each field costs one, and lists cost `limit` times their items).
+pub const MAX_COMPLEXITY: usize = 10000;
+/// The default number of items of lists.
+pub const DEFAULT_LIMIT: usize = 25;
+/// The maximum number of items of lists.
+pub const MAX_LIMIT: usize = 100;
+
+pub type GraphQlSchema = Schema<QueryRoot, EmptyMutation, EmptySubscription>;
+
+fn check_limit(limit: usize) -> Result<()> {
+    if limit > MAX_LIMIT {
+        return Err(Error::new(format!("\"limit\" should not be greater than {}.", MAX_LIMIT)));
+    }
+    Ok(())
+}
+
+async fn get_block(db: &Database, height: u32) -> Option<Block> {
+    db.block_db.read().await.get(height).map(|content| Block { content })
+}
+
+async fn get_transaction(db: &Database, txid: &Txid) -> Option<Transaction> {
+    db.tx_db.read().await.get(txid).map(|value| Transaction { txid: *txid, value })
+}
+
+pub struct Block {
+    content: BlockContentDBValue,
+}
+
+#[Object]
+impl Block {
+    async fn hash(&self) -> String {
+        self.content.block_header.block_hash().to_string()
+    }
+    async fn height(&self) -> u32 {
+        self.content.height
+    }
+    async fn version(&self) -> i32 {
+        self.content.block_header.version
+    }
+    async fn previous_block_hash(&self) -> String {
+        self.content.block_header.prev_blockhash.to_string()
+    }
+    async fn merkle_root(&self) -> String {
+        self.content.block_header.merkle_root.to_string()
+    }
+    async fn time(&self) -> u32 {
+        self.content.block_header.time
+    }
+    async fn bits(&self) -> String {
+        format!("{:x}", self.content.block_header.bits)
+    }
+    async fn nonce(&self) -> u32 {
+        self.content.block_header.nonce
+    }
+    async fn difficulty(&self, ctx: &Context<'_>) -> f64 {
+        get_difficulty(&self.content.block_header, &ctx.data_unchecked::<Database>().config)
+    }
+    async fn size(&self) -> u32 {
+        self.content.size
+    }
+    async fn stripped_size(&self) -> u32 {
+        self.content.strippedsize
+    }
+    async fn weight(&self) -> u32 {
+        self.content.weight
+    }
+    async fn tx_count(&self) -> usize {
+        self.content.txids.len()
+    }
+    async fn txids(&self) -> Vec<String> {
+        self.content.txids.iter().map(|txid| txid.to_string()).collect()
+    }
+    /// The transactions in the block (from the `offset`-th one).
+    #[graphql(complexity = "limit * child_complexity")]
+    async fn txs(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(default)] offset: usize,
+        #[graphql(default_with = "DEFAULT_LIMIT")] limit: usize,
+    ) -> Result<Vec<Transaction>> {
+        check_limit(limit)?;
+        let db = ctx.data_unchecked::<Database>();
+        let mut txs = Vec::new();
+        for txid in self.content.txids.iter().skip(offset).take(limit) {
+            txs.push(get_transaction(db, txid).await.ok_or_else(|| Error::new(format!("Failed to resolve transaction {}.", txid)))?);
+        }
+        Ok(txs)
+    }
+    async fn previous_block(&self, ctx: &Context<'_>) -> Option<Block> {
+        match self.content.height {
+            0 => None,
+            height => get_block(ctx.data_unchecked::<Database>(), height - 1).await,
+        }
+    }
+    async fn next_block(&self, ctx: &Context<'_>) -> Option<Block> {
+        get_block(ctx.data_unchecked::<Database>(), self.content.height + 1).await
+    }
+}
+
+pub struct Transaction {
+    txid: Txid,
+    value: TxDBValue,
+}
+
+impl Transaction {
+    /// Returns pairs of the input and the output it spends (`None` for coinbase inputs).
+    fn inputs_with_previous_txouts(&self) -> Vec<(&TxIn, Option<&TxOut>)> {
+        let mut previous_txouts = self.value.previous_txouts.iter();
+        self.value.tx.input.iter().map(|txin| {
+            let previous_txout = if txin.previous_output.is_null() { None } else { previous_txouts.next() };
+            (txin, previous_txout)
+        }).collect()
+    }
+}
+
+#[Object]
+impl Transaction {
+    async fn txid(&self) -> String {
+        self.txid.to_string()
+    }
+    async fn hash(&self) -> String {
+        self.value.tx.wtxid().to_string()
+    }
+    async fn version(&self) -> i32 {
+        self.value.tx.version
+    }
+    async fn locktime(&self) -> u32 {
+        self.value.tx.lock_time
+    }
+    async fn size(&self) -> usize {
+        self.value.tx.get_size()
+    }
+    async fn vsize(&self) -> usize {
+        get_vsize(&self.value.tx)
+    }
+    async fn weight(&self) -> usize {
+        self.value.tx.get_weight()
+    }
+    async fn coinbase(&self) -> bool {
+        self.value.tx.is_coin_base()
+    }
+    /// The fee (null for coinbase transactions).
+    async fn fee(&self) -> Option<u64> {
+        if self.value.tx.is_coin_base() {
+            return None;
+        }
+        let input_value: u64 = self.value.previous_txouts.iter().map(|txout| txout.value).sum();
+        let output_value: u64 = self.value.tx.output.iter().map(|txout| txout.value).sum();
+        Some(input_value.saturating_sub(output_value))
+    }
+    /// The height of the block which includes the transaction (null if unconfirmed).
+    async fn confirmed_height(&self) -> Option<u32> {
+        self.value.confirmed_height
+    }
+    async fn block(&self, ctx: &Context<'_>) -> Option<Block> {
+        get_block(ctx.data_unchecked::<Database>(), self.value.confirmed_height?).await
+    }
+    async fn hex(&self) -> String {
+        hex::encode(consensus_encode(&self.value.tx))
+    }
+    async fn input_count(&self) -> usize {
+        self.value.tx.input.len()
+    }
+    /// The inputs (from the `offset`-th one).
+    #[graphql(complexity = "limit * child_complexity")]
+    async fn inputs(
+        &self,
+        #[graphql(default)] offset: usize,
+        #[graphql(default_with = "DEFAULT_LIMIT")] limit: usize,
+    ) -> Result<Vec<Input>> {
+        check_limit(limit)?;
+        Ok(self.inputs_with_previous_txouts().into_iter().enumerate().skip(offset).take(limit).map(|(index, (txin, previous_txout))| Input {
+            index: index as u32,
+            txin: txin.clone(),
+            previous_txout: previous_txout.cloned(),
+        }).collect())
+    }
+    async fn output_count(&self) -> usize {
+        self.value.tx.output.len()
+    }
+    /// The outputs (from the `offset`-th one).
+    #[graphql(complexity = "limit * child_complexity")]
+    async fn outputs(
+        &self,
+        #[graphql(default)] offset: usize,
+        #[graphql(default_with = "DEFAULT_LIMIT")] limit: usize,
+    ) -> Result<Vec<Output>> {
+        check_limit(limit)?;
+        Ok(self.value.tx.output.iter().enumerate().skip(offset).take(limit).map(|(vout, txout)| Output {
+            txid: self.txid,
+            vout: vout as u32,
+            txout: txout.clone(),
+        }).collect())
+    }
+}
+
+pub struct Input {
+    index: u32,
+    txin: TxIn,
+    previous_txout: Option<TxOut>,
+}
+
+#[Object]
+impl Input {
+    async fn index(&self) -> u32 {
+        self.index
+    }
+    async fn coinbase(&self) -> bool {
+        self.txin.previous_output.is_null()
+    }
+    /// The txid of the output spent (null for coinbase inputs).
+    async fn previous_txid(&self) -> Option<String> {
+        self.previous_txout.as_ref().map(|_| self.txin.previous_output.txid.to_string())
+    }
+    async fn previous_vout(&self) -> Option<u32> {
+        self.previous_txout.as_ref().map(|_| self.txin.previous_output.vout)
+    }
+    async fn script_sig(&self) -> String {
+        self.txin.script_sig.to_hex()
+    }
+    async fn sequence(&self) -> u32 {
+        self.txin.sequence
+    }
+    async fn witness(&self) -> Vec<String> {
+        self.txin.witness.iter().map(hex::encode).collect()
+    }
+    async fn value(&self) -> Option<u64> {
+        self.previous_txout.as_ref().map(|txout| txout.value)
+    }
+    async fn address(&self, ctx: &Context<'_>) -> Option<String> {
+        script_to_address_string(&self.previous_txout.as_ref()?.script_pubkey, &ctx.data_unchecked::<Database>().config)
+    }
+    async fn previous_output(&self) -> Option<Output> {
+        self.previous_txout.as_ref().map(|txout| Output {
+            txid: self.txin.previous_output.txid,
+            vout: self.txin.previous_output.vout,
+            txout: txout.clone(),
+        })
+    }
+    async fn previous_transaction(&self, ctx: &Context<'_>) -> Option<Transaction> {
+        self.previous_txout.as_ref()?;
+        get_transaction(ctx.data_unchecked::<Database>(), &self.txin.previous_output.txid).await
+    }
+}
+
+pub struct Output {
+    txid: Txid,
+    vout: u32,
+    txout: TxOut,
+}
+
+#[Object]
+impl Output {
+    async fn txid(&self) -> String {
+        self.txid.to_string()
+    }
+    async fn vout(&self) -> u32 {
+        self.vout
+    }
+    async fn value(&self) -> u64 {
+        self.txout.value
+    }
+    async fn script_pub_key(&self) -> String {
+        self.txout.script_pubkey.to_hex()
+    }
+    async fn script_type(&self) -> &'static str {
+        script_type(&self.txout.script_pubkey)
+    }
+    async fn address(&self, ctx: &Context<'_>) -> Option<String> {
+        script_to_address_string(&self.txout.script_pubkey, &ctx.data_unchecked::<Database>().config)
+    }
+    async fn transaction(&self, ctx: &Context<'_>) -> Option<Transaction> {
+        get_transaction(ctx.data_unchecked::<Database>(), &self.txid).await
+    }
+}
+
+pub struct Address {
+    script: Script,
+}
+
+#[Object]
+impl Address {
+    async fn address(&self, ctx: &Context<'_>) -> Option<String> {
+        script_to_address_string(&self.script, &ctx.data_unchecked::<Database>().config)
+    }
+    async fn script_pub_key(&self) -> String {
+        self.script.to_hex()
+    }
+    /// The sum of the values of the confirmed unspent outputs.
+    async fn balance(&self, ctx: &Context<'_>) -> u64 {
+        ctx.data_unchecked::<Database>().utxo_server.read().await.get(&self.script).iter().map(|utxo| utxo.value).sum()
+    }
+    /// The transactions (unconfirmed ones first, and then from the newest to the oldest).
+    /// `after` is the last txid of the previous page.
+    #[graphql(complexity = "limit * child_complexity")]
+    async fn txs(
+        &self,
+        ctx: &Context<'_>,
+        after: Option<String>,
+        #[graphql(default_with = "DEFAULT_LIMIT")] limit: usize,
+    ) -> Result<Vec<Transaction>> {
+        check_limit(limit)?;
+        let db = ctx.data_unchecked::<Database>();
+        let after = match after {
+            Some(after) => {
+                let txid = Txid::from_hex(&after).map_err(|_| Error::new("Failed to decode \"after\" as a txid."))?;
+                match db.tx_db.read().await.get_confirmed_height(&txid) {
+                    Some(confirmed_height) => Some((confirmed_height, txid)),
+                    None => return Err(Error::new("Transaction specified by \"after\" not found.")),
+                }
+            },
+            None => None,
+        };
+        let txids = db.addr_index_db.read().await.get_after(&self.script, after, limit);
+        let mut txs = Vec::new();
+        for txid in txids.iter() {
+            txs.push(get_transaction(db, txid).await.ok_or_else(|| Error::new(format!("Failed to resolve transaction {}.", txid)))?);
+        }
+        Ok(txs)
+    }
+    /// The confirmed unspent outputs (from the `offset`-th one).
+    #[graphql(complexity = "limit * child_complexity")]
+    async fn utxos(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(default)] offset: usize,
+        #[graphql(default_with = "DEFAULT_LIMIT")] limit: usize,
+    ) -> Result<Vec<Output>> {
+        check_limit(limit)?;
+        let db = ctx.data_unchecked::<Database>();
+        let utxos = db.utxo_server.read().await.get(&self.script);
+        let mut outputs = Vec::new();
+        for utxo in utxos.iter().skip(offset).take(limit) {
+            // P2PK outputs found by the P2PKH script need the original script.
+            let script_pubkey = if utxo.p2pk() {
+                match db.tx_db.read().await.get(&utxo.txid) {
+                    Some(value) => value.tx.output[utxo.vout as usize].script_pubkey.clone(),
+                    None => return Err(Error::new(format!("Failed to resolve transaction {}.", utxo.txid))),
+                }
+            } else {
+                self.script.clone()
+            };
+            outputs.push(Output {
+                txid: utxo.txid,
+                vout: utxo.vout,
+                txout: TxOut { value: utxo.value, script_pubkey },
+            });
+        }
+        Ok(outputs)
+    }
+}
+
+pub struct QueryRoot;
+
+#[Object]
+impl QueryRoot {
+    /// The block at the height or with the hash.
+    async fn block(&self, ctx: &Context<'_>, height: Option<u32>, hash: Option<String>) -> Result<Option<Block>> {
+        let db = ctx.data_unchecked::<Database>();
+        match (height, hash) {
+            (Some(height), None) => Ok(get_block(db, height).await),
+            (None, Some(hash)) => {
+                let hash = BlockHash::from_hex(&hash).map_err(|_| Error::new("Failed to decode \"hash\"."))?;
+                Ok(db.block_db.read().await.get_by_hash(&hash).map(|content| Block { content }))
+            },
+            _ => Err(Error::new("Specify either \"height\" or \"hash\".")),
+        }
+    }
+    /// The block at the tip of the chain.
+    async fn tip(&self, ctx: &Context<'_>) -> Option<Block> {
+        let db = ctx.data_unchecked::<Database>();
+        let height = db.synced_height_db.read().await.get()?;
+        get_block(db, height).await
+    }
+    /// The blocks from the height `from` (the tip if not specified) to the genesis block.
+    #[graphql(complexity = "limit * child_complexity")]
+    async fn blocks(&self, ctx: &Context<'_>, from: Option<u32>, #[graphql(default_with = "DEFAULT_LIMIT")] limit: usize) -> Result<Vec<Block>> {
+        check_limit(limit)?;
+        let db = ctx.data_unchecked::<Database>();
+        let from = match from.or(db.synced_height_db.read().await.get()) {
+            Some(from) => from,
+            None => return Ok(Vec::new()),
+        };
+        let mut blocks = Vec::new();
+        for height in (0..=from).rev().take(limit) {
+            match get_block(db, height).await {
+                Some(block) => blocks.push(block),
+                None => break,
+            }
+        }
+        Ok(blocks)
+    }
+    async fn transaction(&self, ctx: &Context<'_>, txid: String) -> Result<Option<Transaction>> {
+        let txid = Txid::from_hex(&txid).map_err(|_| Error::new("Failed to decode \"txid\"."))?;
+        Ok(get_transaction(ctx.data_unchecked::<Database>(), &txid).await)
+    }
+    /// The address (or the hex-encoded script).
+    async fn address(&self, ctx: &Context<'_>, address: String) -> Result<Address> {
+        let script = HttpServer::decode_script_or_address(&address, &ctx.data_unchecked::<Database>().config).map_err(Error::new)?;
+        Ok(Address { script })
+    }
+}
+
+fn schema_builder() -> SchemaBuilder<QueryRoot, EmptyMutation, EmptySubscription> {
+    Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
+        .limit_depth(MAX_DEPTH)
+        .limit_complexity(MAX_COMPLEXITY)
+}
+
+pub fn schema(db: Database) -> GraphQlSchema {
+    schema_builder().data(db).finish()
+}
+
+/// `POST /api/v1/graphql` endpoint.
+pub async fn graphql_handler(req: Request<Body>) -> Result<Response<Body>, Infallible> {
+    let schema = req.data::<GraphQlSchema>().unwrap().clone();
+    let body = match hyper::body::to_bytes(req.into_body()).await {
+        Ok(body) => body,
+        Err(_) => return Ok(HttpServer::bad_request("Failed to read the request body.")),
+    };
+    let request: async_graphql::Request = match serde_json::from_slice(&body) {
+        Ok(request) => request,
+        Err(_) => return Ok(HttpServer::bad_request("Failed to parse the GraphQL request.")),
+    };
+    Ok(HttpServer::json(schema.execute(request).await, false))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[tokio::test]
+    async fn sdl() {
+        let sdl = schema_builder().finish().sdl();
+        for object in ["Block", "Transaction", "Input", "Output", "Address"] {
+            assert!(sdl.contains(&format!("type {} {{", object)), "{}", object);
+        }
+    }
+    #[tokio::test]
+    async fn limits() {
+        // Queries are validated before execution, so no database is required.
+        let schema = schema_builder().finish();
+        let nested = (0..MAX_DEPTH).fold("hash".to_string(), |query, _| format!("previousBlock {{ {} }}", query));
+        let res = schema.execute(format!("{{ tip {{ {} }} }}", nested)).await;
+        assert_eq!(res.errors[0].message, "Query is nested too deep.");
+        let res = schema.execute("{ blocks(limit: 100) { txs(limit: 100) { txid hash } } }").await;
+        assert_eq!(res.errors[0].message, "Query is too complex.");
+        // Inputs and outputs are lists, so following previous transactions multiplies the complexity.
+        let nested = (0..3).fold("txid".to_string(), |query, _| format!("txid inputs {{ previousTransaction {{ {} }} }}", query));
+        let res = schema.execute(format!("{{ tip {{ txs(limit: 1) {{ {} }} }} }}", nested)).await;
+        assert_eq!(res.errors[0].message, "Query is too complex.");
+    }
+}
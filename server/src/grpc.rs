@@ -0,0 +1,327 @@
+//! gRPC service (see `proto/chainseeker.proto`).
+
+use crate::*;
+use std::pin::Pin;
+use futures_util::Stream;
+use bitcoin::Txid;
+use bitcoin::hashes::hex::FromHex;
+use tonic::{Request, Response};
+use crate::db::Database;
+use crate::db::block::BlockContentDBValue;
+use crate::db::tx::TxDBValue;
+use ZeroMQMessage::*;
+
+#[allow(clippy::all)]
+pub mod proto {
+    tonic::include_proto!("chainseeker");
+}
+
+use proto::chainseeker_server::{Chainseeker, ChainseekerServer};
+use proto::get_block_request::HashOrHeight;
+
+pub fn create_grpc_block(content: &BlockContentDBValue) -> proto::Block {
+    proto::Block {
+        hash: content.block_header.block_hash().to_string(),
+        height: content.height,
+        header: consensus_encode(&content.block_header),
+        previous_block_hash: content.block_header.prev_blockhash.to_string(),
+        time: content.block_header.time,
+        size: content.size,
+        stripped_size: content.strippedsize,
+        weight: content.weight,
+        txids: content.txids.iter().map(|txid| txid.to_string()).collect(),
+    }
+}
+
+pub fn create_grpc_tx(value: &TxDBValue, config: &Config) -> proto::Transaction {
+    let tx = &value.tx;
+    let coinbase = tx.is_coin_base();
+    let mut previous_txouts = value.previous_txouts.iter();
+    let inputs = tx.input.iter().map(|txin| {
+        let previous_txout = if txin.previous_output.is_null() { None } else { previous_txouts.next() };
+        proto::Input {
+            coinbase: previous_txout.is_none(),
+            txid: previous_txout.map_or_else(String::new, |_| txin.previous_output.txid.to_string()),
+            vout: previous_txout.map_or(0, |_| txin.previous_output.vout),
+            script_sig: txin.script_sig.to_bytes(),
+            sequence: txin.sequence,
+            witness: txin.witness.clone(),
+            value: previous_txout.map(|txout| txout.value),
+            address: previous_txout.and_then(|txout| script_to_address_string(&txout.script_pubkey, config)),
+        }
+    }).collect();
+    let outputs = tx.output.iter().enumerate().map(|(vout, txout)| proto::Output {
+        vout: vout as u32,
+        value: txout.value,
+        script_pubkey: txout.script_pubkey.to_bytes(),
+        script_type: script_type(&txout.script_pubkey).to_string(),
+        address: script_to_address_string(&txout.script_pubkey, config),
+    }).collect();
+    let input_value: u64 = value.previous_txouts.iter().map(|txout| txout.value).sum();
+    let output_value: u64 = tx.output.iter().map(|txout| txout.value).sum();
+    proto::Transaction {
+        txid: tx.txid().to_string(),
+        hash: tx.wtxid().to_string(),
+        version: tx.version,
+        locktime: tx.lock_time,
+        size: tx.get_size() as u32,
+        vsize: get_vsize(tx) as u32,
+        weight: tx.get_weight() as u32,
+        coinbase,
+        fee: if coinbase { None } else { Some(input_value.saturating_sub(output_value)) },
+        confirmed_height: value.confirmed_height,
+        inputs,
+        outputs,
+        raw: consensus_encode(tx),
+    }
+}
+
+fn invalid_txid() -> tonic::Status {
+    tonic::Status::invalid_argument("Failed to decode txid.")
+}
+
+type GrpcStream<T> = Pin<Box<dyn Stream<Item = Result<T, tonic::Status>> + Send>>;
+
+/// The maximum number of txids `GetAddressTxids` returns at once.
+const MAX_TXIDS: u32 = 1000;
+
+#[derive(Debug, Clone)]
+pub struct GrpcServer {
+    db: Database,
+    events: tokio::sync::broadcast::Sender<ZeroMQMessage>,
+}
+
+impl GrpcServer {
+    /// `events` is the sender of the events emitted by the syncer (see `Syncer::events()`).
+    pub fn new(db: Database, events: tokio::sync::broadcast::Sender<ZeroMQMessage>) -> Self {
+        Self {
+            db,
+            events,
+        }
+    }
+    /// Returns the stream of the messages `f` extracts from the events (skipping events `f` returns `None` for).
+    ///
+    /// The stream fails with `DATA_LOSS` and ends if the subscriber lags behind and events are dropped.
+    fn subscribe<T, F, Fut>(&self, f: F) -> GrpcStream<T>
+        where T: Send + 'static,
+              F: Fn(Database, ZeroMQMessage) -> Fut + Send + Sync + 'static,
+              Fut: std::future::Future<Output = Option<T>> + Send,
+    {
+        let state = Some((self.db.clone(), self.events.subscribe(), f));
+        Box::pin(futures_util::stream::unfold(state, |state| async move {
+            let (db, mut receiver, f) = state?;
+            loop {
+                match receiver.recv().await {
+                    Ok(message) => {
+                        if let Some(item) = f(db.clone(), message).await {
+                            return Some((Ok(item), Some((db, receiver, f))));
+                        }
+                    },
+                    Err(tokio::sync::broadcast::error::RecvError::Lagged(skipped)) => {
+                        let status = tonic::Status::data_loss(format!("Subscriber lagged behind, {} events were dropped.", skipped));
+                        return Some((Err(status), None));
+                    },
+                    Err(tokio::sync::broadcast::error::RecvError::Closed) => return None,
+                }
+            }
+        }))
+    }
+    pub async fn run(&self, endpoint: &str) {
+        let addr = endpoint.parse().expect("Failed to parse gRPC endpoint.");
+        println!("gRPC server is listening on {}", endpoint);
+        tonic::transport::Server::builder()
+            .add_service(ChainseekerServer::new(self.clone()))
+            .serve_with_shutdown(addr, async {
+                tokio::signal::ctrl_c().await.expect("Failed to install Ctrl+C signal handler.");
+            })
+            .await
+            .expect("Failed to run gRPC server.");
+    }
+}
+
+#[tonic::async_trait]
+impl Chainseeker for GrpcServer {
+    async fn get_status(&self, _request: Request<proto::GetStatusRequest>) -> Result<Response<proto::GetStatusResponse>, tonic::Status> {
+        let blocks = self.db.synced_height_db.read().await.get().map_or(-1, |height| height as i32);
+        Ok(Response::new(proto::GetStatusResponse { blocks }))
+    }
+    async fn get_tx(&self, request: Request<proto::GetTxRequest>) -> Result<Response<proto::Transaction>, tonic::Status> {
+        let txid = Txid::from_hex(&request.get_ref().txid).map_err(|_| invalid_txid())?;
+        match self.db.tx_db.read().await.get(&txid) {
+            Some(value) => Ok(Response::new(create_grpc_tx(&value, &self.db.config))),
+            None => Err(tonic::Status::not_found("Transaction not found.")),
+        }
+    }
+    async fn get_block(&self, request: Request<proto::GetBlockRequest>) -> Result<Response<proto::Block>, tonic::Status> {
+        let block_db = self.db.block_db.read().await;
+        let content = match &request.get_ref().hash_or_height {
+            Some(HashOrHeight::Hash(hash)) => {
+                let hash = BlockHash::from_hex(hash).map_err(|_| tonic::Status::invalid_argument("Failed to decode block hash."))?;
+                block_db.get_by_hash(&hash)
+            },
+            Some(HashOrHeight::Height(height)) => block_db.get(*height),
+            None => return Err(tonic::Status::invalid_argument("Specify either \"hash\" or \"height\".")),
+        };
+        match content {
+            Some(content) => Ok(Response::new(create_grpc_block(&content))),
+            None => Err(tonic::Status::not_found("Block not found.")),
+        }
+    }
+    async fn get_address_txids(&self, request: Request<proto::GetAddressTxidsRequest>) -> Result<Response<proto::GetAddressTxidsResponse>, tonic::Status> {
+        let request = request.get_ref();
+        let script = HttpServer::decode_script_or_address(&request.script_or_address, &self.db.config).map_err(tonic::Status::invalid_argument)?;
+        let after = match &request.after {
+            Some(after) => {
+                let txid = Txid::from_hex(after).map_err(|_| invalid_txid())?;
+                match self.db.tx_db.read().await.get_confirmed_height(&txid) {
+                    Some(confirmed_height) => Some((confirmed_height, txid)),
+                    None => return Err(tonic::Status::invalid_argument("Transaction specified by \"after\" not found.")),
+                }
+            },
+            None => None,
+        };
+        let limit = match request.limit {
+            0 => MAX_TXIDS,
+            limit if limit <= MAX_TXIDS => limit,
+            _ => return Err(tonic::Status::invalid_argument(format!("\"limit\" must be at most {}.", MAX_TXIDS))),
+        };
+        let txids = self.db.addr_index_db.read().await.get_after(&script, after, limit as usize);
+        Ok(Response::new(proto::GetAddressTxidsResponse {
+            txids: txids.iter().map(|txid| txid.to_string()).collect(),
+        }))
+    }
+    async fn get_utxos(&self, request: Request<proto::GetUtxosRequest>) -> Result<Response<proto::GetUtxosResponse>, tonic::Status> {
+        let script = HttpServer::decode_script_or_address(&request.get_ref().script_or_address, &self.db.config).map_err(tonic::Status::invalid_argument)?;
+        let utxos = self.db.utxo_server.read().await.get(&script);
+        Ok(Response::new(proto::GetUtxosResponse {
+            // Skip the entries indexed under the P2PKH alias of P2PK outputs, whose script differs from the requested one.
+            utxos: utxos.iter().filter(|utxo| !utxo.p2pk()).map(|utxo| proto::Utxo {
+                txid: utxo.txid.to_string(),
+                vout: utxo.vout,
+                value: utxo.value,
+                height: utxo.height(),
+                coinbase: utxo.coinbase(),
+            }).collect(),
+        }))
+    }
+    type SubscribeBlocksStream = GrpcStream<proto::Block>;
+    async fn subscribe_blocks(&self, _request: Request<proto::SubscribeBlocksRequest>) -> Result<Response<Self::SubscribeBlocksStream>, tonic::Status> {
+        Ok(Response::new(self.subscribe(|db, message| async move {
+            match message {
+                HashBlock(block_hash) => db.block_db.read().await.get_by_hash(&block_hash).map(|content| create_grpc_block(&content)),
+                _ => None,
+            }
+        })))
+    }
+    type SubscribeTxsStream = GrpcStream<proto::Transaction>;
+    async fn subscribe_txs(&self, _request: Request<proto::SubscribeTxsRequest>) -> Result<Response<Self::SubscribeTxsStream>, tonic::Status> {
+        Ok(Response::new(self.subscribe(|db, message| async move {
+            match message {
+                RawTx(tx) => db.tx_db.read().await.get(&tx.txid()).map(|value| create_grpc_tx(&value, &db.config)),
+                _ => None,
+            }
+        })))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures_util::StreamExt;
+    #[test]
+    fn create_grpc_tx() {
+        let config = config_example("rbtc");
+        let block = &fixtures::regtest_blocks()[1];
+        let coinbase = &block.txdata[0];
+        let grpc_tx = super::create_grpc_tx(&TxDBValue { confirmed_height: Some(1), tx: coinbase.clone(), previous_txouts: vec![] }, &config);
+        assert_eq!(grpc_tx.txid, coinbase.txid().to_string());
+        assert!(grpc_tx.coinbase);
+        assert_eq!(grpc_tx.fee, None);
+        assert_eq!(grpc_tx.confirmed_height, Some(1));
+        assert!(grpc_tx.inputs[0].coinbase);
+        assert_eq!(grpc_tx.inputs[0].txid, "");
+        assert_eq!(grpc_tx.inputs[0].value, None);
+        assert_eq!(grpc_tx.outputs.len(), coinbase.output.len());
+        assert_eq!(grpc_tx.outputs[0].value, coinbase.output[0].value);
+        assert_eq!(grpc_tx.outputs[0].address, script_to_address_string(&coinbase.output[0].script_pubkey, &config));
+        assert_eq!(consensus_decode::<bitcoin::Transaction>(&grpc_tx.raw), *coinbase);
+        // Spend the coinbase output.
+        let tx = bitcoin::Transaction {
+            version: 2,
+            lock_time: 0,
+            input: vec![bitcoin::TxIn {
+                previous_output: bitcoin::OutPoint { txid: coinbase.txid(), vout: 0 },
+                script_sig: Script::new(),
+                sequence: 0xfffffffd,
+                witness: vec![vec![0x01]],
+            }],
+            output: vec![bitcoin::TxOut { value: coinbase.output[0].value - 1000, script_pubkey: coinbase.output[0].script_pubkey.clone() }],
+        };
+        let grpc_tx = super::create_grpc_tx(&TxDBValue { confirmed_height: None, tx, previous_txouts: vec![coinbase.output[0].clone()] }, &config);
+        assert!(!grpc_tx.coinbase);
+        assert_eq!(grpc_tx.fee, Some(1000));
+        assert_eq!(grpc_tx.confirmed_height, None);
+        assert_eq!(grpc_tx.inputs[0].txid, coinbase.txid().to_string());
+        assert_eq!(grpc_tx.inputs[0].value, Some(coinbase.output[0].value));
+        assert_eq!(grpc_tx.inputs[0].witness, vec![vec![0x01]]);
+    }
+    #[test]
+    fn create_grpc_block() {
+        let block = &fixtures::regtest_blocks()[1];
+        let grpc_block = super::create_grpc_block(&BlockContentDBValue::new(1, block));
+        assert_eq!(grpc_block.hash, block.block_hash().to_string());
+        assert_eq!(grpc_block.height, 1);
+        assert_eq!(grpc_block.header, consensus_encode(&block.header));
+        assert_eq!(grpc_block.txids, block.txdata.iter().map(|tx| tx.txid().to_string()).collect::<Vec<_>>());
+    }
+    fn open_db(name: &str) -> Database {
+        let path = format!("{}/test/{}", data_dir(), name);
+        if std::path::Path::new(&path).exists() {
+            std::fs::remove_dir_all(&path).unwrap();
+        }
+        Database::new(&format!("test/{}", name), &config_example("rbtc"))
+    }
+    #[tokio::test]
+    async fn subscribe_txs() {
+        let db = open_db("grpc_txs");
+        let blocks = fixtures::regtest_blocks();
+        let (sender, _) = tokio::sync::broadcast::channel(16);
+        // The event sent before subscription is not delivered.
+        db.tx_db.write().await.put_tx(&blocks[1].txdata[0], None).unwrap();
+        sender.send(RawTx(blocks[1].txdata[0].clone())).ok();
+        let server = GrpcServer::new(db.clone(), sender.clone());
+        let mut stream = server.subscribe_txs(Request::new(proto::SubscribeTxsRequest {})).await.unwrap().into_inner();
+        db.tx_db.write().await.put_tx(&blocks[2].txdata[0], None).unwrap();
+        sender.send(RawTx(blocks[2].txdata[0].clone())).unwrap();
+        assert_eq!(stream.next().await.unwrap().unwrap().txid, blocks[2].txdata[0].txid().to_string());
+        // Events the subscription does not care about are skipped.
+        sender.send(HashBlock(blocks[3].block_hash())).unwrap();
+        db.tx_db.write().await.put_tx(&blocks[3].txdata[0], None).unwrap();
+        sender.send(RawTx(blocks[3].txdata[0].clone())).unwrap();
+        assert_eq!(stream.next().await.unwrap().unwrap().txid, blocks[3].txdata[0].txid().to_string());
+    }
+    #[tokio::test]
+    async fn subscribe_blocks() {
+        let db = open_db("grpc_blocks");
+        let blocks = fixtures::regtest_blocks();
+        let (sender, _) = tokio::sync::broadcast::channel(4);
+        let server = GrpcServer::new(db.clone(), sender.clone());
+        let mut stream = server.subscribe_blocks(Request::new(proto::SubscribeBlocksRequest {})).await.unwrap().into_inner();
+        // Every block is delivered, even if several are connected before the subscriber polls.
+        for (height, block) in blocks.iter().enumerate().take(4) {
+            db.block_db.write().await.put(height as u32, block);
+            sender.send(HashBlock(block.block_hash())).unwrap();
+        }
+        for (height, block) in blocks.iter().enumerate().take(4) {
+            let grpc_block = stream.next().await.unwrap().unwrap();
+            assert_eq!(grpc_block.hash, block.block_hash().to_string());
+            assert_eq!(grpc_block.height, height as u32);
+        }
+        // A subscriber lagging behind gets DATA_LOSS and the stream ends.
+        for block in blocks.iter().take(5) {
+            sender.send(HashBlock(block.block_hash())).unwrap();
+        }
+        assert_eq!(stream.next().await.unwrap().unwrap_err().code(), tonic::Code::DataLoss);
+        assert!(stream.next().await.is_none());
+    }
+}
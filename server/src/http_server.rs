@@ -1,5 +1,4 @@
 use crate::*;
-use std::str::FromStr;
 use std::time::Instant;
 use std::convert::Infallible;
 use std::net::SocketAddr;
@@ -8,13 +7,81 @@ use std::collections::HashMap;
 use tokio::sync::RwLock;
 use hyper::{Body, Request, Response, Server, StatusCode};
 use routerify::prelude::*;
-use routerify::{Middleware, Router, RouterService};
+use routerify::{Middleware, Router, RouterBuilder, RouterService};
 use bitcoin::hashes::hex::{FromHex, ToHex};
-use bitcoin::{Script, Address};
-use bitcoincore_rpc::{Auth, Client, RpcApi};
+use bitcoin::{Script, Txid, VarInt};
+use bitcoincore_rpc::RpcApi;
 use chainseeker::*;
 use crate::db::Database;
 use crate::db::block::BlockContentDBValue;
+use crate::db::rich_list::RichListFilter;
+use crate::db::op_return::{OpReturnDBKey, OP_RETURN_PREFIX_LEN};
+
+/// The format of a response body.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResponseFormat {
+    Json,
+    /// Consensus-encoded bytes (`.bin` suffix or `Accept: application/octet-stream`).
+    Bin,
+    /// Consensus-encoded bytes in hex (`.hex` suffix or `Accept: text/plain`).
+    Hex,
+    /// CBOR encoding of the JSON model (`Accept: application/cbor`).
+    Cbor,
+}
+
+impl ResponseFormat {
+    /// Select the format by the `Accept` header, falling back to JSON if no supported media type is acceptable.
+    pub fn from_accept(headers: &hyper::HeaderMap) -> Self {
+        Self::negotiate(headers).unwrap_or(ResponseFormat::Json)
+    }
+    /// Select the supported media type in the `Accept` header with the highest quality value (the first one on a tie).
+    ///
+    /// Media types with `q=0` are not acceptable. Returns `None` if no supported media type is acceptable (e.g. `*/*`).
+    pub fn negotiate(headers: &hyper::HeaderMap) -> Option<Self> {
+        let mut best: Option<(f32, Self)> = None;
+        let media_ranges = headers.get_all(hyper::header::ACCEPT).iter()
+            .filter_map(|value| value.to_str().ok())
+            .flat_map(|value| value.split(','));
+        for media_range in media_ranges {
+            let mut params = media_range.split(';');
+            let format = match params.next().unwrap().trim() {
+                "application/json" => ResponseFormat::Json,
+                "application/octet-stream" => ResponseFormat::Bin,
+                "text/plain" => ResponseFormat::Hex,
+                "application/cbor" => ResponseFormat::Cbor,
+                _ => continue,
+            };
+            let quality = params
+                .filter_map(|param| param.split_once('='))
+                .find(|(key, _)| key.trim().eq_ignore_ascii_case("q"))
+                .map_or(Some(1.0), |(_, value)| value.trim().parse::<f32>().ok());
+            let quality = match quality {
+                Some(quality) if quality > 0.0 => quality,
+                _ => continue,
+            };
+            best = match best {
+                Some((best_quality, _)) if best_quality >= quality => best,
+                _ => Some((quality, format)),
+            };
+        }
+        best.map(|(_, format)| format)
+    }
+    /// Split the Bitcoin-Core-style format suffix (`.json`, `.bin` or `.hex`) from the path parameter.
+    ///
+    /// Falls back to the `Accept` header if the parameter has no suffix.
+    pub fn split_param<'a>(param: &'a str, headers: &hyper::HeaderMap) -> (&'a str, Self) {
+        for (suffix, format) in [(".json", ResponseFormat::Json), (".bin", ResponseFormat::Bin), (".hex", ResponseFormat::Hex)] {
+            if let Some(param) = param.strip_suffix(suffix) {
+                return (param, format);
+            }
+        }
+        (param, Self::from_accept(headers))
+    }
+}
+
+/// The message of an error response, kept to re-encode the response in the negotiated format.
+#[derive(Debug, Clone)]
+struct ErrorMessage(String);
 
 #[derive(Debug, Clone)]
 pub struct HttpServer {
@@ -31,6 +98,9 @@ impl HttpServer {
         }
     }
     fn response(status: &StatusCode, body: String, cacheable: bool) -> Response<Body> {
+        Self::response_with_content_type(status, "application/json", body, cacheable)
+    }
+    pub fn response_with_content_type<B: Into<Body>>(status: &StatusCode, content_type: &str, body: B, cacheable: bool) -> Response<Body> {
         let builder = Response::builder();
         let builder = if cacheable {
             builder
@@ -42,14 +112,18 @@ impl HttpServer {
                 .header("CDN-Cache-Control", "no-store")
         };
         builder
-            .header("Content-Type", "application/json")
+            .header("Content-Type", content_type)
+            // The format is negotiated by the `Accept` header.
+            .header(hyper::header::VARY, "Accept")
             .header(hyper::header::ACCESS_CONTROL_ALLOW_ORIGIN, "*")
             .status(status)
             .body(body.into())
             .unwrap()
     }
     fn error(status: &StatusCode, msg: &str) -> Response<Body> {
-        Self::response(status, format!("{{\"error\":\"{}\"}}", msg), false)
+        let mut res = Self::response(status, serde_json::json!({ "error": msg }).to_string(), false);
+        res.extensions_mut().insert(ErrorMessage(msg.to_string()));
+        res
     }
     pub fn not_found(msg: &str) -> Response<Body> {
         Self::error(&StatusCode::NOT_FOUND, msg)
@@ -72,38 +146,93 @@ impl HttpServer {
             Err(_) => Self::internal_error("Failed to encode to JSON."),
         }
     }
+    /// Respond with the consensus-encoded bytes (hex-encoded for `ResponseFormat::Hex`).
+    fn raw(format: ResponseFormat, bytes: Vec<u8>, cacheable: bool) -> Response<Body> {
+        if format == ResponseFormat::Hex {
+            Self::response_with_content_type(&StatusCode::OK, "text/plain", format!("{}\n", hex::encode(bytes)), cacheable)
+        } else {
+            Self::response_with_content_type(&StatusCode::OK, "application/octet-stream", bytes, cacheable)
+        }
+    }
+    /// Respond with the model in the format negotiated by the `Accept` header (CBOR or JSON).
+    fn model<S>(req: &Request<Body>, object: S, cacheable: bool) -> Response<Body>
+        where S: serde::ser::Serialize,
+    {
+        Self::encode(ResponseFormat::from_accept(req.headers()), object, cacheable)
+    }
+    /// Respond with the model in CBOR for `ResponseFormat::Cbor`, or in JSON otherwise.
+    fn encode<S>(format: ResponseFormat, object: S, cacheable: bool) -> Response<Body>
+        where S: serde::ser::Serialize,
+    {
+        if format != ResponseFormat::Cbor {
+            return Self::json(object, cacheable);
+        }
+        match serde_cbor::to_vec(&object) {
+            Ok(cbor) => Self::response_with_content_type(&StatusCode::OK, "application/cbor", cbor, cacheable),
+            Err(_) => Self::internal_error("Failed to encode to CBOR."),
+        }
+    }
+    /// Re-encode the error response of the REST API in CBOR (leaves the other responses untouched).
+    ///
+    /// The message is taken from the `ErrorMessage` extension `HttpServer::error()` attaches, so the body is not re-parsed.
+    fn error_to_cbor(res: Response<Body>) -> Response<Body> {
+        let message = match res.extensions().get::<ErrorMessage>() {
+            Some(ErrorMessage(message)) => message.clone(),
+            None => return res,
+        };
+        let (mut parts, _) = res.into_parts();
+        match serde_cbor::to_vec(&serde_json::json!({ "error": message })) {
+            Ok(cbor) => {
+                parts.headers.insert(hyper::header::CONTENT_TYPE, hyper::header::HeaderValue::from_static("application/cbor"));
+                Response::from_parts(parts, cbor.into())
+            },
+            Err(_) => Self::internal_error("Failed to encode to CBOR."),
+        }
+    }
+    /// Returns true if the path belongs to the REST API, whose responses are negotiated by the `Accept` header.
+    fn is_rest_path(path: &str) -> bool {
+        path.starts_with("/api/v1/") && !path.starts_with("/api/v1/jsonrpc") && !path.starts_with("/api/v1/graphql")
+    }
     /// `/status` endpoint.
     async fn status_handler(req: Request<Body>) -> Result<Response<Body>, Infallible> {
         let server = req.data::<HttpServer>().unwrap();
-        Ok(Self::json(Status { blocks: server.db.synced_height_db.read().await.get().map_or(-1, |h| h as i32) }, false))
+        Ok(Self::model(&req, Status { blocks: server.db.synced_height_db.read().await.get().map_or(-1, |h| h as i32) }, false))
     }
     /// `/tx/:txid` endpoint.
     async fn tx_handler(req: Request<Body>) -> Result<Response<Body>, Infallible> {
         let server = req.data::<HttpServer>().unwrap();
-        let txid = match req.param("txid").unwrap().parse() {
+        let (txid, format) = ResponseFormat::split_param(req.param("txid").unwrap(), req.headers());
+        let txid = match txid.parse() {
             Ok(txid) => txid,
             Err(_) => return Ok(Self::not_found("Failed to decode txid.")),
         };
-        match server.db.tx_db.read().await.get_as_rest(&txid, &server.db.config) {
-            Some(tx) => {
-                let cacheable = tx.confirmed_height.is_some();
-                Ok(Self::json(tx, cacheable))
-            },
+        if matches!(format, ResponseFormat::Bin | ResponseFormat::Hex) {
+            return match server.db.tx_db.read().await.get(&txid) {
+                // The serialized transaction never changes.
+                Some(value) => Ok(Self::raw(format, consensus_encode(&value.tx), true)),
+                None => Ok(Self::not_found("Transaction not found.")),
+            };
+        }
+        let block_db = server.db.block_db.read().await;
+        let synced_height = server.db.synced_height_db.read().await.get();
+        match server.db.tx_db.read().await.get_as_rest(&txid, &block_db, synced_height, &server.db.config) {
+            // Not cacheable because the number of confirmations changes.
+            Some(tx) => Ok(Self::encode(format, tx, false)),
             None => Ok(Self::not_found("Transaction not found.")),
         }
     }
     /// `/tx/broadcast` endpoint.
     async fn tx_broadcast_handler(req: Request<Body>) -> Result<Response<Body>, Infallible> {
         let server = req.data::<HttpServer>().unwrap();
-        let auth = Auth::UserPass(server.db.config.rpc_user.clone(), server.db.config.rpc_pass.clone());
-        let rpc = Client::new(server.db.config.rpc_endpoint.clone(), auth).unwrap();
+        let rpc = get_rpc(&server.db.config);
+        let format = ResponseFormat::from_accept(req.headers());
         let hex = hyper::body::to_bytes(req.into_body()).await.unwrap();
         let hex = String::from_utf8(hex.to_vec());
         if hex.is_err() {
             return Ok(Self::bad_request("Failed to parse input."));
         }
         match rpc.send_raw_transaction(hex.unwrap()) {
-            Ok(txid) => Ok(Self::ok(format!("{{\"txid\":\"{}\"}}", txid), false)),
+            Ok(txid) => Ok(Self::encode(format, serde_json::json!({ "txid": txid }), false)),
             Err(_) => Ok(Self::bad_request("Failed to broadcast transaction.")),
         }
     }
@@ -128,20 +257,22 @@ impl HttpServer {
                     continue;
                 }
             }
-            let block = server.db.block_db.read().await.get(height);
-            if block.is_none() {
-                break;
-            }
-            let summary = create_block_summary(&block.unwrap());
+            let block = match server.db.block_db.read().await.get(height) {
+                Some(block) => block,
+                None => break,
+            };
+            let block_hash = block.block_header.block_hash();
+            let stats = server.db.block_stats_db.read().await.get(&block_hash);
+            let pool = server.db.pool_db.read().await.get(&block_hash);
+            let summary = create_block_summary(&block, &stats, &pool);
             server.block_summary_cache.write().await.insert(height, summary.clone());
             ret.push(summary);
         }
-        Ok(Self::json(&ret, true))
+        Ok(Self::model(&req, &ret, true))
     }
     /// Helper function for `/block*` APIs.
-    async fn block_content(req: &Request<Body>) -> Result<BlockContentDBValue, Response<Body>> {
+    async fn block_content(req: &Request<Body>, hash_or_height: &str) -> Result<BlockContentDBValue, Response<Body>> {
         let server = req.data::<HttpServer>().unwrap();
-        let hash_or_height = req.param("hash_or_height").unwrap();
         let block_content = if hash_or_height.len() == 64 {
             let block_hash = BlockHash::from_hex(hash_or_height);
             if block_hash.is_err() {
@@ -163,60 +294,244 @@ impl HttpServer {
     /// `/block_with_txids/:hash_or_height` endpoint.
     async fn block_with_txids_handler(req: Request<Body>) -> Result<Response<Body>, Infallible> {
         let server = req.data::<HttpServer>().unwrap();
-        match Self::block_content(&req).await {
-            Ok(block_content) => Ok(Self::json(create_block_with_txids(&block_content, &server.db.config), true)),
+        match Self::block_content(&req, req.param("hash_or_height").unwrap()).await {
+            Ok(block_content) => {
+                let pool = server.db.pool_db.read().await.get(&block_content.block_header.block_hash());
+                Ok(Self::model(&req, create_block_with_txids(&block_content, &pool, &server.db.config), true))
+            },
             Err(res) => Ok(res),
         }
     }
     /// `/block_with_txs/:hash_or_height` endpoint.
+    ///
+    /// Responds with the raw block (same as `/rawblock`) for the `.bin` / `.hex` suffix or `Accept` header.
     async fn block_with_txs_handler(req: Request<Body>) -> Result<Response<Body>, Infallible> {
         let server = req.data::<HttpServer>().unwrap();
-        let tx_db = server.db.tx_db.read().await;
-        match Self::block_content(&req).await {
-            Ok(block_content) => Ok(Self::json(create_block_with_txs(&tx_db, &block_content, &server.db.config), true)),
-            Err(res) => Ok(res),
+        let (hash_or_height, format) = ResponseFormat::split_param(req.param("hash_or_height").unwrap(), req.headers());
+        let block_content = match Self::block_content(&req, hash_or_height).await {
+            Ok(block_content) => block_content,
+            Err(res) => return Ok(res),
+        };
+        if matches!(format, ResponseFormat::Bin | ResponseFormat::Hex) {
+            return Ok(Self::stream_block(server.clone(), block_content, format));
         }
+        let tx_db = server.db.tx_db.read().await;
+        let block_db = server.db.block_db.read().await;
+        let pool = server.db.pool_db.read().await.get(&block_content.block_header.block_hash());
+        Ok(Self::encode(format, create_block_with_txs(&tx_db, &block_db, &block_content, &pool, &server.db.config), true))
     }
     /// `/block/:hash_or_height` endpoint.
     async fn block_handler(req: Request<Body>) -> Result<Response<Body>, Infallible> {
         let server = req.data::<HttpServer>().unwrap();
-        match Self::block_content(&req).await {
-            Ok(block_content) => Ok(Self::json(create_block_header(&block_content, &server.db.config), true)),
+        let (hash_or_height, format) = ResponseFormat::split_param(req.param("hash_or_height").unwrap(), req.headers());
+        match Self::block_content(&req, hash_or_height).await {
+            Ok(block_content) if matches!(format, ResponseFormat::Bin | ResponseFormat::Hex) => {
+                Ok(Self::raw(format, consensus_encode(&block_content.block_header), true))
+            },
+            Ok(block_content) => {
+                let pool = server.db.pool_db.read().await.get(&block_content.block_header.block_hash());
+                Ok(Self::encode(format, create_block_header(&block_content, &pool, &server.db.config), true))
+            },
             Err(res) => Ok(res),
         }
     }
-    fn decode_script_or_address(script_or_address: &str) -> Option<Script> {
-        match Address::from_str(script_or_address) {
-            Ok(addr) => return Some(addr.script_pubkey()),
-            Err(err) => {
-                println!("Failed to decode address: {}.", err);
+    /// `/rawblock/:hash_or_height` endpoint.
+    ///
+    /// Reassembles the block from the stored header and transactions and streams it in consensus encoding
+    /// (hex-encoded with the `.hex` suffix or `Accept: text/plain`).
+    ///
+    /// There is no JSON model (see `/block_with_txs`), so the `.json` suffix is not found
+    /// and `Accept` preferring JSON or CBOR is not acceptable.
+    async fn rawblock_handler(req: Request<Body>) -> Result<Response<Body>, Infallible> {
+        let server = req.data::<HttpServer>().unwrap().clone();
+        let param = req.param("hash_or_height").unwrap();
+        let (hash_or_height, format) = ResponseFormat::split_param(param, req.headers());
+        let format = match format {
+            ResponseFormat::Bin | ResponseFormat::Hex => format,
+            _ if hash_or_height != param => return Ok(Self::not_found("Raw blocks are available only with the \".bin\" or \".hex\" suffix.")),
+            _ => match ResponseFormat::negotiate(req.headers()) {
+                Some(_) => return Ok(Self::error(&StatusCode::NOT_ACCEPTABLE,
+                    "Raw blocks are available only as \"application/octet-stream\" or \"text/plain\".")),
+                None => ResponseFormat::Bin,
+            },
+        };
+        match Self::block_content(&req, hash_or_height).await {
+            Ok(block_content) => Ok(Self::stream_block(server, block_content, format)),
+            Err(res) => Ok(res),
+        }
+    }
+    /// Stream the block in consensus encoding (hex-encoded for `ResponseFormat::Hex`).
+    fn stream_block(server: HttpServer, block_content: BlockContentDBValue, format: ResponseFormat) -> Response<Body> {
+        let hex = format == ResponseFormat::Hex;
+        let encode = move |bytes: Vec<u8>| if hex { hex::encode(bytes).into_bytes() } else { bytes };
+        let content_length = if hex { 2 * block_content.size as usize + 1 } else { block_content.size as usize };
+        let (mut sender, body) = Body::channel();
+        tokio::spawn(async move {
+            let mut head = consensus_encode(&block_content.block_header);
+            head.extend(consensus_encode(&VarInt(block_content.txids.len() as u64)));
+            if sender.send_data(encode(head).into()).await.is_err() {
+                return;
+            }
+            for txid in block_content.txids.iter() {
+                // Lock for each transaction not to block the syncer while streaming to a slow client.
+                let tx = match server.db.tx_db.read().await.get(txid) {
+                    Some(value) => consensus_encode(&value.tx),
+                    None => {
+                        eprintln!("HttpServer: failed to resolve transaction {} in block {}.", txid, block_content.block_header.block_hash());
+                        // Let the client notice the truncated body.
+                        sender.abort();
+                        return;
+                    },
+                };
+                if sender.send_data(encode(tx).into()).await.is_err() {
+                    return;
+                }
+            }
+            if hex {
+                let _ = sender.send_data("\n".into()).await;
             }
+        });
+        let content_type = if hex { "text/plain" } else { "application/octet-stream" };
+        let mut res = Self::response_with_content_type(&StatusCode::OK, content_type, body, true);
+        res.headers_mut().insert(hyper::header::CONTENT_LENGTH, content_length.into());
+        res
+    }
+    /// `/block_stats/:hash_or_height` endpoint.
+    async fn block_stats_handler(req: Request<Body>) -> Result<Response<Body>, Infallible> {
+        let server = req.data::<HttpServer>().unwrap();
+        match Self::block_content(&req, req.param("hash_or_height").unwrap()).await {
+            Ok(block_content) => {
+                match server.db.block_stats_db.read().await.get(&block_content.block_header.block_hash()) {
+                    Some(stats) => Ok(Self::model(&req, create_block_stats(&block_content, &stats), true)),
+                    None => Ok(Self::not_found("Block stats not found.")),
+                }
+            },
+            Err(res) => Ok(res),
         }
-        Script::from_hex(script_or_address).ok()
     }
-    /// `/txids/:script_or_address` endpoint.
-    async fn txids_handler(req: Request<Body>) -> Result<Response<Body>, Infallible> {
+    /// `/pools?from=&to=` endpoint.
+    async fn pools_handler(req: Request<Body>) -> Result<Response<Body>, Infallible> {
+        /// The number of blocks returned by default (about a day).
+        const DEFAULT_BLOCKS: u32 = 144;
+        /// The maximum number of blocks (about a year).
+        const MAX_BLOCKS: u32 = 52_560;
         let server = req.data::<HttpServer>().unwrap();
-        let script = Self::decode_script_or_address(req.param("script_or_address").unwrap());
-        if script.is_none() {
-            return Ok(Self::not_found("Failed to decode input script or address."));
+        let synced_height = match server.db.synced_height_db.read().await.get() {
+            Some(synced_height) => synced_height,
+            None => return Ok(Self::not_found("No block synced yet.")),
+        };
+        let query = Self::query(&req);
+        let to = match query.get("to") {
+            Some(to) => match to.parse::<u32>() {
+                Ok(to) => std::cmp::min(to, synced_height),
+                Err(_) => return Ok(Self::bad_request("Cannot parse \"to\" as an integer.")),
+            },
+            None => synced_height,
+        };
+        let from = match query.get("from") {
+            Some(from) => match from.parse::<u32>() {
+                Ok(from) => from,
+                Err(_) => return Ok(Self::bad_request("Cannot parse \"from\" as an integer.")),
+            },
+            None => to.saturating_sub(DEFAULT_BLOCKS - 1),
+        };
+        if from > to {
+            return Ok(Self::bad_request("\"from\" should not be greater than \"to\"."));
+        }
+        if to - from >= MAX_BLOCKS {
+            return Ok(Self::bad_request(&format!("Cannot query more than {} blocks at once.", MAX_BLOCKS)));
         }
-        let txids = server.db.addr_index_db.read().await.get(&script.unwrap());
+        let block_db = server.db.block_db.read().await;
+        let pool_db = server.db.pool_db.read().await;
+        let prev_time = if from > 0 {
+            block_db.get(from - 1).map(|block| block.block_header.time)
+        } else {
+            None
+        };
+        let mut blocks = Vec::new();
+        for height in from..=to {
+            let block = match block_db.get(height) {
+                Some(block) => block,
+                None => break,
+            };
+            let pool = pool_db.get(&block.block_header.block_hash());
+            blocks.push((block, pool));
+        }
+        Ok(Self::model(&req, create_pools(&blocks, prev_time, &server.db.config), false))
+    }
+    /// Decode the script pubkey in hex or the address of the coin (returns the reason on failure).
+    pub fn decode_script_or_address(script_or_address: &str, config: &Config) -> Result<Script, String> {
+        match address_string_to_script(script_or_address, config) {
+            Ok(script) => Ok(script),
+            Err(err) => Script::from_hex(script_or_address).map_err(|_| err),
+        }
+    }
+    /// Parse the query string of the request into (key, value) pairs.
+    fn query(req: &Request<Body>) -> HashMap<String, String> {
+        req.uri().query().map_or_else(HashMap::new, |query| {
+            query.split('&').filter(|pair| !pair.is_empty()).map(|pair| {
+                let mut iter = pair.splitn(2, '=');
+                (iter.next().unwrap().to_string(), iter.next().unwrap_or("").to_string())
+            }).collect()
+        })
+    }
+    /// Helper function for `/txids` and `/txs` APIs.
+    ///
+    /// Supports `?limit=N&after=TXID` query parameters, where `after` is the last txid of the previous page.
+    async fn address_txids(req: &Request<Body>) -> Result<Vec<Txid>, Response<Body>> {
+        const DEFAULT_LIMIT: usize = 100;
+        const MAX_LIMIT: usize = 1000;
+        let server = req.data::<HttpServer>().unwrap();
+        let script = match Self::decode_script_or_address(req.param("script_or_address").unwrap(), &server.db.config) {
+            Ok(script) => script,
+            Err(err) => return Err(Self::bad_request(&err)),
+        };
+        let query = Self::query(req);
+        let limit = match query.get("limit") {
+            Some(limit) => match limit.parse::<usize>() {
+                Ok(limit) if limit <= MAX_LIMIT => limit,
+                Ok(_) => return Err(Self::bad_request(&format!("\"limit\" must be at most {}.", MAX_LIMIT))),
+                Err(_) => return Err(Self::bad_request("Cannot parse \"limit\" as an integer.")),
+            },
+            None => DEFAULT_LIMIT,
+        };
+        let after = match query.get("after") {
+            Some(after) => {
+                let txid = match Txid::from_hex(after) {
+                    Ok(txid) => txid,
+                    Err(_) => return Err(Self::bad_request("Failed to decode \"after\" as a txid.")),
+                };
+                match server.db.tx_db.read().await.get_confirmed_height(&txid) {
+                    Some(confirmed_height) => Some((confirmed_height, txid)),
+                    None => return Err(Self::bad_request("Transaction specified by \"after\" not found.")),
+                }
+            },
+            None => None,
+        };
+        Ok(server.db.addr_index_db.read().await.get_after(&script, after, limit))
+    }
+    /// `/txids/:script_or_address` endpoint.
+    async fn txids_handler(req: Request<Body>) -> Result<Response<Body>, Infallible> {
+        let txids = match Self::address_txids(&req).await {
+            Ok(txids) => txids,
+            Err(res) => return Ok(res),
+        };
         let txids = txids.iter().map(|txid| txid.to_hex()).collect::<Vec<String>>();
-        Ok(Self::json(&txids, false))
+        Ok(Self::model(&req, &txids, false))
     }
     /// `/txs/:script_or_address` endpoint.
     async fn txs_handler(req: Request<Body>) -> Result<Response<Body>, Infallible> {
         let server = req.data::<HttpServer>().unwrap();
-        let script = Self::decode_script_or_address(req.param("script_or_address").unwrap());
-        if script.is_none() {
-            return Ok(Self::not_found("Failed to decode input script or address."));
-        }
-        let txids = server.db.addr_index_db.read().await.get(&script.unwrap());
+        let txids = match Self::address_txids(&req).await {
+            Ok(txids) => txids,
+            Err(res) => return Ok(res),
+        };
         let tx_db = server.db.tx_db.read().await;
+        let block_db = server.db.block_db.read().await;
+        let synced_height = server.db.synced_height_db.read().await.get();
         let mut txids_not_found = Vec::new();
         let txs = txids.iter().map(|txid| {
-            match tx_db.get_as_rest(txid, &server.db.config) {
+            match tx_db.get_as_rest(txid, &block_db, synced_height, &server.db.config) {
                 Some(tx) => Some(tx),
                 None => {
                     txids_not_found.push(txid.to_string());
@@ -228,42 +543,216 @@ impl HttpServer {
             return Ok(Self::internal_error(&format!("Failed to resolve transactions: {}.", txids_not_found.join(", "))));
         }
         let txs: Vec<chainseeker::Transaction> = txs.into_iter().map(|x| x.unwrap()).collect();
-        Ok(Self::json(&txs, false))
+        Ok(Self::model(&req, &txs, false))
+    }
+    /// `/op_return/:hex_prefix` endpoint.
+    ///
+    /// Supports `?limit=N&after=TXID:VOUT` query parameters, where `after` is the last entry of the previous page.
+    async fn op_return_handler(req: Request<Body>) -> Result<Response<Body>, Infallible> {
+        const DEFAULT_LIMIT: usize = 100;
+        const MAX_LIMIT: usize = 1000;
+        let server = req.data::<HttpServer>().unwrap();
+        let prefix = match hex::decode(req.param("hex_prefix").unwrap()) {
+            Ok(prefix) => prefix,
+            Err(_) => return Ok(Self::bad_request("Failed to decode the prefix as hex.")),
+        };
+        if prefix.len() > OP_RETURN_PREFIX_LEN {
+            return Ok(Self::bad_request(&format!("The prefix must be at most {} bytes.", OP_RETURN_PREFIX_LEN)));
+        }
+        let query = Self::query(&req);
+        let limit = match query.get("limit") {
+            Some(limit) => match limit.parse::<usize>() {
+                Ok(limit) if limit <= MAX_LIMIT => limit,
+                Ok(_) => return Ok(Self::bad_request(&format!("\"limit\" must be at most {}.", MAX_LIMIT))),
+                Err(_) => return Ok(Self::bad_request("Cannot parse \"limit\" as an integer.")),
+            },
+            None => DEFAULT_LIMIT,
+        };
+        let after = match query.get("after") {
+            Some(after) => {
+                let mut iter = after.splitn(2, ':');
+                let txid = iter.next().and_then(|txid| Txid::from_hex(txid).ok());
+                let vout = iter.next().and_then(|vout| vout.parse::<u32>().ok());
+                let (txid, vout) = match (txid, vout) {
+                    (Some(txid), Some(vout)) => (txid, vout),
+                    _ => return Ok(Self::bad_request("Failed to decode \"after\" as \"txid:vout\".")),
+                };
+                let tx_db_value = server.db.tx_db.read().await.get(&txid);
+                let key = tx_db_value.and_then(|tx_db_value| {
+                    let data = op_return_data(&tx_db_value.tx.output.get(vout as usize)?.script_pubkey)?;
+                    Some(OpReturnDBKey::new(&data, tx_db_value.confirmed_height?, txid, vout))
+                });
+                match key {
+                    Some(key) => Some(key),
+                    None => return Ok(Self::bad_request("OP_RETURN output specified by \"after\" not found.")),
+                }
+            },
+            None => None,
+        };
+        let entries = server.db.op_return_db.read().await.get_after(&prefix, after.as_ref(), limit);
+        let entries = entries.iter().map(|(key, value)| create_op_return(key, value)).collect::<Vec<OpReturn>>();
+        Ok(Self::model(&req, &entries, false))
+    }
+    /// `/counterparty_txids/:asset` endpoint.
+    ///
+    /// Supports `?limit=N&after=TXID` query parameters, where `after` is the last txid of the previous page.
+    async fn counterparty_txids_handler(req: Request<Body>) -> Result<Response<Body>, Infallible> {
+        const DEFAULT_LIMIT: usize = 100;
+        const MAX_LIMIT: usize = 1000;
+        let server = req.data::<HttpServer>().unwrap();
+        let asset_id = match counterparty::asset_id(req.param("asset").unwrap(), &server.db.config.counterparty_native_assets) {
+            Some(asset_id) => asset_id,
+            None => return Ok(Self::bad_request("Invalid asset name.")),
+        };
+        let query = Self::query(&req);
+        let limit = match query.get("limit") {
+            Some(limit) => match limit.parse::<usize>() {
+                Ok(limit) if limit <= MAX_LIMIT => limit,
+                Ok(_) => return Ok(Self::bad_request(&format!("\"limit\" must be at most {}.", MAX_LIMIT))),
+                Err(_) => return Ok(Self::bad_request("Cannot parse \"limit\" as an integer.")),
+            },
+            None => DEFAULT_LIMIT,
+        };
+        let after = match query.get("after") {
+            Some(after) => {
+                let txid = match Txid::from_hex(after) {
+                    Ok(txid) => txid,
+                    Err(_) => return Ok(Self::bad_request("Failed to decode \"after\" as a txid.")),
+                };
+                match server.db.tx_db.read().await.get_confirmed_height(&txid) {
+                    Some(Some(height)) => Some((height, txid)),
+                    _ => return Ok(Self::bad_request("Confirmed transaction specified by \"after\" not found.")),
+                }
+            },
+            None => None,
+        };
+        let entries = server.db.counterparty_db.read().await.get_after(asset_id, after, limit);
+        let txids = entries.iter().map(|(_height, txid)| txid.to_hex()).collect::<Vec<String>>();
+        Ok(Self::model(&req, &txids, false))
     }
     /// `/utxos/:script_or_address` endpoint.
     async fn utxos_handler(req: Request<Body>) -> Result<Response<Body>, Infallible> {
         let server = req.data::<HttpServer>().unwrap();
-        let script = Self::decode_script_or_address(req.param("script_or_address").unwrap());
-        if script.is_none() {
-            return Ok(Self::not_found("Failed to decode input script or address."));
-        }
+        let script = match Self::decode_script_or_address(req.param("script_or_address").unwrap(), &server.db.config) {
+            Ok(script) => script,
+            Err(err) => return Ok(Self::bad_request(&err)),
+        };
+        let synced_height = server.db.synced_height_db.read().await.get().unwrap_or(0);
+        let values = server.db.utxo_server.read().await.get(&script);
         let tx_db = server.db.tx_db.read().await;
-        let values = server.db.utxo_server.read().await.get(&script.unwrap());
         let mut utxos: Vec<Utxo> = Vec::new();
         for utxo in values.iter() {
-            match tx_db.get(&utxo.txid) {
-                Some(tx_db_value) => utxos.push(create_utxo(&utxo, &tx_db_value.tx, &server.db.config)),
-                None => return Ok(Self::internal_error(&format!("Failed to resolve previous txid: {}", utxo.txid))),
+            // P2PK outputs found by the P2PKH script need the original script.
+            if utxo.p2pk() {
+                match tx_db.get(&utxo.txid) {
+                    Some(tx_db_value) => {
+                        let script_pubkey = &tx_db_value.tx.output[utxo.vout as usize].script_pubkey;
+                        utxos.push(create_utxo(utxo, script_pubkey, synced_height, &server.db.config));
+                    },
+                    None => return Ok(Self::internal_error(&format!("Failed to resolve previous txid: {}", utxo.txid))),
+                }
+            } else {
+                utxos.push(create_utxo(utxo, &script, synced_height, &server.db.config));
             }
+        }
+        Ok(Self::model(&req, &utxos, false))
+    }
+    /// `/utxo_set_info?height=` endpoint.
+    async fn utxo_set_info_handler(req: Request<Body>) -> Result<Response<Body>, Infallible> {
+        let server = req.data::<HttpServer>().unwrap();
+        let synced_height = match server.db.synced_height_db.read().await.get() {
+            Some(synced_height) => synced_height,
+            None => return Ok(Self::not_found("No block synced yet.")),
+        };
+        let height = match Self::query(&req).get("height") {
+            Some(height) => match height.parse::<u32>() {
+                Ok(height) if height <= synced_height => height,
+                Ok(_) => return Ok(Self::not_found("Block not found.")),
+                Err(_) => return Ok(Self::bad_request("Cannot parse \"height\" as an integer.")),
+            },
+            None => synced_height,
         };
-        Ok(Self::json(&utxos, false))
+        let block = match server.db.block_db.read().await.get(height) {
+            Some(block) => block,
+            None => return Ok(Self::not_found("Block not found.")),
+        };
+        match server.db.utxo_stats_db.read().await.get(height) {
+            Some(stats) => Ok(Self::model(&req, create_utxo_set_info(&block, &stats), false)),
+            None => Ok(Self::not_found("UTXO set statistics not found.")),
+        }
+    }
+    /// Helper function for `/supply*` APIs.
+    async fn supply(req: &Request<Body>, height: u32) -> Response<Body> {
+        let server = req.data::<HttpServer>().unwrap();
+        match server.db.synced_height_db.read().await.get() {
+            Some(synced_height) if height <= synced_height => {},
+            _ => return Self::not_found("Block not found."),
+        }
+        match server.db.supply_db.read().await.get(height) {
+            Some(supply) => Self::model(req, create_supply(&supply), false),
+            None => Self::not_found("Supply not found."),
+        }
+    }
+    /// `/supply` endpoint.
+    async fn supply_handler(req: Request<Body>) -> Result<Response<Body>, Infallible> {
+        let server = req.data::<HttpServer>().unwrap();
+        match server.db.synced_height_db.read().await.get() {
+            Some(synced_height) => Ok(Self::supply(&req, synced_height).await),
+            None => Ok(Self::not_found("No block synced yet.")),
+        }
+    }
+    /// `/supply/:height` endpoint.
+    async fn supply_at_height_handler(req: Request<Body>) -> Result<Response<Body>, Infallible> {
+        match req.param("height").unwrap().parse::<u32>() {
+            Ok(height) => Ok(Self::supply(&req, height).await),
+            Err(_) => Ok(Self::bad_request("Cannot parse \"height\" as an integer.")),
+        }
+    }
+    /// Parse the filter of `/rich_list*` APIs from the query string (`type`, `address_only` and `exclude_burn`).
+    fn rich_list_filter(req: &Request<Body>) -> Result<RichListFilter, String> {
+        let query = Self::query(req);
+        let script_type = match query.get("type") {
+            Some(script_type) => match script_type.parse() {
+                Ok(script_type) => Some(script_type),
+                Err(_) => return Err("Unknown script \"type\".".to_string()),
+            },
+            None => None,
+        };
+        let parse_bool = |key: &str| match query.get(key).map(|value| value.as_str()) {
+            None | Some("false") | Some("0") => Ok(false),
+            Some("true") | Some("1") => Ok(true),
+            Some(_) => Err(format!("Cannot parse \"{}\" as a boolean.", key)),
+        };
+        Ok(RichListFilter {
+            script_type,
+            address_only: parse_bool("address_only")?,
+            exclude_burn: parse_bool("exclude_burn")?,
+        })
     }
     /// `/rich_list_count` endpoint.
     async fn rich_list_count_handler(req: Request<Body>) -> Result<Response<Body>, Infallible> {
+        let filter = match Self::rich_list_filter(&req) {
+            Ok(filter) => filter,
+            Err(err) => return Ok(Self::bad_request(&err)),
+        };
         let server = req.data::<HttpServer>().unwrap();
-        let json = format!("{{\"count\":{}}}", server.db.rich_list.read().await.len());
-        Ok(Self::ok(json, false))
+        let count = server.db.rich_list.read().await.filtered_len(&filter);
+        Ok(Self::model(&req, serde_json::json!({ "count": count }), false))
     }
     /// `/rich_list_addr_rank/:script_or_address` endpoint.
     async fn rich_list_addr_rank_handler(req: Request<Body>) -> Result<Response<Body>, Infallible> {
         let server = req.data::<HttpServer>().unwrap();
-        let script = Self::decode_script_or_address(req.param("script_or_address").unwrap());
-        if script.is_none() {
-            return Ok(Self::not_found("Failed to decode input script or address."));
-        }
-        match server.db.rich_list.read().await.get_index_of(&script.unwrap()) {
-            Some(rank) => Ok(Self::ok(format!("{{\"rank\":{}}}", rank + 1), false)),
-            None => Ok(Self::ok("{\"rank\":null}".to_string(), false)),
+        let script = match Self::decode_script_or_address(req.param("script_or_address").unwrap(), &server.db.config) {
+            Ok(script) => script,
+            Err(err) => return Ok(Self::bad_request(&err)),
+        };
+        let filter = match Self::rich_list_filter(&req) {
+            Ok(filter) => filter,
+            Err(err) => return Ok(Self::bad_request(&err)),
+        };
+        match server.db.rich_list.read().await.get_filtered_index_of(&script, &filter) {
+            Some(rank) => Ok(Self::model(&req, serde_json::json!({ "rank": rank + 1 }), false)),
+            None => Ok(Self::model(&req, serde_json::json!({ "rank": null }), false)),
         }
     }
     /// `/rich_list/:offset/:limit` endpoint.
@@ -276,20 +765,88 @@ impl HttpServer {
             Ok(limit) => limit,
             Err(_) => return Ok(Self::bad_request("Cannot parse \"limit\" as an integer.")),
         };
+        let filter = match Self::rich_list_filter(&req) {
+            Ok(filter) => filter,
+            Err(err) => return Ok(Self::bad_request(&err)),
+        };
         let server = req.data::<HttpServer>().unwrap();
         let rich_list = server.db.rich_list.read().await;
-        Ok(Self::json(&rich_list.get_in_range_as_rest(offset..offset+limit, &server.db.config), false))
+        Ok(Self::model(&req, &rich_list.get_filtered_in_range_as_rest(offset..offset+limit, &filter, &server.db.config), false))
     }
-    pub async fn run(&self, ip: &str, port: u16) {
-        let addr = SocketAddr::from((
-            ip.parse::<std::net::IpAddr>().expect("Failed to parse HTTP IP address."),
-            port));
-        let router = Router::builder()
-            .data((*self).clone())
-            .middleware(Middleware::pre(|req| async move {
-                req.set_context(Instant::now());
-                Ok(req)
-            }))
+    /// `/rich_list_at/:height/:offset/:limit` endpoint.
+    async fn rich_list_at_handler(req: Request<Body>) -> Result<Response<Body>, Infallible> {
+        /// The maximum number of entries returned at once.
+        const MAX_LIMIT: u32 = 1000;
+        let height: u32 = match req.param("height").unwrap().parse() {
+            Ok(height) => height,
+            Err(_) => return Ok(Self::bad_request("Cannot parse \"height\" as an integer.")),
+        };
+        let offset: u32 = match req.param("offset").unwrap().parse() {
+            Ok(offset) => offset,
+            Err(_) => return Ok(Self::bad_request("Cannot parse \"offset\" as an integer.")),
+        };
+        let limit: u32 = match req.param("limit").unwrap().parse() {
+            Ok(limit) => limit,
+            Err(_) => return Ok(Self::bad_request("Cannot parse \"limit\" as an integer.")),
+        };
+        if limit > MAX_LIMIT {
+            return Ok(Self::bad_request(&format!("Cannot query more than {} entries at once.", MAX_LIMIT)));
+        }
+        let server = req.data::<HttpServer>().unwrap();
+        let rich_list_snapshot_db = server.db.rich_list_snapshot_db.read().await;
+        if rich_list_snapshot_db.get(height).is_none() {
+            return Ok(Self::not_found("Rich list snapshot not found."));
+        }
+        let entries = rich_list_snapshot_db.get_entries(height, offset, limit).iter()
+            .map(|entry| entry.as_ref().map(|entry| create_rich_list_entry(entry, &server.db.config)))
+            .collect::<Vec<_>>();
+        Ok(Self::model(&req, &entries, false))
+    }
+    /// `/rich_list_distribution/:height` endpoint.
+    async fn rich_list_distribution_handler(req: Request<Body>) -> Result<Response<Body>, Infallible> {
+        let height: u32 = match req.param("height").unwrap().parse() {
+            Ok(height) => height,
+            Err(_) => return Ok(Self::bad_request("Cannot parse \"height\" as an integer.")),
+        };
+        let server = req.data::<HttpServer>().unwrap();
+        match server.db.rich_list_snapshot_db.read().await.get(height) {
+            Some(snapshot) => Ok(Self::model(&req, create_rich_list_distribution(&snapshot), false)),
+            None => Ok(Self::not_found("Rich list snapshot not found.")),
+        }
+    }
+    /// `/rich_list_addr_rank_history/:script_or_address` endpoint.
+    async fn rich_list_addr_rank_history_handler(req: Request<Body>) -> Result<Response<Body>, Infallible> {
+        let server = req.data::<HttpServer>().unwrap();
+        let script = match Self::decode_script_or_address(req.param("script_or_address").unwrap(), &server.db.config) {
+            Ok(script) => script,
+            Err(err) => return Ok(Self::bad_request(&err)),
+        };
+        let query = Self::query(&req);
+        let from = match query.get("from") {
+            Some(from) => match from.parse::<u32>() {
+                Ok(from) => from,
+                Err(_) => return Ok(Self::bad_request("Cannot parse \"from\" as an integer.")),
+            },
+            None => 0,
+        };
+        let to = match query.get("to") {
+            Some(to) => match to.parse::<u32>() {
+                Ok(to) => to,
+                Err(_) => return Ok(Self::bad_request("Cannot parse \"to\" as an integer.")),
+            },
+            None => u32::MAX,
+        };
+        if from > to {
+            return Ok(Self::bad_request("\"from\" should not be greater than \"to\"."));
+        }
+        let history = server.db.rich_list_snapshot_db.read().await.get_rank_history(&script, from, to).iter()
+            .map(create_rich_list_rank_history_entry)
+            .collect::<Vec<_>>();
+        Ok(Self::model(&req, &history, false))
+    }
+    /// Add the `/api/v1` routes (shared by the REST API and `JsonRpc`).
+    pub fn api_routes(builder: RouterBuilder<Body, Infallible>) -> RouterBuilder<Body, Infallible> {
+        builder
             .get("/api/v1/status", Self::status_handler)
             .get("/api/v1/tx/:txid", Self::tx_handler)
             .put("/api/v1/tx/broadcast", Self::tx_broadcast_handler)
@@ -297,16 +854,55 @@ impl HttpServer {
             .get("/api/v1/block_with_txids/:hash_or_height", Self::block_with_txids_handler)
             .get("/api/v1/block_with_txs/:hash_or_height", Self::block_with_txs_handler)
             .get("/api/v1/block/:hash_or_height", Self::block_handler)
+            .get("/api/v1/rawblock/:hash_or_height", Self::rawblock_handler)
+            .get("/api/v1/block_stats/:hash_or_height", Self::block_stats_handler)
+            .get("/api/v1/pools", Self::pools_handler)
             .get("/api/v1/txids/:script_or_address", Self::txids_handler)
             .get("/api/v1/txs/:script_or_address", Self::txs_handler)
+            .get("/api/v1/op_return/:hex_prefix", Self::op_return_handler)
+            .get("/api/v1/counterparty_txids/:asset", Self::counterparty_txids_handler)
             .get("/api/v1/utxos/:script_or_address", Self::utxos_handler)
+            .get("/api/v1/utxo_set_info", Self::utxo_set_info_handler)
+            .get("/api/v1/supply", Self::supply_handler)
+            .get("/api/v1/supply/:height", Self::supply_at_height_handler)
             .get("/api/v1/rich_list_count", Self::rich_list_count_handler)
             .get("/api/v1/rich_list_addr_rank/:script_or_address", Self::rich_list_addr_rank_handler)
             .get("/api/v1/rich_list/:offset/:limit", Self::rich_list_handler)
+            .get("/api/v1/rich_list_at/:height/:offset/:limit", Self::rich_list_at_handler)
+            .get("/api/v1/rich_list_distribution/:height", Self::rich_list_distribution_handler)
+            .get("/api/v1/rich_list_addr_rank_history/:script_or_address", Self::rich_list_addr_rank_history_handler)
+    }
+    pub async fn run(&self, ip: &str, port: u16) {
+        let addr = SocketAddr::from((
+            ip.parse::<std::net::IpAddr>().expect("Failed to parse HTTP IP address."),
+            port));
+        let builder = Router::builder()
+            .data((*self).clone())
+            .data(JsonRpc::new((*self).clone()))
+            .data(graphql::schema(self.db.clone()))
+            .middleware(Middleware::pre(|req| async move {
+                req.set_context(Instant::now());
+                Ok(req)
+            }));
+        let builder = Self::api_routes(builder)
+            .post("/api/v1/jsonrpc", JsonRpc::http_handler)
+            .get("/api/v1/jsonrpc", JsonRpc::web_socket_handler)
+            .post("/api/v1/graphql", graphql::graphql_handler);
+        // Mount the Esplora-compatible API if enabled.
+        let builder = match &self.db.config.esplora_path {
+            Some(path) => builder.scope(path, EsploraServer::router(self.db.clone())),
+            None => builder,
+        };
+        let router = builder
             .any(|_req| async {
                 Ok(Self::not_found("invalid URL."))
             })
             .middleware(Middleware::post_with_info(|res, req_info| async move {
+                let res = if Self::is_rest_path(req_info.uri().path()) && ResponseFormat::from_accept(req_info.headers()) == ResponseFormat::Cbor {
+                    Self::error_to_cbor(res)
+                } else {
+                    res
+                };
                 let begin = req_info.context::<Instant>().unwrap();
                 println!("HTTP: {} {} {} ({}ms)",
                     req_info.method(), req_info.uri().path(), res.status(), begin.elapsed().as_millis());
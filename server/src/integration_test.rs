@@ -144,10 +144,114 @@ impl MockBitcoinCoreRpc {
             let tx: bitcoin::Transaction = consensus_decode(&hex::decode(&values[0]).unwrap());
             Ok(Value::String(tx.txid().to_string()))
         });
+        io.add_sync_method("getnetworkinfo", |_params: Params| {
+            Ok(serde_json::json!({ "relayfee": 0.00001 }))
+        });
+        // Estimates only for the targets up to 25 blocks.
+        io.add_sync_method("estimatesmartfee", |params: Params| {
+            let values: Vec<Value> = params.parse().unwrap();
+            let target = values[0].as_u64().unwrap();
+            if target <= 25 {
+                Ok(serde_json::json!({ "feerate": 0.00012345, "blocks": target }))
+            } else {
+                Ok(serde_json::json!({ "errors": ["Insufficient data or no feerate found"], "blocks": 0 }))
+            }
+        });
         ServerBuilder::new(io).start_http(&"127.0.0.1:18444".parse().unwrap()).unwrap().wait();
     }
 }
 
+/// Send a request to the Esplora-compatible API and returns the status code and the body.
+async fn esplora(config: &Config, method: hyper::Method, path: &str, body: &str) -> (hyper::StatusCode, String) {
+    let (status, body) = esplora_bytes(config, method, path, body).await;
+    (status, String::from_utf8(body).unwrap())
+}
+
+async fn esplora_bytes(config: &Config, method: hyper::Method, path: &str, body: &str) -> (hyper::StatusCode, Vec<u8>) {
+    let req = Request::builder()
+        .method(method)
+        .uri(format!("http://{}:{}/esplora{}", config.http_ip, config.http_port, path))
+        .body(Body::from(body.to_string()))
+        .unwrap();
+    let res = hyper::Client::new().request(req).await.unwrap();
+    let status = res.status();
+    let body = hyper::body::to_bytes(res.into_body()).await.unwrap();
+    (status, body.to_vec())
+}
+
+async fn esplora_get(config: &Config, path: &str) -> (hyper::StatusCode, String) {
+    esplora(config, hyper::Method::GET, path, "").await
+}
+
+/// Send a GET request to the REST API with the `Accept` header and returns the status code, the content type and the body.
+async fn rest_get(config: &Config, path: &str, accept: &str) -> (hyper::StatusCode, String, Vec<u8>) {
+    let req = Request::builder()
+        .uri(format!("http://{}:{}/api/v1{}", config.http_ip, config.http_port, path))
+        .header(hyper::header::ACCEPT, accept)
+        .body(Body::empty())
+        .unwrap();
+    let res = hyper::Client::new().request(req).await.unwrap();
+    let status = res.status();
+    let content_type = res.headers()[hyper::header::CONTENT_TYPE].to_str().unwrap().to_string();
+    let body = hyper::body::to_bytes(res.into_body()).await.unwrap();
+    (status, content_type, body.to_vec())
+}
+
+/// Send a JSON-RPC request over HTTP and returns the status code and the body.
+async fn json_rpc(config: &Config, body: &str) -> (hyper::StatusCode, String) {
+    let req = Request::builder()
+        .method(hyper::Method::POST)
+        .uri(format!("http://{}:{}/api/v1/jsonrpc", config.http_ip, config.http_port))
+        .body(Body::from(body.to_string()))
+        .unwrap();
+    let res = hyper::Client::new().request(req).await.unwrap();
+    let status = res.status();
+    let body = hyper::body::to_bytes(res.into_body()).await.unwrap();
+    (status, String::from_utf8(body.to_vec()).unwrap())
+}
+
+/// Send a GraphQL query and returns the response.
+async fn graphql(config: &Config, query: &str) -> Value {
+    let req = Request::builder()
+        .method(hyper::Method::POST)
+        .uri(format!("http://{}:{}/api/v1/graphql", config.http_ip, config.http_port))
+        .body(Body::from(serde_json::json!({ "query": query }).to_string()))
+        .unwrap();
+    let res = hyper::Client::new().request(req).await.unwrap();
+    assert_eq!(res.status(), hyper::StatusCode::OK);
+    serde_json::from_slice(&hyper::body::to_bytes(res.into_body()).await.unwrap()).unwrap()
+}
+
+/// Call a unary method of the gRPC service.
+async fn grpc<Req, Res>(config: &Config, method: &'static str, request: Req) -> Result<Res, tonic::Status>
+    where Req: prost::Message + Send + Sync + 'static,
+          Res: prost::Message + Default + Send + Sync + 'static,
+{
+    let mut client = grpc_client(config).await;
+    let path = tonic::codegen::http::uri::PathAndQuery::from_static(method);
+    client.unary(tonic::Request::new(request), path, tonic::codec::ProstCodec::default()).await.map(|res| res.into_inner())
+}
+
+/// Connect to the gRPC service.
+async fn grpc_client(config: &Config) -> tonic::client::Grpc<tonic::transport::Channel> {
+    let endpoint = format!("http://{}", config.grpc_endpoint.as_ref().unwrap());
+    let channel = tonic::transport::Channel::from_shared(endpoint).unwrap().connect().await.unwrap();
+    let mut client = tonic::client::Grpc::new(channel);
+    client.ready().await.unwrap();
+    client
+}
+
+/// Send a line of request to the Electrum server and returns the parsed response.
+async fn electrum(
+    lines: &mut tokio::io::Lines<tokio::io::BufReader<tokio::net::tcp::OwnedReadHalf>>,
+    write: &mut tokio::net::tcp::OwnedWriteHalf,
+    request: Value,
+) -> Value {
+    use tokio::io::AsyncWriteExt;
+    write.write_all(format!("{}\n", request).as_bytes()).await.unwrap();
+    serde_json::from_str(&lines.next_line().await.unwrap().unwrap()).unwrap()
+}
+
 /// Remove `~/.chainseeker/integration` directory
 fn cleanup() {
     let path = format!("{}/{}", data_dir(), COIN);
@@ -166,6 +270,10 @@ async fn integration_test() {
     config.genesis_block_hash = blocks[0].block_hash();
     config.rpc_endpoint = "http://127.0.0.1:18444".to_string();
     config.zmq_endpoint = "tcp://localhost:4444".to_string();
+    config.rich_list_snapshot_interval = 1;
+    config.esplora_path = Some("/esplora".to_string());
+    config.electrum_endpoint = Some("127.0.0.1:50001".to_string());
+    config.grpc_endpoint = Some("127.0.0.1:50051".to_string());
     // Launch MockBitcoinCoreRest.
     let mut rest = MockBitcoinCoreRest::default();
     {
@@ -233,6 +341,13 @@ async fn integration_test() {
     //
     // Fetch transaction (success).
     assert_eq!(client.tx(&txid.to_string()).await.unwrap().txid, txid.to_string());
+    assert_eq!(client.tx(&txid.to_string()).await.unwrap().vin[0].input_type.as_deref(), Some("coinbase"));
+    let tx = client.tx(&txid.to_string()).await.unwrap();
+    assert!(tx.coinbase);
+    assert_eq!(tx.fee, None);
+    assert_eq!(tx.block_hash, Some(blocks[0].block_hash().to_string()));
+    assert_eq!(tx.confirmations, Some(client.status().await.unwrap().blocks as u32 + 1 - tx.confirmed_height.unwrap()));
+    assert_eq!(tx.counterparty, None);
     // Fetch transaction (fail).
     assert!(client.tx(NOT_FOUND_ID).await.is_err());
     //
@@ -257,6 +372,30 @@ async fn integration_test() {
     // Fetch invalid block header.
     assert!(client.block_header("invalid").await.is_err());
     //
+    // Fetch block stats (success).
+    let block_stats = client.block_stats(&best_block_hash).await.unwrap();
+    assert_eq!(block_stats.height, (blocks.len() - 1) as u32);
+    assert_eq!(block_stats.txs, reorged_block.txdata.len() as u32);
+    assert_eq!(client.block_stats(0u32).await.unwrap().hash, blocks[0].block_hash().to_string());
+    // Mining pool attribution (regtest blocks are not mined by any known pool).
+    let block_pool = client.block_header(&best_block_hash).await.unwrap().pool.unwrap();
+    assert_eq!(block_pool.name, None);
+    assert_eq!(block_pool.payout_address, script_to_address_string(&reorged_block.txdata[0].output[0].script_pubkey, &config));
+    assert!(client.block_with_txids(0u32).await.unwrap().pool.is_some());
+    assert!(client.block_summary(0, 1).await.unwrap()[0].pool.is_some());
+    // Fetch pool statistics.
+    let pools = client.pools(None, None).await.unwrap();
+    assert_eq!(pools.to, (blocks.len() - 1) as u32);
+    assert_eq!(pools.blocks, 144.min(blocks.len() as u32));
+    let pools = client.pools(Some(0), Some(1_000_000)).await.unwrap();
+    assert_eq!(pools.blocks, blocks.len() as u32);
+    assert_eq!(pools.pools.len(), 1);
+    assert_eq!(pools.pools[0].name, "Unknown");
+    assert_eq!(pools.pools[0].share, 1.0);
+    assert!(client.pools(Some(10), Some(9)).await.is_err());
+    // Fetch block stats (not found).
+    assert!(client.block_stats(NOT_FOUND_ID).await.is_err());
+    //
     // Fetch transactions (success).
     assert!(!client.txs(&address).await.unwrap().is_empty());
     // Fetch transactions (fail).
@@ -266,21 +405,88 @@ async fn integration_test() {
     assert!(!client.txids(&address).await.unwrap().is_empty());
     // Fetch txids (fail).
     assert!(client.txids(INVALID_ADDRESS).await.is_err());
+    // Fetch txids with pagination (success).
+    let txids = client.txids_page(&address, None, 1000).await.unwrap();
+    assert!(txids.len() > 100);
+    // The first page is limited to 100 txids by default.
+    assert_eq!(client.txids(&address).await.unwrap(), txids[..100]);
+    let first_page = client.txids_page(&address, None, 1).await.unwrap();
+    assert_eq!(first_page, txids[0..1]);
+    assert_eq!(client.txids_page(&address, Some(&first_page[0]), txids.len()).await.unwrap(), txids[1..]);
+    assert_eq!(client.txs_page(&address, Some(&first_page[0]), 1).await.unwrap()[0].txid, txids[1]);
+    // Fetch txids with pagination (invalid limit).
+    assert!(client.get::<Vec<String>>(&format!("txids/{}?limit=invalid", address)).await.is_err());
+    assert!(client.get::<Vec<String>>(&format!("txs/{}?limit=1001", address)).await.is_err());
+    // Fetch txids with pagination (cursor not found).
+    assert!(client.txids_page(&address, Some(NOT_FOUND_ID), 1).await.is_err());
     //
     // Fetch utxos (success).
-    assert!(!client.utxos(&address).await.unwrap().is_empty());
+    let utxos = client.utxos(&address).await.unwrap();
+    assert!(!utxos.is_empty());
+    assert!(utxos.iter().any(|utxo| utxo.coinbase));
+    for utxo in utxos.iter() {
+        assert!(utxo.confirmations > 0);
+        assert_eq!(utxo.height + utxo.confirmations, utxos[0].height + utxos[0].confirmations);
+    }
     // Fetch txids (fail).
     assert!(client.utxos(INVALID_ADDRESS).await.is_err());
+    // Fetch utxos (fail, an address of another network).
+    assert!(client.utxos("bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4").await.is_err());
+    //
+    // Fetch OP_RETURN outputs (success).
+    let op_returns = client.op_return("aa21a9ed").await.unwrap();
+    assert!(op_returns.len() > 1);
+    assert!(op_returns.iter().all(|op_return| op_return.data.starts_with("aa21a9ed")));
+    assert_eq!(op_returns[0].protocol.as_deref(), Some("witness_commitment"));
+    // Fetch OP_RETURN outputs with pagination (success).
+    let first_page = client.op_return_page("aa21a9ed", None, 1).await.unwrap();
+    assert_eq!(first_page, op_returns[0..1]);
+    let after = format!("{}:{}", first_page[0].txid, first_page[0].vout);
+    assert_eq!(client.op_return_page("aa21a9ed", Some(&after), 1).await.unwrap(), op_returns[1..2]);
+    // Fetch OP_RETURN outputs (fail).
+    assert!(client.op_return("xx").await.is_err());
+    assert!(client.op_return_page("aa21a9ed", Some(NOT_FOUND_ID), 1).await.is_err());
+    //
+    // Fetch Counterparty transactions (none on regtest).
+    assert!(client.counterparty_txids("XCP").await.unwrap().is_empty());
+    assert!(client.counterparty_txids_page("PEPECASH", None, 10).await.unwrap().is_empty());
+    // Fetch Counterparty transactions (fail).
+    assert!(client.counterparty_txids("pepecash").await.is_err());
+    assert!(client.counterparty_txids_page("XCP", Some(NOT_FOUND_ID), 1).await.is_err());
     //
     // Fetch block summary (success).
     assert_eq!(client.block_summary(0, blocks.len() as u32).await.unwrap().len(), blocks.len());
     // Fetch block summary (success, again from cache).
     assert_eq!(client.block_summary(0, blocks.len() as u32 + 100).await.unwrap().len(), blocks.len());
+    // Block summaries include block stats.
+    assert_eq!(client.block_summary(1, 1).await.unwrap()[0].stats.as_ref().unwrap().txs, 1);
     // Fetch block summary (invalid offset).
     assert!(client.get::<Vec<chainseeker::BlockSummary>>("block_summary/invalid/3").await.is_err());
     // Fetch block summary (invalid limit).
     assert!(client.get::<Vec<chainseeker::BlockSummary>>("block_summary/0/invalid").await.is_err());
     //
+    // Fetch UTXO set info (should match the statistics computed without reorg).
+    let mut utxo_db = crate::db::UtxoDB::new(&format!("{}/utxo_set_info", COIN), true);
+    for (height, block) in blocks[..blocks.len() - 1].iter().enumerate() {
+        utxo_db.process_block(height as u32, block, false);
+    }
+    utxo_db.process_block((blocks.len() - 1) as u32, &reorged_block, false);
+    let utxo_set_info = client.utxo_set_info(None).await.unwrap();
+    assert_eq!(utxo_set_info, create_utxo_set_info(&crate::db::block::BlockContentDBValue::new((blocks.len() - 1) as u32, &reorged_block), &utxo_db.stats));
+    assert_eq!(utxo_set_info.bestblock, best_block_hash);
+    assert_eq!(client.utxo_set_info(Some(0)).await.unwrap().txouts, 0);
+    // Fetch UTXO set info (not found).
+    assert!(client.utxo_set_info(Some(blocks.len() as u32)).await.is_err());
+    // Fetch supply (the circulating supply should match the total amount of the UTXO set).
+    let supply = client.supply(None).await.unwrap();
+    assert_eq!(supply.height, (blocks.len() - 1) as u32);
+    assert_eq!(supply.circulating, utxo_set_info.total_amount);
+    assert_eq!(supply.total_subsidy, blocks.len() as u64 * 5_000_000_000);
+    assert_eq!(client.supply(Some(0)).await.unwrap().unspendables.genesis_block, 5_000_000_000);
+    // Fetch supply (not found).
+    assert!(client.supply(Some(blocks.len() as u32)).await.is_err());
+    // Fetch supply (invalid height).
+    assert!(client.get::<chainseeker::Supply>("supply/invalid").await.is_err());
     // Fetch rich list count.
     assert!(client.rich_list_count().await.unwrap().count > 0);
     // Fetch address rank (success).
@@ -295,6 +501,48 @@ async fn integration_test() {
     assert!(client.get::<Vec<Option<chainseeker::RichListEntry>>>("rich_list/invalid/3").await.is_err());
     // Fetch rich list (invalid limit).
     assert!(client.get::<Vec<Option<chainseeker::RichListEntry>>>("rich_list/0/invalid").await.is_err());
+    // Fetch filtered rich list (success).
+    let filter = chainseeker::RichListFilter {
+        script_type: Some("witnesspubkeyhash".to_string()),
+        address_only: true,
+        exclude_burn: true,
+    };
+    let count = client.rich_list_count_filtered(&filter).await.unwrap().count;
+    assert!(count > 0 && count < client.rich_list_count().await.unwrap().count);
+    let entries = client.rich_list_filtered(0, count, &filter).await.unwrap();
+    assert!(entries.iter().all(|entry| entry.as_ref().unwrap().script_pub_key.r#type == "witnesspubkeyhash"));
+    assert!(client.rich_list_addr_rank_filtered(&address, &filter).await.unwrap().rank <= client.rich_list_addr_rank(&address).await.unwrap().rank);
+    // Fetch filtered rich list (invalid type).
+    assert!(client.get::<Vec<Option<chainseeker::RichListEntry>>>("rich_list/0/3?type=invalid").await.is_err());
+    // Fetch filtered rich list (invalid boolean).
+    assert!(client.get::<chainseeker::RichListCount>("rich_list_count?exclude_burn=invalid").await.is_err());
+    // Fetch rich list snapshot (success).
+    let synced_height = (blocks.len() - 1) as u32;
+    assert_eq!(client.rich_list_at(synced_height, 0, 3).await.unwrap(), client.rich_list(0, 3).await.unwrap());
+    // Fetch rich list snapshot (not found).
+    assert!(client.rich_list_at(synced_height - 1, 0, 3).await.is_err());
+    // Fetch rich list snapshot (too large limit).
+    assert!(client.rich_list_at(synced_height, 0, 1001).await.is_err());
+    // Fetch rich list snapshot (invalid height).
+    assert!(client.get::<Vec<Option<chainseeker::RichListEntry>>>("rich_list_at/invalid/0/3").await.is_err());
+    // Fetch rich list distribution (success).
+    let distribution = client.rich_list_distribution(synced_height).await.unwrap();
+    assert_eq!(distribution.count, client.rich_list_count().await.unwrap().count);
+    assert_eq!(distribution.buckets.iter().map(|bucket| bucket.count).sum::<u32>(), distribution.count);
+    // Fetch rich list distribution (not found).
+    assert!(client.rich_list_distribution(synced_height - 1).await.is_err());
+    // Fetch address rank history (success).
+    let history = client.rich_list_addr_rank_history(&address, None, None).await.unwrap();
+    assert_eq!(history, vec![chainseeker::RichListRankHistoryEntry {
+        height: synced_height,
+        rank: Some(client.rich_list_addr_rank(&address).await.unwrap().rank),
+        value: history[0].value,
+    }]);
+    assert!(client.rich_list_addr_rank_history(&address, Some(0), Some(synced_height - 1)).await.unwrap().is_empty());
+    // Fetch address rank history (not in the rich list).
+    assert_eq!(client.rich_list_addr_rank_history(NOT_FOUND_ADDRESS, None, None).await.unwrap()[0].rank, None);
+    // Fetch address rank history (invalid range).
+    assert!(client.rich_list_addr_rank_history(&address, Some(1), Some(0)).await.is_err());
     //
     // Put transaction.
     let secp256k1 = bitcoin::secp256k1::Secp256k1::new();
@@ -309,5 +557,398 @@ async fn integration_test() {
     println!("Txid: {}", tx.txid());
     let tx_hex = hex::encode(consensus_encode(&tx));
     println!("Tx: {}", tx_hex);
-    assert_eq!(client.put_tx(tx_hex).await.unwrap().txid, tx.txid().to_string());
+    assert_eq!(client.put_tx(tx_hex.clone()).await.unwrap().txid, tx.txid().to_string());
+    //
+    // Esplora-compatible API.
+    use hyper::StatusCode;
+    use crate::esplora::*;
+    assert_eq!(esplora_get(&config, "/blocks/tip/height").await, (StatusCode::OK, (blocks.len() - 1).to_string()));
+    assert_eq!(esplora_get(&config, "/blocks/tip/hash").await, (StatusCode::OK, best_block_hash.clone()));
+    assert_eq!(esplora_get(&config, "/block-height/0").await, (StatusCode::OK, blocks[0].block_hash().to_string()));
+    let (status, body) = esplora_get(&config, "/blocks").await;
+    assert_eq!(status, StatusCode::OK);
+    let esplora_blocks: Vec<EsploraBlock> = serde_json::from_str(&body).unwrap();
+    assert_eq!(esplora_blocks.len(), 10);
+    assert_eq!(esplora_blocks[0].id, best_block_hash);
+    assert_eq!(esplora_blocks[1].id, blocks[blocks.len() - 2].block_hash().to_string());
+    let (status, body) = esplora_get(&config, &format!("/block/{}", best_block_hash)).await;
+    assert_eq!(status, StatusCode::OK);
+    let esplora_block: EsploraBlock = serde_json::from_str(&body).unwrap();
+    assert_eq!(esplora_block, esplora_blocks[0]);
+    let (_status, body) = esplora_get(&config, &format!("/block/{}/status", blocks[1].block_hash())).await;
+    assert_eq!(serde_json::from_str::<EsploraBlockStatus>(&body).unwrap(), EsploraBlockStatus {
+        in_best_chain: true,
+        height: Some(1),
+        next_best: Some(blocks[2].block_hash().to_string()),
+    });
+    assert_eq!(esplora_get(&config, &format!("/block/{}/header", best_block_hash)).await.1, hex::encode(consensus_encode(&reorged_block.header)));
+    let (_status, body) = esplora_get(&config, &format!("/block/{}/txids", best_block_hash)).await;
+    assert_eq!(serde_json::from_str::<Vec<String>>(&body).unwrap(), reorged_block.txdata.iter().map(|tx| tx.txid().to_string()).collect::<Vec<String>>());
+    assert_eq!(esplora_get(&config, &format!("/block/{}/txid/0", best_block_hash)).await.1, reorged_block.txdata[0].txid().to_string());
+    let (_status, body) = esplora_get(&config, &format!("/block/{}/txs", best_block_hash)).await;
+    assert_eq!(serde_json::from_str::<Vec<EsploraTransaction>>(&body).unwrap().len(), reorged_block.txdata.len());
+    assert_eq!(esplora_get(&config, &format!("/block/{}/txs/1", best_block_hash)).await.0, StatusCode::BAD_REQUEST);
+    assert_eq!(esplora_get(&config, &format!("/block/{}", NOT_FOUND_ID)).await.0, StatusCode::NOT_FOUND);
+    let (_status, body) = esplora_get(&config, &format!("/tx/{}", txid)).await;
+    let esplora_tx: EsploraTransaction = serde_json::from_str(&body).unwrap();
+    assert_eq!(esplora_tx.txid, txid.to_string());
+    assert!(esplora_tx.vin[0].is_coinbase);
+    let (_status, body) = esplora_get(&config, &format!("/tx/{}/status", txid)).await;
+    assert_eq!(serde_json::from_str::<EsploraTxStatus>(&body).unwrap(), esplora_tx.status);
+    assert_eq!(esplora_get(&config, &format!("/tx/{}/hex", txid)).await.1, hex::encode(consensus_encode(&blocks[0].txdata[0])));
+    assert_eq!(esplora_bytes(&config, hyper::Method::GET, &format!("/tx/{}/raw", txid), "").await.1, consensus_encode(&blocks[0].txdata[0]));
+    let reorged_txid = reorged_block.txdata[0].txid();
+    let (_status, body) = esplora_get(&config, &format!("/tx/{}/merkle-proof", reorged_txid)).await;
+    let reorged_txids = reorged_block.txdata.iter().map(|tx| tx.txid()).collect::<Vec<bitcoin::Txid>>();
+    assert_eq!(serde_json::from_str::<EsploraMerkleProof>(&body).unwrap(), EsploraMerkleProof {
+        block_height: (blocks.len() - 1) as u32,
+        merkle: merkle_branch(&reorged_txids, 0).iter().map(|hash| hash.to_string()).collect(),
+        pos: 0,
+    });
+    assert_eq!(esplora_get(&config, &format!("/tx/{}/outspends", txid)).await.0, StatusCode::NOT_FOUND);
+    let (status, body) = esplora_get(&config, "/fee-estimates").await;
+    assert_eq!(status, StatusCode::OK);
+    let fee_estimates: std::collections::HashMap<String, f64> = serde_json::from_str(&body).unwrap();
+    assert_eq!(fee_estimates.len(), 25);
+    assert!((fee_estimates["1"] - 12.345).abs() < 1e-9);
+    assert_eq!(esplora_get(&config, &format!("/tx/{}", NOT_FOUND_ID)).await.0, StatusCode::NOT_FOUND);
+    assert_eq!(esplora_get(&config, &format!("/tx/{}", INVALID_ID)).await.0, StatusCode::BAD_REQUEST);
+    assert_eq!(esplora(&config, hyper::Method::POST, "/tx", &tx_hex).await, (StatusCode::OK, tx.txid().to_string()));
+    let (_status, body) = esplora_get(&config, &format!("/address/{}", address)).await;
+    let esplora_address: EsploraAddress = serde_json::from_str(&body).unwrap();
+    assert!(esplora_address.chain_stats.tx_count > 0);
+    let scripthash = blocks.last().unwrap().txdata[0].output[0].script_pubkey.wscript_hash();
+    let (_status, body) = esplora_get(&config, &format!("/scripthash/{}", scripthash)).await;
+    assert_eq!(serde_json::from_str::<EsploraAddress>(&body).unwrap(), EsploraAddress {
+        address: None,
+        scripthash: Some(scripthash.to_string()),
+        ..esplora_address.clone()
+    });
+    let (_status, body) = esplora_get(&config, &format!("/address/{}/txs", address)).await;
+    let address_txs: Vec<EsploraTransaction> = serde_json::from_str(&body).unwrap();
+    assert!(!address_txs.is_empty() && address_txs.len() <= 25);
+    let (_status, body) = esplora_get(&config, &format!("/address/{}/txs/chain", address)).await;
+    assert_eq!(serde_json::from_str::<Vec<EsploraTransaction>>(&body).unwrap(), address_txs);
+    let (_status, body) = esplora_get(&config, &format!("/scripthash/{}/txs", scripthash)).await;
+    assert_eq!(serde_json::from_str::<Vec<EsploraTransaction>>(&body).unwrap(), address_txs);
+    let (_status, body) = esplora_get(&config, &format!("/address/{}/txs/chain/{}", address, address_txs[0].txid)).await;
+    let next_page: Vec<EsploraTransaction> = serde_json::from_str(&body).unwrap();
+    assert_eq!(next_page[..address_txs.len() - 1], address_txs[1..]);
+    let (_status, body) = esplora_get(&config, &format!("/address/{}/txs/mempool", address)).await;
+    assert!(serde_json::from_str::<Vec<EsploraTransaction>>(&body).unwrap().is_empty());
+    let (status, body) = esplora_get(&config, &format!("/address/{}/utxo", address)).await;
+    assert_eq!(status, StatusCode::OK);
+    let utxos: Vec<EsploraUtxo> = serde_json::from_str(&body).unwrap();
+    let (_status, body) = esplora_get(&config, &format!("/scripthash/{}/utxo", scripthash)).await;
+    assert_eq!(serde_json::from_str::<Vec<EsploraUtxo>>(&body).unwrap(), utxos);
+    assert_eq!(esplora_get(&config, "/scripthash/zz").await.0, StatusCode::BAD_REQUEST);
+    assert_eq!(esplora_get(&config, &format!("/address/{}", INVALID_ADDRESS)).await.0, StatusCode::BAD_REQUEST);
+    //
+    // Electrum protocol.
+    use serde_json::json;
+    use tokio::io::AsyncBufReadExt;
+    let stream = tokio::net::TcpStream::connect(config.electrum_endpoint.as_ref().unwrap()).await.unwrap();
+    let (read, mut write) = stream.into_split();
+    let mut lines = tokio::io::BufReader::new(read).lines();
+    let scripthash = crate::electrum::encode_scripthash(&blocks.last().unwrap().txdata[0].output[0].script_pubkey.wscript_hash());
+    let res = electrum(&mut lines, &mut write, json!({ "id": 0, "method": "server.version", "params": ["test", "1.4"] })).await;
+    assert_eq!(res["id"], 0);
+    assert_eq!(res["result"][1], "1.4");
+    let res = electrum(&mut lines, &mut write, json!({ "id": 1, "method": "blockchain.headers.subscribe", "params": [] })).await;
+    assert_eq!(res["result"], json!({ "height": blocks.len() - 1, "hex": hex::encode(consensus_encode(&reorged_block.header)) }));
+    let res = electrum(&mut lines, &mut write, json!({ "id": 2, "method": "blockchain.scripthash.get_history", "params": [scripthash] })).await;
+    let history = res["result"].as_array().unwrap().clone();
+    assert_eq!(history.len(), esplora_address.chain_stats.tx_count as usize);
+    assert!(history.windows(2).all(|entries| entries[0]["height"].as_u64() <= entries[1]["height"].as_u64()));
+    for entries in history.windows(2).filter(|entries| entries[0]["height"] == entries[1]["height"]) {
+        let txids = client.block_with_txids(entries[0]["height"].as_u64().unwrap()).await.unwrap().txids;
+        let position = |entry: &serde_json::Value| txids.iter().position(|txid| entry["tx_hash"] == *txid);
+        assert!(position(&entries[0]) < position(&entries[1]));
+    }
+    let res = electrum(&mut lines, &mut write, json!({ "id": 3, "method": "blockchain.scripthash.get_balance", "params": [scripthash] })).await;
+    assert_eq!(res["result"], json!({
+        "confirmed": esplora_address.chain_stats.funded_txo_sum - esplora_address.chain_stats.spent_txo_sum,
+        "unconfirmed": 0,
+    }));
+    let res = electrum(&mut lines, &mut write, json!({ "id": 4, "method": "blockchain.scripthash.listunspent", "params": [scripthash] })).await;
+    assert_eq!(
+        res["result"].as_array().unwrap().iter().map(|utxo| utxo["value"].as_u64().unwrap()).sum::<u64>(),
+        esplora_address.chain_stats.funded_txo_sum - esplora_address.chain_stats.spent_txo_sum);
+    let res = electrum(&mut lines, &mut write, json!({ "id": 5, "method": "blockchain.scripthash.subscribe", "params": [scripthash] })).await;
+    let history = history.iter().map(|entry| serde_json::from_value(entry.clone()).unwrap()).collect::<Vec<_>>();
+    assert_eq!(res["result"], json!(crate::electrum::history_status(&history)));
+    let res = electrum(&mut lines, &mut write, json!({ "id": 6, "method": "blockchain.scripthash.get_history", "params": ["xx"] })).await;
+    assert_eq!(res["error"]["code"], 1);
+    let res = electrum(&mut lines, &mut write, json!({ "id": 7, "method": "blockchain.transaction.get", "params": [txid.to_string()] })).await;
+    assert_eq!(res["result"], hex::encode(consensus_encode(&blocks[0].txdata[0])));
+    let res = electrum(&mut lines, &mut write, json!({ "id": 8, "method": "blockchain.transaction.broadcast", "params": [tx_hex] })).await;
+    assert_eq!(res["result"], tx.txid().to_string());
+    let res = electrum(&mut lines, &mut write, json!({ "id": 9, "method": "no.such.method", "params": [] })).await;
+    assert_eq!(res["error"]["code"], -32601);
+    // Batch requests.
+    let res = electrum(&mut lines, &mut write, json!([
+        { "id": 10, "method": "server.ping", "params": [] },
+        { "id": 11, "method": "blockchain.block.header", "params": [0] },
+    ])).await;
+    assert_eq!(res, json!([
+        { "jsonrpc": "2.0", "id": 10, "result": null },
+        { "jsonrpc": "2.0", "id": 11, "result": { "height": 0, "hex": hex::encode(consensus_encode(&blocks[0].header)) } },
+    ]));
+    let res = electrum(&mut lines, &mut write, json!({ "id": 12, "method": "server.features", "params": [] })).await;
+    assert_eq!(res["result"]["genesis_hash"], blocks[0].block_hash().to_string());
+    let res = electrum(&mut lines, &mut write, json!({ "id": 13, "method": "blockchain.block.headers", "params": [1, 3] })).await;
+    assert_eq!(res["result"], json!({
+        "count": 3,
+        "hex": blocks[1..4].iter().map(|block| hex::encode(consensus_encode(&block.header))).collect::<String>(),
+        "max": 2016,
+    }));
+    let res = electrum(&mut lines, &mut write, json!({ "id": 14, "method": "blockchain.block.headers", "params": [blocks.len() - 1, 3] })).await;
+    assert_eq!(res["result"]["count"], 1);
+    let res = electrum(&mut lines, &mut write, json!({
+        "id": 15,
+        "method": "blockchain.transaction.get_merkle",
+        "params": [reorged_txid.to_string(), blocks.len() - 1],
+    })).await;
+    assert_eq!(res["result"], json!({
+        "block_height": blocks.len() - 1,
+        "merkle": merkle_branch(&reorged_txids, 0).iter().map(|hash| hash.to_string()).collect::<Vec<String>>(),
+        "pos": 0,
+    }));
+    let res = electrum(&mut lines, &mut write, json!({ "id": 16, "method": "blockchain.transaction.get_merkle", "params": [reorged_txid.to_string(), 0] })).await;
+    assert_eq!(res["error"]["code"], 1);
+    let res = electrum(&mut lines, &mut write, json!({ "id": 17, "method": "blockchain.estimatefee", "params": [6] })).await;
+    assert_eq!(res["result"], 0.00012345);
+    let res = electrum(&mut lines, &mut write, json!({ "id": 18, "method": "blockchain.estimatefee", "params": [144] })).await;
+    assert_eq!(res["result"], -1.0);
+    let res = electrum(&mut lines, &mut write, json!({ "id": 19, "method": "blockchain.relayfee", "params": [] })).await;
+    assert_eq!(res["result"], 0.00001);
+    // Too long requests close the connection (the server reads one byte more than the limit).
+    let long_request = " ".repeat(1_000_001);
+    tokio::io::AsyncWriteExt::write_all(&mut write, long_request.as_bytes()).await.unwrap();
+    let res: Value = serde_json::from_str(&lines.next_line().await.unwrap().unwrap()).unwrap();
+    assert_eq!(res["error"]["message"], "request too large");
+    assert_eq!(lines.next_line().await.unwrap(), None);
+    //
+    // JSON-RPC.
+    let call = |request: Value| {
+        let config = config.clone();
+        async move {
+            let (status, body) = json_rpc(&config, &request.to_string()).await;
+            assert_eq!(status, StatusCode::OK);
+            serde_json::from_str::<Value>(&body).unwrap()
+        }
+    };
+    let res = call(json!({ "jsonrpc": "2.0", "id": 1, "method": "getStatus" })).await;
+    assert_eq!(res, json!({ "jsonrpc": "2.0", "id": 1, "result": { "blocks": blocks.len() - 1 } }));
+    let res = call(json!({ "jsonrpc": "2.0", "id": "a", "method": "getTx", "params": [txid.to_string()] })).await;
+    assert_eq!(res["id"], "a");
+    assert_eq!(serde_json::from_value::<chainseeker::Transaction>(res["result"].clone()).unwrap(), client.tx(&txid.to_string()).await.unwrap());
+    let res = call(json!({ "jsonrpc": "2.0", "id": 2, "method": "getBlock", "params": { "hashOrHeight": 1 } })).await;
+    assert_eq!(serde_json::from_value::<chainseeker::BlockHeader>(res["result"].clone()).unwrap(), client.block_header(1).await.unwrap());
+    let res = call(json!({ "jsonrpc": "2.0", "id": 3, "method": "getUtxos", "params": { "scriptOrAddress": address } })).await;
+    assert_eq!(serde_json::from_value::<Vec<chainseeker::Utxo>>(res["result"].clone()).unwrap(), client.utxos(&address).await.unwrap());
+    let res = call(json!({ "jsonrpc": "2.0", "id": 4, "method": "getRichList", "params": [0, 5] })).await;
+    assert_eq!(serde_json::from_value::<Vec<Option<chainseeker::RichListEntry>>>(res["result"].clone()).unwrap(), client.rich_list(0, 5).await.unwrap());
+    let res = call(json!({ "jsonrpc": "2.0", "id": 5, "method": "getRawBlock", "params": [1] })).await;
+    assert_eq!(res["result"], hex::encode(consensus_encode(&blocks[1])));
+    let res = call(json!({ "jsonrpc": "2.0", "id": 5, "method": "broadcastTx", "params": [tx_hex] })).await;
+    assert_eq!(res["result"], json!({ "txid": tx.txid().to_string() }));
+    // Errors.
+    assert_eq!(call(json!({ "jsonrpc": "2.0", "id": 6, "method": "getNothing" })).await["error"]["code"], -32601);
+    assert_eq!(call(json!({ "jsonrpc": "2.0", "id": 7, "method": "getTx", "params": [1] })).await["error"]["code"], -32602);
+    assert_eq!(call(json!({ "jsonrpc": "2.0", "id": 8, "method": "getTx", "params": [INVALID_ID] })).await["error"]["code"], -32004);
+    let res = call(json!({ "jsonrpc": "2.0", "id": 9, "method": "getTx", "params": [NOT_FOUND_ID] })).await;
+    assert_eq!(res["error"]["code"], -32004);
+    assert_eq!(res["error"]["message"], "Transaction not found.");
+    // Quotes in the message are preserved.
+    let res = call(json!({ "jsonrpc": "2.0", "id": 10, "method": "getPools", "params": { "from": 5, "to": 1 } })).await;
+    assert_eq!(res["error"], json!({ "code": -32602, "message": "\"from\" should not be greater than \"to\"." }));
+    assert_eq!(call(json!({ "id": 10, "method": "getStatus" })).await["error"]["code"], -32600);
+    assert_eq!(json_rpc(&config, "{").await, (StatusCode::OK, json!({ "jsonrpc": "2.0", "id": null, "error": { "code": -32700, "message": "Failed to parse JSON." } }).to_string()));
+    // Batch requests (the notification gets no response).
+    let res = call(json!([
+        { "jsonrpc": "2.0", "id": 11, "method": "getStatus" },
+        { "jsonrpc": "2.0", "method": "getStatus" },
+        { "jsonrpc": "2.0", "id": 12, "method": "getSupply" },
+    ])).await;
+    assert_eq!(res.as_array().unwrap().len(), 2);
+    assert_eq!(res[0]["id"], 11);
+    assert_eq!(serde_json::from_value::<chainseeker::Supply>(res[1]["result"].clone()).unwrap(), client.supply(None).await.unwrap());
+    assert_eq!(json_rpc(&config, &json!({ "jsonrpc": "2.0", "method": "getStatus" }).to_string()).await, (StatusCode::NO_CONTENT, "".to_string()));
+    let batch = vec![json!({ "jsonrpc": "2.0", "id": 13, "method": "getStatus" }); crate::json_rpc::MAX_BATCH_SIZE + 1];
+    assert_eq!(call(json!(batch)).await["error"]["code"], -32600);
+    // WebSocket.
+    {
+        use futures_util::{StreamExt, SinkExt};
+        use tokio_tungstenite::tungstenite::Message;
+        let url = format!("ws://{}:{}/api/v1/jsonrpc", config.http_ip, config.http_port);
+        let (mut ws, _response) = tokio_tungstenite::connect_async(url).await.unwrap();
+        ws.send(Message::Text(json!({ "jsonrpc": "2.0", "id": 1, "method": "getStatus" }).to_string())).await.unwrap();
+        let res = match ws.next().await.unwrap().unwrap() {
+            Message::Text(text) => serde_json::from_str::<Value>(&text).unwrap(),
+            message => panic!("Unexpected message: {:?}", message),
+        };
+        assert_eq!(res, json!({ "jsonrpc": "2.0", "id": 1, "result": { "blocks": blocks.len() - 1 } }));
+    }
+    //
+    // GraphQL.
+    let res = graphql(&config, "{ tip { hash height previousBlock { hash } nextBlock { hash } } }").await;
+    assert_eq!(res["data"]["tip"], json!({
+        "hash": best_block_hash,
+        "height": blocks.len() - 1,
+        "previousBlock": { "hash": blocks[blocks.len() - 2].block_hash().to_string() },
+        "nextBlock": null,
+    }));
+    let res = graphql(&config, &format!(r#"{{
+        block(height: 1) {{ txCount txs(limit: 1) {{ txid coinbase fee inputs {{ coinbase value }} outputs {{ value address }} }} }}
+        transaction(txid: "{}") {{ confirmedHeight block {{ hash }} hex }}
+    }}"#, txid)).await;
+    assert!(res["errors"].is_null(), "{}", res);
+    let block = client.block_with_txs(1).await.unwrap();
+    assert_eq!(res["data"]["block"]["txCount"], block.txs.len());
+    let graphql_tx = &res["data"]["block"]["txs"][0];
+    assert_eq!(graphql_tx["txid"], block.txs[0].txid);
+    assert_eq!(graphql_tx["coinbase"], true);
+    assert_eq!(graphql_tx["fee"], Value::Null);
+    assert_eq!(graphql_tx["inputs"], json!([{ "coinbase": true, "value": null }]));
+    assert_eq!(
+        graphql_tx["outputs"],
+        json!(block.txs[0].vout.iter().map(|vout| json!({ "value": vout.value, "address": vout.script_pub_key.address })).collect::<Vec<_>>()));
+    assert_eq!(res["data"]["transaction"], json!({
+        "confirmedHeight": 0,
+        "block": { "hash": blocks[0].block_hash().to_string() },
+        "hex": hex::encode(consensus_encode(&blocks[0].txdata[0])),
+    }));
+    // Addresses with pagination.
+    let txids = client.txids_page(&address, None, 1000).await.unwrap();
+    let res = graphql(&config, &format!(r#"{{ address(address: "{}") {{ address balance txs(limit: 2) {{ txid }} utxos(limit: 100) {{ value }} }} }}"#, address)).await;
+    let graphql_address = &res["data"]["address"];
+    assert_eq!(graphql_address["address"], address);
+    assert_eq!(graphql_address["balance"], client.utxos(&address).await.unwrap().iter().map(|utxo| utxo.value).sum::<u64>());
+    assert_eq!(graphql_address["txs"], json!(txids[0..2].iter().map(|txid| json!({ "txid": txid })).collect::<Vec<_>>()));
+    let res = graphql(&config, &format!(r#"{{ address(address: "{}") {{ txs(after: "{}", limit: 2) {{ txid }} }} }}"#, address, txids[1])).await;
+    assert_eq!(res["data"]["address"]["txs"], json!(txids[2..4].iter().map(|txid| json!({ "txid": txid })).collect::<Vec<_>>()));
+    // Previous transactions of inputs.
+    let spending_tx = client.txs(&address).await.unwrap().into_iter().find(|tx| !tx.coinbase).unwrap();
+    let res = graphql(&config, &format!(r#"{{ transaction(txid: "{}") {{ inputs {{ previousTxid previousTransaction {{ txid }} }} }} }}"#, spending_tx.txid)).await;
+    for (input, vin) in res["data"]["transaction"]["inputs"].as_array().unwrap().iter().zip(spending_tx.vin.iter()) {
+        assert_eq!(input["previousTxid"], vin.txid);
+        assert_eq!(input["previousTransaction"]["txid"], vin.txid);
+    }
+    // Paging of outputs.
+    let res = graphql(&config, &format!(r#"{{ transaction(txid: "{}") {{ outputCount outputs(offset: 1) {{ vout }} }} }}"#, spending_tx.txid)).await;
+    assert_eq!(res["data"]["transaction"]["outputCount"], spending_tx.vout.len());
+    assert_eq!(res["data"]["transaction"]["outputs"], json!((1..spending_tx.vout.len()).map(|vout| json!({ "vout": vout })).collect::<Vec<_>>()));
+    // Errors.
+    assert_eq!(graphql(&config, &format!(r#"{{ address(address: "{}") {{ txs(limit: 1000) {{ txid }} }} }}"#, address)).await["errors"][0]["message"],
+        "\"limit\" should not be greater than 100.");
+    assert_eq!(graphql(&config, &format!(r#"{{ address(address: "{}") {{ balance }} }}"#, INVALID_ADDRESS)).await["data"], Value::Null);
+    assert_eq!(graphql(&config, "{ blocks(limit: 100) { txs(limit: 100) { txid hash } } }").await["errors"][0]["message"], "Query is too complex.");
+    //
+    // gRPC.
+    use grpc::proto;
+    let status: proto::GetStatusResponse = grpc(&config, "/chainseeker.Chainseeker/GetStatus", proto::GetStatusRequest {}).await.unwrap();
+    assert_eq!(status.blocks, (blocks.len() - 1) as i32);
+    let grpc_tx: proto::Transaction = grpc(&config, "/chainseeker.Chainseeker/GetTx", proto::GetTxRequest { txid: txid.to_string() }).await.unwrap();
+    assert_eq!(grpc_tx.txid, txid.to_string());
+    assert!(grpc_tx.coinbase);
+    assert_eq!(grpc_tx.fee, None);
+    assert_eq!(grpc_tx.confirmed_height, Some(0));
+    assert_eq!(grpc_tx.raw, consensus_encode(&blocks[0].txdata[0]));
+    let code = |res: Result<proto::Transaction, tonic::Status>| res.unwrap_err().code();
+    assert_eq!(code(grpc(&config, "/chainseeker.Chainseeker/GetTx", proto::GetTxRequest { txid: NOT_FOUND_ID.to_string() }).await), tonic::Code::NotFound);
+    assert_eq!(code(grpc(&config, "/chainseeker.Chainseeker/GetTx", proto::GetTxRequest { txid: INVALID_ID.to_string() }).await), tonic::Code::InvalidArgument);
+    // Blocks.
+    let get_block = |hash_or_height| proto::GetBlockRequest { hash_or_height: Some(hash_or_height) };
+    let grpc_block: proto::Block = grpc(&config, "/chainseeker.Chainseeker/GetBlock",
+        get_block(proto::get_block_request::HashOrHeight::Height((blocks.len() - 1) as u32))).await.unwrap();
+    assert_eq!(grpc_block.hash, best_block_hash);
+    assert_eq!(grpc_block.header, consensus_encode(&reorged_block.header));
+    assert_eq!(grpc_block.txids, reorged_block.txdata.iter().map(|tx| tx.txid().to_string()).collect::<Vec<_>>());
+    let grpc_block_by_hash: proto::Block = grpc(&config, "/chainseeker.Chainseeker/GetBlock",
+        get_block(proto::get_block_request::HashOrHeight::Hash(best_block_hash.clone()))).await.unwrap();
+    assert_eq!(grpc_block_by_hash, grpc_block);
+    let res: Result<proto::Block, _> = grpc(&config, "/chainseeker.Chainseeker/GetBlock",
+        get_block(proto::get_block_request::HashOrHeight::Height(blocks.len() as u32))).await;
+    assert_eq!(res.unwrap_err().code(), tonic::Code::NotFound);
+    // Addresses with pagination.
+    let get_txids = |after: Option<&String>, limit| proto::GetAddressTxidsRequest { script_or_address: address.clone(), after: after.cloned(), limit };
+    let res: proto::GetAddressTxidsResponse = grpc(&config, "/chainseeker.Chainseeker/GetAddressTxids", get_txids(None, 0)).await.unwrap();
+    assert_eq!(res.txids, txids);
+    let res: proto::GetAddressTxidsResponse = grpc(&config, "/chainseeker.Chainseeker/GetAddressTxids", get_txids(Some(&txids[1]), 2)).await.unwrap();
+    assert_eq!(res.txids, txids[2..4]);
+    let res: Result<proto::GetAddressTxidsResponse, _> = grpc(&config, "/chainseeker.Chainseeker/GetAddressTxids", get_txids(None, 1001)).await;
+    assert_eq!(res.unwrap_err().code(), tonic::Code::InvalidArgument);
+    let res: proto::GetUtxosResponse = grpc(&config, "/chainseeker.Chainseeker/GetUtxos", proto::GetUtxosRequest { script_or_address: address.clone() }).await.unwrap();
+    assert_eq!(
+        res.utxos.iter().map(|utxo| utxo.value).sum::<u64>(),
+        client.utxos(&address).await.unwrap().iter().map(|utxo| utxo.value).sum::<u64>());
+    let res: Result<proto::GetUtxosResponse, _> = grpc(&config, "/chainseeker.Chainseeker/GetUtxos", proto::GetUtxosRequest { script_or_address: INVALID_ADDRESS.to_string() }).await;
+    assert_eq!(res.unwrap_err().code(), tonic::Code::InvalidArgument);
+    //
+    // Content negotiation.
+    let raw_tx = consensus_encode(&blocks[0].txdata[0]);
+    let raw_header = consensus_encode(&reorged_block.header);
+    let res = rest_get(&config, &format!("/tx/{}.bin", txid), "*/*").await;
+    assert_eq!(res, (StatusCode::OK, "application/octet-stream".to_string(), raw_tx.clone()));
+    let res = rest_get(&config, &format!("/tx/{}.hex", txid), "*/*").await;
+    assert_eq!(res, (StatusCode::OK, "text/plain".to_string(), format!("{}\n", hex::encode(&raw_tx)).into_bytes()));
+    let res = rest_get(&config, &format!("/tx/{}", txid), "application/octet-stream").await;
+    assert_eq!(res, (StatusCode::OK, "application/octet-stream".to_string(), raw_tx.clone()));
+    let res = rest_get(&config, &format!("/tx/{}.json", txid), "application/octet-stream").await;
+    assert_eq!(res.1, "application/json");
+    assert_eq!(serde_json::from_slice::<chainseeker::Transaction>(&res.2).unwrap().txid, txid.to_string());
+    assert_eq!(rest_get(&config, &format!("/tx/{}.bin", NOT_FOUND_ID), "*/*").await.0, StatusCode::NOT_FOUND);
+    let res = rest_get(&config, &format!("/block/{}.bin", blocks.len() - 1), "*/*").await;
+    assert_eq!(res, (StatusCode::OK, "application/octet-stream".to_string(), raw_header.clone()));
+    let res = rest_get(&config, &format!("/block/{}", best_block_hash), "text/html, text/plain;q=0.9").await;
+    assert_eq!(res, (StatusCode::OK, "text/plain".to_string(), format!("{}\n", hex::encode(&raw_header)).into_bytes()));
+    // CBOR.
+    let res = rest_get(&config, "/status", "application/cbor").await;
+    assert_eq!((res.0, res.1.as_str()), (StatusCode::OK, "application/cbor"));
+    assert_eq!(serde_cbor::from_slice::<chainseeker::Status>(&res.2).unwrap().blocks, (blocks.len() - 1) as i32);
+    let res = rest_get(&config, &format!("/tx/{}", txid), "application/cbor").await;
+    assert_eq!(serde_cbor::from_slice::<chainseeker::Transaction>(&res.2).unwrap(), client.tx(&txid.to_string()).await.unwrap());
+    let res = rest_get(&config, &format!("/tx/{}", NOT_FOUND_ID), "application/cbor").await;
+    assert_eq!((res.0, res.1.as_str()), (StatusCode::NOT_FOUND, "application/cbor"));
+    let res = rest_get(&config, "/rich_list_count", "application/cbor").await;
+    assert!(serde_cbor::from_slice::<serde_json::Value>(&res.2).unwrap()["count"].is_u64());
+    // Quality values.
+    let res = rest_get(&config, "/status", "application/json;q=0.5, application/cbor").await;
+    assert_eq!(res.1, "application/cbor");
+    let res = rest_get(&config, "/status", "application/cbor;q=0, application/json").await;
+    assert_eq!(res.1, "application/json");
+    let res = rest_get(&config, &format!("/block/{}", best_block_hash), "text/plain;q=0.1, application/json;q=0.2").await;
+    assert_eq!(res.1, "application/json");
+    // Only the REST API is negotiated.
+    let req = Request::builder()
+        .uri(format!("http://{}:{}/esplora/block/{}", config.http_ip, config.http_port, best_block_hash))
+        .header(hyper::header::ACCEPT, "application/cbor")
+        .body(Body::empty())
+        .unwrap();
+    let res = hyper::Client::new().request(req).await.unwrap();
+    assert_eq!(res.headers()[hyper::header::CONTENT_TYPE], "application/json");
+    let body = hyper::body::to_bytes(res.into_body()).await.unwrap();
+    assert_eq!(serde_json::from_slice::<serde_json::Value>(&body).unwrap()["id"], best_block_hash.as_str());
+    //
+    // Raw blocks.
+    for (height, block) in blocks.iter().enumerate().take(blocks.len() - 1) {
+        assert_eq!(client.raw_block(height).await.unwrap(), consensus_encode(block));
+    }
+    assert_eq!(client.raw_block(&best_block_hash).await.unwrap(), consensus_encode(&reorged_block));
+    let res = rest_get(&config, &format!("/rawblock/{}.hex", best_block_hash), "*/*").await;
+    assert_eq!(res, (StatusCode::OK, "text/plain".to_string(), format!("{}\n", hex::encode(consensus_encode(&reorged_block))).into_bytes()));
+    let res = rest_get(&config, &format!("/block_with_txs/{}.bin", best_block_hash), "*/*").await;
+    assert_eq!(res, (StatusCode::OK, "application/octet-stream".to_string(), consensus_encode(&reorged_block)));
+    let res = rest_get(&config, &format!("/block_with_txs/{}", blocks.len() - 1), "text/plain").await;
+    assert_eq!(res, (StatusCode::OK, "text/plain".to_string(), format!("{}\n", hex::encode(consensus_encode(&reorged_block))).into_bytes()));
+    assert_eq!(rest_get(&config, &format!("/rawblock/{}", blocks.len()), "*/*").await.0, StatusCode::NOT_FOUND);
+    assert_eq!(rest_get(&config, &format!("/rawblock/{}.json", best_block_hash), "*/*").await.0, StatusCode::NOT_FOUND);
+    let res = rest_get(&config, &format!("/rawblock/{}", best_block_hash), "application/json").await;
+    assert_eq!((res.0, res.1.as_str()), (StatusCode::NOT_ACCEPTABLE, "application/json"));
+    let res = rest_get(&config, &format!("/rawblock/{}", best_block_hash), "application/cbor").await;
+    assert_eq!((res.0, res.1.as_str()), (StatusCode::NOT_ACCEPTABLE, "application/cbor"));
+    let res = rest_get(&config, &format!("/rawblock/{}", best_block_hash), "application/cbor;q=0.5, application/octet-stream").await;
+    assert_eq!(res, (StatusCode::OK, "application/octet-stream".to_string(), consensus_encode(&reorged_block)));
+    assert_eq!(rest_get(&config, &format!("/rawblock/{}", NOT_FOUND_ID), "*/*").await.0, StatusCode::NOT_FOUND);
 }
@@ -0,0 +1,433 @@
+//! JSON-RPC 2.0 interface mirroring the REST API (over HTTP POST and WebSocket at `/api/v1/jsonrpc`).
+//!
+//! Each method is translated into a request to the corresponding `/api/v1` route and dispatched to the handlers of `HttpServer`.
+
+use crate::*;
+use std::convert::Infallible;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use futures_util::{StreamExt, SinkExt};
+use hyper::{Body, Request, Response, StatusCode};
+use hyper::service::Service;
+use routerify::prelude::*;
+use routerify::{Router, RequestServiceBuilder};
+use serde_json::{json, Value};
+use tokio_tungstenite::WebSocketStream;
+use tokio_tungstenite::tungstenite::Message;
+use tokio_tungstenite::tungstenite::protocol::Role;
+use tokio_tungstenite::tungstenite::handshake::derive_accept_key;
+
+/// JSON-RPC error codes.
+pub const PARSE_ERROR: i32 = -32700;
+pub const INVALID_REQUEST: i32 = -32600;
+pub const METHOD_NOT_FOUND: i32 = -32601;
+pub const INVALID_PARAMS: i32 = -32602;
+pub const INTERNAL_ERROR: i32 = -32603;
+/// The requested resource does not exist (HTTP 404).
+pub const NOT_FOUND: i32 = -32004;
+
+/// The maximum number of requests in a batch.
+pub const MAX_BATCH_SIZE: usize = 100;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JsonRpcParamType {
+    String,
+    Integer,
+    Boolean,
+    /// A block hash (string) or a block height (integer).
+    HashOrHeight,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JsonRpcParamLocation {
+    Path,
+    Query,
+    Body,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct JsonRpcParam {
+    /// The name of the param in JSON-RPC requests.
+    pub name: &'static str,
+    /// The name of the path segment or the query parameter in the REST API.
+    pub key: &'static str,
+    pub param_type: JsonRpcParamType,
+    pub location: JsonRpcParamLocation,
+    pub required: bool,
+}
+
+const fn path(name: &'static str, key: &'static str, param_type: JsonRpcParamType) -> JsonRpcParam {
+    JsonRpcParam { name, key, param_type, location: JsonRpcParamLocation::Path, required: true }
+}
+
+const fn query(name: &'static str, key: &'static str, param_type: JsonRpcParamType) -> JsonRpcParam {
+    JsonRpcParam { name, key, param_type, location: JsonRpcParamLocation::Query, required: false }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct JsonRpcMethod {
+    pub name: &'static str,
+    pub http_method: &'static str,
+    /// The path of the REST API (`:key` segments are substituted by params).
+    ///
+    /// A `.hex` suffix selects the hex-encoded response, which is returned as a string.
+    pub path: &'static str,
+    /// The params in the order of positional arguments.
+    pub params: &'static [JsonRpcParam],
+}
+
+const SCRIPT_OR_ADDRESS: JsonRpcParam = path("scriptOrAddress", "script_or_address", JsonRpcParamType::String);
+const HASH_OR_HEIGHT: JsonRpcParam = path("hashOrHeight", "hash_or_height", JsonRpcParamType::HashOrHeight);
+const LIMIT: JsonRpcParam = query("limit", "limit", JsonRpcParamType::Integer);
+const AFTER: JsonRpcParam = query("after", "after", JsonRpcParamType::String);
+const RICH_LIST_TYPE: JsonRpcParam = query("type", "type", JsonRpcParamType::String);
+const ADDRESS_ONLY: JsonRpcParam = query("addressOnly", "address_only", JsonRpcParamType::Boolean);
+const EXCLUDE_BURN: JsonRpcParam = query("excludeBurn", "exclude_burn", JsonRpcParamType::Boolean);
+
+/// The methods exposed (one for each `/api/v1` route).
+pub const JSON_RPC_METHODS: &[JsonRpcMethod] = &[
+    JsonRpcMethod { name: "getStatus", http_method: "GET", path: "/api/v1/status", params: &[] },
+    JsonRpcMethod { name: "getTx", http_method: "GET", path: "/api/v1/tx/:txid", params: &[
+        path("txid", "txid", JsonRpcParamType::String),
+    ] },
+    JsonRpcMethod { name: "broadcastTx", http_method: "PUT", path: "/api/v1/tx/broadcast", params: &[
+        JsonRpcParam { name: "hex", key: "hex", param_type: JsonRpcParamType::String, location: JsonRpcParamLocation::Body, required: true },
+    ] },
+    JsonRpcMethod { name: "getBlockSummary", http_method: "GET", path: "/api/v1/block_summary/:offset/:limit", params: &[
+        path("offset", "offset", JsonRpcParamType::Integer),
+        path("limit", "limit", JsonRpcParamType::Integer),
+    ] },
+    JsonRpcMethod { name: "getBlockWithTxids", http_method: "GET", path: "/api/v1/block_with_txids/:hash_or_height", params: &[HASH_OR_HEIGHT] },
+    JsonRpcMethod { name: "getBlockWithTxs", http_method: "GET", path: "/api/v1/block_with_txs/:hash_or_height", params: &[HASH_OR_HEIGHT] },
+    JsonRpcMethod { name: "getBlock", http_method: "GET", path: "/api/v1/block/:hash_or_height", params: &[HASH_OR_HEIGHT] },
+    JsonRpcMethod { name: "getRawBlock", http_method: "GET", path: "/api/v1/rawblock/:hash_or_height.hex", params: &[HASH_OR_HEIGHT] },
+    JsonRpcMethod { name: "getBlockStats", http_method: "GET", path: "/api/v1/block_stats/:hash_or_height", params: &[HASH_OR_HEIGHT] },
+    JsonRpcMethod { name: "getPools", http_method: "GET", path: "/api/v1/pools", params: &[
+        query("from", "from", JsonRpcParamType::Integer),
+        query("to", "to", JsonRpcParamType::Integer),
+    ] },
+    JsonRpcMethod { name: "getTxids", http_method: "GET", path: "/api/v1/txids/:script_or_address", params: &[SCRIPT_OR_ADDRESS, LIMIT, AFTER] },
+    JsonRpcMethod { name: "getTxs", http_method: "GET", path: "/api/v1/txs/:script_or_address", params: &[SCRIPT_OR_ADDRESS, LIMIT, AFTER] },
+    JsonRpcMethod { name: "getOpReturn", http_method: "GET", path: "/api/v1/op_return/:hex_prefix", params: &[
+        path("hexPrefix", "hex_prefix", JsonRpcParamType::String),
+        LIMIT,
+        AFTER,
+    ] },
+    JsonRpcMethod { name: "getCounterpartyTxids", http_method: "GET", path: "/api/v1/counterparty_txids/:asset", params: &[
+        path("asset", "asset", JsonRpcParamType::String),
+        LIMIT,
+        AFTER,
+    ] },
+    JsonRpcMethod { name: "getUtxos", http_method: "GET", path: "/api/v1/utxos/:script_or_address", params: &[SCRIPT_OR_ADDRESS] },
+    JsonRpcMethod { name: "getUtxoSetInfo", http_method: "GET", path: "/api/v1/utxo_set_info", params: &[
+        query("height", "height", JsonRpcParamType::Integer),
+    ] },
+    JsonRpcMethod { name: "getSupply", http_method: "GET", path: "/api/v1/supply", params: &[] },
+    JsonRpcMethod { name: "getSupplyAt", http_method: "GET", path: "/api/v1/supply/:height", params: &[
+        path("height", "height", JsonRpcParamType::Integer),
+    ] },
+    JsonRpcMethod { name: "getRichListCount", http_method: "GET", path: "/api/v1/rich_list_count", params: &[
+        RICH_LIST_TYPE, ADDRESS_ONLY, EXCLUDE_BURN,
+    ] },
+    JsonRpcMethod { name: "getRichListAddrRank", http_method: "GET", path: "/api/v1/rich_list_addr_rank/:script_or_address", params: &[
+        SCRIPT_OR_ADDRESS, RICH_LIST_TYPE, ADDRESS_ONLY, EXCLUDE_BURN,
+    ] },
+    JsonRpcMethod { name: "getRichList", http_method: "GET", path: "/api/v1/rich_list/:offset/:limit", params: &[
+        path("offset", "offset", JsonRpcParamType::Integer),
+        path("limit", "limit", JsonRpcParamType::Integer),
+        RICH_LIST_TYPE, ADDRESS_ONLY, EXCLUDE_BURN,
+    ] },
+    JsonRpcMethod { name: "getRichListAt", http_method: "GET", path: "/api/v1/rich_list_at/:height/:offset/:limit", params: &[
+        path("height", "height", JsonRpcParamType::Integer),
+        path("offset", "offset", JsonRpcParamType::Integer),
+        path("limit", "limit", JsonRpcParamType::Integer),
+    ] },
+    JsonRpcMethod { name: "getRichListDistribution", http_method: "GET", path: "/api/v1/rich_list_distribution/:height", params: &[
+        path("height", "height", JsonRpcParamType::Integer),
+    ] },
+    JsonRpcMethod { name: "getRichListAddrRankHistory", http_method: "GET", path: "/api/v1/rich_list_addr_rank_history/:script_or_address", params: &[
+        SCRIPT_OR_ADDRESS,
+        query("from", "from", JsonRpcParamType::Integer),
+        query("to", "to", JsonRpcParamType::Integer),
+    ] },
+];
+
+impl JsonRpcParam {
+    /// Convert the value of the param into the representation in the REST API.
+    fn encode(&self, value: &Value) -> Result<String, String> {
+        let encoded = match (self.param_type, value) {
+            (JsonRpcParamType::String, Value::String(value)) | (JsonRpcParamType::HashOrHeight, Value::String(value)) => value.clone(),
+            (JsonRpcParamType::Integer, Value::Number(value)) | (JsonRpcParamType::HashOrHeight, Value::Number(value)) if value.is_u64() => value.to_string(),
+            (JsonRpcParamType::Boolean, Value::Bool(value)) => value.to_string(),
+            _ => return Err(format!("\"{}\" should be {}.", self.name, match self.param_type {
+                JsonRpcParamType::String => "a string",
+                JsonRpcParamType::Integer => "a non-negative integer",
+                JsonRpcParamType::Boolean => "a boolean",
+                JsonRpcParamType::HashOrHeight => "a block hash or a block height",
+            })),
+        };
+        // Path segments and query values are not percent-decoded by the handlers.
+        if self.location != JsonRpcParamLocation::Body && (encoded.is_empty() || !encoded.chars().all(|c| c.is_ascii_alphanumeric() || ":-_.".contains(c))) {
+            return Err(format!("\"{}\" contains invalid characters.", self.name));
+        }
+        Ok(encoded)
+    }
+}
+
+impl JsonRpcMethod {
+    pub fn find(name: &str) -> Option<&'static Self> {
+        JSON_RPC_METHODS.iter().find(|method| method.name == name)
+    }
+    /// Returns the URI and the body of the REST API request for the params (positional or named).
+    pub fn to_http(&self, params: Option<&Value>) -> Result<(String, String), String> {
+        let values = match params {
+            None | Some(Value::Null) => vec![None; self.params.len()],
+            Some(Value::Array(values)) => {
+                if values.len() > self.params.len() {
+                    return Err(format!("\"{}\" takes at most {} params.", self.name, self.params.len()));
+                }
+                (0..self.params.len()).map(|i| values.get(i).filter(|value| !value.is_null())).collect()
+            },
+            Some(Value::Object(values)) => {
+                if let Some(name) = values.keys().find(|name| !self.params.iter().any(|param| param.name == name.as_str())) {
+                    return Err(format!("Unknown param \"{}\".", name));
+                }
+                self.params.iter().map(|param| values.get(param.name).filter(|value| !value.is_null())).collect()
+            },
+            Some(_) => return Err("\"params\" should be an array or an object.".to_string()),
+        };
+        let mut path = self.path.to_string();
+        let mut query = Vec::new();
+        let mut body = String::new();
+        for (param, value) in self.params.iter().zip(values) {
+            let value = match value {
+                Some(value) => param.encode(value)?,
+                None if param.required => return Err(format!("\"{}\" is required.", param.name)),
+                None => continue,
+            };
+            match param.location {
+                JsonRpcParamLocation::Path => path = path.replace(&format!(":{}", param.key), &value),
+                JsonRpcParamLocation::Query => query.push(format!("{}={}", param.key, value)),
+                JsonRpcParamLocation::Body => body = value,
+            }
+        }
+        if !query.is_empty() {
+            path = format!("{}?{}", path, query.join("&"));
+        }
+        Ok((path, body))
+    }
+}
+
+/// The body of the error responses of the REST API.
+#[derive(Debug, serde::Deserialize)]
+struct ErrorBody {
+    error: String,
+}
+
+fn error(id: Value, code: i32, message: &str) -> Value {
+    json!({ "jsonrpc": "2.0", "id": id, "error": { "code": code, "message": message } })
+}
+
+#[derive(Debug, Clone)]
+pub struct JsonRpc {
+    /// Dispatches requests to the `/api/v1` routes of `HttpServer`.
+    service: Arc<RequestServiceBuilder<Body, Infallible>>,
+}
+
+impl JsonRpc {
+    pub fn new(http: HttpServer) -> Self {
+        let router = HttpServer::api_routes(Router::builder().data(http))
+            .any(|_req| async {
+                Ok(HttpServer::not_found("invalid URL."))
+            })
+            .build()
+            .unwrap();
+        Self {
+            service: Arc::new(RequestServiceBuilder::new(router).unwrap()),
+        }
+    }
+    /// Call the method, and returns the result or (code, message) of the error.
+    async fn call(&self, remote_addr: SocketAddr, method: &str, params: Option<&Value>) -> Result<Value, (i32, String)> {
+        let method = JsonRpcMethod::find(method).ok_or_else(|| (METHOD_NOT_FOUND, format!("Unknown method \"{}\".", method)))?;
+        let (uri, body) = method.to_http(params).map_err(|err| (INVALID_PARAMS, err))?;
+        let req = Request::builder()
+            .method(method.http_method)
+            .uri(uri)
+            .body(Body::from(body))
+            .map_err(|err| (INTERNAL_ERROR, err.to_string()))?;
+        let res = self.service.build(remote_addr).call(req).await.map_err(|err| (INTERNAL_ERROR, err.to_string()))?;
+        let status = res.status();
+        let res_content_type = res.headers().get(hyper::header::CONTENT_TYPE).and_then(|value| value.to_str().ok()).map(|value| value.to_string());
+        let body = hyper::body::to_bytes(res.into_body()).await.map_err(|err| (INTERNAL_ERROR, err.to_string()))?;
+        if status == StatusCode::OK {
+            let is_hex = matches!(res_content_type.as_deref(), Some("text/plain"));
+            return if is_hex {
+                String::from_utf8(body.to_vec()).map(|hex| Value::String(hex.trim_end().to_string()))
+                    .map_err(|_| (INTERNAL_ERROR, "Failed to decode the result.".to_string()))
+            } else {
+                serde_json::from_slice(&body).map_err(|_| (INTERNAL_ERROR, "Failed to decode the result.".to_string()))
+            };
+        }
+        let code = match status {
+            StatusCode::BAD_REQUEST => INVALID_PARAMS,
+            StatusCode::NOT_FOUND => NOT_FOUND,
+            _ => INTERNAL_ERROR,
+        };
+        match serde_json::from_slice::<ErrorBody>(&body) {
+            Ok(body) => Err((code, body.error)),
+            Err(_) => Err((code, String::from_utf8_lossy(&body).to_string())),
+        }
+    }
+    /// Handle a request object (returns None for notifications).
+    async fn handle_request(&self, remote_addr: SocketAddr, request: &Value) -> Option<Value> {
+        let request = match request.as_object() {
+            Some(request) => request,
+            None => return Some(error(Value::Null, INVALID_REQUEST, "The request should be an object.")),
+        };
+        let id = request.get("id").cloned();
+        let method = match (request.get("jsonrpc"), request.get("method")) {
+            (Some(Value::String(version)), Some(Value::String(method))) if version == "2.0" => method,
+            _ => return Some(error(id.unwrap_or(Value::Null), INVALID_REQUEST, "Invalid JSON-RPC 2.0 request.")),
+        };
+        let result = self.call(remote_addr, method, request.get("params")).await;
+        let id = id?;
+        Some(match result {
+            Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+            Err((code, message)) => error(id, code, &message),
+        })
+    }
+    /// Handle a single or a batch request (returns None if there is nothing to respond).
+    pub async fn handle(&self, remote_addr: SocketAddr, body: &[u8]) -> Option<Value> {
+        match serde_json::from_slice::<Value>(body) {
+            Ok(Value::Array(requests)) => {
+                if requests.is_empty() {
+                    return Some(error(Value::Null, INVALID_REQUEST, "The batch should not be empty."));
+                }
+                if requests.len() > MAX_BATCH_SIZE {
+                    return Some(error(Value::Null, INVALID_REQUEST, &format!("The batch should contain at most {} requests.", MAX_BATCH_SIZE)));
+                }
+                let mut responses = Vec::new();
+                for request in requests.iter() {
+                    responses.extend(self.handle_request(remote_addr, request).await);
+                }
+                if responses.is_empty() { None } else { Some(Value::Array(responses)) }
+            },
+            Ok(request) => self.handle_request(remote_addr, &request).await,
+            Err(_) => Some(error(Value::Null, PARSE_ERROR, "Failed to parse JSON.")),
+        }
+    }
+    /// `POST /api/v1/jsonrpc` endpoint.
+    pub async fn http_handler(req: Request<Body>) -> Result<Response<Body>, Infallible> {
+        let json_rpc = req.data::<JsonRpc>().unwrap().clone();
+        let remote_addr = req.remote_addr();
+        let body = match hyper::body::to_bytes(req.into_body()).await {
+            Ok(body) => body,
+            Err(_) => return Ok(HttpServer::bad_request("Failed to read the request body.")),
+        };
+        match json_rpc.handle(remote_addr, &body).await {
+            Some(response) => Ok(HttpServer::json(response, false)),
+            None => Ok(HttpServer::response_with_content_type(&StatusCode::NO_CONTENT, "application/json", "".to_string(), false)),
+        }
+    }
+    /// `GET /api/v1/jsonrpc` endpoint (upgraded to WebSocket).
+    pub async fn web_socket_handler(req: Request<Body>) -> Result<Response<Body>, Infallible> {
+        let is_upgrade = matches!(req.headers().get(hyper::header::UPGRADE), Some(upgrade) if upgrade.as_bytes().eq_ignore_ascii_case(b"websocket"));
+        let accept_key = match req.headers().get(hyper::header::SEC_WEBSOCKET_KEY) {
+            Some(key) if is_upgrade => derive_accept_key(key.as_bytes()),
+            _ => return Ok(HttpServer::bad_request("Use POST or upgrade to WebSocket.")),
+        };
+        let json_rpc = req.data::<JsonRpc>().unwrap().clone();
+        let remote_addr = req.remote_addr();
+        tokio::spawn(async move {
+            let upgraded = match hyper::upgrade::on(req).await {
+                Ok(upgraded) => upgraded,
+                Err(_) => return,
+            };
+            let (mut write, mut read) = WebSocketStream::from_raw_socket(upgraded, Role::Server, None).await.split();
+            while let Some(Ok(message)) = read.next().await {
+                let response = match message {
+                    Message::Text(text) => json_rpc.handle(remote_addr, text.as_bytes()).await,
+                    Message::Binary(data) => json_rpc.handle(remote_addr, &data).await,
+                    Message::Close(_) => break,
+                    _ => None,
+                };
+                if let Some(response) = response {
+                    if write.send(Message::Text(response.to_string())).await.is_err() {
+                        // Connection lost.
+                        break;
+                    }
+                }
+            }
+        });
+        Ok(Response::builder()
+            .status(StatusCode::SWITCHING_PROTOCOLS)
+            .header(hyper::header::CONNECTION, "Upgrade")
+            .header(hyper::header::UPGRADE, "websocket")
+            .header(hyper::header::SEC_WEBSOCKET_ACCEPT, accept_key)
+            .body(Body::empty())
+            .unwrap())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn methods() {
+        for method in JSON_RPC_METHODS.iter() {
+            assert_eq!(JsonRpcMethod::find(method.name), Some(method));
+            // Every path param appears in the path.
+            for param in method.params.iter().filter(|param| param.location == JsonRpcParamLocation::Path) {
+                assert!(method.path.contains(&format!(":{}", param.key)), "{} {}", method.name, param.key);
+            }
+        }
+        assert_eq!(JsonRpcMethod::find("getNothing"), None);
+    }
+    #[test]
+    fn every_route_has_method() {
+        let router = HttpServer::api_routes(Router::builder()).build().unwrap();
+        // `Router` does not expose its routes, so take the paths from its debug representation.
+        let routes = format!("{:?}", router);
+        let paths = routes.split("path: \"").skip(1)
+            // Trailing slashes are appended by `RouterBuilder`.
+            .map(|route| route.split('"').next().unwrap().trim_end_matches('/'))
+            .filter(|path| path.starts_with("/api/v1/"))
+            .collect::<Vec<&str>>();
+        assert!(!paths.is_empty());
+        for path in paths.iter() {
+            assert!(JSON_RPC_METHODS.iter().any(|method| method.path.trim_end_matches(".hex") == *path), "No method for {}", path);
+        }
+        assert_eq!(paths.len(), JSON_RPC_METHODS.len());
+    }
+    #[test]
+    fn to_http() {
+        let get_tx = JsonRpcMethod::find("getTx").unwrap();
+        let txid = "0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef";
+        let expected = (format!("/api/v1/tx/{}", txid), "".to_string());
+        assert_eq!(get_tx.to_http(Some(&json!([txid]))), Ok(expected.clone()));
+        assert_eq!(get_tx.to_http(Some(&json!({ "txid": txid }))), Ok(expected));
+        assert!(get_tx.to_http(None).is_err());
+        assert!(get_tx.to_http(Some(&json!([1]))).is_err());
+        assert!(get_tx.to_http(Some(&json!([txid, 1]))).is_err());
+        assert!(get_tx.to_http(Some(&json!({ "txid": txid, "hash": txid }))).is_err());
+        assert!(get_tx.to_http(Some(&json!(["../status"]))).is_err());
+        assert!(get_tx.to_http(Some(&json!(txid))).is_err());
+        // Optional query params.
+        let get_txids = JsonRpcMethod::find("getTxids").unwrap();
+        assert_eq!(get_txids.to_http(Some(&json!({ "scriptOrAddress": "0014", "limit": 10 }))), Ok(("/api/v1/txids/0014?limit=10".to_string(), "".to_string())));
+        assert_eq!(get_txids.to_http(Some(&json!(["0014", null, "abcd"]))), Ok(("/api/v1/txids/0014?after=abcd".to_string(), "".to_string())));
+        assert!(get_txids.to_http(Some(&json!(["0014", -1]))).is_err());
+        let get_rich_list = JsonRpcMethod::find("getRichList").unwrap();
+        assert_eq!(get_rich_list.to_http(Some(&json!({ "offset": 0, "limit": 5, "addressOnly": true }))),
+            Ok(("/api/v1/rich_list/0/5?address_only=true".to_string(), "".to_string())));
+        // Block hash or height.
+        let get_block = JsonRpcMethod::find("getBlock").unwrap();
+        assert_eq!(get_block.to_http(Some(&json!([1]))), Ok(("/api/v1/block/1".to_string(), "".to_string())));
+        assert_eq!(get_block.to_http(Some(&json!([txid]))), Ok((format!("/api/v1/block/{}", txid), "".to_string())));
+        let get_raw_block = JsonRpcMethod::find("getRawBlock").unwrap();
+        assert_eq!(get_raw_block.to_http(Some(&json!([1]))), Ok(("/api/v1/rawblock/1.hex".to_string(), "".to_string())));
+        // Body.
+        let broadcast_tx = JsonRpcMethod::find("broadcastTx").unwrap();
+        assert_eq!(broadcast_tx.to_http(Some(&json!(["0200"]))), Ok(("/api/v1/tx/broadcast".to_string(), "0200".to_string())));
+    }
+}
@@ -5,22 +5,32 @@ use num_format::{Locale, ToFormattedStr, ToFormattedString};
 pub use bitcoin_rest::bitcoin;
 use bitcoin::hashes::hex::FromHex;
 use bitcoin::consensus::{Encodable, Decodable};
-use bitcoin::{BlockHash, Block, BlockHeader, Address, Script, Network};
+use bitcoin::hashes::{Hash, HashEngine, sha256d};
+use bitcoin::{BlockHash, Block, BlockHeader, Address, Script, Network, PubkeyHash, ScriptHash, Txid};
+use bitcoin::blockdata::opcodes;
+use bitcoin::blockdata::constants::WITNESS_SCALE_FACTOR;
 use bitcoin::util::uint::Uint256;
 use bitcoin::util::address::Payload;
 use bitcoin::util::base58;
 use bitcoin::bech32;
-use bitcoin::bech32::ToBase32;
 
 use crate::db::Database;
 
 pub mod rocks_db;
 pub mod rocks_db_multi;
+pub mod muhash;
+pub mod segwit_addr;
+pub mod counterparty;
 pub mod db;
 pub mod zeromq;
 pub mod syncer;
 pub mod rest;
 pub mod http_server;
+pub mod esplora;
+pub mod json_rpc;
+pub mod graphql;
+pub mod grpc;
+pub mod electrum;
 pub mod web_socket_relay;
 #[cfg(test)]
 mod fixtures;
@@ -33,6 +43,10 @@ pub use zeromq::{ZeroMQMessage, ZeroMQClient};
 pub use syncer::Syncer;
 pub use rest::*;
 pub use http_server::HttpServer;
+pub use esplora::EsploraServer;
+pub use json_rpc::JsonRpc;
+pub use electrum::ElectrumServer;
+pub use grpc::GrpcServer;
 pub use web_socket_relay::WebSocketRelay;
 
 const DEFAULT_DATA_DIR: &str = ".chainseeker";
@@ -54,6 +68,7 @@ pub struct Server {
     pub syncer: Syncer,
     pub http: Arc<RwLock<HttpServer>>,
     pub ws: Arc<RwLock<WebSocketRelay>>,
+    pub electrum: ElectrumServer,
 }
 
 impl Server {
@@ -62,11 +77,13 @@ impl Server {
         let syncer = Syncer::new(db.clone()).await;
         let http = HttpServer::new(db.clone());
         let ws = WebSocketRelay::new(&config.ws_endpoint);
+        let electrum = ElectrumServer::new(db.clone());
         Self {
             db,
             syncer,
             http: Arc::new(RwLock::new(http)),
             ws: Arc::new(RwLock::new(ws)),
+            electrum,
         }
     }
     pub async fn run(&mut self) {
@@ -91,6 +108,21 @@ impl Server {
                 ws.read().await.run(rx).await;
             }));
         }
+        // Run ElectrumServer.
+        if let Some(electrum_endpoint) = self.db.config.electrum_endpoint.clone() {
+            let events = self.syncer.events();
+            let electrum = self.electrum.clone();
+            handles.push(tokio::spawn(async move {
+                electrum.run(&electrum_endpoint, events).await;
+            }));
+        }
+        // Run GrpcServer.
+        if let Some(grpc_endpoint) = self.db.config.grpc_endpoint.clone() {
+            let grpc = GrpcServer::new(self.db.clone(), self.syncer.events());
+            handles.push(tokio::spawn(async move {
+                grpc.run(&grpc_endpoint).await;
+            }));
+        }
         // Do initial sync.
         self.syncer.initial_sync().await;
         // Run syncer.
@@ -120,6 +152,11 @@ pub fn get_rest(config: &Config) -> bitcoin_rest::Context {
     bitcoin_rest::new(&config.rest_endpoint)
 }
 
+pub fn get_rpc(config: &Config) -> bitcoincore_rpc::Client {
+    let auth = bitcoincore_rpc::Auth::UserPass(config.rpc_user.clone(), config.rpc_pass.clone());
+    bitcoincore_rpc::Client::new(config.rpc_endpoint.clone(), auth).unwrap()
+}
+
 #[derive(Debug, Clone, serde::Deserialize)]
 pub struct Config {
     pub genesis_block_hash: BlockHash,
@@ -134,6 +171,27 @@ pub struct Config {
     pub http_ip      : String,
     pub http_port    : u16,
     pub ws_endpoint  : String,
+    pub pools_file   : Option<String>,
+    /// The block subsidy at height zero (in satoshis).
+    pub initial_subsidy : u64,
+    /// The number of blocks between subsidy halvings.
+    pub halving_interval: u32,
+    /// The number of blocks between rich list snapshots (zero disables snapshots).
+    pub rich_list_snapshot_interval: u32,
+    /// The number of top entries stored in each rich list snapshot.
+    pub rich_list_snapshot_size    : u32,
+    /// Keep the UTXO server and the rich list on disk instead of memory.
+    pub low_memory: bool,
+    /// The prefix of Counterparty messages ("CNTRPRTY" for Counterparty and "MONAPRTY" for Monaparty).
+    pub counterparty_prefix       : String,
+    /// The names of the Counterparty asset ids zero and one (e.g. "BTC" and "XCP").
+    pub counterparty_native_assets: Vec<String>,
+    /// The path to mount the Esplora-compatible REST API at (e.g. "/api"; disabled if not specified).
+    pub esplora_path: Option<String>,
+    /// The endpoint to serve the Electrum protocol at (e.g. "127.0.0.1:50001"; disabled if not specified).
+    pub electrum_endpoint: Option<String>,
+    /// The endpoint to serve the gRPC service at (e.g. "127.0.0.1:50051"; disabled if not specified).
+    pub grpc_endpoint: Option<String>,
 }
 
 #[derive(Debug, Clone, serde::Deserialize)]
@@ -150,6 +208,17 @@ struct TomlConfigEntry {
     http_ip           : Option<String>,
     http_port         : Option<u16>,
     ws_endpoint       : Option<String>,
+    pools_file        : Option<String>,
+    initial_subsidy   : Option<u64>,
+    halving_interval  : Option<u32>,
+    rich_list_snapshot_interval: Option<u32>,
+    rich_list_snapshot_size    : Option<u32>,
+    low_memory        : Option<bool>,
+    counterparty_prefix       : Option<String>,
+    counterparty_native_assets: Option<Vec<String>>,
+    esplora_path      : Option<String>,
+    electrum_endpoint : Option<String>,
+    grpc_endpoint     : Option<String>,
 }
 
 pub fn default_genesis_block_hash() -> String {
@@ -188,6 +257,27 @@ pub fn default_http_port() -> u16 {
 pub fn default_ws_endpoint() -> String {
     "127.0.0.1:8001".to_string()
 }
+pub fn default_initial_subsidy() -> u64 {
+    50 * 100_000_000
+}
+pub fn default_halving_interval() -> u32 {
+    210_000
+}
+pub fn default_rich_list_snapshot_interval() -> u32 {
+    1000
+}
+pub fn default_rich_list_snapshot_size() -> u32 {
+    1000
+}
+pub fn default_low_memory() -> bool {
+    false
+}
+pub fn default_counterparty_prefix() -> String {
+    "CNTRPRTY".to_string()
+}
+pub fn default_counterparty_native_assets() -> Vec<String> {
+    vec!["BTC".to_string(), "XCP".to_string()]
+}
 
 #[derive(Debug, Clone, serde::Deserialize)]
 struct TomlConfig {
@@ -215,6 +305,24 @@ struct TomlConfig {
     http_port         : u16,
     #[serde(default = "default_ws_endpoint")]
     ws_endpoint       : String,
+    pools_file        : Option<String>,
+    #[serde(default = "default_initial_subsidy")]
+    initial_subsidy   : u64,
+    #[serde(default = "default_halving_interval")]
+    halving_interval  : u32,
+    #[serde(default = "default_rich_list_snapshot_interval")]
+    rich_list_snapshot_interval: u32,
+    #[serde(default = "default_rich_list_snapshot_size")]
+    rich_list_snapshot_size    : u32,
+    #[serde(default = "default_low_memory")]
+    low_memory        : bool,
+    #[serde(default = "default_counterparty_prefix")]
+    counterparty_prefix       : String,
+    #[serde(default = "default_counterparty_native_assets")]
+    counterparty_native_assets: Vec<String>,
+    esplora_path      : Option<String>,
+    electrum_endpoint : Option<String>,
+    grpc_endpoint     : Option<String>,
     coins             : std::collections::HashMap<String, TomlConfigEntry>,
 }
 
@@ -239,6 +347,17 @@ pub fn load_config_from_str(config_str: &str, coin: &str) -> Config {
         http_ip      : coin_config.http_ip      .unwrap_or(config.http_ip      ),
         http_port    : coin_config.http_port    .unwrap_or(config.http_port    ),
         ws_endpoint  : coin_config.ws_endpoint  .unwrap_or(config.ws_endpoint  ),
+        pools_file   : coin_config.pools_file   .or(config.pools_file          ),
+        initial_subsidy : coin_config.initial_subsidy .unwrap_or(config.initial_subsidy ),
+        halving_interval: coin_config.halving_interval.unwrap_or(config.halving_interval),
+        rich_list_snapshot_interval: coin_config.rich_list_snapshot_interval.unwrap_or(config.rich_list_snapshot_interval),
+        rich_list_snapshot_size    : coin_config.rich_list_snapshot_size    .unwrap_or(config.rich_list_snapshot_size    ),
+        low_memory: coin_config.low_memory.unwrap_or(config.low_memory),
+        counterparty_prefix       : coin_config.counterparty_prefix       .unwrap_or(config.counterparty_prefix       ),
+        counterparty_native_assets: coin_config.counterparty_native_assets.unwrap_or(config.counterparty_native_assets),
+        esplora_path: coin_config.esplora_path.or(config.esplora_path),
+        electrum_endpoint: coin_config.electrum_endpoint.or(config.electrum_endpoint),
+        grpc_endpoint: coin_config.grpc_endpoint.or(config.grpc_endpoint),
     }
 }
 
@@ -268,6 +387,36 @@ pub fn consensus_decode<D>(dec: &[u8]) -> D
     D::consensus_decode(dec).unwrap()
 }
 
+/// Returns the virtual size of the transaction (the weight divided by 4, rounded up).
+// TODO: waiting for upstream merge of `Transaction::get_vsize()`.
+pub fn get_vsize(tx: &bitcoin::Transaction) -> usize {
+    let weight = tx.get_weight();
+    weight / WITNESS_SCALE_FACTOR + (weight % WITNESS_SCALE_FACTOR).min(1)
+}
+
+/// Returns the merkle branch of the `pos`-th transaction in a block
+/// (the sibling hashes from the bottom of the merkle tree to the top).
+pub fn merkle_branch(txids: &[Txid], pos: usize) -> Vec<sha256d::Hash> {
+    let mut hashes = txids.iter().map(|txid| sha256d::Hash::from_inner(txid.into_inner())).collect::<Vec<sha256d::Hash>>();
+    let mut pos = pos;
+    let mut branch = Vec::new();
+    while hashes.len() > 1 {
+        // The last hash is paired with itself if the number of hashes is odd.
+        if hashes.len() % 2 == 1 {
+            hashes.push(*hashes.last().unwrap());
+        }
+        branch.push(hashes[pos ^ 1]);
+        hashes = hashes.chunks(2).map(|pair| {
+            let mut engine = sha256d::Hash::engine();
+            engine.input(&pair[0]);
+            engine.input(&pair[1]);
+            sha256d::Hash::from_engine(engine)
+        }).collect();
+        pos /= 2;
+    }
+    branch
+}
+
 pub fn address_to_string(addr: &Address, config: &Config) -> String {
     match addr.payload {
         Payload::PubkeyHash(ref hash) => {
@@ -286,14 +435,148 @@ pub fn address_to_string(addr: &Address, config: &Config) -> String {
             version: ver,
             program: ref prog,
         } => {
-            let vec = vec![vec![ver], prog.to_base32()].concat();
-            bech32::encode(&config.segwit_hrp, &vec).unwrap()
+            segwit_addr::encode(&config.segwit_hrp, ver.to_u8(), prog).expect("Invalid witness program.")
         }
     }
 }
 
+/// Returns the P2PKH script of the public key if `script` is a P2PK script.
+pub fn p2pk_to_p2pkh(script: &Script) -> Option<Script> {
+    if !script.is_p2pk() {
+        return None;
+    }
+    let pubkey = &script.as_bytes()[1..script.len()-1];
+    Some(Script::new_p2pkh(&PubkeyHash::hash(pubkey)))
+}
+
+/// Parse a bare multisig script (`OP_m <pubkey>... OP_n OP_CHECKMULTISIG`) into (m, pubkeys).
+pub fn parse_multisig(script: &Script) -> Option<(u8, Vec<Vec<u8>>)> {
+    use bitcoin::blockdata::script::Instruction;
+    use opcodes::all::{OP_PUSHNUM_1, OP_PUSHNUM_16, OP_CHECKMULTISIG};
+    let pushnum = |instruction: &Instruction| match instruction {
+        Instruction::Op(op) if (OP_PUSHNUM_1.into_u8()..=OP_PUSHNUM_16.into_u8()).contains(&op.into_u8()) =>
+            Some(op.into_u8() - OP_PUSHNUM_1.into_u8() + 1),
+        _ => None,
+    };
+    let instructions = script.instructions().collect::<Result<Vec<Instruction>, _>>().ok()?;
+    let (m, instructions) = instructions.split_first()?;
+    let (checkmultisig, instructions) = instructions.split_last()?;
+    let (n, instructions) = instructions.split_last()?;
+    if *checkmultisig != Instruction::Op(OP_CHECKMULTISIG) {
+        return None;
+    }
+    let (m, n) = (pushnum(m)?, pushnum(n)?);
+    // Public keys are checked only by their sizes as Bitcoin Core does.
+    let pubkeys = instructions.iter().map(|instruction| match instruction {
+        Instruction::PushBytes(pubkey) if (pubkey.len() == 33 && (pubkey[0] == 0x02 || pubkey[0] == 0x03))
+            || (pubkey.len() == 65 && pubkey[0] == 0x04) => Some(pubkey.to_vec()),
+        _ => None,
+    }).collect::<Option<Vec<Vec<u8>>>>()?;
+    if m > n || pubkeys.len() != n as usize {
+        return None;
+    }
+    Some((m, pubkeys))
+}
+
+/// Returns the P2PKH scripts of the participants of a bare multisig script.
+pub fn multisig_to_p2pkhs(script: &Script) -> Vec<Script> {
+    match parse_multisig(script) {
+        Some((_m, pubkeys)) => pubkeys.iter().map(|pubkey| Script::new_p2pkh(&PubkeyHash::hash(pubkey))).collect(),
+        None => Vec::new(),
+    }
+}
+
+/// P2PK scripts are represented as the address of their P2PKH script.
 pub fn script_to_address_string(script: &Script, config: &Config) -> Option<String> {
-    Address::from_script(script, Network::Bitcoin /* any */).map(|addr| address_to_string(&addr, config))
+    if let Some(p2pkh) = p2pk_to_p2pkh(script) {
+        return script_to_address_string(&p2pkh, config);
+    }
+    let addr = Address::from_script(script, Network::Bitcoin /* any */)?;
+    match addr.payload {
+        // Witness v0 programs of invalid lengths do not have an address.
+        Payload::WitnessProgram { version, ref program } => segwit_addr::encode(&config.segwit_hrp, version.to_u8(), program),
+        _ => Some(address_to_string(&addr, config)),
+    }
+}
+
+/// Decode an address of the coin into the script pubkey (the inverse of `address_to_string`).
+///
+/// Returns the reason if the address is invalid or belongs to another network.
+pub fn address_string_to_script(address: &str, config: &Config) -> Result<Script, String> {
+    // Segwit addresses are decoded by `segwit_addr` because rust-bitcoin does not support bech32m (BIP350).
+    if let Some((hrp, version, program)) = segwit_addr::decode_any(address) {
+        if hrp != config.segwit_hrp {
+            return Err(format!("The address belongs to another network (HRP \"{}\").", hrp));
+        }
+        return Ok(Script::new_witness_program(bech32::u5::try_from_u8(version).unwrap(), &program));
+    }
+    if address.to_lowercase().starts_with(&format!("{}1", config.segwit_hrp)) {
+        return Err("Invalid segwit address.".to_string());
+    }
+    let data = match base58::from_check(address) {
+        Ok(data) => data,
+        Err(err) => return Err(format!("Failed to decode address: {}.", err)),
+    };
+    if data.len() != 21 {
+        return Err(format!("Invalid address length: {}.", data.len()));
+    }
+    match data[0] {
+        version if version == config.p2pkh_version => Ok(Script::new_p2pkh(&PubkeyHash::from_slice(&data[1..]).unwrap())),
+        version if version == config.p2sh_version  => Ok(Script::new_p2sh(&ScriptHash::from_slice(&data[1..]).unwrap())),
+        version => Err(format!("The address belongs to another network (version byte {}).", version)),
+    }
+}
+
+/// Returns true if the output can never be spent (same as Bitcoin Core's `CScript::IsUnspendable()`).
+pub fn is_unspendable(script: &Script) -> bool {
+    const MAX_SCRIPT_SIZE: usize = 10_000;
+    script.is_op_return() || script.len() > MAX_SCRIPT_SIZE
+}
+
+/// Returns the data pushed by `script` (None if it contains other opcodes).
+pub fn script_pushes(script: &Script) -> Option<Vec<Vec<u8>>> {
+    script.instructions().map(|instruction| match instruction {
+        Ok(bitcoin::blockdata::script::Instruction::PushBytes(data)) => Some(data.to_vec()),
+        _ => None,
+    }).collect()
+}
+
+/// Returns the data carried by an OP_RETURN script.
+///
+/// The data is the concatenation of the pushed data, or the raw bytes after OP_RETURN if the script contains other opcodes.
+pub fn op_return_data(script: &Script) -> Option<Vec<u8>> {
+    if !script.is_op_return() {
+        return None;
+    }
+    let data = Script::from(script.as_bytes()[1..].to_vec());
+    Some(script_pushes(&data).map_or_else(|| data.to_bytes(), |pushes| pushes.concat()))
+}
+
+/// Well-known markers at the beginning of OP_RETURN data.
+const OP_RETURN_PROTOCOLS: [(&[u8], &str); 5] = [
+    (&[0xaa, 0x21, 0xa9, 0xed], "witness_commitment"),
+    (b"omni", "omni"),
+    (b"RSKBLOCK:", "rsk"),
+    (b"DOCPROOF", "docproof"),
+    (&[0x4f, 0x41, 0x01, 0x00], "open_assets"),
+];
+
+/// Returns the protocol which an OP_RETURN script is likely to belong to.
+pub fn op_return_protocol(script: &Script) -> Option<&'static str> {
+    // Runestones start with OP_RETURN OP_13.
+    if script.is_op_return() && script.as_bytes().get(1) == Some(&opcodes::all::OP_PUSHNUM_13.into_u8()) {
+        return Some("runestone");
+    }
+    let data = op_return_data(script)?;
+    OP_RETURN_PROTOCOLS.iter()
+        .find(|(marker, _protocol)| data.starts_with(marker))
+        .map(|(_marker, protocol)| *protocol)
+}
+
+/// Returns true if the script is a P2TR (witness v1, 32-byte program) output.
+pub fn is_p2tr(script: &Script) -> bool {
+    let bytes = script.as_bytes();
+    bytes.len() == 34 && bytes[0] == opcodes::all::OP_PUSHNUM_1.into_u8() && bytes[1] == 0x20
 }
 
 pub fn uint256_as_f64(num: &Uint256) -> f64 {
@@ -305,6 +588,19 @@ pub fn uint256_as_f64(num: &Uint256) -> f64 {
     ret
 }
 
+/// Compute the block subsidy at `height` from the subsidy schedule in `config`.
+pub fn get_block_subsidy(height: u32, config: &Config) -> u64 {
+    // A zero halving interval means the subsidy never halves.
+    if config.halving_interval == 0 {
+        return config.initial_subsidy;
+    }
+    let halvings = height / config.halving_interval;
+    if halvings >= 64 {
+        return 0;
+    }
+    config.initial_subsidy >> halvings
+}
+
 pub fn get_difficulty(block_header: &BlockHeader, _config: &Config) -> f64 {
     let max_target = Uint256::from_u64(0xFFFF).unwrap() << 208;
     uint256_as_f64(&max_target) / uint256_as_f64(&block_header.target())
@@ -430,7 +726,193 @@ mod tests {
         );
     }
     #[test]
+    fn segwit_address_valid() {
+        // Test vectors come from https://github.com/bitcoin/bips/blob/master/bip-0350.mediawiki.
+        let vectors = [
+            ("btc" , "BC1QW508D6QEJXTDG4Y5R3ZARVARY0C5XW7KV8F3T4", "0014751e76e8199196d454941c45d1b3a323f1433bd6"),
+            ("tbtc", "tb1qrp33g0q5c5txsp9arysrx4k6zdkfs4nce4xj0gdcccefvpysxf3q0sl5k7", "00201863143c14c5166804bd19203356da136c985678cd4d27a1b8c6329604903262"),
+            ("btc" , "bc1pw508d6qejxtdg4y5r3zarvary0c5xw7kw508d6qejxtdg4y5r3zarvary0c5xw7kt5nd6y", "5128751e76e8199196d454941c45d1b3a323f1433bd6751e76e8199196d454941c45d1b3a323f1433bd6"),
+            ("btc" , "BC1SW50QGDZ25J", "6002751e"),
+            ("btc" , "bc1zw508d6qejxtdg4y5r3zarvaryvaxxpcs", "5210751e76e8199196d454941c45d1b3a323"),
+            ("tbtc", "tb1qqqqqp399et2xygdj5xreqhjjvcmzhxw4aywxecjdzew6hylgvsesrxh6hy", "0020000000c4a5cad46221b2a187905e5266362b99d5e91c6ce24d165dab93e86433"),
+            ("tbtc", "tb1pqqqqp399et2xygdj5xreqhjjvcmzhxw4aywxecjdzew6hylgvsesf3hn0c", "5120000000c4a5cad46221b2a187905e5266362b99d5e91c6ce24d165dab93e86433"),
+            ("btc" , "bc1p0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7vqzk5jj0", "512079be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798"),
+        ];
+        for (coin, address, script_pubkey) in vectors.iter() {
+            let config = config_example(coin);
+            let script_pubkey = Script::from_str(script_pubkey).unwrap();
+            assert_eq!(address_string_to_script(address, &config), Ok(script_pubkey.clone()));
+            assert_eq!(script_to_address_string(&script_pubkey, &config).unwrap(), address.to_lowercase());
+        }
+    }
+    #[test]
+    fn segwit_address_invalid() {
+        // Test vectors come from https://github.com/bitcoin/bips/blob/master/bip-0350.mediawiki.
+        let vectors = [
+            // Invalid human-readable part.
+            "tc1p0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7vq5zuyut",
+            // Invalid checksums (bech32 instead of bech32m).
+            "bc1p0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7vqh2y7hd",
+            "tb1z0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7vqglt7rf",
+            "BC1S0XLXVLHEMJA6C4DQV22UAPCTQUPFHLXM9H8Z3K2E72Q4K9HCZ7VQ54WELL",
+            // Invalid checksums (bech32m instead of bech32).
+            "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kemeawh",
+            "tb1q0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7vq24jc47",
+            // Invalid character in checksum.
+            "bc1p38j9r5y49hruaue7wxjce0updqjuyyx0kh56v8s25huc6995vvpql3jow4",
+            // Invalid witness version.
+            "BC130XLXVLHEMJA6C4DQV22UAPCTQUPFHLXM9H8Z3K2E72Q4K9HCZ7VQ7ZWS8R",
+            // Invalid program lengths.
+            "bc1pw5dgrnzv",
+            "bc1p0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7v8n0nx0muaewav253zgeav",
+            "BC1QR508D6QEJXTDG4Y5R3ZARVARYV98GJ9P",
+            // Mixed case.
+            "tb1p0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7vq47Zagq",
+            // Invalid paddings.
+            "bc1p0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7v07qwwzcrf",
+            "tb1p0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7vpggkg4j",
+            // Empty data section.
+            "bc1gmk9yu",
+        ];
+        for address in vectors.iter() {
+            assert!(address_string_to_script(address, &config_example("btc")).is_err(), "{}", address);
+            assert!(address_string_to_script(address, &config_example("tbtc")).is_err(), "{}", address);
+        }
+    }
+    #[test]
+    fn address_string_to_script_other_coins() {
+        let btc = config_example("btc");
+        let mona = config_example("mona");
+        for script_pubkey in [
+            "76a914f22f5563839ba6ba5aa8d3726fcbc675cb3e4c9e88ac",
+            "a91423e522dfc6656a8fda3d47b4fa53f7585ac758cd87",
+            "0014751e76e8199196d454941c45d1b3a323f1433bd6",
+            "512079be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798",
+        ].iter() {
+            let script_pubkey = Script::from_str(script_pubkey).unwrap();
+            let btc_address = script_to_address_string(&script_pubkey, &btc).unwrap();
+            let mona_address = script_to_address_string(&script_pubkey, &mona).unwrap();
+            assert_eq!(address_string_to_script(&btc_address, &btc), Ok(script_pubkey.clone()));
+            assert_eq!(address_string_to_script(&mona_address, &mona), Ok(script_pubkey));
+            // Addresses of other networks are rejected.
+            assert!(address_string_to_script(&btc_address, &mona).unwrap_err().contains("another network"));
+            assert!(address_string_to_script(&mona_address, &btc).unwrap_err().contains("another network"));
+        }
+        assert!(address_string_to_script("xxxxxxxx", &btc).is_err());
+        assert!(address_string_to_script("bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t5", &btc).unwrap_err().contains("segwit"));
+    }
+    #[test]
+    fn script_type() {
+        let script_type = |script_pubkey: &str| crate::script_type(&Script::from_str(script_pubkey).unwrap());
+        assert_eq!(script_type("76a914f22f5563839ba6ba5aa8d3726fcbc675cb3e4c9e88ac"), "pubkeyhash");
+        assert_eq!(script_type("0014751e76e8199196d454941c45d1b3a323f1433bd6"), "witnesspubkeyhash");
+        assert_eq!(script_type("512079be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798"), "witness_v1_taproot");
+        assert_eq!(script_type("6002751e"), "witness_unknown");
+        assert_eq!(script_type("5210751e76e8199196d454941c45d1b3a323"), "witness_unknown");
+        assert_eq!(script_type("410496b538e853519c726a2c91e61ec11600ae1390813a627c66fb8be7947be63c52da7589379515d4e0a604f8141781e62294721166bf621e73a82cbf2342c858eeac"), "pubkey");
+        assert_eq!(script_type("6a0401020304"), "unknown");
+    }
+    #[test]
+    fn script_or_address_to_string_p2pk() {
+        // The output of the block 1 of the mainnet.
+        let config = config_example("btc");
+        let script_pubkey = Script::from_str("410496b538e853519c726a2c91e61ec11600ae1390813a627c66fb8be7947be63c52da7589379515d4e0a604f8141781e62294721166bf621e73a82cbf2342c858eeac").unwrap();
+        let p2pkh = p2pk_to_p2pkh(&script_pubkey).unwrap();
+        assert_eq!(p2pkh, Script::from_str("76a914119b098e2e980a229e139a9ed01a469e518e6f2688ac").unwrap());
+        assert_eq!(script_to_address_string(&script_pubkey, &config).unwrap(), "12c6DSiU4Rq3P4ZxziKxzrL5LmMBrzjrJX");
+        assert_eq!(p2pk_to_p2pkh(&p2pkh), None);
+    }
+    #[test]
+    fn op_return() {
+        let op_return = |hex: &str| Script::from_str(hex).unwrap();
+        // The witness commitment of the block 102 of the regtest fixtures.
+        let commitment = op_return("6a24aa21a9edaaed4e9155661fb1a2bfc87c1458ab0eebc25bf8f0b8126ccc9214c407ebd34f");
+        assert_eq!(op_return_data(&commitment), Some(hex::decode("aa21a9edaaed4e9155661fb1a2bfc87c1458ab0eebc25bf8f0b8126ccc9214c407ebd34f").unwrap()));
+        assert_eq!(op_return_protocol(&commitment), Some("witness_commitment"));
+        // Multiple pushes are concatenated.
+        assert_eq!(op_return_data(&op_return("6a046f6d6e690401020304")), Some(b"omni\x01\x02\x03\x04".to_vec()));
+        assert_eq!(op_return_protocol(&op_return("6a046f6d6e690401020304")), Some("omni"));
+        assert_eq!(op_return_protocol(&op_return("6a5d0114")), Some("runestone"));
+        assert_eq!(op_return_data(&op_return("6a")), Some(Vec::new()));
+        assert_eq!(op_return_protocol(&op_return("6a0401020304")), None);
+        assert_eq!(op_return_data(&op_return("76a914119b098e2e980a229e139a9ed01a469e518e6f2688ac")), None);
+    }
+    #[test]
+    fn multisig() {
+        // 1-of-2 multisig in the mainnet tx 60a20bd93aa49ab4b28d514ec10b06e1829ce6818ec06cd3aabd013ebcdc4bb1.
+        let pubkey1 = "04cc71eb30d653c0c3163990c47b976f3fb3f37cccdcbedb169a1dfef58bbfbfaff7d8a473e7e2e6d317b87bafe8bde97e3cf8f065dec022b51d11fcdd0d348ac4";
+        let pubkey2 = "0461cbdcc5409fb4b4d42b51d33381354d80e550078cb532a34bfa2fcfdeb7d76519aecc62770f5b0e4ef8551946d8a540911abe3e7854a26f39f58b25c15342af";
+        let script_pubkey = Script::from_str(&format!("5141{}41{}52ae", pubkey1, pubkey2)).unwrap();
+        assert_eq!(crate::script_type(&script_pubkey), "multisig");
+        assert_eq!(parse_multisig(&script_pubkey), Some((1, vec![hex::decode(pubkey1).unwrap(), hex::decode(pubkey2).unwrap()])));
+        assert_eq!(multisig_to_p2pkhs(&script_pubkey), vec![
+            p2pk_to_p2pkh(&Script::from_str(&format!("41{}ac", pubkey1)).unwrap()).unwrap(),
+            p2pk_to_p2pkh(&Script::from_str(&format!("41{}ac", pubkey2)).unwrap()).unwrap(),
+        ]);
+        let config = config_example("btc");
+        let rest = crate::create_script_pub_key(&script_pubkey, &config);
+        assert_eq!(rest.address, None);
+        assert_eq!(rest.req_sigs, Some(1));
+        assert_eq!(rest.pubkeys, Some(vec![pubkey1.to_string(), pubkey2.to_string()]));
+        // m > n.
+        assert_eq!(parse_multisig(&Script::from_str(&format!("5241{}51ae", pubkey1)).unwrap()), None);
+        // The number of public keys does not match n.
+        assert_eq!(parse_multisig(&Script::from_str(&format!("5141{}52ae", pubkey1)).unwrap()), None);
+        // Invalid public key.
+        assert_eq!(parse_multisig(&Script::from_str(&format!("5121{}{}51ae", "05", "00".repeat(32))).unwrap()), None);
+        assert!(multisig_to_p2pkhs(&Script::from_str("76a914119b098e2e980a229e139a9ed01a469e518e6f2688ac").unwrap()).is_empty());
+    }
+    #[test]
+    fn block_subsidy() {
+        let btc = config_example("btc");
+        assert_eq!(get_block_subsidy(0, &btc), 5_000_000_000);
+        assert_eq!(get_block_subsidy(209_999, &btc), 5_000_000_000);
+        assert_eq!(get_block_subsidy(210_000, &btc), 2_500_000_000);
+        assert_eq!(get_block_subsidy(630_000, &btc), 625_000_000);
+        assert_eq!(get_block_subsidy(6_720_000, &btc), 1);
+        assert_eq!(get_block_subsidy(6_930_000, &btc), 0);
+        assert_eq!(get_block_subsidy(210_000 * 64, &btc), 0);
+        assert_eq!(get_block_subsidy(150, &config_example("rbtc")), 2_500_000_000);
+        assert_eq!(get_block_subsidy(1_051_200, &config_example("mona")), 2_500_000_000);
+        let mut no_halving = btc.clone();
+        no_halving.halving_interval = 0;
+        assert_eq!(get_block_subsidy(210_000 * 64, &no_halving), 5_000_000_000);
+    }
+    #[test]
+    fn merkle_branch() {
+        for block in fixtures::regtest_blocks().iter() {
+            let txids = block.txdata.iter().map(|tx| tx.txid()).collect::<Vec<Txid>>();
+            for (pos, txid) in txids.iter().enumerate() {
+                let mut index = pos;
+                let mut hash = sha256d::Hash::from_inner(txid.into_inner());
+                for sibling in super::merkle_branch(&txids, pos).iter() {
+                    let mut engine = sha256d::Hash::engine();
+                    if index % 2 == 0 {
+                        engine.input(&hash);
+                        engine.input(sibling);
+                    } else {
+                        engine.input(sibling);
+                        engine.input(&hash);
+                    }
+                    hash = sha256d::Hash::from_engine(engine);
+                    index /= 2;
+                }
+                assert_eq!(hash.into_inner(), block.header.merkle_root.into_inner());
+            }
+        }
+    }
+    #[test]
     fn uint256_as_f64_12345() {
         assert!((uint256_as_f64(&Uint256::from_u64(12345).unwrap()) - 12345f64).abs() < f64::EPSILON);
     }
+    #[test]
+    fn get_vsize() {
+        let block = &fixtures::regtest_blocks()[102];
+        // The weight is a multiple of 4.
+        assert_eq!(block.txdata[0].get_weight(), 568);
+        assert_eq!(super::get_vsize(&block.txdata[0]), 142);
+        // The weight is rounded up.
+        assert_eq!(block.txdata[1].get_weight(), 561);
+        assert_eq!(super::get_vsize(&block.txdata[1]), 141);
+    }
 }
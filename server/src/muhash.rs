@@ -0,0 +1,239 @@
+//! MuHash3072 rolling set hash, compatible with Bitcoin Core's `gettxoutsetinfo muhash`.
+//!
+//! See also: <https://github.com/bitcoin/bitcoin/blob/master/src/crypto/muhash.h>.
+
+use crate::bitcoin::hashes::{sha256, Hash};
+use crate::{bytes_to_u32, bytes_to_u64};
+
+const LIMBS: usize = 48;
+pub const BYTE_SIZE: usize = LIMBS * 8;
+
+/// The modulus is `2^3072 - MAX_PRIME_DIFF`.
+const MAX_PRIME_DIFF: u64 = 1103717;
+
+/// An integer in `[0, 2^3072)` stored as little endian 64-bit limbs.
+#[derive(Clone, PartialEq, Eq)]
+struct Num3072([u64; LIMBS]);
+
+impl std::fmt::Debug for Num3072 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Num3072({})", hex::encode(self.to_bytes()))
+    }
+}
+
+impl Num3072 {
+    fn one() -> Self {
+        let mut limbs = [0u64; LIMBS];
+        limbs[0] = 1;
+        Self(limbs)
+    }
+    fn from_bytes(buf: &[u8]) -> Self {
+        assert_eq!(buf.len(), BYTE_SIZE);
+        let mut limbs = [0u64; LIMBS];
+        for (limb, chunk) in limbs.iter_mut().zip(buf.chunks(8)) {
+            *limb = bytes_to_u64(chunk);
+        }
+        Self(limbs)
+    }
+    fn to_bytes(&self) -> Vec<u8> {
+        self.0.iter().flat_map(|limb| limb.to_le_bytes().to_vec()).collect()
+    }
+    /// Hash arbitrary data into a number (SHA256, then expanded by ChaCha20).
+    fn from_data(data: &[u8]) -> Self {
+        let key = sha256::Hash::hash(data).into_inner();
+        Self::from_bytes(&chacha20_keystream(&key, BYTE_SIZE))
+    }
+    /// Reduce `self + carry * 2^3072` modulo `2^3072 - MAX_PRIME_DIFF`.
+    fn reduce(mut self, mut carry: u64) -> Self {
+        // 2^3072 is congruent to MAX_PRIME_DIFF.
+        while carry != 0 {
+            let mut c = carry as u128 * MAX_PRIME_DIFF as u128;
+            for limb in self.0.iter_mut() {
+                if c == 0 {
+                    break;
+                }
+                let v = *limb as u128 + c;
+                *limb = v as u64;
+                c = v >> 64;
+            }
+            carry = c as u64;
+        }
+        // Subtract the modulus if `self` is not smaller than it.
+        let mut sum = self.clone();
+        let mut c = MAX_PRIME_DIFF as u128;
+        for limb in sum.0.iter_mut() {
+            let v = *limb as u128 + c;
+            *limb = v as u64;
+            c = v >> 64;
+        }
+        if c != 0 { sum } else { self }
+    }
+    fn mul(&self, other: &Self) -> Self {
+        let mut t = [0u64; LIMBS * 2];
+        for i in 0..LIMBS {
+            let mut carry = 0u128;
+            for j in 0..LIMBS {
+                let v = self.0[i] as u128 * other.0[j] as u128 + t[i + j] as u128 + carry;
+                t[i + j] = v as u64;
+                carry = v >> 64;
+            }
+            t[i + LIMBS] = carry as u64;
+        }
+        // Fold the upper half: hi * 2^3072 + lo is congruent to hi * MAX_PRIME_DIFF + lo.
+        let mut r = [0u64; LIMBS];
+        let mut carry = 0u128;
+        for i in 0..LIMBS {
+            let v = t[i] as u128 + t[i + LIMBS] as u128 * MAX_PRIME_DIFF as u128 + carry;
+            r[i] = v as u64;
+            carry = v >> 64;
+        }
+        Self(r).reduce(carry as u64)
+    }
+    /// Compute the modular inverse by Fermat's little theorem (`self^(p-2)`).
+    fn inverse(&self) -> Self {
+        let mut exponent = [u64::MAX; LIMBS];
+        exponent[0] = u64::MAX - MAX_PRIME_DIFF - 1;
+        let mut ret = Self::one();
+        for limb in exponent.iter().rev() {
+            for bit in (0..64).rev() {
+                ret = ret.mul(&ret);
+                if (limb >> bit) & 1 == 1 {
+                    ret = ret.mul(self);
+                }
+            }
+        }
+        ret
+    }
+}
+
+/// ChaCha20 keystream with a zero nonce, starting from the block counter zero.
+fn chacha20_keystream(key: &[u8; 32], len: usize) -> Vec<u8> {
+    fn quarter_round(x: &mut [u32; 16], a: usize, b: usize, c: usize, d: usize) {
+        x[a] = x[a].wrapping_add(x[b]); x[d] = (x[d] ^ x[a]).rotate_left(16);
+        x[c] = x[c].wrapping_add(x[d]); x[b] = (x[b] ^ x[c]).rotate_left(12);
+        x[a] = x[a].wrapping_add(x[b]); x[d] = (x[d] ^ x[a]).rotate_left(8);
+        x[c] = x[c].wrapping_add(x[d]); x[b] = (x[b] ^ x[c]).rotate_left(7);
+    }
+    let mut ret = Vec::with_capacity(len);
+    let mut counter = 0u32;
+    while ret.len() < len {
+        let mut state = [0u32; 16];
+        state[..4].copy_from_slice(&[0x61707865, 0x3320646e, 0x79622d32, 0x6b206574]);
+        for (i, chunk) in key.chunks(4).enumerate() {
+            state[4 + i] = bytes_to_u32(chunk);
+        }
+        state[12] = counter;
+        let mut x = state;
+        for _ in 0..10 {
+            quarter_round(&mut x, 0, 4, 8, 12);
+            quarter_round(&mut x, 1, 5, 9, 13);
+            quarter_round(&mut x, 2, 6, 10, 14);
+            quarter_round(&mut x, 3, 7, 11, 15);
+            quarter_round(&mut x, 0, 5, 10, 15);
+            quarter_round(&mut x, 1, 6, 11, 12);
+            quarter_round(&mut x, 2, 7, 8, 13);
+            quarter_round(&mut x, 3, 4, 9, 14);
+        }
+        for (x, s) in x.iter().zip(state.iter()) {
+            ret.extend_from_slice(&x.wrapping_add(*s).to_le_bytes());
+        }
+        counter += 1;
+    }
+    ret.truncate(len);
+    ret
+}
+
+/// A set hash which supports insertion and removal of elements in any order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MuHash3072 {
+    numerator: Num3072,
+    denominator: Num3072,
+}
+
+impl Default for MuHash3072 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MuHash3072 {
+    /// Create the hash of the empty set.
+    pub fn new() -> Self {
+        Self {
+            numerator: Num3072::one(),
+            denominator: Num3072::one(),
+        }
+    }
+    pub fn insert(&mut self, data: &[u8]) {
+        self.numerator = self.numerator.mul(&Num3072::from_data(data));
+    }
+    pub fn remove(&mut self, data: &[u8]) {
+        self.denominator = self.denominator.mul(&Num3072::from_data(data));
+    }
+    /// Compute the SHA256 digest of the set (in the internal byte order).
+    ///
+    /// Note that Bitcoin Core displays the digest in the reversed byte order (like txids).
+    pub fn finalize(&self) -> [u8; 32] {
+        let num = self.numerator.mul(&self.denominator.inverse());
+        sha256::Hash::hash(&num.to_bytes()).into_inner()
+    }
+    pub fn to_bytes(&self) -> Vec<u8> {
+        [self.numerator.to_bytes(), self.denominator.to_bytes()].concat()
+    }
+    pub fn from_bytes(buf: &[u8]) -> Self {
+        assert_eq!(buf.len(), BYTE_SIZE * 2);
+        Self {
+            numerator: Num3072::from_bytes(&buf[..BYTE_SIZE]),
+            denominator: Num3072::from_bytes(&buf[BYTE_SIZE..]),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    fn reversed_hex(digest: &[u8; 32]) -> String {
+        hex::encode(digest.iter().rev().cloned().collect::<Vec<u8>>())
+    }
+    #[test]
+    fn chacha20() {
+        // RFC 8439, A.1 Test Vector #1.
+        assert_eq!(hex::encode(chacha20_keystream(&[0u8; 32], 64)),
+            "76b8e0ada0f13d90405d6ae55386bd28bdd219b8a08ded1aa836efcc8b770dc7da41597c5157488d7724e03fb8d84a376a43b8f41518a11cc387b669b2ee6586");
+    }
+    #[test]
+    fn num3072() {
+        let a = Num3072::from_data(b"a");
+        assert_eq!(a.mul(&a.inverse()), Num3072::one());
+        // (p - 1) * (p - 1) = 1 (mod p).
+        let mut minus_one = Num3072([u64::MAX; LIMBS]);
+        minus_one.0[0] = u64::MAX - MAX_PRIME_DIFF;
+        assert_eq!(minus_one.mul(&minus_one), Num3072::one());
+        assert_eq!(Num3072::from_bytes(&a.to_bytes()), a);
+    }
+    #[test]
+    fn muhash3072() {
+        // The same test vector as Bitcoin Core's `muhash_tests`.
+        let mut muhash = MuHash3072::new();
+        muhash.insert(&[0u8; 32]);
+        let mut one = [0u8; 32];
+        one[0] = 1;
+        muhash.insert(&one);
+        let mut two = [0u8; 32];
+        two[0] = 2;
+        muhash.remove(&two);
+        assert_eq!(reversed_hex(&muhash.finalize()), "10d312b100cbd32ada024a6646e40d3482fcff103668d2625f10002a607d5863");
+        // The order of operations does not matter.
+        let mut muhash2 = MuHash3072::new();
+        muhash2.remove(&two);
+        muhash2.insert(&one);
+        muhash2.insert(&[0u8; 32]);
+        assert_eq!(muhash2.finalize(), muhash.finalize());
+        // Removing all elements yields the hash of the empty set.
+        muhash.insert(&two);
+        muhash.remove(&one);
+        muhash.remove(&[0u8; 32]);
+        assert_eq!(muhash.finalize(), MuHash3072::new().finalize());
+        assert_eq!(MuHash3072::from_bytes(&muhash.to_bytes()), muhash);
+    }
+}
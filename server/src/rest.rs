@@ -2,10 +2,129 @@ use crate::*;
 use bitcoin::hashes::hex::ToHex;
 use chainseeker::*;
 use crate::db::tx::TxDB;
-use crate::db::block::BlockContentDBValue;
+use crate::db::block::{BlockDB, BlockContentDBValue};
+use crate::db::block_stats::BlockStatsDBValue;
+use crate::db::pool::PoolDBValue;
+use crate::db::supply::SupplyDBValue;
+use crate::db::op_return::{OpReturnDBKey, OpReturnDBValue};
+use crate::counterparty::{CounterpartyMessage, asset_name};
+use crate::db::rich_list::{RICH_LIST_BUCKETS, RichListEntry as RichListDBEntry};
+use crate::db::rich_list_snapshot::{RichListSnapshot, RichListRankHistoryEntry as RichListRankHistoryDBEntry};
+use crate::db::utxo::UtxoSetStats;
 use crate::db::utxo_server::UtxoServerValue;
 
+/// The classification of an input by the previous output script, the scriptSig and the witness.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct InputInfo {
+    pub input_type: Option<&'static str>,
+    pub redeem_script: Option<Vec<u8>>,
+    pub witness_script: Option<Vec<u8>>,
+    pub tapleaf_script: Option<Vec<u8>>,
+    pub control_block: Option<Vec<u8>>,
+    pub sighash_flags: Vec<u8>,
+}
+
+/// Returns the sighash flag if `data` looks like a DER-encoded ECDSA signature followed by the flag.
+fn ecdsa_sighash_flag(data: &[u8]) -> Option<u8> {
+    if data.len() >= 9 && data.len() <= 73 && data[0] == 0x30 && data[1] as usize == data.len() - 3 {
+        return data.last().copied();
+    }
+    None
+}
+
+/// Returns the sighash flag if `data` looks like a BIP340 signature (64 bytes for SIGHASH_DEFAULT).
+fn schnorr_sighash_flag(data: &[u8]) -> Option<u8> {
+    match data.len() {
+        64 => Some(0),
+        65 => Some(data[64]),
+        _ => None,
+    }
+}
+
+/// Format the sighash flag in the same notation as Bitcoin Core (e.g. "ALL|ANYONECANPAY").
+pub fn sighash_flag_to_string(flag: u8) -> String {
+    let base = match flag & 0x7f {
+        0 => "DEFAULT",
+        1 => "ALL",
+        2 => "NONE",
+        3 => "SINGLE",
+        _ => return format!("{:#04x}", flag),
+    };
+    if flag & 0x80 == 0 { base.to_string() } else { format!("{}|ANYONECANPAY", base) }
+}
+
+/// Classify the input spending `previous_script_pubkey` (None if the previous output is unknown).
+pub fn classify_input(txin: &bitcoin::TxIn, previous_script_pubkey: Option<&bitcoin::Script>) -> InputInfo {
+    let mut info = InputInfo::default();
+    if txin.previous_output.is_null() {
+        info.input_type = Some("coinbase");
+        return info;
+    }
+    let script_pubkey = match previous_script_pubkey {
+        Some(script_pubkey) => script_pubkey,
+        None => return info,
+    };
+    let ecdsa = |items: &[Vec<u8>]| items.iter().filter_map(|item| ecdsa_sighash_flag(item)).collect::<Vec<u8>>();
+    let pushes = script_pushes(&txin.script_sig).unwrap_or_default();
+    let witness = &txin.witness;
+    // The last witness item of P2WSH is the witness script and the rest are its arguments.
+    let p2wsh = |info: &mut InputInfo| {
+        let (witness_script, args) = witness.split_last()?;
+        info.witness_script = Some(witness_script.clone());
+        Some(ecdsa(args))
+    };
+    let (input_type, sighash_flags) = if script_pubkey.is_p2pk() {
+        ("pubkey", ecdsa(&pushes))
+    } else if script_pubkey.is_p2pkh() {
+        ("pubkeyhash", ecdsa(&pushes))
+    } else if parse_multisig(script_pubkey).is_some() {
+        ("multisig", ecdsa(&pushes))
+    } else if script_pubkey.is_p2sh() {
+        match pushes.split_last() {
+            Some((redeem_script, args)) => {
+                info.redeem_script = Some(redeem_script.clone());
+                let redeem_script = bitcoin::Script::from(redeem_script.clone());
+                if redeem_script.is_v0_p2wpkh() {
+                    ("p2sh-witnesspubkeyhash", ecdsa(witness))
+                } else if redeem_script.is_v0_p2wsh() {
+                    ("p2sh-witnessscripthash", p2wsh(&mut info).unwrap_or_default())
+                } else {
+                    ("scripthash", ecdsa(args))
+                }
+            },
+            None => ("scripthash", Vec::new()),
+        }
+    } else if script_pubkey.is_v0_p2wpkh() {
+        ("witnesspubkeyhash", ecdsa(witness))
+    } else if script_pubkey.is_v0_p2wsh() {
+        ("witnessscripthash", p2wsh(&mut info).unwrap_or_default())
+    } else if is_p2tr(script_pubkey) {
+        // Drop the annex (BIP341).
+        let items = match witness.split_last() {
+            Some((annex, items)) if !items.is_empty() && annex.first() == Some(&0x50) => items,
+            _ => &witness[..],
+        };
+        match items {
+            [signature] => ("witness_v1_taproot_keypath", schnorr_sighash_flag(signature).into_iter().collect()),
+            [args @ .., tapleaf_script, control_block] => {
+                info.tapleaf_script = Some(tapleaf_script.clone());
+                info.control_block = Some(control_block.clone());
+                ("witness_v1_taproot_scriptpath", args.iter().filter_map(|arg| schnorr_sighash_flag(arg)).collect())
+            },
+            [] => ("witness_v1_taproot", Vec::new()),
+        }
+    } else if script_pubkey.is_witness_program() {
+        ("witness_unknown", Vec::new())
+    } else {
+        ("nonstandard", Vec::new())
+    };
+    info.input_type = Some(input_type);
+    info.sighash_flags = sighash_flags;
+    info
+}
+
 pub fn create_vin(txin: &bitcoin::TxIn, previous_txout: &Option<bitcoin::TxOut>, config: &Config) -> Vin {
+    let info = classify_input(txin, previous_txout.as_ref().map(|txout| &txout.script_pubkey));
     Vin {
         txid: txin.previous_output.txid.to_string(),
         vout: txin.previous_output.vout,
@@ -23,34 +142,63 @@ pub fn create_vin(txin: &bitcoin::TxIn, previous_txout: &Option<bitcoin::TxOut>,
             Some(previous_txout) => script_to_address_string(&previous_txout.script_pubkey, config),
             None => None,
         },
+        input_type: info.input_type.map(|input_type| input_type.to_string()),
+        redeem_script: info.redeem_script.map(hex::encode),
+        witness_script: info.witness_script.map(hex::encode),
+        tapleaf_script: info.tapleaf_script.map(hex::encode),
+        control_block: info.control_block.map(hex::encode),
+        sighash_flags: if info.sighash_flags.is_empty() {
+            None
+        } else {
+            Some(info.sighash_flags.iter().map(|flag| sighash_flag_to_string(*flag)).collect())
+        },
     }
 }
 
-pub fn create_script_pub_key(script_pubkey: &bitcoin::Script, config: &Config) -> ScriptPubKey {
+/// Returns the script type in the same notation as Bitcoin Core.
+pub fn script_type(script_pubkey: &bitcoin::Script) -> &'static str {
+    if is_p2tr(script_pubkey) {
+        return "witness_v1_taproot";
+    }
+    if script_pubkey.is_p2pk() {
+        return "pubkey";
+    }
+    if parse_multisig(script_pubkey).is_some() {
+        return "multisig";
+    }
     let address = bitcoin::Address::from_script(&script_pubkey, bitcoin::Network::Bitcoin /* any */);
-    let address_str = script_to_address_string(&script_pubkey, config);
+    match address.and_then(|address| address.address_type()) {
+        Some(bitcoin::AddressType::P2pkh)  => "pubkeyhash",
+        Some(bitcoin::AddressType::P2sh)   => "scripthash",
+        Some(bitcoin::AddressType::P2wpkh) => "witnesspubkeyhash",
+        Some(bitcoin::AddressType::P2wsh)  => "witnessscripthash",
+        // Witness versions 1 to 16 except for taproot.
+        None if script_pubkey.is_witness_program() && script_pubkey[0] != 0 => "witness_unknown",
+        None => "unknown",
+    }
+}
+
+pub fn create_script_pub_key(script_pubkey: &bitcoin::Script, config: &Config) -> ScriptPubKey {
+    let multisig = parse_multisig(script_pubkey);
     ScriptPubKey {
         asm: script_pubkey.asm(),
         hex: hex::encode(script_pubkey.as_bytes()),
-        r#type: address.map_or("unknown", |address| address.address_type().map_or("unknown", |address_type| {
-            match address_type {
-                bitcoin::AddressType::P2pkh  => "pubkeyhash",
-                bitcoin::AddressType::P2sh   => "scripthash",
-                bitcoin::AddressType::P2wpkh => "witnesspubkeyhash",
-                bitcoin::AddressType::P2wsh  => "witnessscripthash",
-            }
-        })).to_string(),
-        address: address_str,
+        r#type: script_type(script_pubkey).to_string(),
+        address: script_to_address_string(&script_pubkey, config),
+        req_sigs: multisig.as_ref().map(|(m, _pubkeys)| *m),
+        pubkeys: multisig.map(|(_m, pubkeys)| pubkeys.iter().map(hex::encode).collect()),
     }
 }
 
-pub fn create_utxo(value: &UtxoServerValue, tx: &bitcoin::Transaction, config: &Config) -> Utxo {
-    let previous_output = &tx.output[value.vout as usize];
+pub fn create_utxo(value: &UtxoServerValue, script_pubkey: &bitcoin::Script, synced_height: u32, config: &Config) -> Utxo {
     Utxo {
         txid: value.txid.to_string(),
         vout: value.vout,
-        script_pub_key: create_script_pub_key(&previous_output.script_pubkey, config),
-        value: previous_output.value,
+        script_pub_key: create_script_pub_key(script_pubkey, config),
+        value: value.value,
+        confirmations: (synced_height + 1).saturating_sub(value.height()),
+        height: value.height(),
+        coinbase: value.coinbase(),
     }
 }
 
@@ -59,10 +207,41 @@ pub fn create_vout(txout: &bitcoin::TxOut, n: usize, config: &Config) -> Vout {
         value: txout.value,
         n,
         script_pub_key: create_script_pub_key(&txout.script_pubkey, config),
+        protocol: op_return_protocol(&txout.script_pubkey).map(|protocol| protocol.to_string()),
     }
 }
 
-pub fn create_block_header(block_content: &BlockContentDBValue, config: &Config) -> chainseeker::BlockHeader {
+pub fn create_op_return(key: &OpReturnDBKey, value: &OpReturnDBValue) -> OpReturn {
+    OpReturn {
+        txid: key.txid.to_string(),
+        vout: key.vout,
+        height: key.height,
+        data: hex::encode(op_return_data(&value.script_pubkey).unwrap_or_default()),
+        protocol: op_return_protocol(&value.script_pubkey).map(|protocol| protocol.to_string()),
+    }
+}
+
+pub fn create_counterparty(message: &CounterpartyMessage, config: &Config) -> Counterparty {
+    let asset_and_quantity = message.asset_and_quantity();
+    Counterparty {
+        message_type_id: message.type_id,
+        message_type: message.message_type().to_string(),
+        asset: asset_and_quantity.map(|(asset_id, _quantity)| asset_name(asset_id, &config.counterparty_native_assets)),
+        quantity: asset_and_quantity.map(|(_asset_id, quantity)| quantity),
+        data: hex::encode(&message.payload),
+    }
+}
+
+pub fn create_block_pool(pool: &PoolDBValue) -> BlockPool {
+    BlockPool {
+        name          : pool.pool.as_ref().map(|pool| pool.name.clone()),
+        link          : pool.pool.as_ref().and_then(|pool| pool.link.clone()),
+        coinbase_tag  : pool.coinbase_tag.clone(),
+        payout_address: pool.payout_address.clone(),
+    }
+}
+
+pub fn create_block_header(block_content: &BlockContentDBValue, pool: &Option<PoolDBValue>, config: &Config) -> chainseeker::BlockHeader {
     let block_header = &block_content.block_header;
     chainseeker::BlockHeader {
         height           : block_content.height,
@@ -79,11 +258,12 @@ pub fn create_block_header(block_content: &BlockContentDBValue, config: &Config)
         strippedsize     : block_content.strippedsize,
         weight           : block_content.weight,
         ntxs             : block_content.txids.len(),
+        pool             : pool.as_ref().map(create_block_pool),
     }
 }
 
-pub fn create_block_with_txids(block_content: &BlockContentDBValue, config: &Config) -> BlockWithTxids {
-    let block_header = create_block_header(block_content, config);
+pub fn create_block_with_txids(block_content: &BlockContentDBValue, pool: &Option<PoolDBValue>, config: &Config) -> BlockWithTxids {
+    let block_header = create_block_header(block_content, pool, config);
     BlockWithTxids {
         height           : block_header.height,
         header           : block_header.header,
@@ -99,15 +279,17 @@ pub fn create_block_with_txids(block_content: &BlockContentDBValue, config: &Con
         strippedsize     : block_header.strippedsize,
         weight           : block_header.weight,
         txids            : block_content.txids.iter().map(|txid| txid.to_hex()).collect::<Vec<String>>(),
+        pool             : block_header.pool,
     }
 }
 
-pub fn create_block_with_txs(tx_db: &TxDB, block_content: &BlockContentDBValue, config: &Config) -> BlockWithTxs {
-    let block_header = create_block_header(block_content, config);
+/// The number of confirmations of the transactions is omitted so that the response can be cached.
+pub fn create_block_with_txs(tx_db: &TxDB, block_db: &BlockDB, block_content: &BlockContentDBValue, pool: &Option<PoolDBValue>, config: &Config) -> BlockWithTxs {
+    let block_header = create_block_header(block_content, pool, config);
     // TODO: waiting upstream fix: https://github.com/rust-rocksdb/rust-rocksdb/issues/536
     //let txs = tx_db.multi_get_as_rest(block_content.txids.clone());
     let txs = block_content.txids.iter().map(|txid| {
-        tx_db.get_as_rest(txid, config).unwrap()
+        tx_db.get_as_rest(txid, block_db, None, config).unwrap()
     }).collect::<Vec<Transaction>>();
     BlockWithTxs {
         height           : block_header.height,
@@ -124,10 +306,31 @@ pub fn create_block_with_txs(tx_db: &TxDB, block_content: &BlockContentDBValue,
         strippedsize     : block_header.strippedsize,
         weight           : block_header.weight,
         txs,
+        pool             : block_header.pool,
+    }
+}
+
+pub fn create_block_stats(block: &BlockContentDBValue, stats: &BlockStatsDBValue) -> BlockStats {
+    BlockStats {
+        height             : stats.height,
+        hash               : block.block_header.block_hash().to_string(),
+        txs                : stats.txs,
+        ins                : stats.ins,
+        outs               : stats.outs,
+        total_in           : stats.total_in,
+        total_out          : stats.total_out,
+        totalfee           : stats.totalfee,
+        subsidy            : stats.subsidy,
+        minfeerate         : stats.minfeerate,
+        maxfeerate         : stats.maxfeerate,
+        feerate_percentiles: stats.feerate_percentiles,
+        swtxs              : stats.swtxs,
+        taproottxs         : stats.taproottxs,
+        utxo_increase      : stats.utxo_increase,
     }
 }
 
-pub fn create_block_summary(block: &BlockContentDBValue) -> BlockSummary {
+pub fn create_block_summary(block: &BlockContentDBValue, stats: &Option<BlockStatsDBValue>, pool: &Option<PoolDBValue>) -> BlockSummary {
     BlockSummary {
         hash        : block.block_header.block_hash().to_string(),
         time        : block.block_header.time,
@@ -136,6 +339,114 @@ pub fn create_block_summary(block: &BlockContentDBValue) -> BlockSummary {
         strippedsize: block.strippedsize,
         weight      : block.weight,
         txcount     : block.txids.len(),
+        stats       : stats.as_ref().map(|stats| create_block_stats(block, stats)),
+        pool        : pool.as_ref().map(create_block_pool),
+    }
+}
+
+pub fn create_utxo_set_info(block: &BlockContentDBValue, stats: &UtxoSetStats) -> UtxoSetInfo {
+    // Bitcoin Core displays the digest in the reversed byte order.
+    let mut muhash = stats.muhash.finalize();
+    muhash.reverse();
+    UtxoSetInfo {
+        height      : block.height,
+        bestblock   : block.block_header.block_hash().to_string(),
+        txouts      : stats.txouts,
+        bogosize    : stats.bogosize,
+        muhash      : hex::encode(muhash),
+        total_amount: stats.total_amount,
+    }
+}
+
+pub fn create_rich_list_entry(entry: &RichListDBEntry, config: &Config) -> RichListEntry {
+    RichListEntry {
+        script_pub_key: create_script_pub_key(&entry.script_pubkey, config),
+        value: entry.value,
+    }
+}
+
+pub fn create_rich_list_distribution(snapshot: &RichListSnapshot) -> RichListDistribution {
+    RichListDistribution {
+        height     : snapshot.height,
+        count      : snapshot.count,
+        total_value: snapshot.total_value,
+        entries    : snapshot.entries,
+        buckets    : snapshot.buckets.iter().enumerate().map(|(i, bucket)| RichListBucket {
+            min  : RICH_LIST_BUCKETS[i],
+            max  : RICH_LIST_BUCKETS.get(i + 1).copied(),
+            count: bucket.count,
+            value: bucket.value,
+        }).collect(),
+    }
+}
+
+pub fn create_rich_list_rank_history_entry(entry: &RichListRankHistoryDBEntry) -> RichListRankHistoryEntry {
+    RichListRankHistoryEntry {
+        height: entry.height,
+        rank  : entry.rank,
+        value : entry.value,
+    }
+}
+
+pub fn create_supply(supply: &SupplyDBValue) -> Supply {
+    Supply {
+        height           : supply.height,
+        total_subsidy    : supply.total_subsidy,
+        total_fees       : supply.total_fees,
+        total_unspendable: supply.total_unspendable(),
+        unspendables     : SupplyUnspendables {
+            genesis_block    : supply.unspendable_genesis_block,
+            bip30            : supply.unspendable_bip30,
+            scripts          : supply.unspendable_scripts,
+            unclaimed_rewards: supply.unspendable_unclaimed_rewards,
+        },
+        circulating      : supply.circulating(),
+    }
+}
+
+/// Aggregate mining pool statistics of `blocks` (which should be sorted by height).
+///
+/// The hashrate is estimated from the total work of `blocks` and the time elapsed since `prev_time`
+/// (the timestamp of the block preceding `blocks`). If `prev_time` is `None`, the first block is used instead.
+pub fn create_pools(blocks: &[(BlockContentDBValue, Option<PoolDBValue>)], prev_time: Option<u32>, config: &Config) -> Pools {
+    const UNKNOWN: &str = "Unknown";
+    let mut pools: Vec<PoolStats> = Vec::new();
+    for (_block, pool) in blocks.iter() {
+        let info = pool.as_ref().and_then(|pool| pool.pool.as_ref());
+        let name = info.map_or(UNKNOWN, |info| &info.name);
+        match pools.iter_mut().find(|stats| stats.name == name) {
+            Some(stats) => stats.blocks += 1,
+            None => pools.push(PoolStats {
+                name    : name.to_string(),
+                link    : info.and_then(|info| info.link.clone()),
+                blocks  : 1,
+                share   : 0.0,
+                hashrate: 0.0,
+            }),
+        }
+    }
+    // Estimate the network hashrate.
+    let (start_time, worked_blocks) = match prev_time {
+        Some(prev_time) => (prev_time, blocks),
+        None => (blocks.first().map_or(0, |(block, _pool)| block.block_header.time), blocks.get(1..).unwrap_or(&[])),
+    };
+    let work: f64 = worked_blocks.iter()
+        .map(|(block, _pool)| get_difficulty(&block.block_header, config) * 2f64.powi(32))
+        .sum();
+    let timespan = blocks.last().map_or(0, |(block, _pool)| block.block_header.time as i64 - start_time as i64);
+    let hashrate = if timespan > 0 { work / timespan as f64 } else { 0.0 };
+    let total = blocks.len() as u32;
+    for stats in pools.iter_mut() {
+        stats.share = stats.blocks as f64 / total as f64;
+        stats.hashrate = hashrate * stats.share;
+    }
+    pools.sort_by(|a, b| b.blocks.cmp(&a.blocks).then_with(|| a.name.cmp(&b.name)));
+    Pools {
+        from  : blocks.first().map_or(0, |(block, _pool)| block.height),
+        to    : blocks.last().map_or(0, |(block, _pool)| block.height),
+        blocks: total,
+        hashrate,
+        pools,
     }
 }
 
@@ -145,17 +456,109 @@ mod tests {
     #[test]
     fn rest() {
         let tx_db = TxDB::new("test/rest", true);
+        let block_db = BlockDB::new("test/rest", true);
         let regtest_blocks = fixtures::regtest_blocks();
         for (height, block) in regtest_blocks.iter().enumerate() {
+            block_db.put(height as u32, block);
             for tx in block.txdata.iter() {
                 tx_db.put_tx(tx, Some(height as u32)).unwrap();
             }
         }
         let config = config_example("rbtc");
-        let block_rest = create_block_with_txs(&tx_db, &BlockContentDBValue::new(102, &regtest_blocks[102]), &config);
+        let block_rest = create_block_with_txs(&tx_db, &block_db, &BlockContentDBValue::new(102, &regtest_blocks[102]), &None, &config);
         let block_rest_json = serde_json::to_string(&block_rest).unwrap();
         println!("{}", block_rest_json);
-        let block_json = r#"{"height":102,"header":"00000020f4a34bc39e46acbf6ad1cd786d978718b4ef94002eb080e86f383b77798b8b1e197bc47e4d72c8cc02c78a6c89a45db03f9906c1a60448bbeecf7b84566fc66eca41e560ffff7f2001000000","hash":"25263a195c89fae46d08558b1b501617aac630a6854000e19eb7ada6c39e6e0b","version":536870912,"previousblockhash":"1e8b8b79773b386fe880b02e0094efb41887976d78cdd16abfac469ec34ba3f4","merkleroot":"6ec66f56847bcfeebb4804a6c106993fb05da4896c8ac702ccc8724d7ec47b19","time":1625637322,"bits":"207fffff","difficulty":4.6565423739069247e-10,"nonce":1,"size":842,"strippedsize":481,"weight":2285,"txs":[{"confirmedHeight":102,"hex":"020000000001010000000000000000000000000000000000000000000000000000000000000000ffffffff0401660101ffffffff02547a062a0100000016001497033ca70d45fe6d49310859e132a9df98f976250000000000000000266a24aa21a9edaaed4e9155661fb1a2bfc87c1458ab0eebc25bf8f0b8126ccc9214c407ebd34f0120000000000000000000000000000000000000000000000000000000000000000000000000","txid":"b903046c3fb719a2a404e3b749609414b4f914b9cbf2eadf289c8ed2120aaebc","hash":"cb01519e3eee67593f7f0eb955a82a3aa6fc1c83364c50e7c6891b14320d88c7","size":169,"vsize":142,"weight":568,"version":2,"locktime":0,"vin":[{"txid":"0000000000000000000000000000000000000000000000000000000000000000","vout":4294967295,"scriptSig":{"asm":"OP_PUSHBYTES_1 66 OP_PUSHBYTES_1 01","hex":"01660101"},"txinwitness":["0000000000000000000000000000000000000000000000000000000000000000"],"sequence":4294967295,"value":0,"address":null}],"vout":[{"value":5000034900,"n":0,"scriptPubKey":{"asm":"OP_0 OP_PUSHBYTES_20 97033ca70d45fe6d49310859e132a9df98f97625","hex":"001497033ca70d45fe6d49310859e132a9df98f97625","type":"witnesspubkeyhash","address":"bcrt1qjupnefcdghlx6jf3ppv7zv4fm7v0ja39dzzwvd"}},{"value":0,"n":1,"scriptPubKey":{"asm":"OP_RETURN OP_PUSHBYTES_36 aa21a9edaaed4e9155661fb1a2bfc87c1458ab0eebc25bf8f0b8126ccc9214c407ebd34f","hex":"6a24aa21a9edaaed4e9155661fb1a2bfc87c1458ab0eebc25bf8f0b8126ccc9214c407ebd34f","type":"unknown","address":null}}],"fee":-5000034900},{"confirmedHeight":102,"hex":"02000000000101592f96fe043aaa22cdcb6f6e710946aa0af25dff4536759f6873965555a6660c0000000000fdffffff02ecd90f2401000000160014629ef06211f8e75e223b6338228a025bd15b0db900e1f50500000000160014f0e9ede24bceb0c16fdce952279d8094dc6b3f5802473044022057f0dde0e8d094034f47a136ae22a433e0216d5b1cfa3a03d8d259660148744702204377d2dbf3e442128c03097af4d4fa56b52b0cc7d9c24511b99d45a47e8df2d7012102b934d90a0aa5e0b73e04d00df3a8633ee16d24dad9ab21697f1dc5feb43fdbed65000000","txid":"e6a6a7db6faadbfc0815fd3a78c3b5d15cb733dde8aca0e911b6f565ea24ee73","hash":"4fdf235851b72bb7ddc8f6d86e43797bb42e3601ee1eb1e8d8a0e157b3c81af6","size":222,"vsize":141,"weight":561,"version":2,"locktime":101,"vin":[{"txid":"0c66a655559673689f753645ff5df20aaa4609716e6fcbcd22aa3a04fe962f59","vout":0,"scriptSig":{"asm":"","hex":""},"txinwitness":["3044022057f0dde0e8d094034f47a136ae22a433e0216d5b1cfa3a03d8d259660148744702204377d2dbf3e442128c03097af4d4fa56b52b0cc7d9c24511b99d45a47e8df2d701","02b934d90a0aa5e0b73e04d00df3a8633ee16d24dad9ab21697f1dc5feb43fdbed"],"sequence":4294967293,"value":5000000000,"address":"bcrt1qjupnefcdghlx6jf3ppv7zv4fm7v0ja39dzzwvd"}],"vout":[{"value":4899985900,"n":0,"scriptPubKey":{"asm":"OP_0 OP_PUSHBYTES_20 629ef06211f8e75e223b6338228a025bd15b0db9","hex":"0014629ef06211f8e75e223b6338228a025bd15b0db9","type":"witnesspubkeyhash","address":"bcrt1qv200qcs3lrn4ug3mvvuz9zszt0g4krde3uqyzy"}},{"value":100000000,"n":1,"scriptPubKey":{"asm":"OP_0 OP_PUSHBYTES_20 f0e9ede24bceb0c16fdce952279d8094dc6b3f58","hex":"0014f0e9ede24bceb0c16fdce952279d8094dc6b3f58","type":"witnesspubkeyhash","address":"bcrt1q7r57mcjte6cvzm7ua9fz08vqjnwxk06c2v6jdv"}}],"fee":14100},{"confirmedHeight":102,"hex":"0200000000010273ee24ea65f5b611e9a0ace8dd33b75cd1b5c3783afd1508fcdbaa6fdba7a6e60000000000fdffffff73ee24ea65f5b611e9a0ace8dd33b75cd1b5c3783afd1508fcdbaa6fdba7a6e60100000000fdffffff02001110240100000016001413bb0bcb776f3e15fa8800877552044d4db27b96ac58f50500000000160014261b6555a3cb5d3593c0275ff46f31c36e42a4c70247304402207a89cf2b2d7620ace221894746c4a72f5fd5dd5bbe9e56018b182eaf046e9766022050a14136c20402b281c244a918856b9e866b5fc77182334dd6242993e2144b7d0121034968df50370db27d51b294cba769ec47e0accb3a582c312549303c555ca834d102473044022058a1f0d9b8bde83c28954a1cfac6a3f43f8f8b53529df47d0ef2f7d66f8f42bb022026993b9ba88bedff19ec3f70d32bab177d35e0570f8c86a9b2eac5b359189c1d012103d0d5b793a8a23ff2e92b6204c15f72fd51765ad6c01f6ebd9e712adee11bda4065000000","txid":"29704e049abbacf3222cd8af9654afe6c7c91d5d9f2faa5d2f0f3cd5de3a812d","hash":"fb3551865d89fae25b8bcc5a02da25ad91d6fe1c0d4fd391b4c7b1b69e9ece63","size":370,"vsize":208,"weight":832,"version":2,"locktime":101,"vin":[{"txid":"e6a6a7db6faadbfc0815fd3a78c3b5d15cb733dde8aca0e911b6f565ea24ee73","vout":0,"scriptSig":{"asm":"","hex":""},"txinwitness":["304402207a89cf2b2d7620ace221894746c4a72f5fd5dd5bbe9e56018b182eaf046e9766022050a14136c20402b281c244a918856b9e866b5fc77182334dd6242993e2144b7d01","034968df50370db27d51b294cba769ec47e0accb3a582c312549303c555ca834d1"],"sequence":4294967293,"value":4899985900,"address":"bcrt1qv200qcs3lrn4ug3mvvuz9zszt0g4krde3uqyzy"},{"txid":"e6a6a7db6faadbfc0815fd3a78c3b5d15cb733dde8aca0e911b6f565ea24ee73","vout":1,"scriptSig":{"asm":"","hex":""},"txinwitness":["3044022058a1f0d9b8bde83c28954a1cfac6a3f43f8f8b53529df47d0ef2f7d66f8f42bb022026993b9ba88bedff19ec3f70d32bab177d35e0570f8c86a9b2eac5b359189c1d01","03d0d5b793a8a23ff2e92b6204c15f72fd51765ad6c01f6ebd9e712adee11bda40"],"sequence":4294967293,"value":100000000,"address":"bcrt1q7r57mcjte6cvzm7ua9fz08vqjnwxk06c2v6jdv"}],"vout":[{"value":4900000000,"n":0,"scriptPubKey":{"asm":"OP_0 OP_PUSHBYTES_20 13bb0bcb776f3e15fa8800877552044d4db27b96","hex":"001413bb0bcb776f3e15fa8800877552044d4db27b96","type":"witnesspubkeyhash","address":"bcrt1qzwashjmhdulpt75gqzrh25syf4xmy7uk6clm0p"}},{"value":99965100,"n":1,"scriptPubKey":{"asm":"OP_0 OP_PUSHBYTES_20 261b6555a3cb5d3593c0275ff46f31c36e42a4c7","hex":"0014261b6555a3cb5d3593c0275ff46f31c36e42a4c7","type":"witnesspubkeyhash","address":"bcrt1qycdk24dredwnty7qya0lgme3cdhy9fx83qc9wd"}}],"fee":20800}]}"#;
+        let block_json = r#"{"height":102,"header":"00000020f4a34bc39e46acbf6ad1cd786d978718b4ef94002eb080e86f383b77798b8b1e197bc47e4d72c8cc02c78a6c89a45db03f9906c1a60448bbeecf7b84566fc66eca41e560ffff7f2001000000","hash":"25263a195c89fae46d08558b1b501617aac630a6854000e19eb7ada6c39e6e0b","version":536870912,"previousblockhash":"1e8b8b79773b386fe880b02e0094efb41887976d78cdd16abfac469ec34ba3f4","merkleroot":"6ec66f56847bcfeebb4804a6c106993fb05da4896c8ac702ccc8724d7ec47b19","time":1625637322,"bits":"207fffff","difficulty":4.6565423739069247e-10,"nonce":1,"size":842,"strippedsize":481,"weight":2285,"txs":[{"confirmedHeight":102,"hex":"020000000001010000000000000000000000000000000000000000000000000000000000000000ffffffff0401660101ffffffff02547a062a0100000016001497033ca70d45fe6d49310859e132a9df98f976250000000000000000266a24aa21a9edaaed4e9155661fb1a2bfc87c1458ab0eebc25bf8f0b8126ccc9214c407ebd34f0120000000000000000000000000000000000000000000000000000000000000000000000000","txid":"b903046c3fb719a2a404e3b749609414b4f914b9cbf2eadf289c8ed2120aaebc","hash":"cb01519e3eee67593f7f0eb955a82a3aa6fc1c83364c50e7c6891b14320d88c7","size":169,"vsize":142,"weight":568,"version":2,"locktime":0,"vin":[{"txid":"0000000000000000000000000000000000000000000000000000000000000000","vout":4294967295,"scriptSig":{"asm":"OP_PUSHBYTES_1 66 OP_PUSHBYTES_1 01","hex":"01660101"},"txinwitness":["0000000000000000000000000000000000000000000000000000000000000000"],"sequence":4294967295,"value":0,"address":null,"inputType":"coinbase"}],"vout":[{"value":5000034900,"n":0,"scriptPubKey":{"asm":"OP_0 OP_PUSHBYTES_20 97033ca70d45fe6d49310859e132a9df98f97625","hex":"001497033ca70d45fe6d49310859e132a9df98f97625","type":"witnesspubkeyhash","address":"bcrt1qjupnefcdghlx6jf3ppv7zv4fm7v0ja39dzzwvd"}},{"value":0,"n":1,"scriptPubKey":{"asm":"OP_RETURN OP_PUSHBYTES_36 aa21a9edaaed4e9155661fb1a2bfc87c1458ab0eebc25bf8f0b8126ccc9214c407ebd34f","hex":"6a24aa21a9edaaed4e9155661fb1a2bfc87c1458ab0eebc25bf8f0b8126ccc9214c407ebd34f","type":"unknown","address":null},"protocol":"witness_commitment"}],"fee":null,"feeRate":null,"rbf":false,"coinbase":true,"blockHash":"25263a195c89fae46d08558b1b501617aac630a6854000e19eb7ada6c39e6e0b","blockTime":1625637322},{"confirmedHeight":102,"hex":"02000000000101592f96fe043aaa22cdcb6f6e710946aa0af25dff4536759f6873965555a6660c0000000000fdffffff02ecd90f2401000000160014629ef06211f8e75e223b6338228a025bd15b0db900e1f50500000000160014f0e9ede24bceb0c16fdce952279d8094dc6b3f5802473044022057f0dde0e8d094034f47a136ae22a433e0216d5b1cfa3a03d8d259660148744702204377d2dbf3e442128c03097af4d4fa56b52b0cc7d9c24511b99d45a47e8df2d7012102b934d90a0aa5e0b73e04d00df3a8633ee16d24dad9ab21697f1dc5feb43fdbed65000000","txid":"e6a6a7db6faadbfc0815fd3a78c3b5d15cb733dde8aca0e911b6f565ea24ee73","hash":"4fdf235851b72bb7ddc8f6d86e43797bb42e3601ee1eb1e8d8a0e157b3c81af6","size":222,"vsize":141,"weight":561,"version":2,"locktime":101,"vin":[{"txid":"0c66a655559673689f753645ff5df20aaa4609716e6fcbcd22aa3a04fe962f59","vout":0,"scriptSig":{"asm":"","hex":""},"txinwitness":["3044022057f0dde0e8d094034f47a136ae22a433e0216d5b1cfa3a03d8d259660148744702204377d2dbf3e442128c03097af4d4fa56b52b0cc7d9c24511b99d45a47e8df2d701","02b934d90a0aa5e0b73e04d00df3a8633ee16d24dad9ab21697f1dc5feb43fdbed"],"sequence":4294967293,"value":5000000000,"address":"bcrt1qjupnefcdghlx6jf3ppv7zv4fm7v0ja39dzzwvd","inputType":"witnesspubkeyhash","sighashFlags":["ALL"]}],"vout":[{"value":4899985900,"n":0,"scriptPubKey":{"asm":"OP_0 OP_PUSHBYTES_20 629ef06211f8e75e223b6338228a025bd15b0db9","hex":"0014629ef06211f8e75e223b6338228a025bd15b0db9","type":"witnesspubkeyhash","address":"bcrt1qv200qcs3lrn4ug3mvvuz9zszt0g4krde3uqyzy"}},{"value":100000000,"n":1,"scriptPubKey":{"asm":"OP_0 OP_PUSHBYTES_20 f0e9ede24bceb0c16fdce952279d8094dc6b3f58","hex":"0014f0e9ede24bceb0c16fdce952279d8094dc6b3f58","type":"witnesspubkeyhash","address":"bcrt1q7r57mcjte6cvzm7ua9fz08vqjnwxk06c2v6jdv"}}],"fee":14100,"feeRate":100.0,"rbf":true,"coinbase":false,"blockHash":"25263a195c89fae46d08558b1b501617aac630a6854000e19eb7ada6c39e6e0b","blockTime":1625637322},{"confirmedHeight":102,"hex":"0200000000010273ee24ea65f5b611e9a0ace8dd33b75cd1b5c3783afd1508fcdbaa6fdba7a6e60000000000fdffffff73ee24ea65f5b611e9a0ace8dd33b75cd1b5c3783afd1508fcdbaa6fdba7a6e60100000000fdffffff02001110240100000016001413bb0bcb776f3e15fa8800877552044d4db27b96ac58f50500000000160014261b6555a3cb5d3593c0275ff46f31c36e42a4c70247304402207a89cf2b2d7620ace221894746c4a72f5fd5dd5bbe9e56018b182eaf046e9766022050a14136c20402b281c244a918856b9e866b5fc77182334dd6242993e2144b7d0121034968df50370db27d51b294cba769ec47e0accb3a582c312549303c555ca834d102473044022058a1f0d9b8bde83c28954a1cfac6a3f43f8f8b53529df47d0ef2f7d66f8f42bb022026993b9ba88bedff19ec3f70d32bab177d35e0570f8c86a9b2eac5b359189c1d012103d0d5b793a8a23ff2e92b6204c15f72fd51765ad6c01f6ebd9e712adee11bda4065000000","txid":"29704e049abbacf3222cd8af9654afe6c7c91d5d9f2faa5d2f0f3cd5de3a812d","hash":"fb3551865d89fae25b8bcc5a02da25ad91d6fe1c0d4fd391b4c7b1b69e9ece63","size":370,"vsize":208,"weight":832,"version":2,"locktime":101,"vin":[{"txid":"e6a6a7db6faadbfc0815fd3a78c3b5d15cb733dde8aca0e911b6f565ea24ee73","vout":0,"scriptSig":{"asm":"","hex":""},"txinwitness":["304402207a89cf2b2d7620ace221894746c4a72f5fd5dd5bbe9e56018b182eaf046e9766022050a14136c20402b281c244a918856b9e866b5fc77182334dd6242993e2144b7d01","034968df50370db27d51b294cba769ec47e0accb3a582c312549303c555ca834d1"],"sequence":4294967293,"value":4899985900,"address":"bcrt1qv200qcs3lrn4ug3mvvuz9zszt0g4krde3uqyzy","inputType":"witnesspubkeyhash","sighashFlags":["ALL"]},{"txid":"e6a6a7db6faadbfc0815fd3a78c3b5d15cb733dde8aca0e911b6f565ea24ee73","vout":1,"scriptSig":{"asm":"","hex":""},"txinwitness":["3044022058a1f0d9b8bde83c28954a1cfac6a3f43f8f8b53529df47d0ef2f7d66f8f42bb022026993b9ba88bedff19ec3f70d32bab177d35e0570f8c86a9b2eac5b359189c1d01","03d0d5b793a8a23ff2e92b6204c15f72fd51765ad6c01f6ebd9e712adee11bda40"],"sequence":4294967293,"value":100000000,"address":"bcrt1q7r57mcjte6cvzm7ua9fz08vqjnwxk06c2v6jdv","inputType":"witnesspubkeyhash","sighashFlags":["ALL"]}],"vout":[{"value":4900000000,"n":0,"scriptPubKey":{"asm":"OP_0 OP_PUSHBYTES_20 13bb0bcb776f3e15fa8800877552044d4db27b96","hex":"001413bb0bcb776f3e15fa8800877552044d4db27b96","type":"witnesspubkeyhash","address":"bcrt1qzwashjmhdulpt75gqzrh25syf4xmy7uk6clm0p"}},{"value":99965100,"n":1,"scriptPubKey":{"asm":"OP_0 OP_PUSHBYTES_20 261b6555a3cb5d3593c0275ff46f31c36e42a4c7","hex":"0014261b6555a3cb5d3593c0275ff46f31c36e42a4c7","type":"witnesspubkeyhash","address":"bcrt1qycdk24dredwnty7qya0lgme3cdhy9fx83qc9wd"}}],"fee":20800,"feeRate":100.0,"rbf":true,"coinbase":false,"blockHash":"25263a195c89fae46d08558b1b501617aac630a6854000e19eb7ada6c39e6e0b","blockTime":1625637322}],"pool":null}"#;
         assert_eq!(block_rest_json, block_json);
     }
+    #[test]
+    fn classify_input() {
+        use bitcoin::{OutPoint, Script, TxIn, WPubkeyHash};
+        use bitcoin::blockdata::script::Builder;
+        use bitcoin::hashes::Hash;
+        let txin = |script_sig: Script, witness: Vec<Vec<u8>>| TxIn {
+            previous_output: OutPoint { txid: Default::default(), vout: 0 },
+            script_sig,
+            sequence: 0xffffffff,
+            witness,
+        };
+        // A DER-shaped signature followed by the sighash flag.
+        let sig = |flag: u8| vec![0x30, 0x06, 0x02, 0x01, 0x01, 0x02, 0x01, 0x01, flag];
+        let pubkey = vec![0x02; 33];
+        let flags = |info: &InputInfo| info.sighash_flags.iter().map(|flag| sighash_flag_to_string(*flag)).collect::<Vec<String>>();
+        // Coinbase.
+        let coinbase = TxIn { previous_output: OutPoint::null(), ..txin(Script::new(), vec![]) };
+        assert_eq!(super::classify_input(&coinbase, None).input_type, Some("coinbase"));
+        // The previous output is unknown.
+        assert_eq!(super::classify_input(&txin(Script::new(), vec![]), None), InputInfo::default());
+        // P2PKH.
+        let script_sig = Builder::new().push_slice(&sig(0x01)).push_slice(&pubkey).into_script();
+        let info = super::classify_input(&txin(script_sig, vec![]), Some(&Script::new_p2pkh(&bitcoin::PubkeyHash::hash(&pubkey))));
+        assert_eq!(info.input_type, Some("pubkeyhash"));
+        assert_eq!(flags(&info), vec!["ALL"]);
+        // P2SH-P2WPKH.
+        let redeem_script = Script::new_v0_wpkh(&WPubkeyHash::hash(&pubkey));
+        let script_sig = Builder::new().push_slice(redeem_script.as_bytes()).into_script();
+        let info = super::classify_input(&txin(script_sig, vec![sig(0x83), pubkey.clone()]), Some(&Script::new_p2sh(&redeem_script.script_hash())));
+        assert_eq!(info.input_type, Some("p2sh-witnesspubkeyhash"));
+        assert_eq!(info.redeem_script, Some(redeem_script.to_bytes()));
+        assert_eq!(flags(&info), vec!["SINGLE|ANYONECANPAY"]);
+        // P2WSH (2-of-2 multisig).
+        let witness_script = Builder::new()
+            .push_int(2).push_slice(&pubkey).push_slice(&[0x03; 33]).push_int(2)
+            .push_opcode(bitcoin::blockdata::opcodes::all::OP_CHECKMULTISIG).into_script();
+        let witness = vec![vec![], sig(0x01), sig(0x02), witness_script.to_bytes()];
+        let info = super::classify_input(&txin(Script::new(), witness), Some(&Script::new_v0_wsh(&witness_script.wscript_hash())));
+        assert_eq!(info.input_type, Some("witnessscripthash"));
+        assert_eq!(info.witness_script, Some(witness_script.to_bytes()));
+        assert_eq!(flags(&info), vec!["ALL", "NONE"]);
+        // Taproot key path.
+        let p2tr = Script::from(hex::decode("512079be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798").unwrap());
+        let info = super::classify_input(&txin(Script::new(), vec![vec![0x01; 64]]), Some(&p2tr));
+        assert_eq!(info.input_type, Some("witness_v1_taproot_keypath"));
+        assert_eq!(flags(&info), vec!["DEFAULT"]);
+        // Taproot script path with an annex.
+        let signature = [vec![0x01; 64], vec![0x81]].concat();
+        let witness = vec![signature, vec![0x51], vec![0xc0; 33], vec![0x50, 0x00]];
+        let info = super::classify_input(&txin(Script::new(), witness), Some(&p2tr));
+        assert_eq!(info.input_type, Some("witness_v1_taproot_scriptpath"));
+        assert_eq!(info.tapleaf_script, Some(vec![0x51]));
+        assert_eq!(info.control_block, Some(vec![0xc0; 33]));
+        assert_eq!(flags(&info), vec!["ALL|ANYONECANPAY"]);
+        // Nonstandard.
+        let info = super::classify_input(&txin(Script::new(), vec![]), Some(&Script::from(vec![0x51])));
+        assert_eq!(info.input_type, Some("nonstandard"));
+    }
+    #[test]
+    fn pools() {
+        use crate::db::pool::PoolInfo;
+        let config = config_example("rbtc");
+        let regtest_blocks = fixtures::regtest_blocks();
+        let pool = |name: Option<&str>| Some(PoolDBValue {
+            coinbase_script_sig: vec![],
+            coinbase_tag: "".to_string(),
+            payout_address: None,
+            pool: name.map(|name| PoolInfo { name: name.to_string(), link: None }),
+        });
+        let prev_time = regtest_blocks[0].header.time;
+        let blocks = (1..=4).map(|height| {
+            let pool = match height { 1 | 3 => pool(Some("Pool A")), 2 => pool(Some("Pool B")), _ => pool(None) };
+            let mut block = BlockContentDBValue::new(height, &regtest_blocks[height as usize]);
+            // Regtest blocks may share the timestamp, so space them ten minutes apart.
+            block.block_header.time = prev_time + 600 * height;
+            (block, pool)
+        }).collect::<Vec<_>>();
+        let pools = create_pools(&blocks, Some(prev_time), &config);
+        assert_eq!(pools.from, 1);
+        assert_eq!(pools.to, 4);
+        assert_eq!(pools.blocks, 4);
+        assert_eq!(pools.pools.iter().map(|pool| (pool.name.as_str(), pool.blocks)).collect::<Vec<_>>(),
+            vec![("Pool A", 2), ("Pool B", 1), ("Unknown", 1)]);
+        assert_eq!(pools.pools[0].share, 0.5);
+        let work = 4.0 * get_difficulty(&regtest_blocks[1].header, &config) * 2f64.powi(32);
+        assert!(pools.hashrate > 0.0);
+        assert!((pools.hashrate - work / 2400.0).abs() < 1e-9);
+        assert!((pools.pools[0].hashrate - pools.hashrate / 2.0).abs() < 1e-9);
+        assert_eq!(create_pools(&[], None, &config).blocks, 0);
+    }
 }
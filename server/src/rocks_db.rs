@@ -315,6 +315,10 @@ impl<K, V> RocksDB<K, V>
     pub fn prefix_iter(&self, prefix: Vec<u8>) -> RocksDBPrefixIterator<'_, K, V> {
         RocksDBPrefixIterator::new(self.db.prefix_iterator(prefix.clone()), prefix)
     }
+    /// Iterate over the entries which have `prefix`, starting from the first key which is equal to or greater than `from`.
+    pub fn prefix_iter_from(&self, prefix: Vec<u8>, from: Vec<u8>) -> RocksDBPrefixIterator<'_, K, V> {
+        RocksDBPrefixIterator::new(self.db.iterator(rocksdb::IteratorMode::From(&from, rocksdb::Direction::Forward)), prefix)
+    }
     pub fn purge(&self) {
         remove_dir_all(&self.path).unwrap();
     }
@@ -350,7 +354,11 @@ mod tests {
             vec![(key1.clone(), value1.clone()), (key2.clone(), value2.clone())]);
         assert_eq!(
             db.prefix_iter(key1.as_bytes().to_vec()).collect::<Vec<(String, Vec<u32>)>>(),
+            vec![(key1.clone(), value1.clone())]);
+        assert_eq!(
+            db.prefix_iter_from(b"ba".to_vec(), b"baq".to_vec()).collect::<Vec<(String, Vec<u32>)>>(),
             vec![(key1.clone(), value1)]);
+        assert!(db.prefix_iter_from(b"ba".to_vec(), b"bas".to_vec()).next().is_none());
         db.delete(&key1);
         assert_eq!(db.get(&key1), None);
         assert_eq!(db.multi_get(vec![key1, key2]), vec![None, Some(value2)]);
@@ -0,0 +1,116 @@
+//! Segwit address encoding and decoding (bech32 for witness v0 and bech32m for v1+).
+//!
+//! See also: <https://github.com/bitcoin/bips/blob/master/bip-0350.mediawiki>.
+
+const CHARSET: &[u8; 32] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+const GENERATOR: [u32; 5] = [0x3b6a57b2, 0x26508e6d, 0x1ea119fa, 0x3d4233dd, 0x2a1462b3];
+const BECH32_CONST: u32 = 1;
+const BECH32M_CONST: u32 = 0x2bc830a3;
+const MAX_LENGTH: usize = 90;
+const CHECKSUM_LENGTH: usize = 6;
+
+fn polymod(values: &[u8]) -> u32 {
+    let mut chk = 1u32;
+    for value in values.iter() {
+        let top = chk >> 25;
+        chk = ((chk & 0x1ffffff) << 5) ^ *value as u32;
+        for (i, generator) in GENERATOR.iter().enumerate() {
+            if (top >> i) & 1 == 1 {
+                chk ^= generator;
+            }
+        }
+    }
+    chk
+}
+
+fn hrp_expand(hrp: &str) -> Vec<u8> {
+    let hrp = hrp.as_bytes();
+    [
+        hrp.iter().map(|c| c >> 5).collect::<Vec<u8>>(),
+        vec![0],
+        hrp.iter().map(|c| c & 31).collect::<Vec<u8>>(),
+    ].concat()
+}
+
+/// The checksum constant which the witness version requires.
+fn checksum_const(version: u8) -> u32 {
+    if version == 0 { BECH32_CONST } else { BECH32M_CONST }
+}
+
+/// Regroup bits (e.g. 8-bit bytes into 5-bit groups and vice versa).
+fn convert_bits(data: &[u8], from: u32, to: u32, pad: bool) -> Option<Vec<u8>> {
+    let mut acc = 0u32;
+    let mut bits = 0u32;
+    let mut ret = Vec::new();
+    let maxv = (1u32 << to) - 1;
+    let max_acc = (1u32 << (from + to - 1)) - 1;
+    for value in data.iter() {
+        let value = *value as u32;
+        if value >> from != 0 {
+            return None;
+        }
+        acc = ((acc << from) | value) & max_acc;
+        bits += from;
+        while bits >= to {
+            bits -= to;
+            ret.push(((acc >> bits) & maxv) as u8);
+        }
+    }
+    if pad {
+        if bits > 0 {
+            ret.push(((acc << (to - bits)) & maxv) as u8);
+        }
+    } else if bits >= from || ((acc << (to - bits)) & maxv) != 0 {
+        return None;
+    }
+    Some(ret)
+}
+
+/// Returns true if `program` is a valid witness program of `version`.
+fn is_valid_program(version: u8, program: &[u8]) -> bool {
+    if version > 16 || program.len() < 2 || program.len() > 40 {
+        return false;
+    }
+    version != 0 || program.len() == 20 || program.len() == 32
+}
+
+/// Encode a witness program into an address with the human-readable part `hrp`.
+pub fn encode(hrp: &str, version: u8, program: &[u8]) -> Option<String> {
+    if !is_valid_program(version, program) {
+        return None;
+    }
+    let data = [vec![version], convert_bits(program, 8, 5, true)?].concat();
+    let values = [hrp_expand(hrp), data.clone(), vec![0; CHECKSUM_LENGTH]].concat();
+    let checksum = polymod(&values) ^ checksum_const(version);
+    let checksum = (0..CHECKSUM_LENGTH).map(|i| ((checksum >> (5 * (5 - i))) & 31) as u8);
+    let data = data.into_iter().chain(checksum).map(|value| CHARSET[value as usize] as char).collect::<String>();
+    Some(format!("{}1{}", hrp, data))
+}
+
+/// Decode a segwit address of any human-readable part into (hrp, witness version, witness program).
+pub fn decode_any(address: &str) -> Option<(String, u8, Vec<u8>)> {
+    if address.len() > MAX_LENGTH || address.bytes().any(|c| !(33..=126).contains(&c)) {
+        return None;
+    }
+    // Mixed case is not allowed.
+    if address.to_lowercase() != address && address.to_uppercase() != address {
+        return None;
+    }
+    let address = address.to_lowercase();
+    let pos = address.rfind('1')?;
+    if pos == 0 || pos + 1 + CHECKSUM_LENGTH >= address.len() {
+        return None;
+    }
+    let data = address[pos+1..].bytes()
+        .map(|c| CHARSET.iter().position(|x| *x == c).map(|value| value as u8))
+        .collect::<Option<Vec<u8>>>()?;
+    let (version, program) = (*data.first()?, &data[1..data.len()-CHECKSUM_LENGTH]);
+    if polymod(&[hrp_expand(&address[..pos]), data.clone()].concat()) != checksum_const(version) {
+        return None;
+    }
+    let program = convert_bits(program, 5, 8, false)?;
+    if !is_valid_program(version, &program) {
+        return None;
+    }
+    Some((address[..pos].to_string(), version, program))
+}
@@ -4,6 +4,7 @@ use std::sync::Arc;
 use tokio::sync::mpsc::channel;
 use tokio::sync::RwLock;
 use crate::db::{Database, UtxoDB};
+use crate::db::utxo::UtxoEntry;
 use ZeroMQMessage::*;
 
 pub struct Syncer {
@@ -11,15 +12,43 @@ pub struct Syncer {
     rest: bitcoin_rest::Context,
     db: Database,
     utxo_db: UtxoDB,
+    events: tokio::sync::broadcast::Sender<ZeroMQMessage>,
 }
 
+/// The number of events kept for the slowest subscriber of `Syncer::events()`.
+const EVENT_CAPACITY: usize = 1024;
+
 impl Syncer {
     pub async fn new(db: Database) -> Self {
         let stop = Arc::new(RwLock::new(false));
         let rest = get_rest(&db.config);
         // Checks if we can access to Bitcoin Core's REST endpoint.
         rest.chaininfo().await.expect("Could not connect to Bitcoin Core's REST endpoint. Please check if the node is running, and listening on the correct IP address and port.");
-        let utxo_db = UtxoDB::new(&db.coin, false);
+        let mut utxo_db = UtxoDB::new(&db.coin, false);
+        // Restore the UTXO set statistics.
+        if let Some(synced_height) = db.synced_height_db.read().await.get() {
+            let stats = db.utxo_stats_db.read().await.get(synced_height);
+            utxo_db.stats = match stats {
+                Some(stats) => stats,
+                None => {
+                    // The entry is missing if the database was synced before the statistics were tracked.
+                    println!("UTXO set statistics not found at height {}, rebuilding from the UTXO set...", to_locale_string(synced_height));
+                    let stats = utxo_db.compute_stats();
+                    db.utxo_stats_db.write().await.put(synced_height, &stats);
+                    stats
+                },
+            };
+            let supply_db = db.supply_db.write().await;
+            if supply_db.get(synced_height).is_none() {
+                println!("Supply not found at height {}, backfilling from the stored blocks...", to_locale_string(synced_height));
+                supply_db.backfill(synced_height, &*db.block_db.read().await, &*db.tx_db.read().await);
+            }
+            let mut op_return_db = db.op_return_db.write().await;
+            if op_return_db.synced_height() < Some(synced_height) {
+                println!("OP_RETURN index not synced to height {}, backfilling from the stored blocks...", to_locale_string(synced_height));
+                op_return_db.backfill(synced_height, &*db.block_db.read().await, &*db.tx_db.read().await);
+            }
+        }
         // Install Ctrl-C watch.
         {
             let stop = stop.clone();
@@ -33,8 +62,15 @@ impl Syncer {
             rest,
             db,
             utxo_db,
+            events: tokio::sync::broadcast::channel(EVENT_CAPACITY).0,
         }
     }
+    /// Returns the sender of the events emitted after every block connected and transaction accepted since the initial sync.
+    ///
+    /// Unlike the messages sent to `Syncer::run()`'s `tx`, no event is skipped unless a subscriber lags behind by `EVENT_CAPACITY`.
+    pub fn events(&self) -> tokio::sync::broadcast::Sender<ZeroMQMessage> {
+        self.events.clone()
+    }
     pub async fn is_stopped(&self) -> bool {
         *self.stop.read().await
     }
@@ -42,11 +78,23 @@ impl Syncer {
         self.db.utxo_server.write().await.shrink_to_fit();
         self.db.rich_list.write().await.shrink_to_fit();
     }
+    /// The UTXO server and the rich list are loaded after the initial sync, unless the disk-backed ones are synced already.
+    async fn is_utxo_server_live(&self, initial: bool) -> bool {
+        !initial || self.db.utxo_server.read().await.synced_height().is_some()
+    }
+    /// Persist a snapshot of the rich list if `height` is a snapshot height.
+    async fn snapshot_rich_list(&self, height: u32) {
+        let rich_list_snapshot_db = self.db.rich_list_snapshot_db.write().await;
+        if rich_list_snapshot_db.is_snapshot_height(height) {
+            rich_list_snapshot_db.put(height, &*self.db.rich_list.read().await);
+        }
+    }
     async fn process_block(&mut self, initial: bool, height: u32, block: &Block) {
         let begin = Instant::now();
         // Process for UTXOs.
         let begin_utxo = Instant::now();
-        let previous_utxos = self.utxo_db.process_block(block, false);
+        let previous_utxos = self.utxo_db.process_block(height, block, false);
+        self.db.utxo_stats_db.write().await.put(height, &self.utxo_db.stats);
         let utxo_elapsed = begin_utxo.elapsed();
         // Process for TxDB.
         let begin_tx = Instant::now();
@@ -56,10 +104,27 @@ impl Syncer {
         let begin_addr_index = Instant::now();
         self.db.addr_index_db.write().await.process_block(height, block, &previous_utxos);
         let addr_index_elapsed = begin_addr_index.elapsed();
-        // Process if non initial-sync.
-        if !initial {
-            self.db.utxo_server.write().await.process_block(block, &previous_utxos);
-            self.db.rich_list.write().await.process_block(block, &previous_utxos);
+        // Process for OP_RETURN index.
+        self.db.op_return_db.write().await.process_block(height, block);
+        // Process for Counterparty index.
+        self.db.counterparty_db.write().await.process_block(height, block);
+        // Process for block stats.
+        self.db.block_stats_db.write().await.process_block(height, block, &previous_utxos);
+        // Process for mining pool attribution.
+        self.db.pool_db.write().await.process_block(block);
+        // Process for supply.
+        self.db.supply_db.write().await.process_block(height, block, &previous_utxos);
+        // Process for the UTXO server and the rich list.
+        if self.is_utxo_server_live(initial).await {
+            let mut utxo_server = self.db.utxo_server.write().await;
+            utxo_server.process_block(height, block, &previous_utxos);
+            utxo_server.set_synced_height(height);
+            drop(utxo_server);
+            let mut rich_list = self.db.rich_list.write().await;
+            rich_list.process_block(block, &previous_utxos);
+            rich_list.set_synced_height(height);
+            drop(rich_list);
+            self.snapshot_rich_list(height).await;
         }
         // Count vins/vouts.
         let mut vins: usize = 0;
@@ -77,7 +142,7 @@ impl Syncer {
             tx_elapsed.as_millis(), utxo_elapsed.as_millis(),
             addr_index_elapsed.as_millis(), begin.elapsed().as_millis());
     }
-    async fn process_reorgs(&mut self) {
+    async fn process_reorgs(&mut self, initial: bool) {
         let mut height = match self.db.synced_height_db.read().await.get() {
             Some(h) => h,
             None => return,
@@ -104,22 +169,43 @@ impl Syncer {
             };
             // Fetch previous transactions.
             let mut prev_txs = Vec::new();
+            let mut previous_utxos = Vec::new();
             for tx in block.txdata.iter() {
                 for vin in tx.input.iter() {
                     if vin.previous_output.is_null() {
                         continue;
                     }
-                    let prev_tx = tx_db.get(&vin.previous_output.txid).unwrap().tx;
-                    prev_txs.push(prev_tx);
+                    let prev_tx = tx_db.get(&vin.previous_output.txid).unwrap();
+                    let prev_out = &prev_tx.tx.output[vin.previous_output.vout as usize];
+                    previous_utxos.push(UtxoEntry {
+                        script_pubkey: prev_out.script_pubkey.clone(),
+                        txid: vin.previous_output.txid,
+                        vout: vin.previous_output.vout,
+                        value: prev_out.value,
+                    });
+                    prev_txs.push((prev_tx.confirmed_height.unwrap(), prev_tx.tx));
                 }
             }
+            drop(tx_db);
             self.utxo_db.reorg_block(&block, &prev_txs);
+            self.db.addr_index_db.write().await.reorg_block(height, &block, &previous_utxos);
+            self.db.op_return_db.write().await.reorg_block(height, &block);
+            self.db.counterparty_db.write().await.reorg_block(height, &block);
+            if self.is_utxo_server_live(initial).await {
+                let mut utxo_server = self.db.utxo_server.write().await;
+                utxo_server.reorg_block(&block, &previous_utxos, &prev_txs);
+                utxo_server.set_synced_height(height - 1);
+                drop(utxo_server);
+                let mut rich_list = self.db.rich_list.write().await;
+                rich_list.reorg_block(&block, &previous_utxos);
+                rich_list.set_synced_height(height - 1);
+            }
             height -= 1;
             self.db.synced_height_db.write().await.put(height);
         }
     }
     async fn sync(&mut self, initial: bool) -> u32 {
-        self.process_reorgs().await;
+        self.process_reorgs(initial).await;
         let start_height = match self.db.synced_height_db.read().await.get() {
             Some(h) => h + 1,
             None => 0,
@@ -197,11 +283,17 @@ impl Syncer {
                 let block_hash = block_headers[1].block_hash();
                 let block = self.rest.block(&block_hash).await.unwrap();
                 self.process_block(initial, height, &block).await;
+                // Fails only if nobody is subscribing.
+                let _ = self.events.send(HashBlock(block_hash));
             }
         }
         synced_blocks
     }
     async fn load_utxo(&mut self) {
+        if self.db.utxo_server.read().await.synced_height().is_some() {
+            println!("The UTXO server and the rich list on disk are synced, skip loading UTXOs.");
+            return;
+        }
         let begin = Instant::now();
         let mut last_print = Instant::now();
         let mut print_stat = |i: u32, force: bool| {
@@ -216,8 +308,8 @@ impl Syncer {
         let utxo_server = self.db.utxo_server.clone();
         let utxo_server_join = tokio::spawn(async move {
             let mut utxo_server = utxo_server.write().await;
-            while let Some(utxo) = utxo_server_rx.recv().await {
-                utxo_server.push(&utxo);
+            while let Some((utxo, height, coinbase)) = utxo_server_rx.recv().await {
+                utxo_server.push(&utxo, height, coinbase);
             }
         });
         let rich_list = self.db.rich_list.clone();
@@ -229,7 +321,7 @@ impl Syncer {
             rich_list.finalize();
         });
         let mut i = 0;
-        for utxo in self.utxo_db.iter() {
+        for (utxo, height, coinbase) in self.utxo_db.iter_with_height() {
             if self.is_stopped().await {
                 return;
             }
@@ -250,7 +342,7 @@ impl Syncer {
                 continue;
             }
             */
-            utxo_server_tx.send(utxo.clone()).await.unwrap();
+            utxo_server_tx.send((utxo.clone(), height, coinbase)).await.unwrap();
             rich_list_tx.send(utxo).await.unwrap();
         }
         print_stat(i, true);
@@ -261,6 +353,10 @@ impl Syncer {
         drop(rich_list_tx);
         utxo_server_join.await.unwrap();
         rich_list_join.await.unwrap();
+        if let Some(synced_height) = self.db.synced_height_db.read().await.get() {
+            self.db.utxo_server.write().await.set_synced_height(synced_height);
+            self.db.rich_list.write().await.set_synced_height(synced_height);
+        }
         self.shrink_to_fit().await;
         println!("Syncer.load_utxo(): executed in {}ms.", to_locale_string(begin.elapsed().as_millis()));
     }
@@ -280,6 +376,10 @@ impl Syncer {
             to_locale_string(synced_blocks), to_locale_string(begin_elapsed));
         if !self.is_stopped().await {
             self.load_utxo().await;
+            // The rich list is not maintained during the initial sync, so we can only take a snapshot at the synced height.
+            if let Some(synced_height) = self.db.synced_height_db.read().await.get() {
+                self.snapshot_rich_list(synced_height).await;
+            }
             // Report the capacity / actual size.
             println!("(len, cap) = UtxoServer: ({}, {}), RichList: ({}, {})",
                 to_locale_string(self.db.utxo_server.read().await.len()),
@@ -326,6 +426,7 @@ impl Syncer {
                         match self.db.tx_db.write().await.put_tx(&tx, None) {
                             Ok((_, previous_utxos)) => {
                                 self.db.addr_index_db.write().await.process_tx(&tx, &previous_utxos, None);
+                                let _ = self.events.send(RawTx(tx));
                             },
                             Err(previous_txid) => {
                                 println!("Syncer: failed to put transaction: {} (reason: tx {} not found).", txid, previous_txid);